@@ -64,11 +64,21 @@
 
 package com.radixdlt.environment;
 
+import com.radixdlt.lang.Option;
 import com.radixdlt.sbor.codec.CodecMap;
 import com.radixdlt.sbor.codec.StructCodec;
 
 /** REv2 Database configuration options */
-public record DatabaseBackendConfig(String rocksDbPath) {
+public record DatabaseBackendConfig(
+    String rocksDbPath, Option<DatabaseTuningProfile> tuningProfile, boolean inMemory) {
+  public DatabaseBackendConfig(String rocksDbPath) {
+    this(rocksDbPath, Option.none());
+  }
+
+  public DatabaseBackendConfig(String rocksDbPath, Option<DatabaseTuningProfile> tuningProfile) {
+    this(rocksDbPath, tuningProfile, false);
+  }
+
   public static void registerCodec(CodecMap codecMap) {
     codecMap.register(
         DatabaseBackendConfig.class,
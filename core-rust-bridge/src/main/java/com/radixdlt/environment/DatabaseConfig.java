@@ -64,15 +64,44 @@
 
 package com.radixdlt.environment;
 
+import com.google.common.collect.ImmutableList;
+import com.radixdlt.lang.Option;
 import com.radixdlt.sbor.codec.CodecMap;
 import com.radixdlt.sbor.codec.StructCodec;
+import com.radixdlt.utils.UInt64;
 
-/** Database configuration options */
+/**
+ * Database configuration options
+ *
+ * @param enabledSecondaryIndices names of the user-defined secondary indices (known to the Rust
+ *     side's registry) to be maintained
+ * @param ledgerTransactionRetentionEpochCount if present, the payloads and receipts of the ledger
+ *     transactions older than this number of most recent completed epochs are pruned (i.e. the node
+ *     is not an archive node); otherwise, the entire ledger history is retained
+ */
 public record DatabaseConfig(
     boolean enableLocalTransactionExecutionIndex,
     boolean enableAccountChangeIndex,
     boolean enableHistoricalSubstateValues,
-    boolean enableEntityListingIndices) {
+    boolean enableEntityListingIndices,
+    boolean enableResourceChangeIndex,
+    ImmutableList<String> enabledSecondaryIndices,
+    Option<UInt64> ledgerTransactionRetentionEpochCount) {
+  public DatabaseConfig(
+      boolean enableLocalTransactionExecutionIndex,
+      boolean enableAccountChangeIndex,
+      boolean enableHistoricalSubstateValues,
+      boolean enableEntityListingIndices) {
+    this(
+        enableLocalTransactionExecutionIndex,
+        enableAccountChangeIndex,
+        enableHistoricalSubstateValues,
+        enableEntityListingIndices,
+        false,
+        ImmutableList.of(),
+        Option.none());
+  }
+
   public static void registerCodec(CodecMap codecMap) {
     codecMap.register(
         DatabaseConfig.class,
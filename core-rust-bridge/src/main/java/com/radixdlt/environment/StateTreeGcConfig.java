@@ -64,12 +64,34 @@
 
 package com.radixdlt.environment;
 
+import com.radixdlt.lang.Option;
 import com.radixdlt.sbor.codec.CodecMap;
 import com.radixdlt.sbor.codec.StructCodec;
 import com.radixdlt.utils.UInt32;
 import com.radixdlt.utils.UInt64;
 
-public record StateTreeGcConfig(UInt32 intervalSec, UInt64 stateVersionHistoryLength) {
+/**
+ * State hash tree GC configuration options
+ *
+ * @param intervalSec how often to run the GC; 0 disables the periodic runs (i.e. the GC then only
+ *     runs when requested via the System API)
+ * @param historicalStateFullResolutionEpochCount how many most recent completed epochs of the
+ *     historical Substate values to keep at full resolution (if the state history is enabled)
+ * @param historicalStateFullResolutionDurationSec how long (as measured by the ledger's proposer
+ *     timestamps) to keep the historical Substate values at full resolution
+ * @param historicalStateCheckpointEpochInterval if present, the historical Substate values remain
+ *     available at the beginning of every epoch divisible by this interval, indefinitely
+ */
+public record StateTreeGcConfig(
+    UInt32 intervalSec,
+    UInt64 stateVersionHistoryLength,
+    Option<UInt64> historicalStateFullResolutionEpochCount,
+    Option<UInt64> historicalStateFullResolutionDurationSec,
+    Option<UInt64> historicalStateCheckpointEpochInterval) {
+  public StateTreeGcConfig(UInt32 intervalSec, UInt64 stateVersionHistoryLength) {
+    this(intervalSec, stateVersionHistoryLength, Option.none(), Option.none(), Option.none());
+  }
+
   public static void registerCodec(CodecMap codecMap) {
     codecMap.register(
         StateTreeGcConfig.class,
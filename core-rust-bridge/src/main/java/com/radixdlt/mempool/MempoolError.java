@@ -81,6 +81,18 @@ public sealed interface MempoolError {
   record PriorityThresholdNotMet(Option<UInt32> minTipBasisPointsRequired, UInt32 tipBasisPoints)
       implements MempoolError {}
 
+  record ReplacementTipTooLow(
+      UInt32 replacedTipBasisPoints, UInt32 minTipBasisPointsRequired, UInt32 tipBasisPoints)
+      implements MempoolError {}
+
+  record FeePayerQuotaExceeded(
+      UInt32 maxTransactionCount, Option<UInt32> minTipBasisPointsRequired, UInt32 tipBasisPoints)
+      implements MempoolError {}
+
+  record NotaryQuotaExceeded(
+      UInt32 maxTransactionCount, Option<UInt32> minTipBasisPointsRequired, UInt32 tipBasisPoints)
+      implements MempoolError {}
+
   record Duplicate(HashCode notarizedTransactionHash) implements MempoolError {}
 
   record TransactionValidationError(String errorDescription) implements MempoolError {}
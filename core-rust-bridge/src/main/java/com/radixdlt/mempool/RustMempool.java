@@ -122,6 +122,22 @@ public class RustMempool
                 "Mempool is full and transaction's priority threshold not met, min tip basis points"
                     + " required: %s",
                 e.minTipBasisPointsRequired()));
+        case MempoolError.ReplacementTipTooLow e -> throw new MempoolRejectedException(
+            String.format(
+                "Transaction's tip (%s basis points) is too low to replace a pending transaction"
+                    + " with a conflicting intent (%s basis points), min tip basis points"
+                    + " required: %s",
+                e.tipBasisPoints(), e.replacedTipBasisPoints(), e.minTipBasisPointsRequired()));
+        case MempoolError.FeePayerQuotaExceeded e -> throw new MempoolRejectedException(
+            String.format(
+                "Transaction's fee payer already has %s transactions in the mempool, min tip basis"
+                    + " points required to displace one of them: %s",
+                e.maxTransactionCount(), e.minTipBasisPointsRequired()));
+        case MempoolError.NotaryQuotaExceeded e -> throw new MempoolRejectedException(
+            String.format(
+                "Transaction's notary already has %s transactions in the mempool, min tip basis"
+                    + " points required to displace one of them: %s",
+                e.maxTransactionCount(), e.minTipBasisPointsRequired()));
         case MempoolError.Duplicate e -> throw new MempoolDuplicateException(
             String.format(
                 "Mempool already has transaction with notarized hash %s",
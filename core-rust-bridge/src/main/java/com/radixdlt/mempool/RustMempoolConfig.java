@@ -64,12 +64,19 @@
 
 package com.radixdlt.mempool;
 
+import com.radixdlt.lang.Option;
 import com.radixdlt.sbor.codec.CodecMap;
 import com.radixdlt.sbor.codec.StructCodec;
 import com.radixdlt.utils.UInt32;
 import com.radixdlt.utils.UInt64;
 
-public record RustMempoolConfig(UInt64 maxTotalTransactionsSize, UInt32 maxTransactionCount) {
+public record RustMempoolConfig(
+    UInt64 maxTotalTransactionsSize,
+    UInt32 maxTransactionCount,
+    Option<MempoolPersistenceConfig> persistenceConfigOpt,
+    Option<UInt32> replacementMinTipBumpBasisPoints,
+    Option<UInt32> maxTransactionsPerFeePayer,
+    Option<UInt32> maxTransactionsPerNotary) {
   public static void registerCodec(CodecMap codecMap) {
     codecMap.register(
         RustMempoolConfig.class,
@@ -77,8 +84,28 @@ public record RustMempoolConfig(UInt64 maxTotalTransactionsSize, UInt32 maxTrans
   }
 
   public RustMempoolConfig(long maxTotalTransactionsSize, int maxTransactionCount) {
+    this(
+        maxTotalTransactionsSize,
+        maxTransactionCount,
+        Option.none(),
+        Option.none(),
+        Option.none(),
+        Option.none());
+  }
+
+  public RustMempoolConfig(
+      long maxTotalTransactionsSize,
+      int maxTransactionCount,
+      Option<MempoolPersistenceConfig> persistenceConfigOpt,
+      Option<UInt32> replacementMinTipBumpBasisPoints,
+      Option<UInt32> maxTransactionsPerFeePayer,
+      Option<UInt32> maxTransactionsPerNotary) {
     this(
         UInt64.fromNonNegativeLong(maxTotalTransactionsSize),
-        UInt32.fromNonNegativeInt(maxTransactionCount));
+        UInt32.fromNonNegativeInt(maxTransactionCount),
+        persistenceConfigOpt,
+        replacementMinTipBumpBasisPoints,
+        maxTransactionsPerFeePayer,
+        maxTransactionsPerNotary);
   }
 }
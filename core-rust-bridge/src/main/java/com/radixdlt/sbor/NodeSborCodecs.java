@@ -67,11 +67,15 @@ package com.radixdlt.sbor;
 import com.google.common.hash.HashCode;
 import com.google.common.reflect.TypeToken;
 import com.radixdlt.crypto.*;
+import com.radixdlt.db.gc.GcControlsSummary;
+import com.radixdlt.db.gc.GcKind;
+import com.radixdlt.db.gc.GcRunStatus;
 import com.radixdlt.environment.*;
 import com.radixdlt.exceptions.StateManagerRuntimeError;
 import com.radixdlt.genesis.*;
 import com.radixdlt.identifiers.TID;
 import com.radixdlt.mempool.MempoolError;
+import com.radixdlt.mempool.MempoolPersistenceConfig;
 import com.radixdlt.mempool.ProposalTransactionsRequest;
 import com.radixdlt.mempool.RustMempoolConfig;
 import com.radixdlt.message.*;
@@ -127,6 +131,7 @@ public final class NodeSborCodecs {
     UInt32.registerCodec(codecMap);
     UInt64.registerCodec(codecMap);
     RustMempoolConfig.registerCodec(codecMap);
+    MempoolPersistenceConfig.registerCodec(codecMap);
     ProposalTransactionsRequest.registerCodec(codecMap);
     NetworkDefinition.registerCodec(codecMap);
     LoggingConfig.registerCodec(codecMap);
@@ -193,6 +198,7 @@ public final class NodeSborCodecs {
     LeaderRoundCounter.registerCodec(codecMap);
     InvalidCommitRequestError.registerCodec(codecMap);
     DatabaseBackendConfig.registerCodec(codecMap);
+    DatabaseTuningProfile.registerCodec(codecMap);
     DatabaseConfig.registerCodec(codecMap);
     TransactionHeader.registerCodec(codecMap);
     CoreApiServerConfig.registerCodec(codecMap);
@@ -224,6 +230,10 @@ public final class NodeSborCodecs {
     RecentSelfProposalMissStatistic.registerCodec(codecMap);
     StateTreeGcConfig.registerCodec(codecMap);
     LedgerProofsGcConfig.registerCodec(codecMap);
+    DbCheckpointsConfig.registerCodec(codecMap);
+    GcKind.registerCodec(codecMap);
+    GcRunStatus.registerCodec(codecMap);
+    GcControlsSummary.registerCodec(codecMap);
     LedgerSyncLimitsConfig.registerCodec(codecMap);
 
     BFTHeaderDTO.registerCodec(codecMap);
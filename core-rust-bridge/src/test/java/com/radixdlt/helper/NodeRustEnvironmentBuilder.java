@@ -94,6 +94,7 @@ public final class NodeRustEnvironmentBuilder {
             LoggingConfig.getDefault(),
             StateTreeGcConfig.forTesting(),
             LedgerProofsGcConfig.forTesting(),
+            Option.none(),
             LedgerSyncLimitsConfig.defaults(),
             ProtocolConfig.testingDefault(),
             false,
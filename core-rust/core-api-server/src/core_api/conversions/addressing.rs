@@ -708,6 +708,42 @@ pub fn extract_resource_address(
         .ok_or(ExtractionError::InvalidAddress)
 }
 
+pub fn extract_address_as_node_id(
+    extraction_context: &ExtractionContext,
+    address: &str,
+) -> Result<NodeId, ExtractionError> {
+    let (_entity_type, bytes) = extraction_context
+        .address_decoder
+        .validate_and_decode(address)
+        .map_err(|_error| ExtractionError::InvalidAddress)?;
+    if bytes.len() != NodeId::LENGTH {
+        return Err(ExtractionError::InvalidAddress);
+    }
+    Ok(NodeId::from(copy_u8_array(&bytes)))
+}
+
+/// Extracts the typed part of the given key (i.e. ignores its `db_sort_key_hex`, which is derived).
+pub fn extract_substate_key(
+    substate_key: &models::SubstateKey,
+) -> Result<SubstateKey, ExtractionError> {
+    Ok(match substate_key {
+        models::SubstateKey::FieldSubstateKey { id, .. } => {
+            SubstateKey::Field(extract_u8_from_api_i32(*id)?)
+        }
+        models::SubstateKey::MapSubstateKey { key_hex, .. } => SubstateKey::Map(from_hex(key_hex)?),
+        models::SubstateKey::SortedSubstateKey {
+            sort_prefix_hex,
+            key_hex,
+            ..
+        } => {
+            let sort_prefix = from_hex(sort_prefix_hex)?;
+            let sort_prefix = <[u8; 2]>::try_from(sort_prefix.as_slice())
+                .map_err(|_| ExtractionError::InvalidSize { min: 2, max: 2 })?;
+            SubstateKey::Sorted((sort_prefix, from_hex(key_hex)?))
+        }
+    })
+}
+
 pub fn extract_non_fungible_id_from_simple_representation(
     simple_rep: &str,
 ) -> Result<NonFungibleLocalId, ExtractionError> {
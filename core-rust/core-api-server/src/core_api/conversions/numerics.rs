@@ -260,6 +260,20 @@ pub fn extract_u16_from_api_i32(input: i32) -> Result<u16, ExtractionError> {
     Ok(input.try_into().expect("Number invalid somehow"))
 }
 
+pub fn extract_u8_from_api_i32(input: i32) -> Result<u8, ExtractionError> {
+    if input < 0 {
+        return Err(ExtractionError::InvalidInteger {
+            message: "Is negative".to_owned(),
+        });
+    }
+    if input > (u8::MAX as i32) {
+        return Err(ExtractionError::InvalidInteger {
+            message: "Is larger than the max value allowed".to_owned(),
+        });
+    }
+    Ok(input.try_into().expect("Number invalid somehow"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
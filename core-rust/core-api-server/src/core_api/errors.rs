@@ -175,24 +175,41 @@ pub(crate) fn assert_unbounded_endpoints_flag_enabled<E: ErrorDetails>(
     Ok(())
 }
 
+pub(crate) fn assert_not_read_replica<E: ErrorDetails>(
+    state: &CoreApiState,
+) -> Result<(), ResponseError<E>> {
+    if state.state_manager.read_replica_status.is_some() {
+        return Err(client_error(
+            "This endpoint is disabled, as this Core API is served by a read replica - please use the live node's Core API instead.",
+        ));
+    }
+    Ok(())
+}
+
 impl<E: ErrorDetails> From<PreviewerError> for ResponseError<E> {
     fn from(error: PreviewerError) -> Self {
-        client_error(match error {
+        match error {
             PreviewerError::FromEngine(error) => match error {
                 PreviewError::TransactionValidationError(error) => {
-                    format!("Transaction validation error: {error:?}")
+                    client_error(format!("Transaction validation error: {error:?}"))
                 }
+            },
+            PreviewerError::FromStateHistory(error) => error.into(),
+        }
+    }
+}
+
+impl<E: ErrorDetails> From<StateHistoryError> for ResponseError<E> {
+    fn from(error: StateHistoryError) -> Self {
+        client_error(match error {
+            StateHistoryError::StateHistoryDisabled => {
+                "State history feature must be enabled (see the `db.historical_substate_values.enable` Node configuration flag)".to_string()
             }
-            PreviewerError::FromStateHistory(error) => match error {
-                StateHistoryError::StateHistoryDisabled => {
-                    "State history feature must be enabled (see the `db.historical_substate_values.enable` Node configuration flag)".to_string()
-                }
-                StateHistoryError::StateVersionInTooDistantPast { first_available_version } => {
-                    format!("Cannot request state version past the earliest available {} (see the `state_hash_tree.state_version_history_length` Node configuration flag)", first_available_version)
-                }
-                StateHistoryError::StateVersionInFuture { current_version } => {
-                    format!("Cannot request state version ahead of the current top-of-ledger {}", current_version)
-                }
+            StateHistoryError::StateVersionInTooDistantPast { first_available_version } => {
+                format!("Cannot request state version past the earliest available {} (see the `state_hash_tree.state_version_history_length` Node configuration flag)", first_available_version)
+            }
+            StateHistoryError::StateVersionInFuture { current_version } => {
+                format!("Cannot request state version ahead of the current top-of-ledger {}", current_version)
             }
         })
     }
@@ -13,11 +13,35 @@
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type")]
 pub enum LtsTransactionSubmitErrorDetails {
+    #[serde(rename="FeePayerQuotaExceeded")]
+    LtsTransactionSubmitFeePayerQuotaExceededErrorDetails {
+        /// The configured maximum number of transactions of a single fee payer in the mempool.
+        #[serde(rename = "max_transaction_count")]
+        max_transaction_count: i64,
+        /// The string-encoded decimal tip proportion of the submitted (and rejected) transaction.
+        #[serde(rename = "tip_proportion")]
+        tip_proportion: String,
+        /// A lower bound for tip proportion required to replace the fee payer's lowest-priority transaction in the mempool. A missing value means there is no tip that can guarantee submission. 
+        #[serde(rename = "min_tip_proportion_required", skip_serializing_if = "Option::is_none")]
+        min_tip_proportion_required: Option<String>,
+    },
     #[serde(rename="IntentAlreadyCommitted")]
     LtsTransactionSubmitIntentAlreadyCommitted {
         #[serde(rename = "committed_as")]
         committed_as: Box<crate::core_api::generated::models::CommittedIntentMetadata>,
     },
+    #[serde(rename="NotaryQuotaExceeded")]
+    LtsTransactionSubmitNotaryQuotaExceededErrorDetails {
+        /// The configured maximum number of transactions of a single notary in the mempool.
+        #[serde(rename = "max_transaction_count")]
+        max_transaction_count: i64,
+        /// The string-encoded decimal tip proportion of the submitted (and rejected) transaction.
+        #[serde(rename = "tip_proportion")]
+        tip_proportion: String,
+        /// A lower bound for tip proportion required to replace the notary's lowest-priority transaction in the mempool. A missing value means there is no tip that can guarantee submission. 
+        #[serde(rename = "min_tip_proportion_required", skip_serializing_if = "Option::is_none")]
+        min_tip_proportion_required: Option<String>,
+    },
     #[serde(rename="PriorityThresholdNotMet")]
     LtsTransactionSubmitPriorityThresholdNotMetErrorDetails {
         /// NOTE: This is kept for backwards compatibility, but we recommend using `tip_proportion` instead.  Tip percentage of the submitted (and rejected) transaction. For V2 transactions specifying basis point tips, the amount is rounded down. 
@@ -56,6 +80,18 @@ pub enum LtsTransactionSubmitErrorDetails {
         #[serde(rename = "invalid_from_epoch", skip_serializing_if = "Option::is_none")]
         invalid_from_epoch: Option<i64>,
     },
+    #[serde(rename="ReplacementTipTooLow")]
+    LtsTransactionSubmitReplacementTipTooLowErrorDetails {
+        /// The string-encoded decimal tip proportion of the submitted (and rejected) transaction.
+        #[serde(rename = "tip_proportion")]
+        tip_proportion: String,
+        /// The string-encoded decimal tip proportion of the conflicting transaction already in the mempool.
+        #[serde(rename = "replaced_tip_proportion")]
+        replaced_tip_proportion: String,
+        /// The string-encoded decimal minimum tip proportion required to replace the conflicting transaction.
+        #[serde(rename = "min_tip_proportion_required")]
+        min_tip_proportion_required: String,
+    },
 }
 
 
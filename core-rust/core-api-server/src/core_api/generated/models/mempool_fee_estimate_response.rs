@@ -0,0 +1,53 @@
+/*
+ * Radix Core API
+ *
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct MempoolFeeEstimateResponse {
+    /// The number of transactions currently in the mempool.
+    #[serde(rename = "transaction_count")]
+    pub transaction_count: i64,
+    /// The configured maximum number of transactions in the mempool.
+    #[serde(rename = "max_transaction_count")]
+    pub max_transaction_count: i64,
+    /// The total size (in bytes) of the transactions currently in the mempool.
+    #[serde(rename = "total_transactions_size")]
+    pub total_transactions_size: i64,
+    /// The configured maximum total size (in bytes) of the transactions in the mempool.
+    #[serde(rename = "max_total_transactions_size")]
+    pub max_total_transactions_size: i64,
+    /// The string-encoded decimal tip proportion which a new transaction needs in order to be admitted into the mempool. It is non-zero only if the mempool is full (i.e. a lower-priority transaction would have to be evicted). 
+    #[serde(rename = "min_admission_tip_proportion")]
+    pub min_admission_tip_proportion: String,
+    #[serde(rename = "recent_committed_tips", skip_serializing_if = "Option::is_none")]
+    pub recent_committed_tips: Option<Box<crate::core_api::generated::models::CommittedTipStatistics>>,
+    /// The suggested tips, in the order of the requested target proposal counts.
+    #[serde(rename = "suggested_tips")]
+    pub suggested_tips: Vec<crate::core_api::generated::models::SuggestedTip>,
+}
+
+impl MempoolFeeEstimateResponse {
+    pub fn new(transaction_count: i64, max_transaction_count: i64, total_transactions_size: i64, max_total_transactions_size: i64, min_admission_tip_proportion: String, suggested_tips: Vec<crate::core_api::generated::models::SuggestedTip>) -> MempoolFeeEstimateResponse {
+        MempoolFeeEstimateResponse {
+            transaction_count,
+            max_transaction_count,
+            total_transactions_size,
+            max_total_transactions_size,
+            min_admission_tip_proportion,
+            recent_committed_tips: None,
+            suggested_tips,
+        }
+    }
+}
+
+
@@ -40,6 +40,10 @@ pub mod account_vault_entry_substate_all_of;
 pub use self::account_vault_entry_substate_all_of::AccountVaultEntrySubstateAllOf;
 pub mod account_vault_entry_value;
 pub use self::account_vault_entry_value::AccountVaultEntryValue;
+pub mod accumulator_tree_consistency_proof;
+pub use self::accumulator_tree_consistency_proof::AccumulatorTreeConsistencyProof;
+pub mod accumulator_tree_inclusion_proof;
+pub use self::accumulator_tree_inclusion_proof::AccumulatorTreeInclusionProof;
 pub mod active_validator;
 pub use self::active_validator::ActiveValidator;
 pub mod active_validator_index;
@@ -154,6 +158,8 @@ pub mod committed_intent_metadata;
 pub use self::committed_intent_metadata::CommittedIntentMetadata;
 pub mod committed_state_identifier;
 pub use self::committed_state_identifier::CommittedStateIdentifier;
+pub mod committed_tip_statistics;
+pub use self::committed_tip_statistics::CommittedTipStatistics;
 pub mod committed_transaction;
 pub use self::committed_transaction::CommittedTransaction;
 pub mod committed_transaction_balance_changes;
@@ -550,10 +556,18 @@ pub mod lts_transaction_submit_error_response;
 pub use self::lts_transaction_submit_error_response::LtsTransactionSubmitErrorResponse;
 pub mod lts_transaction_submit_error_response_all_of;
 pub use self::lts_transaction_submit_error_response_all_of::LtsTransactionSubmitErrorResponseAllOf;
+pub mod lts_transaction_submit_fee_payer_quota_exceeded_error_details;
+pub use self::lts_transaction_submit_fee_payer_quota_exceeded_error_details::LtsTransactionSubmitFeePayerQuotaExceededErrorDetails;
+pub mod lts_transaction_submit_fee_payer_quota_exceeded_error_details_all_of;
+pub use self::lts_transaction_submit_fee_payer_quota_exceeded_error_details_all_of::LtsTransactionSubmitFeePayerQuotaExceededErrorDetailsAllOf;
 pub mod lts_transaction_submit_intent_already_committed;
 pub use self::lts_transaction_submit_intent_already_committed::LtsTransactionSubmitIntentAlreadyCommitted;
 pub mod lts_transaction_submit_intent_already_committed_all_of;
 pub use self::lts_transaction_submit_intent_already_committed_all_of::LtsTransactionSubmitIntentAlreadyCommittedAllOf;
+pub mod lts_transaction_submit_notary_quota_exceeded_error_details;
+pub use self::lts_transaction_submit_notary_quota_exceeded_error_details::LtsTransactionSubmitNotaryQuotaExceededErrorDetails;
+pub mod lts_transaction_submit_notary_quota_exceeded_error_details_all_of;
+pub use self::lts_transaction_submit_notary_quota_exceeded_error_details_all_of::LtsTransactionSubmitNotaryQuotaExceededErrorDetailsAllOf;
 pub mod lts_transaction_submit_priority_threshold_not_met_error_details;
 pub use self::lts_transaction_submit_priority_threshold_not_met_error_details::LtsTransactionSubmitPriorityThresholdNotMetErrorDetails;
 pub mod lts_transaction_submit_priority_threshold_not_met_error_details_all_of;
@@ -562,6 +576,10 @@ pub mod lts_transaction_submit_rejected_error_details;
 pub use self::lts_transaction_submit_rejected_error_details::LtsTransactionSubmitRejectedErrorDetails;
 pub mod lts_transaction_submit_rejected_error_details_all_of;
 pub use self::lts_transaction_submit_rejected_error_details_all_of::LtsTransactionSubmitRejectedErrorDetailsAllOf;
+pub mod lts_transaction_submit_replacement_tip_too_low_error_details;
+pub use self::lts_transaction_submit_replacement_tip_too_low_error_details::LtsTransactionSubmitReplacementTipTooLowErrorDetails;
+pub mod lts_transaction_submit_replacement_tip_too_low_error_details_all_of;
+pub use self::lts_transaction_submit_replacement_tip_too_low_error_details_all_of::LtsTransactionSubmitReplacementTipTooLowErrorDetailsAllOf;
 pub mod lts_transaction_submit_request;
 pub use self::lts_transaction_submit_request::LtsTransactionSubmitRequest;
 pub mod lts_transaction_submit_response;
@@ -574,6 +592,10 @@ pub mod map_substate_key;
 pub use self::map_substate_key::MapSubstateKey;
 pub mod map_substate_key_all_of;
 pub use self::map_substate_key_all_of::MapSubstateKeyAllOf;
+pub mod mempool_fee_estimate_request;
+pub use self::mempool_fee_estimate_request::MempoolFeeEstimateRequest;
+pub mod mempool_fee_estimate_response;
+pub use self::mempool_fee_estimate_response::MempoolFeeEstimateResponse;
 pub mod mempool_list_request;
 pub use self::mempool_list_request::MempoolListRequest;
 pub mod mempool_list_response;
@@ -1066,12 +1088,24 @@ pub mod state_resource_request;
 pub use self::state_resource_request::StateResourceRequest;
 pub mod state_resource_response;
 pub use self::state_resource_response::StateResourceResponse;
+pub mod state_substate_proof_request;
+pub use self::state_substate_proof_request::StateSubstateProofRequest;
+pub mod state_substate_proof_response;
+pub use self::state_substate_proof_response::StateSubstateProofResponse;
+pub mod state_tree_proof_level;
+pub use self::state_tree_proof_level::StateTreeProofLevel;
+pub mod state_tree_proof_sibling;
+pub use self::state_tree_proof_sibling::StateTreeProofSibling;
+pub mod state_tree_tier_proof;
+pub use self::state_tree_tier_proof::StateTreeTierProof;
 pub mod state_updates;
 pub use self::state_updates::StateUpdates;
 pub mod state_validator_request;
 pub use self::state_validator_request::StateValidatorRequest;
 pub mod state_validator_response;
 pub use self::state_validator_response::StateValidatorResponse;
+pub mod state_version_range;
+pub use self::state_version_range::StateVersionRange;
 pub mod static_blueprint_payload_def;
 pub use self::static_blueprint_payload_def::StaticBlueprintPayloadDef;
 pub mod static_blueprint_payload_def_all_of;
@@ -1116,6 +1150,14 @@ pub mod stream_proofs_request;
 pub use self::stream_proofs_request::StreamProofsRequest;
 pub mod stream_proofs_response;
 pub use self::stream_proofs_response::StreamProofsResponse;
+pub mod stream_resource_transaction_outcomes_request;
+pub use self::stream_resource_transaction_outcomes_request::StreamResourceTransactionOutcomesRequest;
+pub mod stream_resource_transaction_outcomes_response;
+pub use self::stream_resource_transaction_outcomes_response::StreamResourceTransactionOutcomesResponse;
+pub mod stream_secondary_index_transaction_outcomes_request;
+pub use self::stream_secondary_index_transaction_outcomes_request::StreamSecondaryIndexTransactionOutcomesRequest;
+pub mod stream_secondary_index_transaction_outcomes_response;
+pub use self::stream_secondary_index_transaction_outcomes_response::StreamSecondaryIndexTransactionOutcomesResponse;
 pub mod stream_transactions_error_details;
 pub use self::stream_transactions_error_details::StreamTransactionsErrorDetails;
 pub mod stream_transactions_error_details_type;
@@ -1142,6 +1184,8 @@ pub mod substate_id;
 pub use self::substate_id::SubstateId;
 pub mod substate_id_all_of;
 pub use self::substate_id_all_of::SubstateIdAllOf;
+pub mod substate_inclusion_proof;
+pub use self::substate_inclusion_proof::SubstateInclusionProof;
 pub mod substate_key;
 pub use self::substate_key::SubstateKey;
 pub mod substate_key_type;
@@ -1154,6 +1198,8 @@ pub mod substate_type;
 pub use self::substate_type::SubstateType;
 pub mod substate_value;
 pub use self::substate_value::SubstateValue;
+pub mod suggested_tip;
+pub use self::suggested_tip::SuggestedTip;
 pub mod system_costing_parameters;
 pub use self::system_costing_parameters::SystemCostingParameters;
 pub mod system_field_kind;
@@ -1180,6 +1226,10 @@ pub mod transaction_call_preview_request;
 pub use self::transaction_call_preview_request::TransactionCallPreviewRequest;
 pub mod transaction_call_preview_response;
 pub use self::transaction_call_preview_response::TransactionCallPreviewResponse;
+pub mod transaction_consistency_proof_request;
+pub use self::transaction_consistency_proof_request::TransactionConsistencyProofRequest;
+pub mod transaction_consistency_proof_response;
+pub use self::transaction_consistency_proof_response::TransactionConsistencyProofResponse;
 pub mod transaction_format_options;
 pub use self::transaction_format_options::TransactionFormatOptions;
 pub mod transaction_header;
@@ -1190,6 +1240,10 @@ pub mod transaction_id_key;
 pub use self::transaction_id_key::TransactionIdKey;
 pub mod transaction_identifiers;
 pub use self::transaction_identifiers::TransactionIdentifiers;
+pub mod transaction_inclusion_proof_request;
+pub use self::transaction_inclusion_proof_request::TransactionInclusionProofRequest;
+pub mod transaction_inclusion_proof_response;
+pub use self::transaction_inclusion_proof_response::TransactionInclusionProofResponse;
 pub mod transaction_intent;
 pub use self::transaction_intent::TransactionIntent;
 pub mod transaction_intent_status;
@@ -1250,12 +1304,18 @@ pub mod transaction_submit_error_response;
 pub use self::transaction_submit_error_response::TransactionSubmitErrorResponse;
 pub mod transaction_submit_error_response_all_of;
 pub use self::transaction_submit_error_response_all_of::TransactionSubmitErrorResponseAllOf;
+pub mod transaction_submit_fee_payer_quota_exceeded_error_details;
+pub use self::transaction_submit_fee_payer_quota_exceeded_error_details::TransactionSubmitFeePayerQuotaExceededErrorDetails;
 pub mod transaction_submit_intent_already_committed;
 pub use self::transaction_submit_intent_already_committed::TransactionSubmitIntentAlreadyCommitted;
+pub mod transaction_submit_notary_quota_exceeded_error_details;
+pub use self::transaction_submit_notary_quota_exceeded_error_details::TransactionSubmitNotaryQuotaExceededErrorDetails;
 pub mod transaction_submit_priority_threshold_not_met_error_details;
 pub use self::transaction_submit_priority_threshold_not_met_error_details::TransactionSubmitPriorityThresholdNotMetErrorDetails;
 pub mod transaction_submit_rejected_error_details;
 pub use self::transaction_submit_rejected_error_details::TransactionSubmitRejectedErrorDetails;
+pub mod transaction_submit_replacement_tip_too_low_error_details;
+pub use self::transaction_submit_replacement_tip_too_low_error_details::TransactionSubmitReplacementTipTooLowErrorDetails;
 pub mod transaction_submit_request;
 pub use self::transaction_submit_request::TransactionSubmitRequest;
 pub mod transaction_submit_response;
@@ -0,0 +1,44 @@
+/*
+ * Radix Core API
+ *
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct StateSubstateProofRequest {
+    /// The logical name of the network
+    #[serde(rename = "network")]
+    pub network: String,
+    /// The Bech32m-encoded human readable version of the address of the entity owning the Substate. Both global and internal (e.g. vault) entities are supported. 
+    #[serde(rename = "entity_address")]
+    pub entity_address: String,
+    #[serde(rename = "partition_number")]
+    pub partition_number: i32,
+    #[serde(rename = "substate_key")]
+    pub substate_key: Option<crate::core_api::generated::models::SubstateKey>, // Using Option permits Default trait; Will always be Some in normal use
+    /// An optional state version at which the proof should be generated. A ledger proof must exist at exactly this state version. If not provided, the latest ledger proof is used. 
+    #[serde(rename = "state_version", skip_serializing_if = "Option::is_none")]
+    pub state_version: Option<i64>,
+}
+
+impl StateSubstateProofRequest {
+    pub fn new(network: String, entity_address: String, partition_number: i32, substate_key: crate::core_api::generated::models::SubstateKey) -> StateSubstateProofRequest {
+        StateSubstateProofRequest {
+            network,
+            entity_address,
+            partition_number,
+            substate_key: Option::Some(substate_key),
+            state_version: None,
+        }
+    }
+}
+
+
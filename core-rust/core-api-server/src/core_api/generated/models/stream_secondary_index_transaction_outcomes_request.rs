@@ -0,0 +1,46 @@
+/*
+ * Radix Core API
+ *
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+/// StreamSecondaryIndexTransactionOutcomesRequest : A request to retrieve a sublist of committed transactions indexed under the given key by the given secondary index. 
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct StreamSecondaryIndexTransactionOutcomesRequest {
+    /// The logical name of the network
+    #[serde(rename = "network")]
+    pub network: String,
+    /// The name of a secondary index enabled on the node (e.g. `changed_entity` or `emitted_event_type`).
+    #[serde(rename = "index_name")]
+    pub index_name: String,
+    /// The hex-encoded key, in the index-specific encoding.
+    #[serde(rename = "key_hex")]
+    pub key_hex: String,
+    #[serde(rename = "from_state_version")]
+    pub from_state_version: i64,
+    /// The maximum number of transactions that will be returned.
+    #[serde(rename = "limit")]
+    pub limit: i32,
+}
+
+impl StreamSecondaryIndexTransactionOutcomesRequest {
+    /// A request to retrieve a sublist of committed transactions indexed under the given key by the given secondary index. 
+    pub fn new(network: String, index_name: String, key_hex: String, from_state_version: i64, limit: i32) -> StreamSecondaryIndexTransactionOutcomesRequest {
+        StreamSecondaryIndexTransactionOutcomesRequest {
+            network,
+            index_name,
+            key_hex,
+            from_state_version,
+            limit,
+        }
+    }
+}
+
+
@@ -0,0 +1,38 @@
+/*
+ * Radix Core API
+ *
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+
+/// SubstateInclusionProof : A Merkle proof of a Substate's inclusion in the state tree, which consists of 3 nested tiers of Jellyfish Merkle Trees. The Entity tier (keyed by DB node keys) has leaves holding root hashes of Partition tiers (keyed by single-byte partition numbers), which in turn have leaves holding root hashes of Substate tiers (keyed by DB sort keys), whose leaves hold hashes of Substate values. Within each tier, a leaf hash is `blake2b_256(leaf_key ++ value_hash)`, and an internal node hash is computed from its 16 children as a binary Merkle tree of `blake2b_256(left ++ right)` hashes, in which empty subtrees are represented by 32 zero bytes, and subtrees containing only a single leaf are represented by that leaf. 
+
+
+
+#[derive(Clone, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct SubstateInclusionProof {
+    #[serde(rename = "entity_tier")]
+    pub entity_tier: Box<crate::core_api::generated::models::StateTreeTierProof>,
+    #[serde(rename = "partition_tier")]
+    pub partition_tier: Box<crate::core_api::generated::models::StateTreeTierProof>,
+    #[serde(rename = "substate_tier")]
+    pub substate_tier: Box<crate::core_api::generated::models::StateTreeTierProof>,
+}
+
+impl SubstateInclusionProof {
+    /// A Merkle proof of a Substate's inclusion in the state tree, which consists of 3 nested tiers of Jellyfish Merkle Trees. The Entity tier (keyed by DB node keys) has leaves holding root hashes of Partition tiers (keyed by single-byte partition numbers), which in turn have leaves holding root hashes of Substate tiers (keyed by DB sort keys), whose leaves hold hashes of Substate values. Within each tier, a leaf hash is `blake2b_256(leaf_key ++ value_hash)`, and an internal node hash is computed from its 16 children as a binary Merkle tree of `blake2b_256(left ++ right)` hashes, in which empty subtrees are represented by 32 zero bytes, and subtrees containing only a single leaf are represented by that leaf. 
+    pub fn new(entity_tier: crate::core_api::generated::models::StateTreeTierProof, partition_tier: crate::core_api::generated::models::StateTreeTierProof, substate_tier: crate::core_api::generated::models::StateTreeTierProof) -> SubstateInclusionProof {
+        SubstateInclusionProof {
+            entity_tier: Box::new(entity_tier),
+            partition_tier: Box::new(partition_tier),
+            substate_tier: Box::new(substate_tier),
+        }
+    }
+}
+
+
@@ -18,6 +18,12 @@ pub enum TransactionSubmitErrorDetailsType {
     IntentAlreadyCommitted,
     #[serde(rename = "Rejected")]
     Rejected,
+    #[serde(rename = "ReplacementTipTooLow")]
+    ReplacementTipTooLow,
+    #[serde(rename = "FeePayerQuotaExceeded")]
+    FeePayerQuotaExceeded,
+    #[serde(rename = "NotaryQuotaExceeded")]
+    NotaryQuotaExceeded,
 
 }
 
@@ -27,6 +33,9 @@ impl ToString for TransactionSubmitErrorDetailsType {
             Self::PriorityThresholdNotMet => String::from("PriorityThresholdNotMet"),
             Self::IntentAlreadyCommitted => String::from("IntentAlreadyCommitted"),
             Self::Rejected => String::from("Rejected"),
+            Self::ReplacementTipTooLow => String::from("ReplacementTipTooLow"),
+            Self::FeePayerQuotaExceeded => String::from("FeePayerQuotaExceeded"),
+            Self::NotaryQuotaExceeded => String::from("NotaryQuotaExceeded"),
         }
     }
 }
@@ -42,8 +42,8 @@ pub(crate) async fn handle_lts_stream_account_transaction_outcomes(
     if !database.is_local_transaction_execution_index_enabled() {
         return Err(client_error(
             "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
-            To use this endpoint, you will need to enable the index in the config, wipe ledger and restart. \
-            Please note the resync will take a while.",
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
         ));
     }
 
@@ -55,6 +55,8 @@ pub(crate) async fn handle_lts_stream_account_transaction_outcomes(
         ));
     }
 
+    check_ledger_transaction_available(database.deref(), from_state_version)?;
+
     let max_state_version = database.max_state_version();
 
     let state_versions =
@@ -33,10 +33,11 @@ pub(crate) async fn handle_lts_stream_transaction_outcomes(
     if !database.is_local_transaction_execution_index_enabled() {
         return Err(client_error(
             "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
-            To use this endpoint, you will need to enable the index in the config, wipe ledger and restart. \
-            Please note the resync will take a while.",
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
         ));
     }
+    check_local_transaction_execution_available(database.deref(), from_state_version)?;
 
     let max_state_version = database.max_state_version();
 
@@ -51,6 +52,7 @@ pub(crate) async fn handle_lts_stream_transaction_outcomes(
     let mut current_total_size = response.get_json_size();
     let bundles = database
         .get_committed_transaction_bundle_iter(from_state_version)
+        .map_err(transactions_pruned_error)?
         .take(limit);
     for bundle in bundles {
         let CommittedTransactionBundle {
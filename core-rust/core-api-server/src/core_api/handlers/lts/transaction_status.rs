@@ -6,6 +6,7 @@ pub(crate) async fn handle_lts_transaction_status(
     Json(request): Json<models::LtsTransactionStatusRequest>,
 ) -> Result<Json<models::LtsTransactionStatusResponse>, ResponseError<()>> {
     assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
 
     let mapping_context = MappingContext::new_for_uncommitted_data(&state.network);
     let extraction_context = ExtractionContext::new(&state.network);
@@ -23,8 +24,8 @@ pub(crate) async fn handle_lts_transaction_status(
     if !database.is_local_transaction_execution_index_enabled() {
         return Err(client_error(
             "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
-            To use this endpoint, you will need to enable the index in the config, wipe ledger and restart. \
-            Please note the resync will take a while.",
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
         ));
     }
 
@@ -45,6 +46,7 @@ pub(crate) async fn handle_lts_transaction_status(
         });
 
     if let Some(txn_state_version) = txn_state_version_opt {
+        check_local_transaction_execution_available(database.deref(), txn_state_version)?;
         let hashes = database
             .get_committed_transaction_identifiers(txn_state_version)
             .expect("Txn identifiers are missing")
@@ -12,6 +12,7 @@ pub(crate) async fn handle_lts_transaction_submit(
     ResponseError<LtsTransactionSubmitErrorDetails>,
 > {
     assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
     let mapping_context = MappingContext::new_for_uncommitted_data(&state.network);
 
     let transaction_bytes = from_hex(&request.notarized_transaction_hex)
@@ -40,6 +41,45 @@ pub(crate) async fn handle_lts_transaction_submit(
                 min_tip_proportion_required: min_tip_basis_points_required.map(|x| to_api_decimal(&TipSpecifier::BasisPoints(x).proportion())),
             },
         )),
+        Err(MempoolAddError::ReplacementTipTooLow {
+            replaced_tip_basis_points,
+            min_tip_basis_points_required,
+            tip_basis_points,
+        }) => Err(detailed_error(
+            StatusCode::BAD_REQUEST,
+            "A transaction with the same intent (or a conflicting subintent) is already in the mempool, and the submitted transaction's tip is not sufficiently higher to replace it. Try submitting with a larger tip.",
+            LtsTransactionSubmitErrorDetails::LtsTransactionSubmitReplacementTipTooLowErrorDetails {
+                tip_proportion: to_api_decimal(&TipSpecifier::BasisPoints(tip_basis_points).proportion()),
+                replaced_tip_proportion: to_api_decimal(&TipSpecifier::BasisPoints(replaced_tip_basis_points).proportion()),
+                min_tip_proportion_required: to_api_decimal(&TipSpecifier::BasisPoints(min_tip_basis_points_required).proportion()),
+            },
+        )),
+        Err(MempoolAddError::FeePayerQuotaExceeded {
+            max_transaction_count,
+            min_tip_basis_points_required,
+            tip_basis_points,
+        }) => Err(detailed_error(
+            StatusCode::BAD_REQUEST,
+            "The transaction's fee payer already has the maximum allowed number of transactions in the mempool, and the submitted transaction's tip is not sufficient to replace any of them. Try again once they are committed, or submit with a larger tip.",
+            LtsTransactionSubmitErrorDetails::LtsTransactionSubmitFeePayerQuotaExceededErrorDetails {
+                max_transaction_count: max_transaction_count as i64,
+                tip_proportion: to_api_decimal(&TipSpecifier::BasisPoints(tip_basis_points).proportion()),
+                min_tip_proportion_required: min_tip_basis_points_required.map(|x| to_api_decimal(&TipSpecifier::BasisPoints(x).proportion())),
+            },
+        )),
+        Err(MempoolAddError::NotaryQuotaExceeded {
+            max_transaction_count,
+            min_tip_basis_points_required,
+            tip_basis_points,
+        }) => Err(detailed_error(
+            StatusCode::BAD_REQUEST,
+            "The transaction's notary already has the maximum allowed number of transactions in the mempool, and the submitted transaction's tip is not sufficient to replace any of them. Try again once they are committed, or submit with a larger tip.",
+            LtsTransactionSubmitErrorDetails::LtsTransactionSubmitNotaryQuotaExceededErrorDetails {
+                max_transaction_count: max_transaction_count as i64,
+                tip_proportion: to_api_decimal(&TipSpecifier::BasisPoints(tip_basis_points).proportion()),
+                min_tip_proportion_required: min_tip_basis_points_required.map(|x| to_api_decimal(&TipSpecifier::BasisPoints(x).proportion())),
+            },
+        )),
         Err(MempoolAddError::Duplicate(_)) => Ok(models::LtsTransactionSubmitResponse::new(true)),
         Err(MempoolAddError::Rejected(rejection, notarized_transaction_hash)) => {
             if let Some(already_committed_error) = rejection.transaction_intent_already_committed_error() {
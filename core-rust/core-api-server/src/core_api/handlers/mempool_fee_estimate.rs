@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+const MAX_TARGET_COUNT: usize = 10;
+const MAX_TARGET_PROPOSAL_COUNT: u32 = 1000;
+
+#[tracing::instrument(level = "debug", skip(state))]
+pub(crate) async fn handle_mempool_fee_estimate(
+    State(state): State<CoreApiState>,
+    Json(request): Json<models::MempoolFeeEstimateRequest>,
+) -> Result<Json<models::MempoolFeeEstimateResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
+
+    let target_proposal_counts = match request.target_proposal_counts {
+        Some(target_proposal_counts) => {
+            if target_proposal_counts.len() > MAX_TARGET_COUNT {
+                return Err(client_error(format!(
+                    "At most {MAX_TARGET_COUNT} target proposal counts may be given"
+                )));
+            }
+            target_proposal_counts
+                .into_iter()
+                .map(|target| {
+                    u32::try_from(target)
+                        .ok()
+                        .filter(|target| (1..=MAX_TARGET_PROPOSAL_COUNT).contains(target))
+                        .ok_or_else(|| {
+                            client_error(format!(
+                                "A target proposal count must be between 1 and {MAX_TARGET_PROPOSAL_COUNT}"
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>, ResponseError<()>>>()?
+        }
+        None => DEFAULT_FEE_ESTIMATE_TARGET_PROPOSAL_COUNTS.to_vec(),
+    };
+
+    let estimate = state
+        .state_manager
+        .fee_estimator
+        .estimate(&target_proposal_counts);
+    let occupancy = &estimate.mempool_occupancy;
+
+    Ok(Json(models::MempoolFeeEstimateResponse {
+        transaction_count: to_api_u32_as_i64(occupancy.transaction_count),
+        max_transaction_count: to_api_u32_as_i64(occupancy.max_transaction_count),
+        total_transactions_size: to_api_size_as_i64(occupancy.total_transactions_size)?,
+        max_total_transactions_size: to_api_size_as_i64(occupancy.max_total_transactions_size)?,
+        min_admission_tip_proportion: to_api_tip_proportion(
+            estimate.min_admission_tip_basis_points,
+        ),
+        recent_committed_tips: estimate
+            .recent_committed_tips
+            .map(|statistics| -> Result<_, MappingError> {
+                Ok(Box::new(models::CommittedTipStatistics {
+                    user_transaction_count: to_api_index_as_i64(statistics.user_transaction_count)?,
+                    median_tip_proportion: to_api_tip_proportion(
+                        statistics.median_tip_basis_points,
+                    ),
+                    p90_tip_proportion: to_api_tip_proportion(statistics.p90_tip_basis_points),
+                    max_tip_proportion: to_api_tip_proportion(statistics.max_tip_basis_points),
+                }))
+            })
+            .transpose()?,
+        suggested_tips: estimate
+            .suggestions
+            .into_iter()
+            .map(|suggestion| models::SuggestedTip {
+                // The targets were validated to fit above:
+                target_proposal_count: suggestion.target_proposal_count as i32,
+                tip_percentage: i32::try_from(suggestion.tip_basis_points.div_ceil(100))
+                    .unwrap_or(i32::MAX),
+                tip_proportion: to_api_tip_proportion(suggestion.tip_basis_points),
+            })
+            .collect(),
+    }))
+}
+
+fn to_api_tip_proportion(tip_basis_points: u32) -> String {
+    to_api_decimal(&TipSpecifier::BasisPoints(tip_basis_points).proportion())
+}
+
+fn to_api_size_as_i64(size: u64) -> Result<i64, MappingError> {
+    size.try_into().map_err(|_| MappingError::IntegerError {
+        message: "Size too large".to_string(),
+    })
+}
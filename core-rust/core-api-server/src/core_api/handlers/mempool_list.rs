@@ -7,6 +7,7 @@ pub(crate) async fn handle_mempool_list(
 ) -> Result<Json<models::MempoolListResponse>, ResponseError<()>> {
     assert_matching_network(&request.network, &state.network)?;
     assert_unbounded_endpoints_flag_enabled(&state)?;
+    assert_not_read_replica(&state)?;
     let mapping_context = MappingContext::new(&state.network);
 
     Ok(Json(models::MempoolListResponse {
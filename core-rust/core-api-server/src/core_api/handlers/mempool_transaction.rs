@@ -5,6 +5,7 @@ pub(crate) async fn handle_mempool_transaction(
     Json(request): Json<models::MempoolTransactionRequest>,
 ) -> Result<Json<models::MempoolTransactionResponse>, ResponseError<()>> {
     assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
 
     if request.payload_hashes.len() > MAX_BATCH_COUNT_PER_REQUEST.into() {
         return Err(client_error(format!(
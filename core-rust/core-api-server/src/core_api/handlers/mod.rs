@@ -1,5 +1,6 @@
 pub(crate) mod lts;
 
+mod mempool_fee_estimate;
 mod mempool_list;
 mod mempool_transaction;
 mod state_access_controller;
@@ -9,13 +10,18 @@ mod state_consensus_manager;
 mod state_non_fungible;
 mod state_package;
 mod state_resource;
+mod state_substate_proof;
 mod state_validator;
 mod status_network_configuration;
 mod status_network_status;
 mod status_scenarios;
 mod stream_proofs;
+mod stream_resource_transaction_outcomes;
+mod stream_secondary_index_transaction_outcomes;
 mod stream_transactions;
 mod transaction_callpreview;
+mod transaction_consistency_proof;
+mod transaction_inclusion_proof;
 mod transaction_parse;
 mod transaction_preview;
 mod transaction_preview_v2;
@@ -23,6 +29,7 @@ mod transaction_receipt;
 mod transaction_status;
 mod transaction_submit;
 
+pub(crate) use mempool_fee_estimate::*;
 pub(crate) use mempool_list::*;
 pub(crate) use mempool_transaction::*;
 pub(crate) use state_access_controller::*;
@@ -32,13 +39,18 @@ pub(crate) use state_consensus_manager::*;
 pub(crate) use state_non_fungible::*;
 pub(crate) use state_package::*;
 pub(crate) use state_resource::*;
+pub(crate) use state_substate_proof::*;
 pub(crate) use state_validator::*;
 pub(crate) use status_network_configuration::*;
 pub(crate) use status_network_status::*;
 pub(crate) use status_scenarios::*;
 pub(crate) use stream_proofs::*;
+pub(crate) use stream_resource_transaction_outcomes::*;
+pub(crate) use stream_secondary_index_transaction_outcomes::*;
 pub(crate) use stream_transactions::*;
 pub(crate) use transaction_callpreview::*;
+pub(crate) use transaction_consistency_proof::*;
+pub(crate) use transaction_inclusion_proof::*;
 pub(crate) use transaction_parse::*;
 pub(crate) use transaction_preview::*;
 pub(crate) use transaction_preview_v2::*;
@@ -0,0 +1,118 @@
+use crate::core_api::handlers::to_api_ledger_proof;
+use crate::prelude::*;
+use state_manager::store::historical_state::{StateHistoryError, VersionScopingSupport};
+use state_manager::store::state_tree_proofs::*;
+
+pub(crate) async fn handle_state_substate_proof(
+    state: State<CoreApiState>,
+    Json(request): Json<models::StateSubstateProofRequest>,
+) -> Result<Json<models::StateSubstateProofResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+
+    let mapping_context = MappingContext::new(&state.network);
+    let extraction_context = ExtractionContext::new(&state.network);
+
+    let node_id = extract_address_as_node_id(&extraction_context, &request.entity_address)
+        .map_err(|err| err.into_response_error("entity_address"))?;
+    let partition_number = PartitionNumber(
+        extract_u8_from_api_i32(request.partition_number)
+            .map_err(|err| err.into_response_error("partition_number"))?,
+    );
+    let substate_key = extract_substate_key(&extract_api_substate_key(request.substate_key)?)
+        .map_err(|err| err.into_response_error("substate_key"))?;
+    let state_version = request
+        .state_version
+        .map(extract_state_version)
+        .transpose()
+        .map_err(|err| err.into_response_error("state_version"))?;
+
+    let database = state.state_manager.database.snapshot();
+    let ledger_proof = match state_version {
+        Some(state_version) => database.get_proof(state_version).ok_or_else(|| {
+            client_error(format!(
+                "There is no ledger proof at state version {}",
+                state_version
+            ))
+        })?,
+        None => database
+            .get_latest_proof()
+            .ok_or_else(|| client_error("There is no ledger proof yet"))?,
+    };
+
+    // Reading the Substate's value at a past state version requires the state history - we check
+    // it up-front (i.e. before computing the proof), to fail fast with a clear error:
+    let scoped_database = database
+        .deref()
+        .scoped_at(Some(ledger_proof.ledger_header.state_version))
+        .map_err(|error| match error {
+            StateHistoryError::StateHistoryDisabled => client_error(
+                "Proofs at past state versions require the state history feature (see the `db.historical_substate_values.enable` Node configuration flag); omit the `state_version` to get a proof at the current top-of-ledger",
+            ),
+            other => ResponseError::from(other),
+        })?;
+
+    let proof = prove_substate_inclusion(
+        database.deref(),
+        ledger_proof.ledger_header.state_version,
+        &node_id,
+        partition_number,
+        &substate_key,
+    )
+    .map_err(|error| match error {
+        SubstateProofError::StateVersionNotRetained => client_error(format!(
+            "The state tree at state version {} is no longer retained on this node",
+            ledger_proof.ledger_header.state_version
+        )),
+        SubstateProofError::SubstateNotFound => not_found_error("Substate not found"),
+    })?;
+
+    let db_partition_key = SpreadPrefixKeyMapper::to_db_partition_key(&node_id, partition_number);
+    let db_sort_key = SpreadPrefixKeyMapper::to_db_sort_key(&substate_key);
+    let substate_value = scoped_database
+        .get_raw_substate_by_db_key(&db_partition_key, &db_sort_key)
+        .ok_or_else(|| not_found_error("Substate not found"))?;
+
+    Ok(Json(models::StateSubstateProofResponse {
+        ledger_proof: Box::new(to_api_ledger_proof(&mapping_context, ledger_proof)?),
+        db_node_key_hex: to_hex(&db_partition_key.node_key),
+        db_sort_key_hex: to_hex(&db_sort_key.0),
+        substate_value_hex: to_hex(substate_value),
+        proof: Box::new(to_api_substate_inclusion_proof(&proof)),
+    }))
+}
+
+fn extract_api_substate_key(
+    substate_key: Option<models::SubstateKey>,
+) -> Result<models::SubstateKey, ResponseError<()>> {
+    substate_key.ok_or_else(|| client_error("Missing substate_key"))
+}
+
+fn to_api_substate_inclusion_proof(
+    proof: &SubstateInclusionProof,
+) -> models::SubstateInclusionProof {
+    models::SubstateInclusionProof {
+        entity_tier: Box::new(to_api_state_tree_tier_proof(&proof.entity_tier)),
+        partition_tier: Box::new(to_api_state_tree_tier_proof(&proof.partition_tier)),
+        substate_tier: Box::new(to_api_state_tree_tier_proof(&proof.substate_tier)),
+    }
+}
+
+fn to_api_state_tree_tier_proof(tier_proof: &StateTreeTierProof) -> models::StateTreeTierProof {
+    models::StateTreeTierProof {
+        levels: tier_proof
+            .levels
+            .iter()
+            .map(|level| models::StateTreeProofLevel {
+                siblings: level
+                    .siblings
+                    .iter()
+                    .map(|sibling| models::StateTreeProofSibling {
+                        nibble: i32::from(sibling.nibble),
+                        hash: to_hex(sibling.hash),
+                        is_leaf: sibling.is_leaf,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn handle_stream_resource_transaction_outcomes(
+    state: State<CoreApiState>,
+    Json(request): Json<models::StreamResourceTransactionOutcomesRequest>,
+) -> Result<Json<models::StreamResourceTransactionOutcomesResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+
+    let mapping_context = MappingContext::new(&state.network);
+    let extraction_context = ExtractionContext::new(&state.network);
+
+    let resource_address = extract_resource_address(&extraction_context, &request.resource_address)
+        .map_err(|err| err.into_response_error("resource_address"))?;
+
+    let from_state_version = extract_state_version(request.from_state_version)
+        .map_err(|err| err.into_response_error("from_state_version"))?;
+
+    let limit: usize = request
+        .limit
+        .try_into()
+        .map_err(|_| client_error("limit cannot be negative"))?;
+
+    if limit == 0 {
+        return Err(client_error("limit must be positive"));
+    }
+
+    if limit > MAX_BATCH_COUNT_PER_REQUEST.into() {
+        return Err(client_error(format!(
+            "limit must <= {MAX_BATCH_COUNT_PER_REQUEST}"
+        )));
+    }
+
+    let database = state.state_manager.database.snapshot();
+
+    if !database.is_local_transaction_execution_index_enabled() {
+        return Err(client_error(
+            "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
+        ));
+    }
+
+    let Some(index_last_processed_state_version) =
+        database.get_resource_change_index_last_processed_state_version()
+    else {
+        return Err(client_error(
+            "This endpoint requires that the ResourceChangeIndex is enabled on the node. \
+            To use this endpoint, you will need to enable the index in the config and restart the node. \
+            Please note the index catchup build will take some time.",
+        ));
+    };
+    let max_state_version = database.max_state_version();
+    if index_last_processed_state_version < max_state_version {
+        return Err(client_error(format!(
+            "The ResourceChangeIndex is still catching up (processed up to state version {} out of {})",
+            index_last_processed_state_version.number(),
+            max_state_version.number()
+        )));
+    }
+    // The resource change index only covers the transactions with locally stored executions:
+    check_local_transaction_execution_available(database.deref(), from_state_version)?;
+
+    let state_versions =
+        database.get_state_versions_for_resource_iter(resource_address, from_state_version);
+
+    let mut response = models::StreamResourceTransactionOutcomesResponse {
+        from_state_version: to_api_state_version(from_state_version)?,
+        count: MAX_BATCH_COUNT_PER_REQUEST as i32, // placeholder to get a better size approximation for the header
+        max_ledger_state_version: to_api_state_version(max_state_version)?,
+        committed_transaction_outcomes: Vec::new(),
+    };
+
+    // Reserve enough for the "header" fields
+    let mut current_total_size = response.get_json_size();
+    for state_version in state_versions.take(limit) {
+        let committed_transaction_outcome = to_api_lts_committed_transaction_outcome(
+            database.deref(),
+            &mapping_context,
+            state_version,
+            database
+                .get_committed_local_transaction_receipt(state_version)
+                .expect("Transaction receipt index corrupted"),
+            database
+                .get_committed_transaction_identifiers(state_version)
+                .expect("Transaction identifiers index corrupted"),
+        )?;
+
+        let committed_transaction_size = committed_transaction_outcome.get_json_size();
+        current_total_size += committed_transaction_size;
+
+        response
+            .committed_transaction_outcomes
+            .push(committed_transaction_outcome);
+
+        if current_total_size > CAP_BATCH_RESPONSE_WHEN_ABOVE_BYTES {
+            break;
+        }
+    }
+    response.count = response.committed_transaction_outcomes.len() as i32;
+
+    Ok(Json(response))
+}
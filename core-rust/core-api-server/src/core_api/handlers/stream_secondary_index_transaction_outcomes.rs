@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn handle_stream_secondary_index_transaction_outcomes(
+    state: State<CoreApiState>,
+    Json(request): Json<models::StreamSecondaryIndexTransactionOutcomesRequest>,
+) -> Result<Json<models::StreamSecondaryIndexTransactionOutcomesResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+
+    let mapping_context = MappingContext::new(&state.network);
+
+    let key = from_hex(&request.key_hex).map_err(|err| err.into_response_error("key_hex"))?;
+
+    let from_state_version = extract_state_version(request.from_state_version)
+        .map_err(|err| err.into_response_error("from_state_version"))?;
+
+    let limit: usize = request
+        .limit
+        .try_into()
+        .map_err(|_| client_error("limit cannot be negative"))?;
+
+    if limit == 0 {
+        return Err(client_error("limit must be positive"));
+    }
+
+    if limit > MAX_BATCH_COUNT_PER_REQUEST.into() {
+        return Err(client_error(format!(
+            "limit must <= {MAX_BATCH_COUNT_PER_REQUEST}"
+        )));
+    }
+
+    let database = state.state_manager.database.snapshot();
+
+    if !database.is_local_transaction_execution_index_enabled() {
+        return Err(client_error(
+            "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
+        ));
+    }
+
+    let Some(index_last_processed_state_version) =
+        database.get_secondary_index_last_processed_state_version(&request.index_name)
+    else {
+        return Err(client_error(format!(
+            "This endpoint requires that the `{}` secondary index is enabled on the node. \
+            To use this endpoint, you will need to enable the index in the config and restart the node. \
+            Please note the index catchup build will take some time.",
+            request.index_name
+        )));
+    };
+    // The returned outcomes are based on the locally stored transaction executions:
+    check_local_transaction_execution_available(database.deref(), from_state_version)?;
+
+    let max_state_version = database.max_state_version();
+
+    let skipped_state_version_ranges = database
+        .get_secondary_index_skipped_state_version_ranges(&request.index_name)
+        .into_iter()
+        .map(|(from, to)| {
+            Ok(models::StateVersionRange {
+                from_state_version: to_api_state_version(from)?,
+                to_state_version: to_api_state_version(to)?,
+            })
+        })
+        .collect::<Result<Vec<_>, MappingError>>()?;
+
+    let state_versions = database.get_state_versions_for_secondary_index_key_iter(
+        &request.index_name,
+        &key,
+        from_state_version,
+    );
+
+    let mut response = models::StreamSecondaryIndexTransactionOutcomesResponse {
+        from_state_version: to_api_state_version(from_state_version)?,
+        count: MAX_BATCH_COUNT_PER_REQUEST as i32, // placeholder to get a better size approximation for the header
+        max_ledger_state_version: to_api_state_version(max_state_version)?,
+        index_last_processed_state_version: to_api_state_version(
+            index_last_processed_state_version,
+        )?,
+        skipped_state_version_ranges,
+        committed_transaction_outcomes: Vec::new(),
+    };
+
+    // Reserve enough for the "header" fields
+    let mut current_total_size = response.get_json_size();
+    for state_version in state_versions.take(limit) {
+        let committed_transaction_outcome = to_api_lts_committed_transaction_outcome(
+            database.deref(),
+            &mapping_context,
+            state_version,
+            database
+                .get_committed_local_transaction_receipt(state_version)
+                .expect("Transaction receipt index corrupted"),
+            database
+                .get_committed_transaction_identifiers(state_version)
+                .expect("Transaction identifiers index corrupted"),
+        )?;
+
+        let committed_transaction_size = committed_transaction_outcome.get_json_size();
+        current_total_size += committed_transaction_size;
+
+        response
+            .committed_transaction_outcomes
+            .push(committed_transaction_outcome);
+
+        if current_total_size > CAP_BATCH_RESPONSE_WHEN_ABOVE_BYTES {
+            break;
+        }
+    }
+    response.count = response.committed_transaction_outcomes.len() as i32;
+
+    Ok(Json(response))
+}
@@ -41,10 +41,11 @@ pub(crate) async fn handle_stream_transactions(
     if !database.is_local_transaction_execution_index_enabled() {
         return Err(client_error(
             "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
-            To use this endpoint, you will need to enable the index in the config, wipe ledger and restart. \
-            Please note the resync will take a while.",
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
         ));
     }
+    check_local_transaction_execution_available(database.deref(), from_state_version)?;
 
     let max_ledger_state_version = to_api_state_version(database.max_state_version())?;
 
@@ -84,7 +85,9 @@ pub(crate) async fn handle_stream_transactions(
 
     // Reserve enough for the "header" fields
     let mut current_total_size = response.get_json_size();
-    let bundles_iter = database.get_committed_transaction_bundle_iter(from_state_version);
+    let bundles_iter = database
+        .get_committed_transaction_bundle_iter(from_state_version)
+        .map_err(transactions_pruned_error)?;
     let proofs_iter = if request.include_proofs.is_some_and(|value| value) {
         database.get_proof_iter(from_state_version)
     } else {
@@ -0,0 +1,72 @@
+use crate::core_api::handlers::{
+    to_api_accu_tree_proof_error, to_api_accumulator_tree_inclusion_proof, to_api_ledger_proof,
+};
+use crate::prelude::*;
+use state_manager::store::accu_tree_proofs::*;
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn handle_transaction_consistency_proof(
+    state: State<CoreApiState>,
+    Json(request): Json<models::TransactionConsistencyProofRequest>,
+) -> Result<Json<models::TransactionConsistencyProofResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+
+    let mapping_context = MappingContext::new(&state.network);
+
+    let from_state_version = extract_state_version(request.from_state_version)
+        .map_err(|err| err.into_response_error("from_state_version"))?;
+    let to_state_version = request
+        .to_state_version
+        .map(extract_state_version)
+        .transpose()
+        .map_err(|err| err.into_response_error("to_state_version"))?;
+
+    let database = state.state_manager.database.snapshot();
+
+    let from_ledger_proof = database.get_proof(from_state_version).ok_or_else(|| {
+        client_error(format!(
+            "There is no ledger proof at state version {}",
+            from_state_version
+        ))
+    })?;
+    let to_ledger_proof = match to_state_version {
+        Some(to_state_version) => database.get_proof(to_state_version).ok_or_else(|| {
+            client_error(format!(
+                "There is no ledger proof at state version {}",
+                to_state_version
+            ))
+        })?,
+        None => get_latest_proof_of_same_epoch(database.deref(), from_state_version)
+            .ok_or_else(|| not_found_error("No ledger proof found in the requested epoch"))?,
+    };
+
+    let proof = prove_ledger_consistency(
+        database.deref(),
+        &from_ledger_proof.ledger_header,
+        &to_ledger_proof.ledger_header,
+    )
+    .map_err(to_api_accu_tree_proof_error)?;
+
+    Ok(Json(models::TransactionConsistencyProofResponse {
+        from_ledger_proof: Box::new(to_api_ledger_proof(&mapping_context, from_ledger_proof)?),
+        to_ledger_proof: Box::new(to_api_ledger_proof(&mapping_context, to_ledger_proof)?),
+        transaction_tree_proof: Box::new(to_api_accumulator_tree_consistency_proof(
+            &proof.transaction_tree_proof,
+        )?),
+        receipt_tree_proof: Box::new(to_api_accumulator_tree_consistency_proof(
+            &proof.receipt_tree_proof,
+        )?),
+    }))
+}
+
+fn to_api_accumulator_tree_consistency_proof<N: AsRef<[u8]>>(
+    proof: &AccuTreeConsistencyProof<N>,
+) -> Result<models::AccumulatorTreeConsistencyProof, MappingError> {
+    Ok(models::AccumulatorTreeConsistencyProof {
+        old_tree_size: to_api_index_as_i64(proof.old_tree_size)?,
+        old_last_leaf_proof: Box::new(to_api_accumulator_tree_inclusion_proof(
+            &proof.old_last_leaf,
+            &proof.inclusion_proof,
+        )?),
+    })
+}
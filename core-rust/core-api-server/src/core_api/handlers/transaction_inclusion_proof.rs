@@ -0,0 +1,88 @@
+use crate::core_api::handlers::to_api_ledger_proof;
+use crate::prelude::*;
+use state_manager::store::accu_tree_proofs::*;
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn handle_transaction_inclusion_proof(
+    state: State<CoreApiState>,
+    Json(request): Json<models::TransactionInclusionProofRequest>,
+) -> Result<Json<models::TransactionInclusionProofResponse>, ResponseError<()>> {
+    assert_matching_network(&request.network, &state.network)?;
+
+    let mapping_context = MappingContext::new(&state.network);
+    let extraction_context = ExtractionContext::new(&state.network);
+
+    let intent_hash = extract_transaction_intent_hash(&extraction_context, request.intent_hash)
+        .map_err(|err| err.into_response_error("intent_hash"))?;
+    let at_state_version = request
+        .at_state_version
+        .map(extract_state_version)
+        .transpose()
+        .map_err(|err| err.into_response_error("at_state_version"))?;
+
+    let database = state.state_manager.database.snapshot();
+
+    let state_version = database
+        .get_txn_state_version_by_identifier(&intent_hash)
+        .ok_or_else(|| {
+            not_found_error(format!(
+                "Committed transaction not found with intent hash: {intent_hash:?}"
+            ))
+        })?;
+    let ledger_proof = match at_state_version {
+        Some(at_state_version) => database.get_proof(at_state_version).ok_or_else(|| {
+            client_error(format!(
+                "There is no ledger proof at state version {}",
+                at_state_version
+            ))
+        })?,
+        None => get_latest_proof_of_same_epoch(database.deref(), state_version)
+            .ok_or_else(|| not_found_error("No ledger proof found in the transaction's epoch"))?,
+    };
+
+    let proof = prove_ledger_transaction_inclusion(
+        database.deref(),
+        state_version,
+        &ledger_proof.ledger_header,
+    )
+    .map_err(to_api_accu_tree_proof_error)?;
+
+    Ok(Json(models::TransactionInclusionProofResponse {
+        state_version: to_api_state_version(state_version)?,
+        ledger_proof: Box::new(to_api_ledger_proof(&mapping_context, ledger_proof)?),
+        transaction_tree_proof: Box::new(to_api_accumulator_tree_inclusion_proof(
+            &proof.transaction_leaf,
+            &proof.transaction_tree_proof,
+        )?),
+        receipt_tree_proof: Box::new(to_api_accumulator_tree_inclusion_proof(
+            &proof.receipt_leaf,
+            &proof.receipt_tree_proof,
+        )?),
+    }))
+}
+
+pub(crate) fn to_api_accumulator_tree_inclusion_proof<N: AsRef<[u8]>>(
+    leaf: &N,
+    proof: &AccuTreeInclusionProof<N>,
+) -> Result<models::AccumulatorTreeInclusionProof, MappingError> {
+    Ok(models::AccumulatorTreeInclusionProof {
+        leaf_index: to_api_index_as_i64(proof.leaf_index)?,
+        tree_size: to_api_index_as_i64(proof.tree_size)?,
+        leaf_hash: to_hex(leaf),
+        sibling_hashes: proof.siblings.iter().map(to_hex).collect(),
+    })
+}
+
+pub(crate) fn to_api_accu_tree_proof_error(error: AccuTreeProofError) -> ResponseError<()> {
+    match error {
+        AccuTreeProofError::TransactionNotUnderRoot => client_error(
+            "The transaction is not committed under the requested ledger proof (it must come from the same epoch, at or after the transaction)",
+        ),
+        AccuTreeProofError::HeadersNotConsistentInEpoch => client_error(
+            "The requested ledger proofs must come from the same epoch, in ascending order",
+        ),
+        AccuTreeProofError::TreeSlicesNotAvailable => not_found_error(
+            "The accumulator tree data required for the proof is not available on this node",
+        ),
+    }
+}
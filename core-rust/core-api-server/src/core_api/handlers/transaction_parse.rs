@@ -156,6 +156,7 @@ fn attempt_parsing_as_notarized_transaction(
                                 &hashes,
                                 SystemTime::now(),
                             )
+                            .attempt
                             .rejection;
                         match rejection {
                             Some(rejection) => Err(rejection),
@@ -6,6 +6,7 @@ pub(crate) async fn handle_transaction_preview(
     Json(request): Json<models::TransactionPreviewRequest>,
 ) -> Result<Json<models::TransactionPreviewResponse>, ResponseError<()>> {
     assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
     let mapping_context = MappingContext::new(&state.network);
 
     let at_state_version = request
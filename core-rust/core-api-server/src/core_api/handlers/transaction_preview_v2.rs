@@ -12,6 +12,7 @@ pub(crate) async fn handle_transaction_preview_v2(
     ResponseError<models::TransactionPreviewV2ErrorDetails>,
 > {
     assert_matching_network(&request.network, &state.network)?;
+    assert_not_read_replica(&state)?;
     let mapping_context = MappingContext::new(&state.network);
 
     let at_state_version = request
@@ -20,14 +20,15 @@ pub(crate) async fn handle_transaction_receipt(
     if !database.is_local_transaction_execution_index_enabled() {
         return Err(client_error(
             "This endpoint requires that the LocalTransactionExecutionIndex is enabled on the node. \
-            To use this endpoint, you will need to enable the index in the config, wipe ledger and restart. \
-            Please note the resync will take a while.",
+            To use this endpoint, you will need to enable the index in the config and restart. \
+            Please note that the past transactions will only be indexed as far as the state history allows.",
         ));
     }
 
     let txn_state_version_opt = database.get_txn_state_version_by_identifier(&intent_hash);
 
     if let Some(txn_state_version) = txn_state_version_opt {
+        check_local_transaction_execution_available(database.deref(), txn_state_version)?;
         let raw = database
             .get_committed_transaction(txn_state_version)
             .expect("Txn is missing");
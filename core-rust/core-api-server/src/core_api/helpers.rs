@@ -127,6 +127,49 @@ pub(crate) fn read_current_ledger_header(
         .ledger_header
 }
 
+/// Returns an error if the local transaction execution at the given state version is not available
+/// (i.e. when the local transaction execution index was enabled retroactively, and its backfill did
+/// not reach that far into the past, or when the transaction itself was already pruned).
+pub(crate) fn check_local_transaction_execution_available<E: ErrorDetails>(
+    database: &StateManagerDatabase<impl ReadableRocks>,
+    state_version: StateVersion,
+) -> Result<(), ResponseError<E>> {
+    check_ledger_transaction_available(database, state_version)?;
+    let available_from = database.get_first_stored_local_transaction_execution_state_version();
+    if state_version < available_from {
+        return Err(client_error(format!(
+            "Local transaction executions are only available from state version {}",
+            available_from.number()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error if the full data of the ledger transaction at the given state version was
+/// already pruned (i.e. on a node configured with a limited ledger transaction retention).
+pub(crate) fn check_ledger_transaction_available<E: ErrorDetails>(
+    database: &StateManagerDatabase<impl ReadableRocks>,
+    state_version: StateVersion,
+) -> Result<(), ResponseError<E>> {
+    let first_available_state_version =
+        database.get_first_stored_ledger_transaction_state_version();
+    if state_version < first_available_state_version {
+        return Err(transactions_pruned_error(TransactionsPrunedError {
+            first_available_state_version,
+        }));
+    }
+    Ok(())
+}
+
+pub(crate) fn transactions_pruned_error<E: ErrorDetails>(
+    error: TransactionsPrunedError,
+) -> ResponseError<E> {
+    client_error(format!(
+        "The requested transactions were pruned on this node (it only retains the full ledger transactions from state version {}; use an archive node for older ones)",
+        error.first_available_state_version.number()
+    ))
+}
+
 struct ByteCountWriter<'a> {
     bytes: &'a mut usize,
 }
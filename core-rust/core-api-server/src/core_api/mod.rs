@@ -82,7 +82,9 @@ pub(crate) use conversions::*;
 pub(crate) use errors::*;
 pub(crate) use extractors::*;
 pub(crate) use helpers::*;
-pub(crate) use server::{create_server, CoreApiServerConfig, CoreApiState};
+pub use server::{
+    create_router, create_server, CoreApiServerConfig, CoreApiServerFlags, CoreApiState,
+};
 
 pub(crate) mod models {
     pub(crate) use super::generated::models::*;
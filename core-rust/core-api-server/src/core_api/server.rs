@@ -100,6 +100,20 @@ pub async fn create_server<F>(
 ) where
     F: Future<Output = ()>,
 {
+    let router = create_router(core_api_state, metric_registry);
+
+    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
+
+    axum::Server::bind(&bind_addr)
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(shutdown_signal)
+        .await
+        .unwrap();
+}
+
+/// Creates the API's complete router (i.e. the one served by [`create_server()`]), so that a
+/// caller may serve it with extra layers of its own.
+pub fn create_router(core_api_state: CoreApiState, metric_registry: &MetricRegistry) -> Router {
     let router = Router::new()
         // This only adds a route for /core, /core/ doesn't seem possible using /nest
         .route("/", get(handle_provide_info_at_root_path))
@@ -146,6 +160,7 @@ pub async fn create_server<F>(
         // Mempool Sub-API
         .route("/mempool/list", post(handle_mempool_list))
         .route("/mempool/transaction", post(handle_mempool_transaction))
+        .route("/mempool/fee-estimate", post(handle_mempool_fee_estimate))
         // Transaction Sub-API
         .route(
             "/transaction/parse",
@@ -157,6 +172,14 @@ pub async fn create_server<F>(
         )
         .route("/transaction/status", post(handle_transaction_status))
         .route("/transaction/receipt", post(handle_transaction_receipt))
+        .route(
+            "/transaction/inclusion-proof",
+            post(handle_transaction_inclusion_proof),
+        )
+        .route(
+            "/transaction/consistency-proof",
+            post(handle_transaction_consistency_proof),
+        )
         .route(
             "/transaction/preview",
             post(handle_transaction_preview).layer(DefaultBodyLimit::max(LARGE_REQUEST_MAX_BYTES)),
@@ -173,6 +196,14 @@ pub async fn create_server<F>(
         // Stream Sub-API
         .route("/stream/proofs", post(handle_stream_proofs))
         .route("/stream/transactions", post(handle_stream_transactions))
+        .route(
+            "/stream/resource-transaction-outcomes",
+            post(handle_stream_resource_transaction_outcomes),
+        )
+        .route(
+            "/stream/secondary-index-transaction-outcomes",
+            post(handle_stream_secondary_index_transaction_outcomes),
+        )
         // State Sub-API
         .route(
             "/state/consensus-manager",
@@ -188,11 +219,12 @@ pub async fn create_server<F>(
         .route("/state/package", post(handle_state_package))
         .route("/state/resource", post(handle_state_resource))
         .route("/state/non-fungible", post(handle_state_non_fungible))
+        .route("/state/substate-proof", post(handle_state_substate_proof))
         .with_state(core_api_state);
 
     let metrics = Arc::new(CoreApiMetrics::new(metric_registry));
 
-    let prefixed_router = Router::new()
+    Router::new()
         .nest("/core", router)
         .route("/", get(handle_no_core_path))
         .layer(CatchPanicLayer::custom(InternalServerErrorResponseForPanic))
@@ -201,15 +233,7 @@ pub async fn create_server<F>(
         .route_layer(MetricsLayer::new(metrics.clone()))
         .layer(map_response(emit_error_response_event))
         .fallback(handle_not_found)
-        .with_state(metrics);
-
-    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
-
-    axum::Server::bind(&bind_addr)
-        .serve(prefixed_router.into_make_service())
-        .with_graceful_shutdown(shutdown_signal)
-        .await
-        .unwrap();
+        .with_state(metrics)
 }
 
 #[tracing::instrument]
@@ -68,6 +68,8 @@ extern crate serde_json;
 mod core_api;
 pub mod jni;
 
+pub use core_api::{create_router, create_server, CoreApiServerFlags, CoreApiState};
+
 #[allow(unused_imports)]
 pub(crate) mod prelude {
     pub(crate) use crate::engine_prelude::*;
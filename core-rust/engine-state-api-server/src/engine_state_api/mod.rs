@@ -91,7 +91,7 @@ pub(crate) use handlers::*;
 pub(crate) use paging::*;
 pub(crate) use programmatic_json::*;
 pub(crate) use readers::*;
-pub(crate) use server::{create_server, EngineStateApiServerConfig, EngineStateApiState};
+pub use server::{create_router, create_server, EngineStateApiServerConfig, EngineStateApiState};
 
 pub(crate) mod models {
     pub(crate) use super::generated::models::*;
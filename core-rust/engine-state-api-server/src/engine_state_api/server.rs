@@ -99,6 +99,23 @@ pub async fn create_server<F>(
 ) where
     F: Future<Output = ()>,
 {
+    let router = create_router(engine_state_api_state, metric_registry);
+
+    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
+
+    axum::Server::bind(&bind_addr)
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(shutdown_signal)
+        .await
+        .unwrap();
+}
+
+/// Creates the API's complete router (i.e. the one served by [`create_server()`]), so that a
+/// caller may serve it with extra layers of its own.
+pub fn create_router(
+    engine_state_api_state: EngineStateApiState,
+    metric_registry: &Registry,
+) -> Router {
     let router = Router::new()
         .route("/extra/entity-search", post(handle_extra_entity_search))
         .route("/blueprint/info", post(handle_blueprint_info))
@@ -135,7 +152,7 @@ pub async fn create_server<F>(
 
     let metrics = Arc::new(EngineStateApiMetrics::new(metric_registry));
 
-    let prefixed_router = Router::new()
+    Router::new()
         .nest("/engine-state", router)
         .route("/", get(handle_missing_engine_state_path))
         .layer(CatchPanicLayer::custom(InternalServerErrorResponseForPanic))
@@ -144,15 +161,7 @@ pub async fn create_server<F>(
         .route_layer(MetricsLayer::new(metrics.clone()))
         .layer(map_response(emit_error_response_event))
         .fallback(handle_not_found)
-        .with_state(metrics);
-
-    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
-
-    axum::Server::bind(&bind_addr)
-        .serve(prefixed_router.into_make_service())
-        .with_graceful_shutdown(shutdown_signal)
-        .await
-        .unwrap();
+        .with_state(metrics)
 }
 
 #[tracing::instrument]
@@ -68,6 +68,8 @@ extern crate serde_json;
 mod engine_state_api;
 pub mod jni;
 
+pub use engine_state_api::{create_router, create_server, EngineStateApiState};
+
 #[allow(unused_imports)]
 pub(crate) mod prelude {
     pub(crate) use crate::engine_prelude::*;
@@ -78,6 +78,7 @@ fn export_extern_functions() {
 
     // state-manager
     state_manager::jni::db_checkpoints::export_extern_functions();
+    state_manager::jni::gc_control::export_extern_functions();
     state_manager::jni::mempool::export_extern_functions();
     state_manager::jni::node_rust_environment::export_extern_functions();
     state_manager::jni::protocol_update::export_extern_functions();
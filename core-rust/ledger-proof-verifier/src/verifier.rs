@@ -0,0 +1,642 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::proofs::*;
+use radix_common::prelude::*;
+
+/// A verifier of a chain of [`VerifiableLedgerProof`]s (e.g. the ones returned by the Core API's
+/// `/stream/proofs`), anchored at a trusted epoch-change proof.
+///
+/// It only needs the proofs themselves (i.e. no database or transaction execution), and checks:
+/// - that each consensus proof is signed by a stake-weighted quorum (i.e. more than 2/3) of the
+///   validator set of its epoch (as announced by the preceding epoch-change proof),
+/// - that the epochs follow the announced epoch changes (i.e. no epoch can be skipped),
+/// - that the protocol update proofs only appear after a quorum-signed proof initiating that
+///   protocol update.
+///
+/// Note: the protocol update proofs carry no signatures - their contents only become attested by
+/// the next quorum-signed proof (which is why they are not allowed to change the validator set).
+/// Proofs within an epoch may be skipped (e.g. when the Node has already pruned them).
+#[derive(Debug, Clone)]
+pub struct LedgerProofChainVerifier {
+    epoch: Epoch,
+    validator_set: Vec<VerifiableValidator>,
+    state_version: u64,
+    expected_protocol_update: Option<String>,
+}
+
+/// A kind of successfully verified [`VerifiableLedgerProof`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum VerifiedLedgerProofKind {
+    /// A consensus proof, signed by validators of the given total stake.
+    SignedByQuorum { signed_stake: Decimal },
+    /// An (unsigned) proof of an expected protocol update's batch.
+    ProtocolUpdateBatch,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum LedgerProofVerificationError {
+    /// The trusted anchor proof does not carry the next epoch's validator set.
+    AnchorNotAnEpochChangeProof,
+    NonIncreasingStateVersion {
+        previous: u64,
+        actual: u64,
+    },
+    UnexpectedEpoch {
+        expected: Epoch,
+        actual: Epoch,
+    },
+    /// The signing key and validator address do not match any validator of the current epoch.
+    UnknownSigner {
+        validator_address: ComponentAddress,
+    },
+    DuplicateSigner {
+        validator_address: ComponentAddress,
+    },
+    InvalidSignature {
+        validator_address: ComponentAddress,
+    },
+    InsufficientSignedStake {
+        signed_stake: Decimal,
+        required_stake: Decimal,
+    },
+    UnexpectedProtocolUpdate {
+        protocol_version_name: String,
+    },
+    /// A protocol update proof (i.e. unsigned) announces an epoch change.
+    UnsignedEpochChange,
+}
+
+impl LedgerProofChainVerifier {
+    /// Creates a verifier anchored at the given trusted epoch-change proof (e.g. the genesis' one,
+    /// or any other obtained out-of-band).
+    pub fn new(
+        trusted_epoch_proof: &VerifiableLedgerProof,
+    ) -> Result<Self, LedgerProofVerificationError> {
+        let header = &trusted_epoch_proof.ledger_header;
+        let next_epoch = header
+            .next_epoch
+            .as_ref()
+            .ok_or(LedgerProofVerificationError::AnchorNotAnEpochChangeProof)?;
+        Ok(Self {
+            epoch: next_epoch.epoch,
+            validator_set: next_epoch.validator_set.clone(),
+            state_version: header.state_version,
+            expected_protocol_update: header.next_protocol_version.clone(),
+        })
+    }
+
+    /// Returns the epoch of which the next consensus proof is expected.
+    pub fn current_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the validator set of the [`Self::current_epoch()`].
+    pub fn current_validator_set(&self) -> &[VerifiableValidator] {
+        &self.validator_set
+    }
+
+    /// Returns the state version of the latest verified proof.
+    pub fn latest_state_version(&self) -> u64 {
+        self.state_version
+    }
+
+    /// Verifies the given proof as the next one in the chain, and (only if successful) advances
+    /// the verifier's state past it.
+    pub fn verify_next(
+        &mut self,
+        proof: &VerifiableLedgerProof,
+    ) -> Result<VerifiedLedgerProofKind, LedgerProofVerificationError> {
+        let header = &proof.ledger_header;
+        if header.state_version <= self.state_version {
+            return Err(LedgerProofVerificationError::NonIncreasingStateVersion {
+                previous: self.state_version,
+                actual: header.state_version,
+            });
+        }
+        if header.epoch != self.epoch {
+            return Err(LedgerProofVerificationError::UnexpectedEpoch {
+                expected: self.epoch,
+                actual: header.epoch,
+            });
+        }
+
+        let verified_kind = match &proof.origin {
+            VerifiableLedgerProofOrigin::Consensus {
+                opaque,
+                timestamped_signatures,
+            } => {
+                let signed_stake = self.verify_quorum(opaque, header, timestamped_signatures)?;
+                self.expected_protocol_update = header.next_protocol_version.clone();
+                if let Some(next_epoch) = &header.next_epoch {
+                    self.epoch = next_epoch.epoch;
+                    self.validator_set = next_epoch.validator_set.clone();
+                }
+                VerifiedLedgerProofKind::SignedByQuorum { signed_stake }
+            }
+            VerifiableLedgerProofOrigin::ProtocolUpdate {
+                protocol_version_name,
+            } => {
+                if self.expected_protocol_update.as_ref() != Some(protocol_version_name) {
+                    return Err(LedgerProofVerificationError::UnexpectedProtocolUpdate {
+                        protocol_version_name: protocol_version_name.clone(),
+                    });
+                }
+                if header.next_epoch.is_some() {
+                    return Err(LedgerProofVerificationError::UnsignedEpochChange);
+                }
+                // A protocol update may directly trigger another one (which then becomes expected):
+                if let Some(next_protocol_version) = &header.next_protocol_version {
+                    self.expected_protocol_update = Some(next_protocol_version.clone());
+                }
+                VerifiedLedgerProofKind::ProtocolUpdateBatch
+            }
+        };
+        self.state_version = header.state_version;
+        Ok(verified_kind)
+    }
+
+    fn verify_quorum(
+        &self,
+        opaque: &Hash,
+        header: &VerifiableLedgerHeader,
+        timestamped_signatures: &[VerifiableValidatorSignature],
+    ) -> Result<Decimal, LedgerProofVerificationError> {
+        let mut signed_validator_indices = index_set_new();
+        let mut signed_stake = Decimal::ZERO;
+        for timestamped_signature in timestamped_signatures {
+            let validator_address = timestamped_signature.validator_address;
+            let Some((validator_index, validator)) =
+                self.validator_set
+                    .iter()
+                    .enumerate()
+                    .find(|(_, validator)| {
+                        validator.address == validator_address
+                            && validator.key == timestamped_signature.key
+                    })
+            else {
+                return Err(LedgerProofVerificationError::UnknownSigner { validator_address });
+            };
+            if !signed_validator_indices.insert(validator_index) {
+                return Err(LedgerProofVerificationError::DuplicateSigner { validator_address });
+            }
+            let vote_hash =
+                compute_consensus_vote_hash(opaque, header, timestamped_signature.timestamp_ms);
+            if !verify_secp256k1(&vote_hash, &validator.key, &timestamped_signature.signature) {
+                return Err(LedgerProofVerificationError::InvalidSignature { validator_address });
+            }
+            signed_stake = signed_stake + validator.stake;
+        }
+
+        let total_stake = self
+            .validator_set
+            .iter()
+            .fold(Decimal::ZERO, |total, validator| total + validator.stake);
+        let required_stake = compute_quorum_threshold(total_stake);
+        if signed_stake < required_stake {
+            return Err(LedgerProofVerificationError::InsufficientSignedStake {
+                signed_stake,
+                required_stake,
+            });
+        }
+        Ok(signed_stake)
+    }
+}
+
+/// Computes the hash signed by a validator voting for the given committed header (at the given
+/// local timestamp).
+/// This must match the consensus' (i.e. Java) `ConsensusHasher`.
+pub fn compute_consensus_vote_hash(
+    opaque: &Hash,
+    header: &VerifiableLedgerHeader,
+    timestamp_ms: i64,
+) -> Hash {
+    let mut bytes = Vec::new();
+    // The version of the hashed structure (0 means "with a committed header"):
+    bytes.extend_from_slice(&0u32.to_be_bytes());
+    bytes.extend_from_slice(opaque.as_ref());
+    bytes.extend_from_slice(&header.state_version.to_be_bytes());
+    bytes.extend_from_slice(header.transaction_root.as_ref());
+    bytes.extend_from_slice(header.receipt_root.as_ref());
+    bytes.extend_from_slice(header.state_root.as_ref());
+    bytes.extend_from_slice(&header.epoch.number().to_be_bytes());
+    bytes.extend_from_slice(&header.round.number().to_be_bytes());
+    bytes.extend_from_slice(&header.consensus_parent_round_timestamp_ms.to_be_bytes());
+    bytes.extend_from_slice(&header.proposer_timestamp_ms.to_be_bytes());
+    match &header.next_epoch {
+        Some(next_epoch) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&(next_epoch.validator_set.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(&next_epoch.epoch.number().to_be_bytes());
+            for validator in &next_epoch.validator_set {
+                bytes.extend_from_slice(&validator.key.0);
+                // The consensus' "power" is the stake's 192-bit subunits count, in big-endian:
+                let mut power = validator.stake.attos().to_le_bytes();
+                power.reverse();
+                bytes.extend_from_slice(&power);
+            }
+        }
+        None => bytes.push(0),
+    }
+    if let Some(next_protocol_version) = &header.next_protocol_version {
+        bytes.extend_from_slice(next_protocol_version.as_bytes());
+    }
+    bytes.extend_from_slice(&timestamp_ms.to_be_bytes());
+    hash(bytes)
+}
+
+/// Computes the minimum stake of a quorum, tolerating `f` faulty subunits out of `n = 3f + 1`.
+fn compute_quorum_threshold(total_stake: Decimal) -> Decimal {
+    if total_stake.is_zero() {
+        return Decimal::ZERO;
+    }
+    let acceptable_faults = (total_stake - Decimal::ONE_ATTO) / dec!(3);
+    total_stake - acceptable_faults
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    struct TestValidator {
+        private_key: Secp256k1PrivateKey,
+        info: VerifiableValidator,
+    }
+
+    fn test_validators(seeds: std::ops::Range<u64>) -> Vec<TestValidator> {
+        seeds
+            .map(|seed| {
+                let private_key = Secp256k1PrivateKey::from_u64(seed).unwrap();
+                let key = private_key.public_key();
+                let address = ComponentAddress::preallocated_account_from_public_key(
+                    &PublicKey::Secp256k1(key),
+                );
+                TestValidator {
+                    private_key,
+                    info: VerifiableValidator {
+                        address,
+                        key,
+                        stake: dec!(100),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn test_header(epoch: u64, state_version: u64) -> VerifiableLedgerHeader {
+        VerifiableLedgerHeader {
+            epoch: Epoch::of(epoch),
+            round: Round::of(state_version),
+            state_version,
+            state_root: Hash([0; Hash::LENGTH]),
+            transaction_root: Hash([0; Hash::LENGTH]),
+            receipt_root: Hash([0; Hash::LENGTH]),
+            consensus_parent_round_timestamp_ms: 1000 * state_version as i64,
+            proposer_timestamp_ms: 1000 * state_version as i64,
+            next_epoch: None,
+            next_protocol_version: None,
+        }
+    }
+
+    fn with_next_epoch(
+        mut header: VerifiableLedgerHeader,
+        validators: &[TestValidator],
+    ) -> VerifiableLedgerHeader {
+        header.next_epoch = Some(VerifiableNextEpoch {
+            epoch: Epoch::of(header.epoch.number() + 1),
+            validator_set: validators
+                .iter()
+                .map(|validator| validator.info.clone())
+                .collect(),
+        });
+        header
+    }
+
+    fn signed_proof(
+        header: VerifiableLedgerHeader,
+        signers: &[TestValidator],
+    ) -> VerifiableLedgerProof {
+        let opaque = Hash([7; Hash::LENGTH]);
+        let timestamped_signatures = signers
+            .iter()
+            .map(|signer| {
+                let timestamp_ms = header.proposer_timestamp_ms + 1;
+                let vote_hash = compute_consensus_vote_hash(&opaque, &header, timestamp_ms);
+                VerifiableValidatorSignature {
+                    key: signer.info.key,
+                    validator_address: signer.info.address,
+                    timestamp_ms,
+                    signature: signer.private_key.sign(&vote_hash),
+                }
+            })
+            .collect();
+        VerifiableLedgerProof {
+            ledger_header: header,
+            origin: VerifiableLedgerProofOrigin::Consensus {
+                opaque,
+                timestamped_signatures,
+            },
+        }
+    }
+
+    fn protocol_update_proof(
+        header: VerifiableLedgerHeader,
+        protocol_version_name: &str,
+    ) -> VerifiableLedgerProof {
+        VerifiableLedgerProof {
+            ledger_header: header,
+            origin: VerifiableLedgerProofOrigin::ProtocolUpdate {
+                protocol_version_name: protocol_version_name.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn quorum_signed_chain_is_verified_across_epochs() {
+        let first_validators = test_validators(1..5);
+        let second_validators = test_validators(5..8);
+        let mut verifier = LedgerProofChainVerifier::new(&signed_proof(
+            with_next_epoch(test_header(1, 10), &first_validators),
+            &[],
+        ))
+        .unwrap();
+
+        let outcome = verifier
+            .verify_next(&signed_proof(test_header(2, 15), &first_validators[..3]))
+            .unwrap();
+        assert_eq!(
+            outcome,
+            VerifiedLedgerProofKind::SignedByQuorum {
+                signed_stake: dec!(300)
+            }
+        );
+        verifier
+            .verify_next(&signed_proof(
+                with_next_epoch(test_header(2, 20), &second_validators),
+                &first_validators[1..],
+            ))
+            .unwrap();
+        assert_eq!(verifier.current_epoch(), Epoch::of(3));
+        assert_eq!(verifier.current_validator_set().len(), 3);
+
+        // The previous epoch's validators no longer count:
+        assert_eq!(
+            verifier.verify_next(&signed_proof(test_header(3, 25), &first_validators[..3])),
+            Err(LedgerProofVerificationError::UnknownSigner {
+                validator_address: first_validators[0].info.address
+            })
+        );
+        verifier
+            .verify_next(&signed_proof(test_header(3, 25), &second_validators))
+            .unwrap();
+        assert_eq!(verifier.latest_state_version(), 25);
+    }
+
+    #[test]
+    fn proofs_without_valid_quorum_are_rejected() {
+        let validators = test_validators(1..5);
+        let mut verifier = LedgerProofChainVerifier::new(&signed_proof(
+            with_next_epoch(test_header(1, 10), &validators),
+            &[],
+        ))
+        .unwrap();
+
+        // 2 out of 4 equal stakes is not more than 2/3:
+        assert_eq!(
+            verifier.verify_next(&signed_proof(test_header(2, 11), &validators[..2])),
+            Err(LedgerProofVerificationError::InsufficientSignedStake {
+                signed_stake: dec!(200),
+                required_stake: dec!(400) - (dec!(400) - Decimal::ONE_ATTO) / dec!(3),
+            })
+        );
+
+        // A signature over different contents:
+        let mut tampered_proof = signed_proof(test_header(2, 11), &validators[..3]);
+        tampered_proof.ledger_header.proposer_timestamp_ms += 1;
+        assert_eq!(
+            verifier.verify_next(&tampered_proof),
+            Err(LedgerProofVerificationError::InvalidSignature {
+                validator_address: validators[0].info.address
+            })
+        );
+
+        // A repeated signer:
+        let mut duplicated_proof = signed_proof(test_header(2, 11), &validators[..3]);
+        if let VerifiableLedgerProofOrigin::Consensus {
+            timestamped_signatures,
+            ..
+        } = &mut duplicated_proof.origin
+        {
+            timestamped_signatures[2] = timestamped_signatures[1].clone();
+        }
+        assert_eq!(
+            verifier.verify_next(&duplicated_proof),
+            Err(LedgerProofVerificationError::DuplicateSigner {
+                validator_address: validators[1].info.address
+            })
+        );
+
+        // A skipped epoch change:
+        assert_eq!(
+            verifier.verify_next(&signed_proof(test_header(3, 11), &validators)),
+            Err(LedgerProofVerificationError::UnexpectedEpoch {
+                expected: Epoch::of(2),
+                actual: Epoch::of(3),
+            })
+        );
+
+        // None of the above advanced the verifier:
+        assert_eq!(verifier.latest_state_version(), 10);
+        verifier
+            .verify_next(&signed_proof(test_header(2, 11), &validators))
+            .unwrap();
+        assert_eq!(
+            verifier.verify_next(&signed_proof(test_header(2, 11), &validators)),
+            Err(LedgerProofVerificationError::NonIncreasingStateVersion {
+                previous: 11,
+                actual: 11,
+            })
+        );
+    }
+
+    #[test]
+    fn protocol_update_proofs_are_only_accepted_after_initiation() {
+        let validators = test_validators(1..5);
+        let mut verifier = LedgerProofChainVerifier::new(&signed_proof(
+            with_next_epoch(test_header(1, 10), &validators),
+            &[],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            verifier.verify_next(&protocol_update_proof(test_header(2, 11), "test-update")),
+            Err(LedgerProofVerificationError::UnexpectedProtocolUpdate {
+                protocol_version_name: "test-update".to_string()
+            })
+        );
+
+        let mut init_header = with_next_epoch(test_header(2, 20), &validators);
+        init_header.next_protocol_version = Some("test-update".to_string());
+        verifier
+            .verify_next(&signed_proof(init_header, &validators))
+            .unwrap();
+        assert_eq!(
+            verifier.verify_next(&protocol_update_proof(test_header(3, 21), "test-update")),
+            Ok(VerifiedLedgerProofKind::ProtocolUpdateBatch)
+        );
+        assert_eq!(
+            verifier.verify_next(&protocol_update_proof(
+                with_next_epoch(test_header(3, 22), &validators[..1]),
+                "test-update"
+            )),
+            Err(LedgerProofVerificationError::UnsignedEpochChange)
+        );
+        verifier
+            .verify_next(&protocol_update_proof(test_header(3, 22), "test-update"))
+            .unwrap();
+
+        // The next signed proof concludes the protocol update:
+        verifier
+            .verify_next(&signed_proof(test_header(3, 25), &validators))
+            .unwrap();
+        assert_eq!(
+            verifier.verify_next(&protocol_update_proof(test_header(3, 26), "test-update")),
+            Err(LedgerProofVerificationError::UnexpectedProtocolUpdate {
+                protocol_version_name: "test-update".to_string()
+            })
+        );
+    }
+
+    /// The consensus hashes (and signs) the votes in Java, so this vector is pinned on both sides:
+    /// it must stay in sync with the Java `ConsensusHasherTest`.
+    #[test]
+    fn consensus_vote_hash_matches_java_known_answer() {
+        let signers = test_validators(1..2);
+        let next_validator_key = Secp256k1PrivateKey::from_u64(2).unwrap().public_key();
+        let opaque = Hash([7; Hash::LENGTH]);
+        let header = VerifiableLedgerHeader {
+            epoch: Epoch::of(5),
+            round: Round::of(17),
+            state_version: 1234,
+            state_root: Hash([3; Hash::LENGTH]),
+            transaction_root: Hash([1; Hash::LENGTH]),
+            receipt_root: Hash([2; Hash::LENGTH]),
+            consensus_parent_round_timestamp_ms: 1700000000000,
+            proposer_timestamp_ms: 1700000000500,
+            next_epoch: Some(VerifiableNextEpoch {
+                epoch: Epoch::of(6),
+                validator_set: vec![VerifiableValidator {
+                    address: ComponentAddress::preallocated_account_from_public_key(
+                        &PublicKey::Secp256k1(next_validator_key),
+                    ),
+                    key: next_validator_key,
+                    stake: dec!(100),
+                }],
+            }),
+            next_protocol_version: Some("test-protocol-version".to_string()),
+        };
+        let timestamp_ms = 1700000000600;
+
+        let vote_hash = compute_consensus_vote_hash(&opaque, &header, timestamp_ms);
+        assert_eq!(
+            vote_hash,
+            Hash::from_str("e74568cab85cf044101013c67e35ad05a1b0ba12093f880f27d7c0aa7d479ae9")
+                .unwrap()
+        );
+
+        // The (RFC 6979 deterministic) signature produced by the Java `ECKeyPair`:
+        let java_signature = Secp256k1Signature::from_str(
+            "00e1ae2458d270c5b1c44130912d5e432a6ac8fd560a834d4d3d73f98ffd2f57f26e0373a9770a2c9287b5752f0437f9cdb956ff3f32d3af3ac7fe1254708345b6",
+        )
+        .unwrap();
+        assert!(verify_secp256k1(
+            &vote_hash,
+            &signers[0].info.key,
+            &java_signature
+        ));
+        let mut verifier = LedgerProofChainVerifier::new(&signed_proof(
+            with_next_epoch(test_header(4, 1000), &signers),
+            &[],
+        ))
+        .unwrap();
+        assert_eq!(
+            verifier.verify_next(&VerifiableLedgerProof {
+                ledger_header: header,
+                origin: VerifiableLedgerProofOrigin::Consensus {
+                    opaque,
+                    timestamped_signatures: vec![VerifiableValidatorSignature {
+                        key: signers[0].info.key,
+                        validator_address: signers[0].info.address,
+                        timestamp_ms,
+                        signature: java_signature,
+                    }],
+                },
+            }),
+            Ok(VerifiedLedgerProofKind::SignedByQuorum {
+                signed_stake: dec!(100)
+            })
+        );
+    }
+}
@@ -68,6 +68,8 @@ extern crate serde_json;
 pub mod jni;
 mod mesh_api;
 
+pub use mesh_api::{create_router, create_server, MeshApiState};
+
 #[allow(unused_imports)]
 pub(crate) mod prelude {
     pub(crate) use crate::engine_prelude::*;
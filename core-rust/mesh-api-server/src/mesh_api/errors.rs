@@ -227,3 +227,12 @@ pub(crate) fn assert_matching_network(
     }
     Ok(())
 }
+
+pub(crate) fn assert_not_read_replica(state: &MeshApiState) -> Result<(), ResponseError> {
+    if state.state_manager.read_replica_status.is_some() {
+        return Err(ResponseError::from(ApiError::EndpointNotSupported).with_details(
+            "Endpoint disabled, as this Mesh API is served by a read replica - please use the live node's Mesh API instead".to_string(),
+        ));
+    }
+    Ok(())
+}
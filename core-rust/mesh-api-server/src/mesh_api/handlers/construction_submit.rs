@@ -5,6 +5,7 @@ pub(crate) async fn handle_construction_submit(
     Json(request): Json<models::ConstructionSubmitRequest>,
 ) -> Result<Json<models::TransactionIdentifierResponse>, ResponseError> {
     assert_matching_network(&request.network_identifier, &state.network)?;
+    assert_not_read_replica(&state)?;
 
     let raw = RawNotarizedTransaction::from_hex(&request.signed_transaction).map_err(|_| {
         ResponseError::from(ApiError::InvalidTransaction).with_details(format!(
@@ -29,6 +30,9 @@ pub(crate) async fn handle_construction_submit(
         Ok(_) => Ok(()),
         Err(e) => match e {
             e @ MempoolAddError::PriorityThresholdNotMet { .. } => Err(format!("{:?}", e)),
+            e @ MempoolAddError::ReplacementTipTooLow { .. } => Err(format!("{:?}", e)),
+            e @ MempoolAddError::FeePayerQuotaExceeded { .. } => Err(format!("{:?}", e)),
+            e @ MempoolAddError::NotaryQuotaExceeded { .. } => Err(format!("{:?}", e)),
             MempoolAddError::Duplicate(_) => Ok(()),
             MempoolAddError::Rejected(rejection, _) => match rejection.reason {
                 MempoolRejectionReason::SubintentAlreadyFinalized(_)
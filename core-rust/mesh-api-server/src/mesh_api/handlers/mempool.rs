@@ -5,6 +5,7 @@ pub(crate) async fn handle_mempool(
     Json(request): Json<models::NetworkRequest>,
 ) -> Result<Json<models::MempoolResponse>, ResponseError> {
     assert_matching_network(&request.network_identifier, &state.network)?;
+    assert_not_read_replica(&state)?;
 
     let mapping_context = MappingContext::new(&state.network);
     let mempool = &state.state_manager.mempool_manager;
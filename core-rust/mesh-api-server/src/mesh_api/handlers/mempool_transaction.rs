@@ -5,6 +5,7 @@ pub(crate) async fn handle_mempool_transaction(
     Json(request): Json<models::MempoolTransactionRequest>,
 ) -> Result<Json<models::MempoolTransactionResponse>, ResponseError> {
     assert_matching_network(&request.network_identifier, &state.network)?;
+    assert_not_read_replica(&state)?;
 
     let extraction_context = ExtractionContext::new(&state.network);
     let mapping_context = MappingContext::new(&state.network);
@@ -77,7 +77,7 @@ mod generated;
 pub(crate) use conversions::*;
 pub(crate) use errors::*;
 pub(crate) use helpers::*;
-pub(crate) use server::{create_server, MeshApiServerConfig, MeshApiState};
+pub use server::{create_router, create_server, MeshApiServerConfig, MeshApiState};
 
 pub(crate) mod models {
     pub(crate) use super::generated::models::*;
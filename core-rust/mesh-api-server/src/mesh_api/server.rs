@@ -109,6 +109,20 @@ pub async fn create_server<F>(
 ) where
     F: Future<Output = ()>,
 {
+    let router = create_router(mesh_api_state, metric_registry);
+
+    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
+
+    axum::Server::bind(&bind_addr)
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(shutdown_signal)
+        .await
+        .unwrap();
+}
+
+/// Creates the API's complete router (i.e. the one served by [`create_server()`]), so that a
+/// caller may serve it with extra layers of its own.
+pub fn create_router(mesh_api_state: MeshApiState, metric_registry: &Registry) -> Router {
     let router = Router::new()
         .route("/network/status", post(handle_network_status))
         .route("/network/list", post(handle_network_list))
@@ -139,7 +153,7 @@ pub async fn create_server<F>(
 
     let metrics = Arc::new(MeshApiMetrics::new(metric_registry));
 
-    let prefixed_router = Router::new()
+    Router::new()
         .nest("/mesh", router)
         .route("/", get(handle_missing_mesh_path))
         .layer(CatchPanicLayer::custom(InternalServerErrorResponseForPanic))
@@ -148,15 +162,7 @@ pub async fn create_server<F>(
         .route_layer(MetricsLayer::new(metrics.clone()))
         .layer(map_response(emit_error_response_event))
         .fallback(handle_not_found)
-        .with_state(metrics);
-
-    let bind_addr = bind_addr.parse().expect("Failed to parse bind address");
-
-    axum::Server::bind(&bind_addr)
-        .serve(prefixed_router.into_make_service())
-        .with_graceful_shutdown(shutdown_signal)
-        .await
-        .unwrap();
+        .with_state(metrics)
 }
 
 async fn handle_endpoint_not_supported(
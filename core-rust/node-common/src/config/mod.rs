@@ -78,6 +78,27 @@ pub const DEFAULT_MEMPOOL_MAX_TRANSACTION_COUNT: u32 = 10_000;
 pub struct MempoolConfig {
     pub max_total_transactions_size: u64,
     pub max_transaction_count: u32,
+    /// If set, the mempool contents (and the pending transaction records) survive Node restarts.
+    pub persistence: Option<MempoolPersistenceConfig>,
+    /// If set, a transaction submitting an already-pending intent (or any already-pending
+    /// subintent) replaces the conflicting pending transactions, provided that its tip exceeds
+    /// theirs by at least this many basis points. Otherwise, it is rejected.
+    /// If not set, the conflicting transactions simply co-exist in the mempool.
+    pub replacement_min_tip_bump_basis_points: Option<u32>,
+    /// If set, at most this many transactions locking their fee from the same account (or other
+    /// global component) may be present in the mempool at the same time.
+    pub max_transactions_per_fee_payer: Option<u32>,
+    /// If set, at most this many transactions notarized by the same public key may be present in
+    /// the mempool at the same time.
+    pub max_transactions_per_notary: Option<u32>,
+}
+
+#[derive(Debug, Clone, Sbor)]
+pub struct MempoolPersistenceConfig {
+    /// A file to which the mempool is saved (and from which it is restored on boot-up).
+    pub file_path: String,
+    /// How often to save the mempool, in seconds (it is also saved on a graceful shutdown).
+    pub interval_sec: u32,
 }
 
 impl MempoolConfig {
@@ -85,6 +106,10 @@ impl MempoolConfig {
         Self {
             max_total_transactions_size: DEFAULT_MEMPOOL_MAX_TOTAL_TRANSACTIONS_SIZE,
             max_transaction_count: DEFAULT_MEMPOOL_MAX_TRANSACTION_COUNT,
+            persistence: None,
+            replacement_min_tip_bump_basis_points: None,
+            max_transactions_per_fee_payer: None,
+            max_transactions_per_notary: None,
         }
     }
 
@@ -92,6 +117,10 @@ impl MempoolConfig {
         Self {
             max_total_transactions_size: 10 * 1024 * 1024,
             max_transaction_count: 10,
+            persistence: None,
+            replacement_min_tip_bump_basis_points: None,
+            max_transactions_per_fee_payer: None,
+            max_transactions_per_notary: None,
         }
     }
 }
@@ -101,3 +130,17 @@ impl Default for MempoolConfig {
         MempoolConfig::standard()
     }
 }
+
+/// Resolves one of the well-known networks by its logical name (e.g. `mainnet`), as given by an
+/// operator to the offline tools.
+pub fn resolve_network_definition(logical_name: &str) -> Result<NetworkDefinition, String> {
+    [
+        NetworkDefinition::mainnet(),
+        NetworkDefinition::stokenet(),
+        NetworkDefinition::localnet(),
+        NetworkDefinition::simulator(),
+    ]
+    .into_iter()
+    .find(|network_definition| network_definition.logical_name == logical_name)
+    .ok_or_else(|| format!("unsupported network: {logical_name}"))
+}
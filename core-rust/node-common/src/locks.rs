@@ -226,6 +226,14 @@ impl<T> Mutex<T> {
     pub fn lock(&self) -> impl DerefMut<Target = T> + '_ {
         LockGuard::new(|| self.underlying.lock(), self.listener.clone())
     }
+
+    /// Delegates to the [`parking_lot::Mutex::try_lock()`] (i.e. returns [`None`] instead of
+    /// waiting, if the mutex is currently held).
+    pub fn try_lock(&self) -> Option<impl DerefMut<Target = T> + '_> {
+        self.underlying
+            .try_lock()
+            .map(|underlying| LockGuard::new(|| underlying, self.listener.clone()))
+    }
 }
 
 /// A facade for a [`parking_lot::RwLock`] with a support for an arbitrary [`LockListener`].
@@ -0,0 +1,519 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use rocksdb::{IteratorMode, WriteBatch};
+
+/// A [`WriteableRocks`] whose backend is chosen at runtime: either a persistent [`DirectRocks`], or
+/// a non-persistent [`InMemoryRocks`].
+///
+/// This allows the node's components (which are written against a single database type) to be
+/// instantiated over an in-memory database, e.g. in tests or for ephemeral nodes.
+///
+/// Implementation note:
+/// The backends have different column family handle types, so this wrapper resolves its own
+/// [`ConfigurableCfHandle`]s (by index of the column families it was created with) and translates
+/// them to the backend's handles on each access.
+pub struct ConfigurableRocks {
+    cf_handles: IndexMap<String, ConfigurableCfHandle>,
+    backend: ConfigurableRocksBackend,
+}
+
+enum ConfigurableRocksBackend {
+    RocksDb(DirectRocks),
+    InMemory(InMemoryRocks),
+}
+
+/// A handle of a column family within [`ConfigurableRocks`].
+pub struct ConfigurableCfHandle {
+    index: usize,
+}
+
+impl ConfigurableRocks {
+    /// Wraps the given RocksDB instance, which must have all the given column families.
+    pub fn rocks_db(
+        rocks: DirectRocks,
+        column_family_names: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let cf_handles = Self::resolve_cf_handles(column_family_names);
+        for name in cf_handles.keys() {
+            rocks.db.cf_handle(name).expect(name);
+        }
+        Self {
+            cf_handles,
+            backend: ConfigurableRocksBackend::RocksDb(rocks),
+        }
+    }
+
+    /// Creates an empty in-memory instance having the given column families.
+    pub fn in_memory(column_family_names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let cf_handles = Self::resolve_cf_handles(column_family_names);
+        let rocks = InMemoryRocks::new(cf_handles.keys().cloned());
+        Self {
+            cf_handles,
+            backend: ConfigurableRocksBackend::InMemory(rocks),
+        }
+    }
+
+    /// Returns the underlying RocksDB instance, or [`None`] if this is an in-memory instance.
+    pub fn as_rocks_db(&self) -> Option<&DirectRocks> {
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => Some(rocks),
+            ConfigurableRocksBackend::InMemory(_) => None,
+        }
+    }
+
+    /// Returns `true` if this instance is persistent (i.e. backed by RocksDB).
+    pub fn is_persistent(&self) -> bool {
+        self.as_rocks_db().is_some()
+    }
+
+    fn resolve_cf_handles(
+        column_family_names: impl IntoIterator<Item = impl Into<String>>,
+    ) -> IndexMap<String, ConfigurableCfHandle> {
+        column_family_names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| (name.into(), ConfigurableCfHandle { index }))
+            .collect()
+    }
+
+    fn resolve_cf_handle(&self, name: &str) -> &ConfigurableCfHandle {
+        self.cf_handles.get(name).expect(name)
+    }
+
+    fn cf_name(&self, cf: &ConfigurableCfHandle) -> &str {
+        let (name, _) = self
+            .cf_handles
+            .get_index(cf.index)
+            .expect("handle of a known column family");
+        name
+    }
+}
+
+impl ReadableRocks for ConfigurableRocks {
+    type CfHandle = ConfigurableCfHandle;
+
+    fn cf_handle(&self, name: &str) -> &ConfigurableCfHandle {
+        self.resolve_cf_handle(name)
+    }
+
+    fn iterator_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        mode: IteratorMode,
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        let name = self.cf_name(cf);
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => {
+                rocks.iterator_cf(rocks.cf_handle(name), mode)
+            }
+            ConfigurableRocksBackend::InMemory(rocks) => {
+                rocks.iterator_cf(rocks.cf_handle(name), mode)
+            }
+        }
+    }
+
+    fn prefix_iterator_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        from: &[u8],
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        let name = self.cf_name(cf);
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => {
+                rocks.prefix_iterator_cf(rocks.cf_handle(name), from)
+            }
+            ConfigurableRocksBackend::InMemory(rocks) => {
+                rocks.prefix_iterator_cf(rocks.cf_handle(name), from)
+            }
+        }
+    }
+
+    fn get_pinned_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        key: impl AsRef<[u8]>,
+    ) -> Option<impl AsRef<[u8]> + '_> {
+        let name = self.cf_name(cf);
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => rocks
+                .get_pinned_cf(rocks.cf_handle(name), key)
+                .map(EitherBytes::Left),
+            ConfigurableRocksBackend::InMemory(rocks) => rocks
+                .get_pinned_cf(rocks.cf_handle(name), key)
+                .map(EitherBytes::Right),
+        }
+    }
+
+    fn multi_get_cf<'a>(
+        &'a self,
+        keys: impl IntoIterator<Item = (&'a ConfigurableCfHandle, impl AsRef<[u8]>)>,
+    ) -> Vec<Option<Vec<u8>>> {
+        let keys = keys.into_iter().map(|(cf, key)| (self.cf_name(cf), key));
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => {
+                rocks.multi_get_cf(keys.map(|(name, key)| (rocks.cf_handle(name), key)))
+            }
+            ConfigurableRocksBackend::InMemory(rocks) => {
+                rocks.multi_get_cf(keys.map(|(name, key)| (rocks.cf_handle(name), key)))
+            }
+        }
+    }
+}
+
+impl WriteableRocks for ConfigurableRocks {
+    type WriteBatch = ConfigurableWriteBatch;
+    type Snapshot<'s> = ConfigurableSnapshotRocks<'s>;
+
+    fn write(&self, batch: ConfigurableWriteBatch) {
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => {
+                let mut native_batch = WriteBatch::default();
+                batch.replay_into(&mut native_batch, |cf| rocks.cf_handle(self.cf_name(cf)));
+                rocks.write(native_batch);
+            }
+            ConfigurableRocksBackend::InMemory(rocks) => {
+                let mut native_batch = InMemoryWriteBatch::default();
+                batch.replay_into(&mut native_batch, |cf| rocks.cf_handle(self.cf_name(cf)));
+                rocks.write(native_batch);
+            }
+        }
+    }
+
+    fn snapshot(&self) -> ConfigurableSnapshotRocks<'_> {
+        let backend = match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => {
+                ConfigurableSnapshotBackend::RocksDb(rocks.snapshot())
+            }
+            ConfigurableRocksBackend::InMemory(rocks) => {
+                ConfigurableSnapshotBackend::InMemory(rocks.snapshot())
+            }
+        };
+        ConfigurableSnapshotRocks {
+            rocks: self,
+            backend,
+        }
+    }
+
+    fn compact_range_cf(&self, cf_name: &str, from: &[u8], to: &[u8]) -> usize {
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => rocks.compact_range_cf(cf_name, from, to),
+            ConfigurableRocksBackend::InMemory(rocks) => rocks.compact_range_cf(cf_name, from, to),
+        }
+    }
+}
+
+impl SecondaryRocks for ConfigurableRocks {
+    fn try_catchup_with_primary(&self) {
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => rocks.try_catchup_with_primary(),
+            ConfigurableRocksBackend::InMemory(_) => {
+                // An in-memory instance cannot have a primary - there is nothing to catch up with.
+            }
+        }
+    }
+}
+
+impl CheckpointableRocks for ConfigurableRocks {
+    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), String> {
+        match &self.backend {
+            ConfigurableRocksBackend::RocksDb(rocks) => rocks.create_checkpoint(checkpoint_path),
+            ConfigurableRocksBackend::InMemory(_) => {
+                Err("an in-memory database does not support checkpoints".to_string())
+            }
+        }
+    }
+}
+
+/// A snapshot of [`ConfigurableRocks`].
+pub struct ConfigurableSnapshotRocks<'r> {
+    rocks: &'r ConfigurableRocks,
+    backend: ConfigurableSnapshotBackend<'r>,
+}
+
+enum ConfigurableSnapshotBackend<'r> {
+    RocksDb(SnapshotRocks<'r>),
+    InMemory(InMemorySnapshotRocks<'r>),
+}
+
+impl ReadableRocks for ConfigurableSnapshotRocks<'_> {
+    type CfHandle = ConfigurableCfHandle;
+
+    fn cf_handle(&self, name: &str) -> &ConfigurableCfHandle {
+        self.rocks.resolve_cf_handle(name)
+    }
+
+    fn iterator_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        mode: IteratorMode,
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        let name = self.rocks.cf_name(cf);
+        match &self.backend {
+            ConfigurableSnapshotBackend::RocksDb(snapshot) => {
+                snapshot.iterator_cf(snapshot.cf_handle(name), mode)
+            }
+            ConfigurableSnapshotBackend::InMemory(snapshot) => {
+                snapshot.iterator_cf(snapshot.cf_handle(name), mode)
+            }
+        }
+    }
+
+    fn prefix_iterator_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        from: &[u8],
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        let name = self.rocks.cf_name(cf);
+        match &self.backend {
+            ConfigurableSnapshotBackend::RocksDb(snapshot) => {
+                snapshot.prefix_iterator_cf(snapshot.cf_handle(name), from)
+            }
+            ConfigurableSnapshotBackend::InMemory(snapshot) => {
+                snapshot.prefix_iterator_cf(snapshot.cf_handle(name), from)
+            }
+        }
+    }
+
+    fn get_pinned_cf(
+        &self,
+        cf: &ConfigurableCfHandle,
+        key: impl AsRef<[u8]>,
+    ) -> Option<impl AsRef<[u8]> + '_> {
+        let name = self.rocks.cf_name(cf);
+        match &self.backend {
+            ConfigurableSnapshotBackend::RocksDb(snapshot) => snapshot
+                .get_pinned_cf(snapshot.cf_handle(name), key)
+                .map(EitherBytes::Left),
+            ConfigurableSnapshotBackend::InMemory(snapshot) => snapshot
+                .get_pinned_cf(snapshot.cf_handle(name), key)
+                .map(EitherBytes::Right),
+        }
+    }
+
+    fn multi_get_cf<'a>(
+        &'a self,
+        keys: impl IntoIterator<Item = (&'a ConfigurableCfHandle, impl AsRef<[u8]>)>,
+    ) -> Vec<Option<Vec<u8>>> {
+        let keys = keys
+            .into_iter()
+            .map(|(cf, key)| (self.rocks.cf_name(cf), key));
+        match &self.backend {
+            ConfigurableSnapshotBackend::RocksDb(snapshot) => {
+                snapshot.multi_get_cf(keys.map(|(name, key)| (snapshot.cf_handle(name), key)))
+            }
+            ConfigurableSnapshotBackend::InMemory(snapshot) => {
+                snapshot.multi_get_cf(keys.map(|(name, key)| (snapshot.cf_handle(name), key)))
+            }
+        }
+    }
+}
+
+impl CheckpointableRocks for ConfigurableSnapshotRocks<'_> {
+    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), String> {
+        match &self.backend {
+            ConfigurableSnapshotBackend::RocksDb(snapshot) => {
+                snapshot.create_checkpoint(checkpoint_path)
+            }
+            ConfigurableSnapshotBackend::InMemory(_) => {
+                Err("an in-memory database does not support checkpoints".to_string())
+            }
+        }
+    }
+}
+
+/// A batch of updates to be written to [`ConfigurableRocks`].
+///
+/// The updates are only buffered here, and then replayed into the backend's native batch on
+/// [`WriteableRocks::write()`] (since a [`Default`] batch cannot know its backend up-front).
+#[derive(Default)]
+pub struct ConfigurableWriteBatch {
+    operations: Vec<(usize, ConfigurableWriteOperation)>,
+    size_in_bytes: usize,
+}
+
+enum ConfigurableWriteOperation {
+    Put { key: Vec<u8>, value: Vec<u8> },
+    Delete { key: Vec<u8> },
+    DeleteRange { from: Vec<u8>, to: Vec<u8> },
+}
+
+impl ConfigurableWriteBatch {
+    fn replay_into<'h, B: RocksWriteBatch>(
+        self,
+        native_batch: &mut B,
+        resolve_cf: impl Fn(&ConfigurableCfHandle) -> &'h B::CfHandle,
+    ) where
+        B::CfHandle: 'h,
+    {
+        for (cf_index, operation) in self.operations {
+            let cf = resolve_cf(&ConfigurableCfHandle { index: cf_index });
+            match operation {
+                ConfigurableWriteOperation::Put { key, value } => {
+                    native_batch.put_cf(cf, key, value)
+                }
+                ConfigurableWriteOperation::Delete { key } => native_batch.delete_cf(cf, key),
+                ConfigurableWriteOperation::DeleteRange { from, to } => {
+                    native_batch.delete_range_cf(cf, from, to)
+                }
+            }
+        }
+    }
+}
+
+impl RocksWriteBatch for ConfigurableWriteBatch {
+    type CfHandle = ConfigurableCfHandle;
+
+    fn put_cf(&mut self, cf: &ConfigurableCfHandle, key: Vec<u8>, value: Vec<u8>) {
+        self.size_in_bytes += key.len() + value.len();
+        self.operations
+            .push((cf.index, ConfigurableWriteOperation::Put { key, value }));
+    }
+
+    fn delete_cf(&mut self, cf: &ConfigurableCfHandle, key: Vec<u8>) {
+        self.size_in_bytes += key.len();
+        self.operations
+            .push((cf.index, ConfigurableWriteOperation::Delete { key }));
+    }
+
+    fn delete_range_cf(&mut self, cf: &ConfigurableCfHandle, from: Vec<u8>, to: Vec<u8>) {
+        self.size_in_bytes += from.len() + to.len();
+        self.operations.push((
+            cf.index,
+            ConfigurableWriteOperation::DeleteRange { from, to },
+        ));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+}
+
+/// Value bytes coming from one of the two backends.
+enum EitherBytes<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: AsRef<[u8]>, R: AsRef<[u8]>> AsRef<[u8]> for EitherBytes<L, R> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            EitherBytes::Left(bytes) => bytes.as_ref(),
+            EitherBytes::Right(bytes) => bytes.as_ref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CF: &str = "test";
+
+    fn keys(rocks: &impl ReadableRocks<CfHandle = ConfigurableCfHandle>) -> Vec<Vec<u8>> {
+        rocks
+            .iterator_cf(rocks.cf_handle(TEST_CF), IteratorMode::Start)
+            .map(|(key, _)| key.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn in_memory_backend_applies_batches_and_isolates_snapshots() {
+        let rocks = ConfigurableRocks::in_memory([TEST_CF]);
+        assert!(!rocks.is_persistent());
+
+        let cf = rocks.cf_handle(TEST_CF);
+        let mut batch = ConfigurableWriteBatch::default();
+        batch.put_cf(cf, vec![1], vec![10]);
+        batch.put_cf(cf, vec![2], vec![20]);
+        batch.put_cf(cf, vec![3], vec![30]);
+        rocks.write(batch);
+
+        let snapshot = rocks.snapshot();
+        let mut batch = ConfigurableWriteBatch::default();
+        batch.delete_range_cf(cf, vec![1], vec![3]);
+        batch.delete_cf(cf, vec![3]);
+        batch.put_cf(cf, vec![4], vec![40]);
+        rocks.write(batch);
+
+        assert_eq!(keys(&snapshot), vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(keys(&rocks), vec![vec![4]]);
+        assert_eq!(
+            snapshot
+                .get_pinned_cf(snapshot.cf_handle(TEST_CF), [2])
+                .map(|value| value.as_ref().to_vec()),
+            Some(vec![20])
+        );
+        assert_eq!(
+            rocks.multi_get_cf([(cf, vec![2u8]), (cf, vec![4u8])]),
+            vec![None, Some(vec![40])]
+        );
+        assert!(rocks.create_checkpoint(PathBuf::from("unused")).is_err());
+    }
+}
@@ -0,0 +1,467 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use parking_lot::RwLock;
+use rocksdb::{Direction, IteratorMode};
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+/// An in-memory, non-persistent implementation of the [`WriteableRocks`].
+///
+/// It is meant for use-cases which do not need durability, but benefit from a fast and
+/// deterministic setup and teardown (e.g. tests, or ephemeral nodes used only for previews).
+///
+/// The entries of each column family live in an ordered map (sorted lexicographically by key
+/// bytes, exactly like in RocksDB). The maps are shared copy-on-write, which makes the snapshots
+/// cheap: a map is only cloned when it gets written to while some snapshot still references it.
+pub struct InMemoryRocks {
+    cf_handles: IndexMap<String, InMemoryCfHandle>,
+    column_families: RwLock<Vec<Arc<InMemoryCfEntries>>>,
+}
+
+type InMemoryCfEntries = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// A handle of a column family within [`InMemoryRocks`].
+pub struct InMemoryCfHandle {
+    index: usize,
+}
+
+impl InMemoryRocks {
+    /// Creates an empty instance having the given column families.
+    pub fn new(column_family_names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let cf_handles = column_family_names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| (name.into(), InMemoryCfHandle { index }))
+            .collect::<IndexMap<_, _>>();
+        let column_families = RwLock::new(
+            (0..cf_handles.len())
+                .map(|_| Arc::new(InMemoryCfEntries::new()))
+                .collect(),
+        );
+        Self {
+            cf_handles,
+            column_families,
+        }
+    }
+
+    fn resolve_cf_handle(&self, name: &str) -> &InMemoryCfHandle {
+        self.cf_handles.get(name).expect(name)
+    }
+
+    fn cf_entries(&self, cf: &InMemoryCfHandle) -> Arc<InMemoryCfEntries> {
+        self.column_families.read()[cf.index].clone()
+    }
+}
+
+impl ReadableRocks for InMemoryRocks {
+    type CfHandle = InMemoryCfHandle;
+
+    fn cf_handle(&self, name: &str) -> &InMemoryCfHandle {
+        self.resolve_cf_handle(name)
+    }
+
+    fn iterator_cf(
+        &self,
+        cf: &InMemoryCfHandle,
+        mode: IteratorMode,
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        Box::new(InMemoryIterator::new(self.cf_entries(cf), mode))
+    }
+
+    fn get_pinned_cf(
+        &self,
+        cf: &InMemoryCfHandle,
+        key: impl AsRef<[u8]>,
+    ) -> Option<impl AsRef<[u8]> + '_> {
+        self.column_families.read()[cf.index]
+            .get(key.as_ref())
+            .cloned()
+    }
+
+    fn multi_get_cf<'a>(
+        &'a self,
+        keys: impl IntoIterator<Item = (&'a InMemoryCfHandle, impl AsRef<[u8]>)>,
+    ) -> Vec<Option<Vec<u8>>> {
+        let column_families = self.column_families.read();
+        keys.into_iter()
+            .map(|(cf, key)| column_families[cf.index].get(key.as_ref()).cloned())
+            .collect()
+    }
+}
+
+impl WriteableRocks for InMemoryRocks {
+    type WriteBatch = InMemoryWriteBatch;
+    type Snapshot<'s> = InMemorySnapshotRocks<'s>;
+
+    fn write(&self, batch: InMemoryWriteBatch) {
+        let mut column_families = self.column_families.write();
+        for (cf_index, operation) in batch.operations {
+            let entries = Arc::make_mut(&mut column_families[cf_index]);
+            match operation {
+                InMemoryWriteOperation::Put { key, value } => {
+                    entries.insert(key, value);
+                }
+                InMemoryWriteOperation::Delete { key } => {
+                    entries.remove(&key);
+                }
+                InMemoryWriteOperation::DeleteRange { from, to } => {
+                    if from >= to {
+                        continue; // an empty range (which `BTreeMap::range()` would panic on)
+                    }
+                    let keys = entries
+                        .range(from..to)
+                        .map(|(key, _)| key.clone())
+                        .collect::<Vec<_>>();
+                    for key in keys {
+                        entries.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+
+    fn snapshot(&self) -> InMemorySnapshotRocks<'_> {
+        InMemorySnapshotRocks {
+            rocks: self,
+            column_families: self.column_families.read().clone(),
+        }
+    }
+
+    fn compact_range_cf(&self, _cf_name: &str, _from: &[u8], _to: &[u8]) -> usize {
+        0 // the deletes are applied eagerly, so there is nothing to reclaim
+    }
+}
+
+/// A snapshot of [`InMemoryRocks`].
+pub struct InMemorySnapshotRocks<'r> {
+    rocks: &'r InMemoryRocks,
+    column_families: Vec<Arc<InMemoryCfEntries>>,
+}
+
+impl ReadableRocks for InMemorySnapshotRocks<'_> {
+    type CfHandle = InMemoryCfHandle;
+
+    fn cf_handle(&self, name: &str) -> &InMemoryCfHandle {
+        self.rocks.resolve_cf_handle(name)
+    }
+
+    fn iterator_cf(
+        &self,
+        cf: &InMemoryCfHandle,
+        mode: IteratorMode,
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        Box::new(InMemoryIterator::new(
+            self.column_families[cf.index].clone(),
+            mode,
+        ))
+    }
+
+    fn get_pinned_cf(
+        &self,
+        cf: &InMemoryCfHandle,
+        key: impl AsRef<[u8]>,
+    ) -> Option<impl AsRef<[u8]> + '_> {
+        self.column_families[cf.index].get(key.as_ref())
+    }
+
+    fn multi_get_cf<'a>(
+        &'a self,
+        keys: impl IntoIterator<Item = (&'a InMemoryCfHandle, impl AsRef<[u8]>)>,
+    ) -> Vec<Option<Vec<u8>>> {
+        keys.into_iter()
+            .map(|(cf, key)| self.column_families[cf.index].get(key.as_ref()).cloned())
+            .collect()
+    }
+}
+
+/// A batch of updates to be written to [`InMemoryRocks`].
+#[derive(Default)]
+pub struct InMemoryWriteBatch {
+    operations: Vec<(usize, InMemoryWriteOperation)>,
+    size_in_bytes: usize,
+}
+
+enum InMemoryWriteOperation {
+    Put { key: Vec<u8>, value: Vec<u8> },
+    Delete { key: Vec<u8> },
+    DeleteRange { from: Vec<u8>, to: Vec<u8> },
+}
+
+impl RocksWriteBatch for InMemoryWriteBatch {
+    type CfHandle = InMemoryCfHandle;
+
+    fn put_cf(&mut self, cf: &InMemoryCfHandle, key: Vec<u8>, value: Vec<u8>) {
+        self.size_in_bytes += key.len() + value.len();
+        self.operations
+            .push((cf.index, InMemoryWriteOperation::Put { key, value }));
+    }
+
+    fn delete_cf(&mut self, cf: &InMemoryCfHandle, key: Vec<u8>) {
+        self.size_in_bytes += key.len();
+        self.operations
+            .push((cf.index, InMemoryWriteOperation::Delete { key }));
+    }
+
+    fn delete_range_cf(&mut self, cf: &InMemoryCfHandle, from: Vec<u8>, to: Vec<u8>) {
+        self.size_in_bytes += from.len() + to.len();
+        self.operations
+            .push((cf.index, InMemoryWriteOperation::DeleteRange { from, to }));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+}
+
+/// An iterator over a (frozen) column family's entries.
+///
+/// It remembers the last returned key and re-seeks right past it on each step, which keeps the
+/// iteration lazy without holding any borrow of the underlying map.
+struct InMemoryIterator {
+    entries: Arc<InMemoryCfEntries>,
+    direction: Direction,
+    next_bound: Bound<Vec<u8>>,
+}
+
+impl InMemoryIterator {
+    fn new(entries: Arc<InMemoryCfEntries>, mode: IteratorMode) -> Self {
+        let (direction, next_bound) = match mode {
+            IteratorMode::Start => (Direction::Forward, Bound::Unbounded),
+            IteratorMode::End => (Direction::Reverse, Bound::Unbounded),
+            IteratorMode::From(key, direction) => (direction, Bound::Included(key.to_vec())),
+        };
+        Self {
+            entries,
+            direction,
+            next_bound,
+        }
+    }
+}
+
+impl Iterator for InMemoryIterator {
+    type Item = KVBytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bound = self.next_bound.as_ref();
+        let (key, value) = match self.direction {
+            Direction::Forward => self.entries.range((bound, Bound::Unbounded)).next(),
+            Direction::Reverse => self.entries.range((Bound::Unbounded, bound)).next_back(),
+        }?;
+        self.next_bound = Bound::Excluded(key.clone());
+        Some((
+            key.clone().into_boxed_slice(),
+            value.clone().into_boxed_slice(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CF: &str = "test";
+
+    fn put_all(rocks: &InMemoryRocks, entries: Vec<(Vec<u8>, Vec<u8>)>) {
+        let mut batch = InMemoryWriteBatch::default();
+        for (key, value) in entries {
+            batch.put_cf(rocks.cf_handle(TEST_CF), key, value);
+        }
+        rocks.write(batch);
+    }
+
+    fn keys(
+        rocks: &impl ReadableRocks<CfHandle = InMemoryCfHandle>,
+        mode: IteratorMode,
+    ) -> Vec<Vec<u8>> {
+        rocks
+            .iterator_cf(rocks.cf_handle(TEST_CF), mode)
+            .map(|(key, _)| key.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn iterates_in_lexicographical_order_from_any_key() {
+        let rocks = InMemoryRocks::new([TEST_CF]);
+        put_all(
+            &rocks,
+            vec![
+                (vec![2], vec![]),
+                (vec![1, 5], vec![]),
+                (vec![1], vec![]),
+                (vec![3], vec![]),
+            ],
+        );
+
+        assert_eq!(
+            keys(&rocks, IteratorMode::Start),
+            vec![vec![1], vec![1, 5], vec![2], vec![3]]
+        );
+        assert_eq!(
+            keys(&rocks, IteratorMode::End),
+            vec![vec![3], vec![2], vec![1, 5], vec![1]]
+        );
+        assert_eq!(
+            keys(&rocks, IteratorMode::From(&[1, 7], Direction::Forward)),
+            vec![vec![2], vec![3]]
+        );
+        assert_eq!(
+            keys(&rocks, IteratorMode::From(&[2], Direction::Reverse)),
+            vec![vec![2], vec![1, 5], vec![1]]
+        );
+    }
+
+    #[test]
+    fn snapshot_is_not_affected_by_subsequent_writes() {
+        let rocks = InMemoryRocks::new([TEST_CF]);
+        put_all(&rocks, vec![(vec![1], vec![10]), (vec![2], vec![20])]);
+
+        let snapshot = rocks.snapshot();
+        let mut batch = InMemoryWriteBatch::default();
+        let cf = rocks.cf_handle(TEST_CF);
+        batch.delete_range_cf(cf, vec![], vec![2]);
+        batch.put_cf(cf, vec![2], vec![21]);
+        batch.put_cf(cf, vec![3], vec![30]);
+        rocks.write(batch);
+
+        assert_eq!(keys(&snapshot, IteratorMode::Start), vec![vec![1], vec![2]]);
+        assert_eq!(keys(&rocks, IteratorMode::Start), vec![vec![2], vec![3]]);
+        assert_eq!(
+            rocks.multi_get_cf([(cf, vec![1u8]), (cf, vec![2u8])]),
+            vec![None, Some(vec![21])]
+        );
+        assert_eq!(
+            snapshot.multi_get_cf([(cf, vec![1u8]), (cf, vec![2u8])]),
+            vec![Some(vec![10]), Some(vec![20])]
+        );
+    }
+
+    struct TestCf;
+    impl DefaultCf for TestCf {
+        type Key = Vec<u8>;
+        type Value = Vec<u8>;
+
+        const NAME_SOURCE: &'static str = TEST_CF;
+        type KeyCodec = DirectDbCodec;
+        type ValueCodec = DirectDbCodec;
+    }
+
+    #[test]
+    fn coalesced_delete_uses_ranges_only_for_adjacent_keys() {
+        let rocks = InMemoryRocks::new([TEST_CF]);
+        put_all(
+            &rocks,
+            (1..=9u8).map(|key| (vec![key], vec![])).collect::<Vec<_>>(),
+        );
+
+        let deleted_keys = [
+            vec![1],
+            vec![2],
+            vec![3],
+            vec![5],
+            vec![7],
+            vec![8],
+            vec![10],
+        ];
+        let range_deleted_count = TypedDbContext::new(&rocks, BufferedWriteSupport::new(&rocks))
+            .cf(TestCf)
+            .delete_many_coalesced(deleted_keys.iter(), 2);
+
+        // The runs [1, 2, 3] and [7, 8] are range-deleted; the others are point-deleted (and the
+        // absent key 10 is a no-op):
+        assert_eq!(range_deleted_count, 5);
+        assert_eq!(
+            keys(&rocks, IteratorMode::Start),
+            vec![vec![4], vec![6], vec![9]]
+        );
+    }
+
+    #[test]
+    fn coalesced_delete_uses_ranges_only_for_dense_keys() {
+        let rocks = InMemoryRocks::new([TEST_CF]);
+        put_all(
+            &rocks,
+            [1u8, 3, 5, 7, 8, 9]
+                .into_iter()
+                .map(|key| (vec![key], vec![]))
+                .collect::<Vec<_>>(),
+        );
+
+        let deleted_keys = [vec![1], vec![3], vec![5], vec![8], vec![9]];
+        let range_deleted_count = TypedDbContext::new(&rocks, BufferedWriteSupport::new(&rocks))
+            .cf(TestCf)
+            .delete_many_coalesced(deleted_keys.iter(), 2);
+
+        // The keys [1, 3, 5] are adjacent in the database, but sparse (so they are point-deleted),
+        // while the dense run [8, 9] is range-deleted:
+        assert_eq!(range_deleted_count, 2);
+        assert_eq!(keys(&rocks, IteratorMode::Start), vec![vec![7]]);
+    }
+}
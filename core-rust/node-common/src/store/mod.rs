@@ -1,5 +1,9 @@
+mod configurable_rocks;
+mod in_memory_rocks;
 mod rocks_db;
 mod typed_cf_api;
 
+pub use configurable_rocks::*;
+pub use in_memory_rocks::*;
 pub use rocks_db::*;
 pub use typed_cf_api::*;
@@ -64,9 +64,7 @@
 
 use crate::prelude::*;
 use rocksdb::checkpoint::Checkpoint;
-use rocksdb::{
-    AsColumnFamilyRef, ColumnFamily, DBPinnableSlice, IteratorMode, Snapshot, WriteBatch, DB,
-};
+use rocksdb::{ColumnFamily, Direction, IteratorMode, ReadOptions, Snapshot, WriteBatch, DB};
 
 use std::path::PathBuf;
 
@@ -80,26 +78,47 @@ pub type KVBytes = (Box<[u8]>, Box<[u8]>);
 /// itself, while we desperately need it to abstract the DB-reading code from the actual source of
 /// data.
 ///
+/// Despite its name, the trait does not require an actual RocksDB: any ordered key-value store
+/// with column families may implement it (see e.g. [`InMemoryRocks`]). Only the [`IteratorMode`]
+/// and [`Direction`](rocksdb::Direction) enums are borrowed from the `rocksdb` crate, as a plain vocabulary.
+///
 /// A note on changed error handling:
 /// The original methods typically return [`Result`]s. Our trait assumes panics instead, since we
 /// treat all database access errors as fatal anyways.
 pub trait ReadableRocks {
+    /// A backend-specific handle of a column family.
+    type CfHandle;
+
     /// Resolves the column family by name.
-    fn cf_handle(&self, name: &str) -> &ColumnFamily;
+    fn cf_handle(&self, name: &str) -> &Self::CfHandle;
 
     /// Starts iteration over key-value pairs, according to the given [`IteratorMode`].
     fn iterator_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &Self::CfHandle,
         mode: IteratorMode,
     ) -> Box<dyn Iterator<Item = KVBytes> + '_>;
 
+    /// Starts a forward iteration over key-value pairs, from the given key (inclusive), which *may*
+    /// stop after the last key sharing the given key's prefix (as defined by the column family's
+    /// prefix extractor, if configured).
+    ///
+    /// This allows the backend to use its prefix Bloom filters. The callers must still bound the
+    /// iteration on their own (and must not rely on any key outside of the prefix).
+    fn prefix_iterator_cf(
+        &self,
+        cf: &Self::CfHandle,
+        from: &[u8],
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        self.iterator_cf(cf, IteratorMode::From(from, Direction::Forward))
+    }
+
     /// Gets a single value by key.
     fn get_pinned_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &Self::CfHandle,
         key: impl AsRef<[u8]>,
-    ) -> Option<DBPinnableSlice>;
+    ) -> Option<impl AsRef<[u8]> + '_>;
 
     /// Gets multiple values by keys.
     ///
@@ -109,19 +128,57 @@ pub trait ReadableRocks {
     /// TODO(when the rustc feature mentioned above becomes stable): get rid of the `<'a>`.
     fn multi_get_cf<'a>(
         &'a self,
-        keys: impl IntoIterator<Item = (&'a (impl AsColumnFamilyRef + 'a), impl AsRef<[u8]>)>,
+        keys: impl IntoIterator<Item = (&'a Self::CfHandle, impl AsRef<[u8]>)>,
     ) -> Vec<Option<Vec<u8>>>;
 }
 
 /// A write-supporting extension of the [`ReadableRocks`].
 ///
-/// Naturally, it is expected that only a "direct" RocksDB instance can implement this one.
+/// Naturally, it is expected that only a "direct" database instance (i.e. not a snapshot) can
+/// implement this one.
 pub trait WriteableRocks: ReadableRocks {
+    /// A backend-specific batch of updates.
+    type WriteBatch: RocksWriteBatch<CfHandle = Self::CfHandle>;
+
+    /// A backend-specific snapshot type.
+    type Snapshot<'s>: ReadableRocks
+    where
+        Self: 's;
+
     /// Atomically writes the given batch of updates.
-    fn write(&self, batch: WriteBatch);
+    fn write(&self, batch: Self::WriteBatch);
 
     /// Returns a snapshot of the current state.
-    fn snapshot(&self) -> SnapshotRocks;
+    fn snapshot(&self) -> Self::Snapshot<'_>;
+
+    /// Compacts the given key range ("from inclusive, to exclusive") of the column family of the
+    /// given name, physically dropping the deleted entries (and their tombstones).
+    /// Returns the (approximate) number of reclaimed bytes.
+    ///
+    /// This is a blocking and potentially long operation, meant only for background processes
+    /// (e.g. the GCs, right after deleting a large range of keys).
+    fn compact_range_cf(&self, cf_name: &str, from: &[u8], to: &[u8]) -> usize;
+}
+
+/// A batch of updates to be atomically written by [`WriteableRocks::write()`].
+pub trait RocksWriteBatch: Default {
+    /// A backend-specific handle of a column family (see [`ReadableRocks::CfHandle`]).
+    type CfHandle;
+
+    /// Upserts the given value at the given key.
+    fn put_cf(&mut self, cf: &Self::CfHandle, key: Vec<u8>, value: Vec<u8>);
+
+    /// Deletes the entry of the given key.
+    fn delete_cf(&mut self, cf: &Self::CfHandle, key: Vec<u8>);
+
+    /// Deletes all entries from the given key range ("from inclusive, to exclusive").
+    fn delete_range_cf(&mut self, cf: &Self::CfHandle, from: Vec<u8>, to: Vec<u8>);
+
+    /// Returns `true` if there are no updates in this batch.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the (approximate) size of the batched data, in bytes.
+    fn size_in_bytes(&self) -> usize;
 }
 
 /// A [`ReadableRocks`] instance opened as secondary instance.
@@ -133,7 +190,33 @@ pub trait SecondaryRocks: ReadableRocks {
 
 /// RocksDB checkpoint support.
 pub trait CheckpointableRocks {
-    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), rocksdb::Error>;
+    /// Creates a checkpoint (i.e. a consistent, openable copy of the database) at the given path.
+    /// Returns a human-readable description of a failure.
+    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), String>;
+}
+
+impl RocksWriteBatch for WriteBatch {
+    type CfHandle = ColumnFamily;
+
+    fn put_cf(&mut self, cf: &ColumnFamily, key: Vec<u8>, value: Vec<u8>) {
+        WriteBatch::put_cf(self, cf, key, value);
+    }
+
+    fn delete_cf(&mut self, cf: &ColumnFamily, key: Vec<u8>) {
+        WriteBatch::delete_cf(self, cf, key);
+    }
+
+    fn delete_range_cf(&mut self, cf: &ColumnFamily, from: Vec<u8>, to: Vec<u8>) {
+        WriteBatch::delete_range_cf(self, cf, from, to);
+    }
+
+    fn is_empty(&self) -> bool {
+        WriteBatch::is_empty(self)
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        WriteBatch::size_in_bytes(self)
+    }
 }
 
 /// Direct RocksDB instance.
@@ -142,33 +225,51 @@ pub struct DirectRocks {
 }
 
 impl ReadableRocks for DirectRocks {
+    type CfHandle = ColumnFamily;
+
     fn cf_handle(&self, name: &str) -> &ColumnFamily {
         self.db.cf_handle(name).expect(name)
     }
 
     fn iterator_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &ColumnFamily,
         mode: IteratorMode,
     ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
         Box::new(
             self.db
-                .iterator_cf(cf, mode)
+                .iterator_cf_opt(cf, total_order_read_options(), mode)
                 .map(|result| result.expect("reading from DB iterator")),
         )
     }
 
+    fn prefix_iterator_cf(
+        &self,
+        cf: &ColumnFamily,
+        from: &[u8],
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        Box::new(
+            self.db
+                .iterator_cf_opt(
+                    cf,
+                    prefix_read_options(),
+                    IteratorMode::From(from, Direction::Forward),
+                )
+                .map(|result| result.expect("reading from DB prefix iterator")),
+        )
+    }
+
     fn get_pinned_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &ColumnFamily,
         key: impl AsRef<[u8]>,
-    ) -> Option<DBPinnableSlice> {
+    ) -> Option<impl AsRef<[u8]> + '_> {
         self.db.get_pinned_cf(cf, key).expect("DB get by key")
     }
 
     fn multi_get_cf<'a>(
         &'a self,
-        keys: impl IntoIterator<Item = (&'a (impl AsColumnFamilyRef + 'a), impl AsRef<[u8]>)>,
+        keys: impl IntoIterator<Item = (&'a ColumnFamily, impl AsRef<[u8]>)>,
     ) -> Vec<Option<Vec<u8>>> {
         self.db
             .multi_get_cf(keys)
@@ -179,16 +280,55 @@ impl ReadableRocks for DirectRocks {
 }
 
 impl WriteableRocks for DirectRocks {
+    type WriteBatch = WriteBatch;
+    type Snapshot<'s> = SnapshotRocks<'s>;
+
     fn write(&self, batch: WriteBatch) {
         self.db.write(batch).expect("DB write batch");
     }
 
-    fn snapshot(&self) -> SnapshotRocks {
+    fn snapshot(&self) -> SnapshotRocks<'_> {
         SnapshotRocks {
             db: &self.db,
             snapshot: self.db.snapshot(),
         }
     }
+
+    fn compact_range_cf(&self, cf_name: &str, from: &[u8], to: &[u8]) -> usize {
+        let size_before = self.get_range_sst_size(cf_name, from, to);
+        self.db
+            .compact_range_cf(self.cf_handle(cf_name), Some(from), Some(to));
+        let size_after = self.get_range_sst_size(cf_name, from, to);
+        size_before.saturating_sub(size_after)
+    }
+}
+
+impl DirectRocks {
+    /// Sums the sizes of the column family's SST files which hold some part of the given key range.
+    /// Note: a boundary SST may of course hold keys from outside the range too - hence the result
+    /// should only be compared against a measurement of the same range.
+    fn get_range_sst_size(&self, cf_name: &str, from: &[u8], to: &[u8]) -> usize {
+        let live_files = self.db.live_files().unwrap_or_else(|err| {
+            warn!("could not get DB live files; returning 0: {:?}", err);
+            Vec::new()
+        });
+        live_files
+            .into_iter()
+            .filter(|live_file| live_file.column_family_name == cf_name)
+            .filter(|live_file| {
+                let starts_before_end = live_file
+                    .start_key
+                    .as_ref()
+                    .map_or(true, |start_key| start_key.as_slice() < to);
+                let ends_after_start = live_file
+                    .end_key
+                    .as_ref()
+                    .map_or(true, |end_key| end_key.as_slice() >= from);
+                starts_before_end && ends_after_start
+            })
+            .map(|live_file| live_file.size)
+            .sum()
+    }
 }
 
 impl SecondaryRocks for DirectRocks {
@@ -200,17 +340,33 @@ impl SecondaryRocks for DirectRocks {
 }
 
 impl CheckpointableRocks for DirectRocks {
-    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), rocksdb::Error> {
-        create_checkpoint(&self.db, checkpoint_path)
+    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), String> {
+        create_checkpoint(&self.db, checkpoint_path).map_err(|err| err.to_string())
     }
 }
 
 impl CheckpointableRocks for SnapshotRocks<'_> {
-    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), rocksdb::Error> {
-        create_checkpoint(self.db, checkpoint_path)
+    fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), String> {
+        create_checkpoint(self.db, checkpoint_path).map_err(|err| err.to_string())
     }
 }
 
+/// Read options of a regular iteration.
+/// Note: the "total order" is only relevant for the column families which have a prefix extractor
+/// configured - it makes the iteration correct also beyond the prefix of the initial key.
+fn total_order_read_options() -> ReadOptions {
+    let mut read_options = ReadOptions::default();
+    read_options.set_total_order_seek(true);
+    read_options
+}
+
+/// Read options of a [`ReadableRocks::prefix_iterator_cf()`].
+fn prefix_read_options() -> ReadOptions {
+    let mut read_options = ReadOptions::default();
+    read_options.set_prefix_same_as_start(true);
+    read_options
+}
+
 fn create_checkpoint(db: &DB, checkpoint_path: PathBuf) -> Result<(), rocksdb::Error> {
     let checkpoint = Checkpoint::new(db)?;
     checkpoint.create_checkpoint(checkpoint_path)?;
@@ -228,27 +384,45 @@ pub struct SnapshotRocks<'db> {
 }
 
 impl ReadableRocks for SnapshotRocks<'_> {
+    type CfHandle = ColumnFamily;
+
     fn cf_handle(&self, name: &str) -> &ColumnFamily {
         self.db.cf_handle(name).expect(name)
     }
 
     fn iterator_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &ColumnFamily,
         mode: IteratorMode,
     ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
         Box::new(
             self.snapshot
-                .iterator_cf(cf, mode)
+                .iterator_cf_opt(cf, total_order_read_options(), mode)
                 .map(|result| result.expect("reading from snapshot DB iterator")),
         )
     }
 
+    fn prefix_iterator_cf(
+        &self,
+        cf: &ColumnFamily,
+        from: &[u8],
+    ) -> Box<dyn Iterator<Item = KVBytes> + '_> {
+        Box::new(
+            self.snapshot
+                .iterator_cf_opt(
+                    cf,
+                    prefix_read_options(),
+                    IteratorMode::From(from, Direction::Forward),
+                )
+                .map(|result| result.expect("reading from snapshot DB prefix iterator")),
+        )
+    }
+
     fn get_pinned_cf(
         &self,
-        cf: &impl AsColumnFamilyRef,
+        cf: &ColumnFamily,
         key: impl AsRef<[u8]>,
-    ) -> Option<DBPinnableSlice> {
+    ) -> Option<impl AsRef<[u8]> + '_> {
         self.snapshot
             .get_pinned_cf(cf, key)
             .expect("snapshot DB get by key")
@@ -256,7 +430,7 @@ impl ReadableRocks for SnapshotRocks<'_> {
 
     fn multi_get_cf<'a>(
         &'a self,
-        keys: impl IntoIterator<Item = (&'a (impl AsColumnFamilyRef + 'a), impl AsRef<[u8]>)>,
+        keys: impl IntoIterator<Item = (&'a ColumnFamily, impl AsRef<[u8]>)>,
     ) -> Vec<Option<Vec<u8>>> {
         self.snapshot
             .multi_get_cf(keys)
@@ -63,7 +63,7 @@
  */
 
 use crate::prelude::*;
-use rocksdb::{ColumnFamily, Direction, IteratorMode, WriteBatch};
+use rocksdb::{Direction, IteratorMode};
 use std::ops::Range;
 
 /// An optional-write-enabling marker trait to be used with [`TypedDbContext`].
@@ -85,12 +85,12 @@ impl WriteSupport for NoWriteSupport {}
 /// until [`BufferedWriteSupport::flush()`] happens (either an explicit one, likely propagated from
 /// [`TypedDbContext::flush()`], or an implicit one on [`Drop`]).
 pub struct BufferedWriteSupport<'r, R: WriteableRocks> {
-    buffer: WriteBuffer,
+    buffer: WriteBuffer<R::WriteBatch>,
     rocks: &'r R,
 }
 
 impl<'r, R: WriteableRocks> BufferedWriteSupport<'r, R> {
-    /// Creates an instance that will flush to the given RocksDB.
+    /// Creates an instance that will flush to the given database.
     pub fn new(rocks: &'r R) -> Self {
         Self {
             buffer: WriteBuffer::default(),
@@ -102,7 +102,7 @@ impl<'r, R: WriteableRocks> BufferedWriteSupport<'r, R> {
 impl<R: WriteableRocks> WriteSupport for BufferedWriteSupport<'_, R> {}
 
 impl<R: WriteableRocks> BufferedWriteSupport<'_, R> {
-    /// Writes the batch to the RocksDB and flips the internal buffer.
+    /// Writes the batch to the database and flips the internal buffer.
     fn flush(&self) {
         let write_batch = self.buffer.flip();
         if !write_batch.is_empty() {
@@ -132,7 +132,7 @@ pub struct TypedDbContext<'r, R: ReadableRocks, W: WriteSupport> {
 }
 
 impl<'r, R: ReadableRocks, W: WriteSupport> TypedDbContext<'r, R, W> {
-    /// Creates an instance using the given RocksDB.
+    /// Creates an instance using the given database.
     /// The write capabilities depend on the given [`WriteSupport`] implementation.
     pub fn new(rocks: &'r R, write_support: W) -> Self {
         Self {
@@ -169,14 +169,14 @@ impl<'r, R: ReadableRocks, W: WriteSupport> TypedDbContext<'r, R, W> {
 /// A higher-level DB access API bound to its [`TypedDbContext`] and scoped at a specific column
 /// family.
 pub struct TypedCfApi<'r, 'w, CF: TypedCf, R: ReadableRocks, W: WriteSupport> {
-    cf: ResolvedCf<'r, CF>,
+    cf: ResolvedCf<'r, CF, R::CfHandle>,
     rocks: &'r R,
     write_support: &'w W,
 }
 
 impl<'r, 'w, CF: TypedCf, R: ReadableRocks, W: WriteSupport> TypedCfApi<'r, 'w, CF, R, W> {
     /// Creates an instance for the given column family.
-    fn new(cf: ResolvedCf<'r, CF>, rocks: &'r R, write_support: &'w W) -> Self {
+    fn new(cf: ResolvedCf<'r, CF, R::CfHandle>, rocks: &'r R, write_support: &'w W) -> Self {
         Self {
             cf,
             rocks,
@@ -221,6 +221,89 @@ impl<'r, CF: TypedCf, R: WriteableRocks> TypedCfApi<'r, '_, CF, R, BufferedWrite
             .buffer
             .delete(self.cf.handle, self.cf.key_codec.encode(key));
     }
+
+    /// Deletes the entries of all the given keys, replacing every run of at least `min_run_len`
+    /// keys which are dense (i.e. each encoded key is an immediate successor of the previous one)
+    /// and adjacent in the database (i.e. have no other key in between) with a single range
+    /// delete.
+    ///
+    /// This avoids the "tombstone storm" of a mass point-delete, at the cost of some reads (i.e.
+    /// one seek per sufficiently long dense run - sparse keys are point-deleted without any read).
+    /// It is only correct if no other key can be concurrently inserted within the range of the
+    /// given keys.
+    /// Returns the number of keys covered by range deletes.
+    pub fn delete_many_coalesced<'k>(
+        &self,
+        keys: impl IntoIterator<Item = &'k CF::Key>,
+        min_run_len: usize,
+    ) -> usize
+    where
+        CF::Key: 'k,
+    {
+        let mut encoded_keys = keys
+            .into_iter()
+            .map(|key| self.cf.key_codec.encode(key))
+            .collect::<Vec<_>>();
+        encoded_keys.sort();
+        encoded_keys.dedup();
+
+        let mut range_deleted_count = 0;
+        let mut remaining_keys = encoded_keys.as_slice();
+        while !remaining_keys.is_empty() {
+            let dense_len = count_dense_keys(remaining_keys);
+            // Note: a key which is not present in the database forms a (trivial) run on its own.
+            let run_len = if dense_len >= min_run_len {
+                self.count_adjacent_keys(&remaining_keys[..dense_len])
+                    .max(1)
+            } else {
+                dense_len
+            };
+            let (run, rest) = remaining_keys.split_at(run_len);
+            if run_len >= min_run_len {
+                // An immediate lexicographical successor of the run's last key:
+                let mut range_end = run[run_len - 1].clone();
+                range_end.push(0);
+                self.write_support
+                    .buffer
+                    .delete_range(self.cf.handle, run[0].clone(), range_end);
+                range_deleted_count += run_len;
+            } else {
+                for key in run {
+                    self.write_support
+                        .buffer
+                        .delete(self.cf.handle, key.clone());
+                }
+            }
+            remaining_keys = rest;
+        }
+        range_deleted_count
+    }
+
+    /// Counts the leading keys of the given sorted slice which are present in the database and
+    /// adjacent there.
+    fn count_adjacent_keys(&self, sorted_encoded_keys: &[Vec<u8>]) -> usize {
+        self.rocks
+            .iterator_cf(
+                self.cf.handle,
+                IteratorMode::From(&sorted_encoded_keys[0], Direction::Forward),
+            )
+            .zip(sorted_encoded_keys)
+            .take_while(|((db_key, _), key)| db_key.as_ref() == key.as_slice())
+            .count()
+    }
+
+    /// Compacts the given key range ("from inclusive, to exclusive") of this column family.
+    /// Returns the (approximate) number of reclaimed bytes.
+    ///
+    /// Unlike the writes, this operation is not buffered: it happens immediately (and thus only
+    /// affects the deletes which were already flushed).
+    pub fn compact_range(&self, from_key: &CF::Key, to_key: &CF::Key) -> usize {
+        self.rocks.compact_range_cf(
+            self.cf.inner.name(),
+            &self.cf.key_codec.encode(from_key),
+            &self.cf.key_codec.encode(to_key),
+        )
+    }
 }
 
 impl<'r, KC: BoundedDbCodec, CF: TypedCf<KeyCodec = KC>, R: WriteableRocks>
@@ -333,6 +416,32 @@ impl<
         ))
     }
 
+    /// Returns a forward iterator starting at the given key (inclusive), which *may* stop after the
+    /// last entry sharing the given key's prefix (see [`ReadableRocks::prefix_iterator_cf()`]).
+    /// The caller must bound the iteration within the prefix on its own.
+    pub fn iterate_prefix_from(
+        &self,
+        from: &CF::Key,
+    ) -> Box<dyn Iterator<Item = (CF::Key, CF::Value)> + 'r>
+    where
+        CF::KeyCodec: 'r,
+        CF::ValueCodec: 'r,
+    {
+        // create dedicated instances; do not reference those cached by `&self` from returned value:
+        let key_codec = self.cf.inner.key_codec();
+        let value_codec = self.cf.inner.value_codec();
+        Box::new(
+            self.rocks
+                .prefix_iterator_cf(self.cf.handle, &self.cf.key_codec.encode(from))
+                .map(move |(key, value)| {
+                    (
+                        key_codec.decode(key.as_ref()),
+                        value_codec.decode(value.as_ref()),
+                    )
+                }),
+        )
+    }
+
     /// Returns an iterator based on the [`IteratorMode`] (which already contains encoded key).
     ///
     /// This is an internal shared implementation detail for different iteration flavors.
@@ -476,6 +585,15 @@ pub trait TypedCf {
     fn key_codec(&self) -> Self::KeyCodec;
     /// Creates a new [`DbCodec`] for values within this column family.
     fn value_codec(&self) -> Self::ValueCodec;
+
+    /// Returns the name of the actual column family represented by this instance.
+    ///
+    /// This is simply the [`Self::NAME`] in all usual cases. Only a "family of column families"
+    /// (i.e. a type having many instances, each backed by a different RocksDB column family) needs
+    /// to override it - and then it should use the [`Self::NAME`] as a common prefix.
+    fn name(&self) -> &str {
+        Self::NAME
+    }
 }
 
 /// A convenience trait implementing [`TypedCf`] for a simple case where both [`DbCodec`]s have
@@ -819,27 +937,34 @@ impl<T: core::hash::Hash + Eq + Clone> DbCodec<T> for PredefinedDbCodec<T> {
     }
 }
 
-/// An internal wrapper for [`WriteBatch`], allowing to use it with interior mutability.
-#[derive(Default)]
-struct WriteBuffer {
-    write_batch: RefCell<WriteBatch>,
+/// An internal wrapper for a [`RocksWriteBatch`], allowing to use it with interior mutability.
+struct WriteBuffer<B: RocksWriteBatch> {
+    write_batch: RefCell<B>,
 }
 
-impl WriteBuffer {
-    pub fn put(&self, cf: &ColumnFamily, key: Vec<u8>, value: Vec<u8>) {
+impl<B: RocksWriteBatch> Default for WriteBuffer<B> {
+    fn default() -> Self {
+        Self {
+            write_batch: RefCell::new(B::default()),
+        }
+    }
+}
+
+impl<B: RocksWriteBatch> WriteBuffer<B> {
+    pub fn put(&self, cf: &B::CfHandle, key: Vec<u8>, value: Vec<u8>) {
         self.write_batch.borrow_mut().put_cf(cf, key, value);
     }
 
-    pub fn delete(&self, cf: &ColumnFamily, key: Vec<u8>) {
+    pub fn delete(&self, cf: &B::CfHandle, key: Vec<u8>) {
         self.write_batch.borrow_mut().delete_cf(cf, key);
     }
 
-    pub fn delete_range(&self, cf: &ColumnFamily, from: Vec<u8>, to: Vec<u8>) {
+    pub fn delete_range(&self, cf: &B::CfHandle, from: Vec<u8>, to: Vec<u8>) {
         self.write_batch.borrow_mut().delete_range_cf(cf, from, to);
     }
 
-    pub fn flip(&self) -> WriteBatch {
-        self.write_batch.replace(WriteBatch::default())
+    pub fn flip(&self) -> B {
+        self.write_batch.replace(B::default())
     }
 
     pub fn byte_size(&self) -> usize {
@@ -848,17 +973,17 @@ impl WriteBuffer {
 }
 
 /// An internal wrapper for a [`TypedCf`] and dependencies resolved from it.
-struct ResolvedCf<'r, CF: TypedCf> {
+struct ResolvedCf<'r, CF: TypedCf, H> {
     inner: CF,
-    handle: &'r ColumnFamily,    // only a cache - computable from `typed_cf`
+    handle: &'r H,               // only a cache - computable from `typed_cf`
     key_codec: CF::KeyCodec,     // only a cache - computable from `typed_cf`
     value_codec: CF::ValueCodec, // only a cache - computable from `typed_cf`
 }
 
-impl<'r, CF: TypedCf> ResolvedCf<'r, CF> {
+impl<'r, CF: TypedCf, H> ResolvedCf<'r, CF, H> {
     /// Resolves and caches properties of the given [`TypedCf`].
-    pub fn resolve<R: ReadableRocks>(rocks: &'r R, inner: CF) -> Self {
-        let handle = rocks.cf_handle(CF::NAME);
+    pub fn resolve<R: ReadableRocks<CfHandle = H>>(rocks: &'r R, inner: CF) -> Self {
+        let handle = rocks.cf_handle(inner.name());
         let key_codec = inner.key_codec();
         let value_codec = inner.value_codec();
         Self {
@@ -869,3 +994,30 @@ impl<'r, CF: TypedCf> ResolvedCf<'r, CF> {
         }
     }
 }
+
+/// Counts the leading keys of the given sorted slice which are dense, i.e. where each encoded key
+/// is an immediate (same-length, big-endian) successor of the previous one.
+/// Always returns at least 1 for a non-empty slice.
+fn count_dense_keys(sorted_encoded_keys: &[Vec<u8>]) -> usize {
+    1 + sorted_encoded_keys
+        .windows(2)
+        .take_while(|pair| is_immediate_successor(&pair[0], &pair[1]))
+        .count()
+}
+
+/// Checks whether the `next` key is the `previous` key incremented by 1 (when both are treated
+/// as big-endian unsigned integers of the same length).
+fn is_immediate_successor(previous: &[u8], next: &[u8]) -> bool {
+    if previous.len() != next.len() {
+        return false;
+    }
+    let mut incremented = previous.to_vec();
+    for byte in incremented.iter_mut().rev() {
+        let (sum, overflow) = byte.overflowing_add(1);
+        *byte = sum;
+        if !overflow {
+            return incremented == next;
+        }
+    }
+    false
+}
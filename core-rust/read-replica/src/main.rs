@@ -0,0 +1,251 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+//! Serves the Core, Engine State and Mesh APIs from a read replica of a live Node.
+//!
+//! Usage:
+//! ```text
+//! read_replica <network> <primary_db_path> <secondary_db_path> <core_api_bind_address> [<engine_state_api_bind_address> [<mesh_api_bind_address>]]
+//! ```
+//!
+//! The replica opens the Node's state manager database (i.e. `<node_db_dir>/state_manager`) as a
+//! RocksDB secondary instance, keeping its own info logs at `<secondary_db_path>`, and catches up
+//! with the Node every `RADIXDLT_READ_REPLICA_CATCH_UP_INTERVAL_MS` (1000 by default).
+//! The endpoints which need the Node's mempool (i.e. submit, preview, transaction status and
+//! mempool ones) are disabled, and every response carries the `x-radix-replica-*` headers
+//! reporting the replica's staleness.
+
+mod headers;
+
+use axum::middleware::map_response_with_state;
+use axum::Router;
+use headers::add_read_replica_headers;
+use node_common::environment::setup_tracing;
+use node_common::prelude::*;
+use node_common::scheduler::Scheduler;
+use state_manager::engine_prelude::*;
+use state_manager::prelude::*;
+use std::process::ExitCode;
+use std::str::FromStr;
+use tokio::runtime::Runtime;
+
+const DEFAULT_CATCH_UP_INTERVAL: Duration = Duration::from_secs(1);
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        [network, primary_db_path, secondary_db_path, core_api_bind_address] => run(
+            network,
+            primary_db_path,
+            secondary_db_path,
+            core_api_bind_address,
+            None,
+            None,
+        ),
+        [network, primary_db_path, secondary_db_path, core_api_bind_address, engine_state_api_bind_address] => {
+            run(
+                network,
+                primary_db_path,
+                secondary_db_path,
+                core_api_bind_address,
+                Some(engine_state_api_bind_address),
+                None,
+            )
+        }
+        [network, primary_db_path, secondary_db_path, core_api_bind_address, engine_state_api_bind_address, mesh_api_bind_address] => {
+            run(
+                network,
+                primary_db_path,
+                secondary_db_path,
+                core_api_bind_address,
+                Some(engine_state_api_bind_address),
+                Some(mesh_api_bind_address),
+            )
+        }
+        _ => Err("usage:\n  \
+            read_replica <network> <primary_db_path> <secondary_db_path> <core_api_bind_address> [<engine_state_api_bind_address> [<mesh_api_bind_address>]]"
+            .to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    network: &str,
+    primary_db_path: &str,
+    secondary_db_path: &str,
+    core_api_bind_address: &str,
+    engine_state_api_bind_address: Option<&str>,
+    mesh_api_bind_address: Option<&str>,
+) -> Result<(), String> {
+    let network_definition = resolve_network_definition(network)?;
+    let catch_up_interval = match std::env::var("RADIXDLT_READ_REPLICA_CATCH_UP_INTERVAL_MS") {
+        Ok(interval_ms) => Duration::from_millis(
+            interval_ms
+                .parse()
+                .map_err(|error| format!("invalid catch-up interval: {error}"))?,
+        ),
+        Err(_) => DEFAULT_CATCH_UP_INTERVAL,
+    };
+
+    let runtime = Runtime::new().map_err(|error| format!("cannot start runtime: {error}"))?;
+    setup_tracing(
+        &runtime,
+        std::env::var("JAEGER_AGENT_ENDPOINT").ok(),
+        std::env::var("RADIXDLT_LOG_LEVEL")
+            .ok()
+            .and_then(|level| LogLevel::from_str(level.as_str()).ok())
+            .unwrap_or(LogLevel::INFO),
+    );
+
+    let metric_registry = MetricRegistry::new();
+    let lock_factory = LockFactory::new("rr").measured(&metric_registry);
+    let scheduler = Scheduler::new("rr")
+        .use_tokio(&runtime)
+        .track_running_tasks()
+        .measured(&metric_registry);
+
+    let state_manager = StateManager::new_read_replica(
+        ReadReplicaConfig {
+            network_definition: network_definition.clone(),
+            primary_db_path: primary_db_path.to_string(),
+            secondary_db_path: secondary_db_path.to_string(),
+            protocol_config: resolve_protocol_config(&network_definition),
+            catch_up_interval,
+        },
+        &lock_factory,
+        &metric_registry,
+        &scheduler,
+    )
+    .map_err(|error| format!("cannot open the secondary database: {error}"))?;
+    // Keeps the catch-up task running until the servers shut down:
+    let _running_task_tracker = scheduler.into_task_tracker();
+    let read_replica_status = state_manager
+        .read_replica_status
+        .clone()
+        .expect("a read replica always tracks its status");
+
+    let mut servers = vec![runtime.spawn({
+        let bind_address = core_api_bind_address.to_string();
+        let state = core_api_server::CoreApiState {
+            network: network_definition.clone(),
+            flags: core_api_server::CoreApiServerFlags {
+                enable_unbounded_endpoints: false,
+            },
+            state_manager: state_manager.clone(),
+        };
+        let router = core_api_server::create_router(state, &metric_registry);
+        serve(bind_address, router, read_replica_status.clone())
+    })];
+    if let Some(bind_address) = engine_state_api_bind_address {
+        let bind_address = bind_address.to_string();
+        let state = engine_state_api_server::EngineStateApiState {
+            network: network_definition.clone(),
+            state_manager: state_manager.clone(),
+        };
+        let router = engine_state_api_server::create_router(state, &metric_registry);
+        servers.push(runtime.spawn(serve(bind_address, router, read_replica_status.clone())));
+    }
+    if let Some(bind_address) = mesh_api_bind_address {
+        let bind_address = bind_address.to_string();
+        let state = mesh_api_server::MeshApiState {
+            network: network_definition.clone(),
+            state_manager: state_manager.clone(),
+            node_display_version: env!("CARGO_PKG_VERSION").to_string(),
+        };
+        let router = mesh_api_server::create_router(state, &metric_registry);
+        servers.push(runtime.spawn(serve(bind_address, router, read_replica_status.clone())));
+    }
+
+    for server in servers {
+        runtime
+            .block_on(server)
+            .map_err(|error| format!("server failed: {error}"))?;
+    }
+    Ok(())
+}
+
+/// Serves the given API router, adding the headers which report the replica's staleness to every
+/// response.
+async fn serve(bind_address: String, router: Router, read_replica_status: Arc<ReadReplicaStatus>) {
+    let router = router.layer(map_response_with_state(
+        read_replica_status,
+        add_read_replica_headers,
+    ));
+    let bind_address = bind_address.parse().expect("Failed to parse bind address");
+    axum::Server::bind(&bind_address)
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .unwrap();
+}
+
+async fn shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
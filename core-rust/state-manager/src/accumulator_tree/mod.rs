@@ -64,10 +64,12 @@
 
 use crate::prelude::*;
 
+mod proofs;
 mod slice_merger;
 mod storage;
 mod tree_builder;
 
+pub use proofs::*;
 pub use slice_merger::*;
 pub use storage::*;
 pub use tree_builder::*;
@@ -0,0 +1,248 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use super::storage::SeekableAccuTreeStore;
+use super::tree_builder::Merklizable;
+use std::marker::PhantomData;
+
+/// A merkle proof of a single leaf's presence in an accumulator tree of a particular size.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccuTreeInclusionProof<N> {
+    /// The index of the proven leaf.
+    pub leaf_index: usize,
+    /// The number of leaves in the tree (i.e. the tree of which the root is proven).
+    pub tree_size: usize,
+    /// The siblings met on the path from the proven leaf to the root, arranged from leaves to root.
+    /// A sibling which does not exist in the tree is represented by [`Merklizable::zero()`] (i.e.
+    /// exactly as it was treated when the tree was built).
+    pub siblings: Vec<N>,
+}
+
+impl<N: Merklizable + Eq> AccuTreeInclusionProof<N> {
+    /// Verifies that the given leaf is present at [`Self::leaf_index`] of a tree having the given
+    /// root.
+    pub fn verify(&self, leaf: &N, root: &N) -> bool {
+        if self.leaf_index >= self.tree_size || self.siblings.len() != tree_height(self.tree_size) {
+            return false;
+        }
+        let mut node_index = self.leaf_index;
+        let mut node = None;
+        for sibling in self.siblings.iter() {
+            let current = node.as_ref().unwrap_or(leaf);
+            node = Some(if node_index % 2 == 0 {
+                N::merge(current, sibling)
+            } else {
+                N::merge(sibling, current)
+            });
+            node_index /= 2;
+        }
+        node.as_ref().unwrap_or(leaf) == root
+    }
+}
+
+/// A merkle proof of an older accumulator tree being a prefix of a newer one (i.e. of the newer tree
+/// being created only by appending leaves to the older one).
+///
+/// The proof consists of the older tree's last leaf and its inclusion proof in the newer tree: all
+/// the left siblings on that leaf's path are complete sub-trees (i.e. they are the same in both
+/// trees), and all the right siblings are known to be absent from the older tree. Hence the same
+/// path is sufficient to compute both roots.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccuTreeConsistencyProof<N> {
+    /// The number of leaves in the older tree.
+    pub old_tree_size: usize,
+    /// The last leaf of the older tree.
+    pub old_last_leaf: N,
+    /// The inclusion proof of the [`Self::old_last_leaf`] in the newer tree.
+    pub inclusion_proof: AccuTreeInclusionProof<N>,
+}
+
+impl<N: Merklizable + Eq> AccuTreeConsistencyProof<N> {
+    /// Verifies that the older tree having the given root is a prefix of the newer tree having the
+    /// given root.
+    pub fn verify(&self, old_root: &N, new_root: &N) -> bool {
+        let inclusion_proof = &self.inclusion_proof;
+        if self.old_tree_size == 0
+            || inclusion_proof.leaf_index + 1 != self.old_tree_size
+            || !inclusion_proof.verify(&self.old_last_leaf, new_root)
+        {
+            return false;
+        }
+        let zero = N::zero();
+        let mut node_index = inclusion_proof.leaf_index;
+        let mut node = None;
+        for sibling in inclusion_proof
+            .siblings
+            .iter()
+            .take(tree_height(self.old_tree_size))
+        {
+            let current = node.as_ref().unwrap_or(&self.old_last_leaf);
+            node = Some(if node_index % 2 == 0 {
+                N::merge(current, &zero)
+            } else {
+                N::merge(sibling, current)
+            });
+            node_index /= 2;
+        }
+        node.as_ref().unwrap_or(&self.old_last_leaf) == old_root
+    }
+}
+
+/// A generator of merkle proofs for an accumulator tree of a particular size, backed by the slices
+/// stored by [`super::tree_builder::AccuTree`].
+/// The storage key used by this implementation follows the `AccuTree`'s convention (i.e. it is the
+/// number of leaf nodes in the tree after the given slice was appended).
+/// Each node is read from the slice which appended the last leaf of the node's sub-tree (within
+/// the proven tree size), since that is the latest slice which has (re)computed it.
+pub struct AccuTreeProver<S, N> {
+    store: S,
+    tree_size: usize,
+    phantom_data: PhantomData<N>,
+}
+
+impl<S: SeekableAccuTreeStore<usize, N>, N: Merklizable> AccuTreeProver<S, N> {
+    /// Creates a prover of the tree (backed by the given store) at the moment when it held the
+    /// given number of leaves.
+    /// The given size must be one of the slice boundaries (i.e. a key of a stored slice).
+    pub fn new(store: S, tree_size: usize) -> Self {
+        Self {
+            store,
+            tree_size,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Returns the leaf at the given index.
+    /// Returns [`None`] if the leaf is not in the tree, or the slice holding it is not stored.
+    pub fn get_leaf(&self, leaf_index: usize) -> Option<N> {
+        if leaf_index >= self.tree_size {
+            return None;
+        }
+        self.get_node(0, leaf_index)
+    }
+
+    /// Generates a proof of the leaf at the given index being present in the tree.
+    /// Returns [`None`] if the leaf is not in the tree, or any of the slices needed to compute the
+    /// proof is not stored.
+    pub fn prove_inclusion(&self, leaf_index: usize) -> Option<AccuTreeInclusionProof<N>> {
+        if leaf_index >= self.tree_size {
+            return None;
+        }
+        let mut siblings = Vec::new();
+        let mut node_index = leaf_index;
+        let mut level_node_count = self.tree_size;
+        for level in 0..tree_height(self.tree_size) {
+            let sibling_index = node_index ^ 1;
+            siblings.push(if sibling_index < level_node_count {
+                self.get_node(level, sibling_index)?
+            } else {
+                N::zero()
+            });
+            node_index /= 2;
+            level_node_count = (level_node_count + 1) / 2;
+        }
+        Some(AccuTreeInclusionProof {
+            leaf_index,
+            tree_size: self.tree_size,
+            siblings,
+        })
+    }
+
+    /// Generates a proof of the tree holding the given number of leaves being a prefix of this
+    /// tree.
+    /// Returns [`None`] if the given size is 0 or exceeds this tree's size, or any of the slices
+    /// needed to compute the proof is not stored.
+    pub fn prove_consistency(&self, old_tree_size: usize) -> Option<AccuTreeConsistencyProof<N>> {
+        if old_tree_size == 0 {
+            return None;
+        }
+        let old_last_leaf_index = old_tree_size - 1;
+        Some(AccuTreeConsistencyProof {
+            old_tree_size,
+            old_last_leaf: self.get_leaf(old_last_leaf_index)?,
+            inclusion_proof: self.prove_inclusion(old_last_leaf_index)?,
+        })
+    }
+
+    fn get_node(&self, level: usize, node_index: usize) -> Option<N> {
+        let sub_tree_end = ((node_index + 1) << level).min(self.tree_size);
+        let (slice_end, slice) = self.store.get_first_tree_slice_from(&sub_tree_end)?;
+        let slice_start = slice_end.checked_sub(slice.leaves().len())?;
+        if slice_start >= sub_tree_end {
+            return None; // the slice which appended the needed leaf is not stored
+        }
+        let slice_level = slice.levels.into_iter().nth(level)?;
+        let level_index = node_index.checked_sub(slice_start >> level)?;
+        slice_level.nodes.into_iter().nth(level_index)
+    }
+}
+
+/// Returns the number of merge levels (i.e. the number of levels excluding the leaves) of an
+/// accumulator tree holding the given number of leaves.
+fn tree_height(tree_size: usize) -> usize {
+    if tree_size <= 1 {
+        return 0;
+    }
+    (usize::BITS - (tree_size - 1).leading_zeros()) as usize
+}
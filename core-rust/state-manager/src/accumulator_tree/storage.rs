@@ -80,6 +80,20 @@ pub trait WriteableAccuTreeStore<K, N> {
     fn put_tree_slice(&mut self, key: K, slice: TreeSlice<N>);
 }
 
+/// An extension of the accumulator tree storage SPI, needed only for generating merkle proofs (i.e.
+/// for locating the slice which last updated a particular node).
+pub trait SeekableAccuTreeStore<K, N> {
+    /// Gets the first vertical `TreeSlice` stored at a key greater than or equal to the given one,
+    /// together with its actual key.
+    fn get_first_tree_slice_from(&self, key: &K) -> Option<(K, TreeSlice<N>)>;
+}
+
+impl<T: SeekableAccuTreeStore<K, N>, K, N> SeekableAccuTreeStore<K, N> for &T {
+    fn get_first_tree_slice_from(&self, key: &K) -> Option<(K, TreeSlice<N>)> {
+        (*self).get_first_tree_slice_from(key)
+    }
+}
+
 /// A convenience read+write storage trait.
 pub trait AccuTreeStore<K, N>: ReadableAccuTreeStore<K, N> + WriteableAccuTreeStore<K, N> {}
 impl<T: ReadableAccuTreeStore<K, N> + WriteableAccuTreeStore<K, N>, K, N> AccuTreeStore<K, N>
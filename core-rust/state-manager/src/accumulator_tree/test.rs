@@ -62,8 +62,9 @@
  * permissions under this License.
  */
 
+use super::proofs::AccuTreeProver;
 use super::slice_merger::AccuTreeSliceMerger;
-use super::storage::{ReadableAccuTreeStore, WriteableAccuTreeStore};
+use super::storage::{ReadableAccuTreeStore, SeekableAccuTreeStore, WriteableAccuTreeStore};
 use super::storage::{TreeSlice, TreeSliceLevel};
 use super::tree_builder::{AccuTree, Merklizable};
 use crate::engine_prelude::*;
@@ -319,6 +320,82 @@ fn compacting_result_is_same_as_single_built_slice() {
     assert_eq!(built_second_slice, merged_second_slice)
 }
 
+#[test]
+fn inclusion_proofs_of_all_leaves_verify_against_roots_at_all_slice_boundaries() {
+    let mut store = MemoryAccuTreeStore::new();
+    let mut tree = AccuTree::new(&mut store, 0);
+    tree.append(strings(&["a", "b", "c", "d", "e"]));
+    tree.append(strings(&["f", "g"]));
+    tree.append(strings(&["h"]));
+    tree.append(strings(&["i", "j", "k", "l", "m", "n", "o", "p", "q"]));
+    let leaves = strings(&[
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q",
+    ]);
+
+    for tree_size in [5, 7, 8, 17] {
+        let root = store.slices.get(&tree_size).unwrap().root().clone();
+        let prover = AccuTreeProver::new(&store, tree_size);
+        for (leaf_index, leaf) in leaves.iter().take(tree_size).enumerate() {
+            assert_eq!(prover.get_leaf(leaf_index).as_ref(), Some(leaf));
+            let proof = prover.prove_inclusion(leaf_index).unwrap();
+            assert!(proof.verify(leaf, &root));
+            assert!(!proof.verify(&"x".to_string(), &root));
+        }
+        assert_eq!(prover.prove_inclusion(tree_size), None);
+    }
+}
+
+#[test]
+fn inclusion_proof_reads_siblings_from_latest_slices() {
+    let mut store = MemoryAccuTreeStore::new();
+    let mut tree = AccuTree::new(&mut store, 0);
+    tree.append(strings(&["a", "b", "c", "d", "e"]));
+    tree.append(strings(&["f", "g"]));
+    let proof = AccuTreeProver::new(&store, 7).prove_inclusion(1).unwrap();
+    assert_eq!(proof.siblings, strings(&["a", "(c+d)", "((e+f)+(g+))"]));
+}
+
+#[test]
+fn consistency_proofs_verify_between_all_slice_boundaries() {
+    let mut store = MemoryAccuTreeStore::new();
+    let mut tree = AccuTree::new(&mut store, 0);
+    let boundaries = [1, 2, 5, 7, 8, 9, 16, 23];
+    let mut current_len = 0;
+    for boundary in boundaries {
+        tree.append(
+            (current_len..boundary)
+                .map(|index| index.to_string())
+                .collect::<Vec<String>>(),
+        );
+        current_len = boundary;
+    }
+
+    for (old_position, old_tree_size) in boundaries.iter().enumerate() {
+        let old_root = store.slices.get(old_tree_size).unwrap().root().clone();
+        for new_tree_size in boundaries.iter().skip(old_position) {
+            let new_root = store.slices.get(new_tree_size).unwrap().root().clone();
+            let proof = AccuTreeProver::new(&store, *new_tree_size)
+                .prove_consistency(*old_tree_size)
+                .unwrap();
+            assert!(proof.verify(&old_root, &new_root));
+            assert!(!proof.verify(&new_root, &old_root) || old_root == new_root);
+        }
+    }
+}
+
+#[test]
+fn proofs_are_not_generated_from_missing_slices() {
+    let mut store = MemoryAccuTreeStore::new();
+    let mut tree = AccuTree::new(&mut store, 0);
+    tree.append(strings(&["a", "b", "c"]));
+    tree.append(strings(&["d", "e"]));
+    store.slices.remove(&3);
+    let prover = AccuTreeProver::new(&store, 5);
+    assert_eq!(prover.prove_inclusion(1), None);
+    assert_eq!(prover.prove_consistency(3), None);
+    assert!(prover.prove_inclusion(4).is_some());
+}
+
 fn slice(levels: &[TreeSliceLevel<String>]) -> TreeSlice<String> {
     TreeSlice {
         levels: levels.iter().rev().cloned().collect(),
@@ -365,6 +442,18 @@ impl<K: Eq + core::hash::Hash, N: Clone> ReadableAccuTreeStore<K, N> for MemoryA
     }
 }
 
+impl<K: Ord + core::hash::Hash + Clone, N: Clone> SeekableAccuTreeStore<K, N>
+    for MemoryAccuTreeStore<K, N>
+{
+    fn get_first_tree_slice_from(&self, key: &K) -> Option<(K, TreeSlice<N>)> {
+        self.slices
+            .iter()
+            .filter(|(slice_key, _)| *slice_key >= key)
+            .min_by_key(|(slice_key, _)| (*slice_key).clone())
+            .map(|(slice_key, slice)| (slice_key.clone(), slice.clone()))
+    }
+}
+
 impl<K: Eq + core::hash::Hash + Clone, N> WriteableAccuTreeStore<K, N>
     for MemoryAccuTreeStore<K, N>
 {
@@ -0,0 +1,137 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+//! An offline database consistency checker.
+//!
+//! Usage:
+//! ```text
+//! db_consistency_checker <db_path> [<max_reported_violations>]
+//! ```
+//!
+//! The database is opened in a read-only mode. Since a live Node keeps writing to its database,
+//! the check should rather target a checkpoint of it (see `RustDbCheckpoints`), which represents a
+//! consistent point-in-time view.
+//! The tool prints all reported violations and exits with a failure status if any were found.
+
+use state_manager::prelude::*;
+use state_manager::store::consistency_check::*;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [db_path] => check(db_path, ConsistencyCheckConfig::default()),
+        [db_path, max_reported_violations] => match max_reported_violations.parse() {
+            Ok(max_reported_violations) => check(
+                db_path,
+                ConsistencyCheckConfig {
+                    max_reported_violations,
+                },
+            ),
+            Err(error) => Err(format!("invalid max reported violations: {error}")),
+        },
+        _ => Err("usage:\n  \
+            db_consistency_checker <db_path> [<max_reported_violations>]"
+            .to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn check(db_path: &str, config: ConsistencyCheckConfig) -> Result<(), String> {
+    let database = StateManagerDatabase::new_read_only(PathBuf::from(db_path));
+    let report = check_database_consistency(&database, &config);
+    for violation in &report.violations {
+        println!("{violation:?}");
+    }
+    println!(
+        "Checked {} substates, {} state tree leaves, {} transactions, {} transaction hash index entries and {} ancestry records",
+        report.checked_substate_count,
+        report.checked_state_tree_leaf_count,
+        report.checked_transaction_count,
+        report.checked_transaction_hash_index_entry_count,
+        report.checked_node_ancestry_record_count
+    );
+    if report.is_consistent() {
+        println!("The database is consistent");
+        Ok(())
+    } else {
+        Err(format!(
+            "Found {} violations ({} printed above)",
+            report.violation_count,
+            report.violations.len()
+        ))
+    }
+}
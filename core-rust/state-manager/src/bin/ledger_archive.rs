@@ -0,0 +1,209 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+//! An offline ledger export/import tool.
+//!
+//! Usage:
+//! ```text
+//! ledger_archive export <network> <source_db_path> <archive_path> [<chunk_transaction_count>]
+//! ledger_archive import <archive_path> <target_db_path> <genesis_data_path>
+//! ```
+//!
+//! The `export` only needs read access to the source database (i.e. may run alongside the Node).
+//! The `import` replays the archive into the target database (which may be fresh, or the result
+//! of a previously interrupted import). The genesis data file must contain the raw SBOR-encoded
+//! genesis data of the archive's network.
+
+use node_common::prelude::*;
+use node_common::scheduler::Scheduler;
+use state_manager::engine_prelude::*;
+use state_manager::jni::LedgerSyncLimitsConfig;
+use state_manager::prelude::*;
+use state_manager::store::jmt_gc::StateTreeGcConfig;
+use state_manager::store::ledger_archive::*;
+use state_manager::store::proofs_gc::LedgerProofsGcConfig;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["export", network, source_db_path, archive_path] => export(
+            network,
+            source_db_path,
+            archive_path,
+            DEFAULT_ARCHIVE_CHUNK_TRANSACTION_COUNT,
+        ),
+        ["export", network, source_db_path, archive_path, chunk_transaction_count] => {
+            match chunk_transaction_count.parse() {
+                Ok(chunk_transaction_count) => {
+                    export(network, source_db_path, archive_path, chunk_transaction_count)
+                }
+                Err(error) => Err(format!("invalid chunk transaction count: {error}")),
+            }
+        }
+        ["import", archive_path, target_db_path, genesis_data_path] => {
+            import(archive_path, target_db_path, genesis_data_path)
+        }
+        _ => Err("usage:\n  \
+            ledger_archive export <network> <source_db_path> <archive_path> [<chunk_transaction_count>]\n  \
+            ledger_archive import <archive_path> <target_db_path> <genesis_data_path>"
+            .to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn export(
+    network: &str,
+    source_db_path: &str,
+    archive_path: &str,
+    chunk_transaction_count: usize,
+) -> Result<(), String> {
+    let network_definition = resolve_network_definition(network)?;
+    let database = StateManagerDatabase::new_read_only(PathBuf::from(source_db_path));
+    if !database.is_local_transaction_execution_index_enabled() {
+        return Err("the source database does not store local transaction executions".to_string());
+    }
+    if database.get_first_stored_local_transaction_execution_state_version()
+        != StateVersion::pre_genesis().next().unwrap()
+    {
+        return Err(
+            "the source database does not store local transaction executions since genesis"
+                .to_string(),
+        );
+    }
+    let output = BufWriter::new(
+        File::create(archive_path).map_err(|error| format!("cannot create archive: {error}"))?,
+    );
+    let (_, summary) = export_ledger_archive(
+        &database,
+        &network_definition,
+        output,
+        chunk_transaction_count,
+    )
+    .map_err(|error| format!("export failed: {error:?}"))?;
+    println!(
+        "Exported {} transactions and {} proofs in {} chunks",
+        summary.transaction_count, summary.proof_count, summary.chunk_count
+    );
+    Ok(())
+}
+
+fn import(archive_path: &str, target_db_path: &str, genesis_data_path: &str) -> Result<(), String> {
+    let input = BufReader::new(
+        File::open(archive_path).map_err(|error| format!("cannot open archive: {error}"))?,
+    );
+    let mut reader = LedgerArchiveReader::new(input)
+        .map_err(|error| format!("cannot read archive: {error:?}"))?;
+    let raw_genesis_data = std::fs::read(genesis_data_path)
+        .map_err(|error| format!("cannot read genesis data: {error}"))?;
+    let genesis_data = scrypto_decode::<JavaGenesisData>(&raw_genesis_data)
+        .map_err(|error| format!("cannot decode genesis data: {error:?}"))?;
+
+    let network_definition = reader.header().network_definition.clone();
+    let state_manager = StateManager::new(
+        StateManagerConfig {
+            network_definition: network_definition.clone(),
+            mempool_config: None,
+            vertex_limits_config: None,
+            database_backend_config: DatabaseBackendConfig {
+                rocks_db_path: target_db_path.to_string(),
+                tuning_profile: None,
+                in_memory: false,
+            },
+            database_config: DatabaseConfig::default(),
+            logging_config: LoggingConfig::default(),
+            state_tree_gc_config: StateTreeGcConfig::default(),
+            ledger_proofs_gc_config: LedgerProofsGcConfig::default(),
+            db_checkpoints_config: None,
+            ledger_sync_limits_config: LedgerSyncLimitsConfig::default(),
+            protocol_config: resolve_protocol_config(&network_definition),
+            no_fees: false,
+            scenarios_execution_config: ScenariosExecutionConfig::default(),
+        },
+        None,
+        Arc::new(FixedGenesisDataResolver::new(genesis_data)),
+        &LockFactory::new("ledger_archive"),
+        &MetricRegistry::new(),
+        // A bare scheduler: no background tasks (e.g. GC) are needed during the import
+        &Scheduler::new("ledger_archive"),
+    );
+
+    let summary = import_ledger_archive(&state_manager, &mut reader)
+        .map_err(|error| format!("import failed: {error:?}"))?;
+    println!(
+        "Imported up to state version {} ({} proofs committed, {} proofs verified)",
+        summary.resultant_state_version,
+        summary.committed_proof_count,
+        summary.verified_proof_count
+    );
+    Ok(())
+}
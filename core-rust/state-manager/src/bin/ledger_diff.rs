@@ -0,0 +1,168 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+//! An offline tool locating the divergence between two ledgers.
+//!
+//! Usage:
+//! ```text
+//! ledger_diff <left_db_path> <right_db_path>
+//! ```
+//!
+//! Reports the first state version at which the ledgers' hashes differ, and then drills down into
+//! the diverging transaction: its state changes (as captured by both receipts, if retained) and the
+//! Substates which differ in the state trees (if the version is still retained in both of them).
+//! Both databases are opened read-only, so they may be checkpoints of running Nodes.
+
+use state_manager::prelude::*;
+use state_manager::store::ledger_diff::*;
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// A maximum number of differences printed for each drill-down.
+const MAX_PRINTED_DIFFERENCES: usize = 100;
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [left_db_path, right_db_path] => diff(left_db_path, right_db_path),
+        _ => Err("usage:\n  ledger_diff <left_db_path> <right_db_path>".to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn diff(left_db_path: &str, right_db_path: &str) -> Result<(), String> {
+    let left = StateManagerDatabase::try_new_read_only(PathBuf::from(left_db_path))
+        .map_err(|error| format!("cannot open left database: {error}"))?;
+    let right = StateManagerDatabase::try_new_read_only(PathBuf::from(right_db_path))
+        .map_err(|error| format!("cannot open right database: {error}"))?;
+    let Some(divergence) = find_first_divergence(&left, &right) else {
+        println!(
+            "No divergence found (left ends at state version {}, right at {})",
+            left.max_state_version(),
+            right.max_state_version()
+        );
+        return Ok(());
+    };
+    let state_version = divergence.state_version;
+    println!("Ledgers diverge at state version {}", state_version);
+    println!(
+        "  left:  transaction {:?}, {:?}",
+        divergence.left.transaction_hashes.ledger_transaction_hash,
+        divergence.left.resultant_ledger_hashes
+    );
+    println!(
+        "  right: transaction {:?}, {:?}",
+        divergence.right.transaction_hashes.ledger_transaction_hash,
+        divergence.right.resultant_ledger_hashes
+    );
+
+    match (
+        left.get_committed_ledger_transaction_receipt(state_version),
+        right.get_committed_ledger_transaction_receipt(state_version),
+    ) {
+        (Some(left_receipt), Some(right_receipt)) => {
+            let differences =
+                diff_state_changes(&left_receipt.state_changes, &right_receipt.state_changes);
+            println!(
+                "Receipts' state changes differ at {} places",
+                differences.len()
+            );
+            print_differences(&differences);
+        }
+        _ => println!("Receipts' state changes unavailable (pruned)"),
+    }
+
+    match diff_state_trees(&left, &right, state_version) {
+        Ok(differences) => {
+            println!("State trees differ at {} substates", differences.len());
+            print_differences(&differences);
+        }
+        Err(error) => println!("State trees unavailable: {error:?}"),
+    }
+    Ok(())
+}
+
+fn print_differences<T: Debug>(differences: &[T]) {
+    for difference in differences.iter().take(MAX_PRINTED_DIFFERENCES) {
+        println!("  {difference:?}");
+    }
+    if differences.len() > MAX_PRINTED_DIFFERENCES {
+        println!(
+            "  ... and {} more",
+            differences.len() - MAX_PRINTED_DIFFERENCES
+        );
+    }
+}
@@ -0,0 +1,245 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+//! An offline state snapshot dump/load tool.
+//!
+//! Usage:
+//! ```text
+//! state_snapshot dump <network> <source_db_path> <snapshot_path> [<anchor_epoch>]
+//! state_snapshot load <network> <snapshot_path> <target_db_path> <trusted_epoch_proof_hash>
+//! state_snapshot epoch-proof-hash <db_path> <epoch>
+//! ```
+//!
+//! The `dump` only needs read access to the source database. By default, it snapshots the state at
+//! the latest epoch proof (which requires the source database to either be exactly at that proof,
+//! or to have the state history enabled).
+//! The `load` initializes a new database at the (empty) target path, which can then be used by a
+//! Node. It only accepts a snapshot of the given network, with a quorum-signed proofs chain leading
+//! from the trusted epoch proof to the snapshot's anchor. The trusted epoch proof's hash must come
+//! from a trusted source (not from the snapshot's provider) - e.g. the `epoch-proof-hash` command
+//! computes it from an own Node's database. Trusting the genesis' epoch proof (i.e. the one
+//! initiating the network's first epoch) verifies the entire chain.
+
+use node_common::prelude::*;
+use state_manager::engine_prelude::*;
+use state_manager::prelude::*;
+use state_manager::store::state_snapshot::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["dump", network, source_db_path, snapshot_path] => {
+            dump(network, source_db_path, snapshot_path, None)
+        }
+        ["dump", network, source_db_path, snapshot_path, anchor_epoch] => {
+            match anchor_epoch.parse::<u64>() {
+                Ok(anchor_epoch) => dump(
+                    network,
+                    source_db_path,
+                    snapshot_path,
+                    Some(Epoch::of(anchor_epoch)),
+                ),
+                Err(error) => Err(format!("invalid anchor epoch: {error}")),
+            }
+        }
+        ["load", network, snapshot_path, target_db_path, trusted_epoch_proof_hash] => {
+            match Hash::from_str(trusted_epoch_proof_hash) {
+                Ok(trusted_epoch_proof_hash) => load(
+                    network,
+                    snapshot_path,
+                    target_db_path,
+                    trusted_epoch_proof_hash,
+                ),
+                Err(error) => Err(format!("invalid trusted epoch proof hash: {error:?}")),
+            }
+        }
+        ["epoch-proof-hash", db_path, epoch] => match epoch.parse::<u64>() {
+            Ok(epoch) => print_epoch_proof_hash(db_path, Epoch::of(epoch)),
+            Err(error) => Err(format!("invalid epoch: {error}")),
+        },
+        _ => Err("usage:\n  \
+            state_snapshot dump <network> <source_db_path> <snapshot_path> [<anchor_epoch>]\n  \
+            state_snapshot load <network> <snapshot_path> <target_db_path> <trusted_epoch_proof_hash>\n  \
+            state_snapshot epoch-proof-hash <db_path> <epoch>"
+            .to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn dump(
+    network: &str,
+    source_db_path: &str,
+    snapshot_path: &str,
+    anchor_epoch: Option<Epoch>,
+) -> Result<(), String> {
+    let network_definition = resolve_network_definition(network)?;
+    let database = StateManagerDatabase::new_read_only(PathBuf::from(source_db_path));
+    let anchor_epoch = match anchor_epoch {
+        Some(anchor_epoch) => anchor_epoch,
+        None => database
+            .get_latest_epoch_proof()
+            .and_then(|proof| proof.ledger_header.next_epoch)
+            .map(|next_epoch| next_epoch.epoch)
+            .ok_or_else(|| "the source database has no epoch proof".to_string())?,
+    };
+    let output = BufWriter::new(
+        File::create(snapshot_path).map_err(|error| format!("cannot create snapshot: {error}"))?,
+    );
+    let (_, summary) = dump_state_snapshot(
+        &database,
+        &network_definition,
+        anchor_epoch,
+        output,
+        DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+    )
+    .map_err(|error| format!("dump failed: {error:?}"))?;
+    println!(
+        "Dumped {} substates, {} ancestry records and {} epoch changes at state version {} (start of epoch {})",
+        summary.substate_count,
+        summary.node_ancestry_record_count,
+        summary.epoch_change_count,
+        summary.anchor_state_version,
+        anchor_epoch.number()
+    );
+    Ok(())
+}
+
+fn load(
+    network: &str,
+    snapshot_path: &str,
+    target_db_path: &str,
+    trusted_epoch_proof_hash: Hash,
+) -> Result<(), String> {
+    let network_definition = resolve_network_definition(network)?;
+    let input = BufReader::new(
+        File::open(snapshot_path).map_err(|error| format!("cannot open snapshot: {error}"))?,
+    );
+    let mut reader = StateSnapshotReader::new(input)
+        .map_err(|error| format!("cannot read snapshot: {error:?}"))?;
+    let anchor_proof = reader
+        .header()
+        .anchor_proof
+        .clone()
+        .fully_update_and_into_latest_version();
+    println!(
+        "Loading {} snapshot anchored at {:?}",
+        network_definition.logical_name, anchor_proof
+    );
+
+    let database = StateManagerDatabase::new(
+        PathBuf::from(target_db_path),
+        DatabaseConfig {
+            // The historical values cannot be loaded (but may be enabled on the Node's boot-up)
+            enable_historical_substate_values: false,
+            ..DatabaseConfig::default()
+        },
+        &network_definition,
+    )
+    .map_err(|error| format!("cannot open target database: {error:?}"))?;
+    let summary = load_state_snapshot(
+        &database,
+        &network_definition,
+        &mut reader,
+        SnapshotProofVerification::SignedChain {
+            trusted_epoch_proof_header_hash: trusted_epoch_proof_hash,
+        },
+    )
+    .map_err(|error| format!("load failed: {error:?}"))?;
+    println!(
+        "Loaded {} substates, {} ancestry records and {} epoch changes; verified state root at state version {}",
+        summary.substate_count,
+        summary.node_ancestry_record_count,
+        summary.epoch_change_count,
+        summary.anchor_state_version
+    );
+    Ok(())
+}
+
+fn print_epoch_proof_hash(db_path: &str, epoch: Epoch) -> Result<(), String> {
+    let database = StateManagerDatabase::new_read_only(PathBuf::from(db_path));
+    let epoch_proof = database.get_epoch_proof(epoch).ok_or_else(|| {
+        format!(
+            "the database has no proof initiating epoch {}",
+            epoch.number()
+        )
+    })?;
+    println!("{}", compute_ledger_header_hash(&epoch_proof.ledger_header));
+    Ok(())
+}
@@ -64,6 +64,7 @@
 
 use crate::prelude::*;
 
+use crate::store::db_checkpoints::DbCheckpointManager;
 use crate::system_commits::*;
 
 pub struct Committer {
@@ -71,10 +72,12 @@ pub struct Committer {
     validator: Arc<RwLock<TransactionValidator>>,
     transaction_executor_factory: Arc<TransactionExecutorFactory>,
     mempool_manager: Arc<MempoolManager>,
+    fee_estimator: Arc<FeeEstimator>,
     execution_cache_manager: Arc<ExecutionCacheManager>,
     protocol_manager: Arc<ProtocolManager>,
     ledger_metrics: Arc<LedgerMetrics>,
     formatter: Arc<Formatter>,
+    db_checkpoint_manager: Option<Arc<DbCheckpointManager>>,
 }
 
 impl Committer {
@@ -84,20 +87,24 @@ impl Committer {
         transaction_executor_factory: Arc<TransactionExecutorFactory>,
         ledger_transaction_validator: Arc<RwLock<TransactionValidator>>,
         mempool_manager: Arc<MempoolManager>,
+        fee_estimator: Arc<FeeEstimator>,
         execution_cache_manager: Arc<ExecutionCacheManager>,
         protocol_manager: Arc<ProtocolManager>,
         ledger_metrics: Arc<LedgerMetrics>,
         formatter: Arc<Formatter>,
+        db_checkpoint_manager: Option<Arc<DbCheckpointManager>>,
     ) -> Self {
         Self {
             database,
             validator: ledger_transaction_validator,
             transaction_executor_factory,
             mempool_manager,
+            fee_estimator,
             execution_cache_manager,
             protocol_manager,
             ledger_metrics,
             formatter,
+            db_checkpoint_manager,
         }
     }
 }
@@ -121,6 +128,7 @@ impl Committer {
         let commit_ledger_header = &proof.ledger_header;
         let commit_state_version = commit_ledger_header.state_version;
         let commit_proposer_timestamp_ms = commit_ledger_header.proposer_timestamp_ms;
+        let commits_epoch_change = commit_ledger_header.next_epoch.is_some();
 
         // We advance the top-of-ledger, hence lock:
         let database = self.database.lock();
@@ -209,6 +217,7 @@ impl Committer {
 
         let mut transactions_metrics_data = Vec::new();
         let mut committed_user_transactions = Vec::new();
+        let mut committed_user_transaction_tip_proportions = Vec::new();
 
         // Step 3.: Actually execute the transactions, collect their results into DB structures
         let mut commit_bundle_builder = series_executor.start_commit_builder();
@@ -252,6 +261,13 @@ impl Committer {
                 };
                 let nullifications = commit.local_receipt.local_execution.nullifications.clone();
                 committed_user_transactions.push((identifiers, nullifications));
+                committed_user_transaction_tip_proportions.push(
+                    commit
+                        .local_receipt
+                        .local_execution
+                        .transaction_costing_parameters
+                        .tip_proportion,
+                );
             }
             transactions_metrics_data.push(TransactionMetricsData::new(&raw, &commit));
 
@@ -289,6 +305,9 @@ impl Committer {
             committed_user_transactions,
         );
 
+        self.fee_estimator
+            .observe_committed_batch(committed_user_transaction_tip_proportions);
+
         self.ledger_metrics.update(
             commit_state_version,
             round_counters.clone(),
@@ -297,6 +316,13 @@ impl Committer {
             transactions_metrics_data,
         );
 
+        // The committed epoch change proof is the top-of-ledger now (and until our next commit):
+        if commits_epoch_change {
+            if let Some(db_checkpoint_manager) = &self.db_checkpoint_manager {
+                db_checkpoint_manager.run();
+            }
+        }
+
         Ok(CommitSummary {
             validator_round_counters: round_counters,
             num_user_transactions,
@@ -196,6 +196,21 @@ enum MempoolAddErrorJava {
         min_tip_percentage_required: Option<u32>,
         tip_basis_points: u32,
     },
+    ReplacementTipTooLow {
+        replaced_tip_basis_points: u32,
+        min_tip_basis_points_required: u32,
+        tip_basis_points: u32,
+    },
+    FeePayerQuotaExceeded {
+        max_transaction_count: u32,
+        min_tip_basis_points_required: Option<u32>,
+        tip_basis_points: u32,
+    },
+    NotaryQuotaExceeded {
+        max_transaction_count: u32,
+        min_tip_basis_points_required: Option<u32>,
+        tip_basis_points: u32,
+    },
     Duplicate(NotarizedTransactionHash),
     TransactionValidationError(String),
     Rejected(String),
@@ -211,6 +226,33 @@ impl MempoolAddErrorJava {
                 min_tip_percentage_required,
                 tip_basis_points,
             },
+            MempoolAddError::ReplacementTipTooLow {
+                replaced_tip_basis_points,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => MempoolAddErrorJava::ReplacementTipTooLow {
+                replaced_tip_basis_points,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            },
+            MempoolAddError::FeePayerQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => MempoolAddErrorJava::FeePayerQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            },
+            MempoolAddError::NotaryQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => MempoolAddErrorJava::NotaryQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            },
             MempoolAddError::Duplicate(hash) => MempoolAddErrorJava::Duplicate(hash),
             MempoolAddError::Rejected(rejection, _) => {
                 MempoolAddErrorJava::Rejected(rejection.reason.to_string(formatter))
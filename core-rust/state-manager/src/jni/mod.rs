@@ -64,6 +64,7 @@
 
 pub mod db_checkpoints;
 pub mod fatal_panic_handler;
+pub mod gc_control;
 pub mod mempool;
 pub mod node_rust_environment;
 pub mod p2p;
@@ -76,6 +76,7 @@ use tracing::Level;
 use super::fatal_panic_handler::FatalPanicHandler;
 
 use crate::protocol::ProtocolManager;
+use crate::store::gc_control::GcControls;
 use crate::store::rocks_db::ActualStateManagerDatabase;
 use crate::transaction::Preparator;
 use p2p::rocks_db::{ActualAddressBookDatabase, ActualSafetyStoreDatabase};
@@ -180,6 +181,8 @@ impl JNINodeRustEnvironment {
         let config = StateManagerConfig {
             database_backend_config: DatabaseBackendConfig {
                 rocks_db_path: state_manager_db_path,
+                tuning_profile: config.database_backend_config.tuning_profile,
+                in_memory: config.database_backend_config.in_memory,
             },
             ..config
         };
@@ -196,6 +199,7 @@ impl JNINodeRustEnvironment {
             .take_rust_field(j_node_rust_env, POINTER_JNI_FIELD_NAME)
             .unwrap();
 
+        jni_node_rust_env.state_manager.save_mempool_if_persisted();
         drop(jni_node_rust_env);
     }
 
@@ -277,6 +281,14 @@ impl JNINodeRustEnvironment {
             .ledger_metrics
             .clone()
     }
+
+    pub fn get_gc_controls(env: &JNIEnv, j_node_rust_env: JObject) -> GcControls {
+        Self::get(env, j_node_rust_env)
+            .state_manager
+            .gc_controls
+            .clone()
+            .expect("the JNI-managed state manager always runs its GCs")
+    }
 }
 
 pub fn export_extern_functions() {}
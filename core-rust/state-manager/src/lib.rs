@@ -74,6 +74,7 @@ mod mempool;
 mod metrics;
 mod protocol;
 pub mod query;
+mod read_replica;
 mod receipt;
 mod staging;
 pub mod state_manager;
@@ -96,11 +97,13 @@ pub mod prelude {
     pub use crate::mempool::*;
     pub use crate::protocol::*;
     pub use crate::query::*;
+    pub use crate::read_replica::*;
     pub use crate::receipt::*;
     pub use crate::state_manager::*;
     pub use crate::store::*;
     pub use crate::transaction::*;
     pub use crate::types::*;
+    pub use ledger_proof_verifier::*;
 
     // Extra items on the internal prelude
     pub(crate) use crate::accumulator_tree::*;
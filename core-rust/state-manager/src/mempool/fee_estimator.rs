@@ -0,0 +1,334 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+/// The targets (in numbers of proposals) used when the caller does not specify any.
+pub const DEFAULT_FEE_ESTIMATE_TARGET_PROPOSAL_COUNTS: [u32; 3] = [1, 3, 10];
+
+/// How many most recent committed batches contribute to the committed tip statistics.
+const TRACKED_COMMITTED_BATCH_COUNT: usize = 100;
+
+/// A multiplier converting a tip proportion to basis points.
+const BASIS_POINTS_PER_UNIT: i32 = 10_000;
+
+/// A node-side signal for choosing a transaction's tip.
+///
+/// The suggested tips are derived from the current [`PriorityMempool`] contents: a transaction is
+/// expected to get proposed within N proposals if it outranks all pending transactions except for
+/// the ones fitting in N proposals (and if the mempool admits it at all). The tips of recently
+/// committed user transactions are tracked and reported alongside, as an indication of the tips
+/// actually paid by the competing transactions.
+pub struct FeeEstimator {
+    mempool_manager: Arc<MempoolManager>,
+    max_transactions_per_proposal: u32,
+    recent_committed_batches: Mutex<VecDeque<Vec<u32>>>,
+}
+
+/// An overview of the mempool contents relevant for tip estimation (see
+/// [`MempoolManager::get_tip_overview()`]).
+#[derive(Debug, Clone)]
+pub struct MempoolTipOverview {
+    pub occupancy: MempoolOccupancy,
+    pub lowest_tip_basis_points: Option<u32>,
+    /// Ordered by the proposal priority, starting from the highest one.
+    pub highest_tips_basis_points: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeEstimate {
+    pub mempool_occupancy: MempoolOccupancy,
+    /// The lowest tip which would currently get a new transaction admitted into the mempool
+    /// (i.e. non-zero only if the mempool is full).
+    pub min_admission_tip_basis_points: u32,
+    pub recent_committed_tips: Option<CommittedTipStatistics>,
+    /// Ordered as the requested target proposal counts.
+    pub suggestions: Vec<TipSuggestion>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommittedTipStatistics {
+    pub user_transaction_count: usize,
+    pub median_tip_basis_points: u32,
+    pub p90_tip_basis_points: u32,
+    pub max_tip_basis_points: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TipSuggestion {
+    pub target_proposal_count: u32,
+    pub tip_basis_points: u32,
+}
+
+impl FeeEstimator {
+    pub fn new(
+        mempool_manager: Arc<MempoolManager>,
+        vertex_limits_config: &VertexLimitsConfig,
+        lock_factory: &LockFactory,
+    ) -> Self {
+        Self {
+            mempool_manager,
+            max_transactions_per_proposal: vertex_limits_config.max_transaction_count,
+            recent_committed_batches: lock_factory
+                .named("recent_committed_batches")
+                .new_mutex(VecDeque::with_capacity(TRACKED_COMMITTED_BATCH_COUNT)),
+        }
+    }
+
+    /// Records the tip proportions (taken from the transactions' [`LocalTransactionExecution`]s)
+    /// of the user transactions committed in a single batch.
+    pub fn observe_committed_batch(&self, user_transaction_tip_proportions: Vec<Decimal>) {
+        if user_transaction_tip_proportions.is_empty() {
+            return;
+        }
+        let tips_basis_points = user_transaction_tip_proportions
+            .into_iter()
+            .map(|tip_proportion| {
+                u32::try_from(tip_proportion * BASIS_POINTS_PER_UNIT).unwrap_or(u32::MAX)
+            })
+            .collect();
+        let mut recent_committed_batches = self.recent_committed_batches.lock();
+        if recent_committed_batches.len() == TRACKED_COMMITTED_BATCH_COUNT {
+            recent_committed_batches.pop_front();
+        }
+        recent_committed_batches.push_back(tips_basis_points);
+    }
+
+    /// Estimates the tips required for getting a transaction proposed within each of the given
+    /// numbers of proposals.
+    pub fn estimate(&self, target_proposal_counts: &[u32]) -> FeeEstimate {
+        let recent_committed_tips = self.get_committed_tip_statistics();
+
+        let max_ranked_count = target_proposal_counts
+            .iter()
+            .map(|target| self.get_proposal_capacity(*target))
+            .max()
+            .unwrap_or(0);
+        let overview = self.mempool_manager.get_tip_overview(max_ranked_count);
+
+        let min_admission_tip_basis_points = if overview.occupancy.is_full() {
+            overview
+                .lowest_tip_basis_points
+                .map(|lowest| lowest.saturating_add(1))
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let suggestions = target_proposal_counts
+            .iter()
+            .map(|target_proposal_count| {
+                let capacity = self.get_proposal_capacity(*target_proposal_count);
+                // A transaction must outrank the last one that would still fit in the capacity
+                // (ties are resolved in favor of the older transaction, hence the `+ 1`):
+                let outranking_tip_basis_points = capacity
+                    .checked_sub(1)
+                    .and_then(|index| overview.highest_tips_basis_points.get(index))
+                    .map(|tip| tip.saturating_add(1))
+                    .unwrap_or(0);
+                TipSuggestion {
+                    target_proposal_count: *target_proposal_count,
+                    tip_basis_points: outranking_tip_basis_points
+                        .max(min_admission_tip_basis_points),
+                }
+            })
+            .collect();
+
+        FeeEstimate {
+            mempool_occupancy: overview.occupancy,
+            min_admission_tip_basis_points,
+            recent_committed_tips,
+            suggestions,
+        }
+    }
+
+    fn get_proposal_capacity(&self, proposal_count: u32) -> usize {
+        usize::try_from(proposal_count.saturating_mul(self.max_transactions_per_proposal))
+            .unwrap_or(usize::MAX)
+    }
+
+    fn get_committed_tip_statistics(&self) -> Option<CommittedTipStatistics> {
+        let sorted_tips = self
+            .recent_committed_batches
+            .lock()
+            .iter()
+            .flatten()
+            .cloned()
+            .sorted()
+            .collect::<Vec<_>>();
+        let max_tip_basis_points = *sorted_tips.last()?;
+        let percentile = |percent: usize| sorted_tips[(sorted_tips.len() - 1) * percent / 100];
+        Some(CommittedTipStatistics {
+            user_transaction_count: sorted_tips.len(),
+            median_tip_basis_points: percentile(50),
+            p90_tip_basis_points: percentile(90),
+            max_tip_basis_points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        build_faucet_transaction_with_tip, commit_round_updates_until_epoch,
+        create_bootstrapped_state_manager, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    #[test]
+    fn empty_mempool_suggests_no_tip_and_reports_committed_tips() {
+        let state_manager = create_bootstrapped_state_manager(
+            StateManagerConfig::new_for_testing_in_memory(),
+            BabylonSettings::test_default(),
+        );
+        let fee_estimator = &state_manager.fee_estimator;
+
+        fee_estimator.observe_committed_batch(vec![Decimal::ONE / 100, Decimal::ZERO]);
+        fee_estimator.observe_committed_batch(vec![]);
+        fee_estimator.observe_committed_batch(vec![Decimal::ONE * 5 / 100]);
+        let estimate = fee_estimator.estimate(&DEFAULT_FEE_ESTIMATE_TARGET_PROPOSAL_COUNTS);
+
+        assert!(!estimate.mempool_occupancy.is_full());
+        assert_eq!(estimate.min_admission_tip_basis_points, 0);
+        assert_eq!(suggested_tips(&estimate), vec![(1, 0), (3, 0), (10, 0)]);
+        let committed_tips = estimate.recent_committed_tips.unwrap();
+        assert_eq!(committed_tips.user_transaction_count, 3);
+        assert_eq!(committed_tips.median_tip_basis_points, 100);
+        assert_eq!(committed_tips.max_tip_basis_points, 500);
+    }
+
+    #[test]
+    fn populated_mempool_suggestions_outrank_last_transaction_fitting_in_target() {
+        let state_manager = create_state_manager_with_pending_tips(5, &[1, 4, 2, 3]);
+
+        let estimate = state_manager.fee_estimator.estimate(&[1, 2, 3]);
+
+        assert!(!estimate.mempool_occupancy.is_full());
+        assert_eq!(estimate.min_admission_tip_basis_points, 0);
+        // The pending tips (ranked) are 400, 300, 200 and 100 basis points, with 2 transactions per
+        // proposal: the 2nd and the 4th must be outranked (and the 6th does not exist).
+        assert_eq!(suggested_tips(&estimate), vec![(1, 301), (2, 101), (3, 0)]);
+    }
+
+    #[test]
+    fn full_mempool_suggestions_are_at_least_min_admission_tip() {
+        let state_manager = create_state_manager_with_pending_tips(4, &[1, 4, 2, 3]);
+
+        let estimate = state_manager.fee_estimator.estimate(&[1, 2, 3]);
+
+        assert!(estimate.mempool_occupancy.is_full());
+        // A new transaction must outrank the one which would be evicted (i.e. the lowest tip):
+        assert_eq!(estimate.min_admission_tip_basis_points, 101);
+        assert_eq!(
+            suggested_tips(&estimate),
+            vec![(1, 301), (2, 101), (3, 101)]
+        );
+    }
+
+    fn create_state_manager_with_pending_tips(
+        max_mempool_transaction_count: u32,
+        tip_percentages: &[u16],
+    ) -> StateManager {
+        let mut config = StateManagerConfig::new_for_testing_in_memory();
+        config.mempool_config = Some(MempoolConfig {
+            max_transaction_count: max_mempool_transaction_count,
+            ..MempoolConfig::new_for_testing()
+        });
+        config.vertex_limits_config = Some(VertexLimitsConfig {
+            max_transaction_count: 2,
+            ..VertexLimitsConfig::standard()
+        });
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(2));
+        for (key, tip_percentage) in tip_percentages.iter().enumerate() {
+            let account = ComponentAddress::preallocated_account_from_public_key(
+                &Secp256k1PrivateKey::from_u64(key as u64 + 1)
+                    .unwrap()
+                    .public_key(),
+            );
+            state_manager
+                .mempool_manager
+                .add_if_committable(
+                    MempoolAddSource::CoreApi,
+                    build_faucet_transaction_with_tip(Epoch::of(2), account, *tip_percentage),
+                    false,
+                )
+                .unwrap();
+        }
+        state_manager
+    }
+
+    fn suggested_tips(estimate: &FeeEstimate) -> Vec<(u32, u32)> {
+        estimate
+            .suggestions
+            .iter()
+            .map(|suggestion| {
+                (
+                    suggestion.target_proposal_count,
+                    suggestion.tip_basis_points,
+                )
+            })
+            .collect()
+    }
+}
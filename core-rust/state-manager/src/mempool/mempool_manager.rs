@@ -97,6 +97,7 @@ pub struct MempoolManager {
     pending_transaction_result_cache: RwLock<PendingTransactionResultCache>,
     /// WARNING: Be sure to take out this lock in the correct order, as per the [`MempoolManager`] doc.
     committability_validator: Arc<CommittabilityValidator>,
+    vertex_limits_config: VertexLimitsConfig,
     metrics: MempoolManagerMetrics,
 }
 
@@ -107,6 +108,7 @@ impl MempoolManager {
         relay_dispatcher: MempoolRelayDispatcher,
         pending_transaction_result_cache: RwLock<PendingTransactionResultCache>,
         committability_validator: Arc<CommittabilityValidator>,
+        vertex_limits_config: VertexLimitsConfig,
         metric_registry: &MetricRegistry,
     ) -> Self {
         Self {
@@ -114,6 +116,7 @@ impl MempoolManager {
             relay_dispatcher: Some(relay_dispatcher),
             pending_transaction_result_cache,
             committability_validator,
+            vertex_limits_config,
             metrics: MempoolManagerMetrics::new(metric_registry),
         }
     }
@@ -123,6 +126,7 @@ impl MempoolManager {
         mempool: RwLock<PriorityMempool>,
         pending_transaction_result_cache: RwLock<PendingTransactionResultCache>,
         committability_validator: Arc<CommittabilityValidator>,
+        vertex_limits_config: VertexLimitsConfig,
         metric_registry: &MetricRegistry,
     ) -> Self {
         Self {
@@ -130,10 +134,13 @@ impl MempoolManager {
             relay_dispatcher: None,
             pending_transaction_result_cache,
             committability_validator,
+            vertex_limits_config,
             metrics: MempoolManagerMetrics::new(metric_registry),
         }
     }
 
+    /// Picks the transactions to form a proposal (see [`PriorityMempool::get_proposal_transactions()`]),
+    /// within the given count/size limits and the configured vertex execution cost limit.
     pub fn get_proposal_transactions(
         &self,
         max_count: usize,
@@ -143,6 +150,8 @@ impl MempoolManager {
         self.mempool.read().get_proposal_transactions(
             max_count,
             max_payload_size_bytes,
+            self.vertex_limits_config
+                .max_total_execution_cost_units_consumed as u64,
             user_payload_hashes_to_exclude,
         )
     }
@@ -151,6 +160,18 @@ impl MempoolManager {
         self.mempool.read().get_count()
     }
 
+    /// Returns the mempool's occupancy and the tips of (at most) the given number of its
+    /// highest-priority transactions (see [`FeeEstimator`]).
+    pub fn get_tip_overview(&self, max_ranked_count: usize) -> MempoolTipOverview {
+        let mempool = self.mempool.read();
+        MempoolTipOverview {
+            occupancy: mempool.get_occupancy(),
+            lowest_tip_basis_points: mempool.get_lowest_priority_tip_basis_points(),
+            highest_tips_basis_points: mempool
+                .get_highest_priority_tips_basis_points(max_ranked_count),
+        }
+    }
+
     /// Picks a random subset of transactions to be relayed via a mempool sync.
     /// Obeys the given count/size limits.
     pub fn get_relay_transactions(
@@ -215,6 +236,66 @@ impl MempoolManager {
             .collect()
     }
 
+    /// Captures the mempool's transactions and the pending transaction records, in a persistable
+    /// form (see [`PersistedPendingTransactionRecord::capture()`] for the records which are skipped).
+    pub fn capture_snapshot(&self) -> MempoolSnapshot {
+        // Note: locks taken in the order documented on the `MempoolManager`
+        let pending_transaction_result_cache = self.pending_transaction_result_cache.read();
+        let mempool = self.mempool.read();
+        MempoolSnapshot {
+            transactions: mempool
+                .iter_by_state_version()
+                .map(|data| PersistedMempoolTransaction {
+                    raw: data.transaction.raw.clone(),
+                    source: data.source,
+                })
+                .collect(),
+            pending_transaction_records: pending_transaction_result_cache
+                .iter_pending_transaction_records()
+                .filter_map(
+                    |(notarized_transaction_hash, record, intent_hash, subintent_hashes)| {
+                        PersistedPendingTransactionRecord::capture(
+                            notarized_transaction_hash,
+                            record,
+                            intent_hash,
+                            subintent_hashes,
+                        )
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// Restores a previously captured snapshot: first puts back the pending transaction records,
+    /// and then re-adds the transactions using the regular [`Self::add_if_committable()`] (i.e.
+    /// against the current state, and regardless of the restored records' retry schedules).
+    pub fn restore_snapshot(&self, snapshot: MempoolSnapshot) -> MempoolRestoreSummary {
+        let mut summary = MempoolRestoreSummary::default();
+        {
+            // We use a block to scope the lock guard
+            let mut pending_transaction_result_cache =
+                self.pending_transaction_result_cache.write();
+            for persisted_record in snapshot.pending_transaction_records {
+                let (notarized_transaction_hash, intent_hash, subintent_hashes, record) =
+                    persisted_record.restore();
+                pending_transaction_result_cache.restore_pending_transaction_record(
+                    notarized_transaction_hash,
+                    intent_hash,
+                    subintent_hashes,
+                    record,
+                );
+                summary.restored_pending_transaction_record_count += 1;
+            }
+        }
+        for transaction in snapshot.transactions {
+            match self.add_if_committable(transaction.source, transaction.raw, true) {
+                Ok(_) => summary.restored_transaction_count += 1,
+                Err(_) => summary.dropped_transaction_count += 1,
+            }
+        }
+        summary
+    }
+
     /// Checks the committability of up to `max_reevaluated_count` of transactions executed against
     /// earliest state versions and removes the newly rejected ones from the mempool.
     pub fn recheck_committability_of_mempool_transaction_batch(&self, max_reevaluated_count: u32) {
@@ -232,13 +313,13 @@ impl MempoolManager {
             let user_hashes = &candidate_transaction.transaction.hashes;
             let metadata = TransactionMetadata::read_from_user_executable(executable, user_hashes);
 
-            let attempt = self.committability_validator.check_for_rejection(
+            let check = self.committability_validator.check_for_rejection(
                 executable,
                 user_hashes,
                 SystemTime::now(),
             );
 
-            self.observe_pending_transaction_execution_attempt(metadata, attempt);
+            self.observe_pending_transaction_execution_attempt(metadata, check.attempt);
         }
     }
 
@@ -341,6 +422,7 @@ impl MempoolManager {
         let PendingExecutedTransaction {
             executable,
             user_hashes,
+            fee_payer,
             latest_attempt_against_state,
         } = record
             .should_accept_into_mempool(check_result)
@@ -348,10 +430,17 @@ impl MempoolManager {
                 MempoolAddError::Rejected(rejection, Some(notarized_transaction_hash))
             })?;
 
+        let parties = MempoolTransactionParties::new(
+            fee_payer,
+            &raw_transaction
+                .into_typed()
+                .expect("Transaction was already successfully prepared"),
+        );
         let mempool_transaction = Arc::new(MempoolTransaction {
             executable,
             hashes: user_hashes,
             raw: raw_transaction,
+            parties,
         });
         match self.mempool.write().add_transaction_if_not_present(
             mempool_transaction.clone(),
@@ -393,11 +482,9 @@ impl MempoolManager {
                 // Transaction was valid - let's also attempt to execute it
                 let user_hashes = validated.hashes();
                 let executable = validated.create_executable();
-                let attempt = self.committability_validator.check_for_rejection(
-                    &executable,
-                    &user_hashes,
-                    current_time,
-                );
+                let CommittabilityCheck { attempt, fee_payer } = self
+                    .committability_validator
+                    .check_for_rejection(&executable, &user_hashes, current_time);
                 let record = self
                     .pending_transaction_result_cache
                     .write()
@@ -407,6 +494,7 @@ impl MempoolManager {
                     CheckMetadata::Fresh(StaticValidation::Valid {
                         executable,
                         user_hashes,
+                        fee_payer,
                     }),
                 )
             }
@@ -480,6 +568,18 @@ impl MempoolManager {
         pending_cache.track_transaction_result(transaction_metadata, attempt);
     }
 
+    /// Records the execution cost units actually consumed by the given transactions (i.e. when
+    /// executed as part of a prepared proposal), for estimating the cost of future proposals.
+    pub(crate) fn observe_execution_cost_units(
+        &self,
+        observations: Vec<(NotarizedTransactionHash, u32)>,
+    ) {
+        let mut mempool = self.mempool.write();
+        for (notarized_transaction_hash, execution_cost_units) in observations {
+            mempool.observe_execution_cost_units(&notarized_transaction_hash, execution_cost_units);
+        }
+    }
+
     /// Removes all the transactions that have the given intent hashes.
     /// This method is meant to be called for transactions that were successfully committed - and
     /// this assumption is important for metric correctness.
@@ -543,6 +643,14 @@ impl MempoolManager {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct MempoolRestoreSummary {
+    pub restored_pending_transaction_record_count: usize,
+    pub restored_transaction_count: usize,
+    /// Transactions which turned out to be no longer committable (or could not fit the mempool).
+    pub dropped_transaction_count: usize,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum ForceRecalculation {
     Yes,
@@ -573,6 +681,49 @@ pub enum StaticValidation {
     Valid {
         executable: ExecutableTransaction,
         user_hashes: UserTransactionHashes,
+        fee_payer: Option<GlobalAddress>,
     },
     Invalid,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        build_faucet_transaction, commit_round_updates_until_epoch,
+        create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    #[test]
+    fn fee_payer_is_resolved_from_committability_check_execution() {
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(2));
+        let account = ComponentAddress::preallocated_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+
+        let transaction = state_manager
+            .mempool_manager
+            .add_if_committable(
+                MempoolAddSource::CoreApi,
+                build_faucet_transaction(Epoch::of(2), account),
+                false,
+            )
+            .unwrap();
+
+        // The faucet's vault pays the fee (and the account only receives a deposit):
+        assert_eq!(
+            transaction.parties,
+            MempoolTransactionParties {
+                fee_payer: Some(FAUCET.into()),
+                notary: Secp256k1PrivateKey::from_u64(2)
+                    .unwrap()
+                    .public_key()
+                    .into(),
+            }
+        );
+    }
+}
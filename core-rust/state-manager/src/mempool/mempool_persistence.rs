@@ -0,0 +1,617 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::checksummed_records::*;
+use std::fs;
+use std::io::{BufReader, BufWriter, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const MEMPOOL_SNAPSHOT_MAGIC: &[u8; 8] = b"RDXMEMPL";
+
+define_single_versioned! {
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub VersionedMempoolSnapshotRecord(MempoolSnapshotRecordVersions) => MempoolSnapshotRecord = MempoolSnapshotRecordV1
+}
+
+/// A single, checksummed unit of the persisted mempool.
+/// A valid file consists of any number of the content records, terminated by a
+/// [`MempoolSnapshotRecordV1::End`].
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum MempoolSnapshotRecordV1 {
+    Transaction(PersistedMempoolTransaction),
+    PendingTransactionRecord(PersistedPendingTransactionRecord),
+    End(MempoolSnapshotEnd),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct MempoolSnapshotEnd {
+    /// The number of content records (i.e. excluding this end marker).
+    pub record_count: u64,
+}
+
+/// The persistable contents of the [`PriorityMempool`] and [`PendingTransactionResultCache`] (see
+/// [`MempoolManager::capture_snapshot()`]).
+#[derive(Debug, Clone, Default)]
+pub struct MempoolSnapshot {
+    pub transactions: Vec<PersistedMempoolTransaction>,
+    /// Ordered from the least recently used one.
+    pub pending_transaction_records: Vec<PersistedPendingTransactionRecord>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct PersistedMempoolTransaction {
+    pub raw: RawNotarizedTransaction,
+    pub source: MempoolAddSource,
+}
+
+/// A [`PendingTransactionRecord`] (together with its cache keys) in a persistable form.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct PersistedPendingTransactionRecord {
+    pub notarized_transaction_hash: NotarizedTransactionHash,
+    pub transaction_intent_hash: TransactionIntentHash,
+    pub subintent_hashes: Vec<SubintentHash>,
+    pub intent_invalid_from_epoch: Option<Epoch>,
+    pub latest_attempt: PersistedTransactionAttempt,
+    pub earliest_permanent_rejection: Option<PersistedTransactionAttempt>,
+    pub latest_rejection_against_committed_state: Option<PersistedTransactionAttempt>,
+    pub latest_rejection_against_prepared_state: Option<PersistedTransactionAttempt>,
+    pub retry_from: PersistedRetryFrom,
+    pub non_rejection_count: u32,
+    pub rejection_count: u32,
+    pub first_tracked_unix_millis: u64,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct PersistedTransactionAttempt {
+    pub rejection: Option<PersistedRejectionReason>,
+    pub against_state: AtState,
+    pub unix_millis: u64,
+}
+
+/// A persistable subset of [`MempoolRejectionReason`].
+/// The static validation errors are not persisted - they do not depend on the ledger state, and are
+/// simply re-detected if the same payload is submitted again.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum PersistedRejectionReason {
+    TransactionIntentAlreadyCommitted(AlreadyCommittedError),
+    SubintentAlreadyFinalized(SubintentAlreadyFinalizedError),
+    FromExecution(ExecutionRejectionReason),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum PersistedRetryFrom {
+    Never,
+    FromUnixMillis(u64),
+    FromEpoch(Epoch),
+    Whenever,
+}
+
+impl PersistedPendingTransactionRecord {
+    /// Captures the given record, or returns [`None`] if any of its attempts was rejected by the
+    /// static validation (see [`PersistedRejectionReason`]).
+    pub fn capture(
+        notarized_transaction_hash: &NotarizedTransactionHash,
+        record: &PendingTransactionRecord,
+        transaction_intent_hash: &TransactionIntentHash,
+        subintent_hashes: &[SubintentHash],
+    ) -> Option<Self> {
+        let capture_optional = |attempt: &Option<TransactionAttempt>| match attempt {
+            Some(attempt) => PersistedTransactionAttempt::capture(attempt).map(Some),
+            None => Some(None),
+        };
+        Some(Self {
+            notarized_transaction_hash: *notarized_transaction_hash,
+            transaction_intent_hash: *transaction_intent_hash,
+            subintent_hashes: subintent_hashes.to_vec(),
+            intent_invalid_from_epoch: record.intent_invalid_from_epoch,
+            latest_attempt: PersistedTransactionAttempt::capture(&record.latest_attempt)?,
+            earliest_permanent_rejection: capture_optional(&record.earliest_permanent_rejection)?,
+            latest_rejection_against_committed_state: capture_optional(
+                &record.latest_rejection_against_committed_state,
+            )?,
+            latest_rejection_against_prepared_state: capture_optional(
+                &record.latest_rejection_against_prepared_state,
+            )?,
+            retry_from: match &record.retry_from {
+                RetryFrom::Never => PersistedRetryFrom::Never,
+                RetryFrom::FromTime(time) => {
+                    PersistedRetryFrom::FromUnixMillis(to_unix_millis(time))
+                }
+                RetryFrom::FromEpoch(epoch) => PersistedRetryFrom::FromEpoch(*epoch),
+                RetryFrom::Whenever => PersistedRetryFrom::Whenever,
+            },
+            non_rejection_count: record.non_rejection_count,
+            rejection_count: record.rejection_count,
+            first_tracked_unix_millis: to_unix_millis(&record.first_tracked_timestamp),
+        })
+    }
+
+    /// Returns the cache keys (i.e. notarized, intent and subintent hashes) and the restored record.
+    pub fn restore(
+        self,
+    ) -> (
+        NotarizedTransactionHash,
+        TransactionIntentHash,
+        Vec<SubintentHash>,
+        PendingTransactionRecord,
+    ) {
+        let record = PendingTransactionRecord {
+            intent_invalid_from_epoch: self.intent_invalid_from_epoch,
+            latest_attempt: self.latest_attempt.restore(),
+            earliest_permanent_rejection: self
+                .earliest_permanent_rejection
+                .map(PersistedTransactionAttempt::restore),
+            latest_rejection_against_committed_state: self
+                .latest_rejection_against_committed_state
+                .map(PersistedTransactionAttempt::restore),
+            latest_rejection_against_prepared_state: self
+                .latest_rejection_against_prepared_state
+                .map(PersistedTransactionAttempt::restore),
+            retry_from: match self.retry_from {
+                PersistedRetryFrom::Never => RetryFrom::Never,
+                PersistedRetryFrom::FromUnixMillis(millis) => {
+                    RetryFrom::FromTime(from_unix_millis(millis))
+                }
+                PersistedRetryFrom::FromEpoch(epoch) => RetryFrom::FromEpoch(epoch),
+                PersistedRetryFrom::Whenever => RetryFrom::Whenever,
+            },
+            non_rejection_count: self.non_rejection_count,
+            rejection_count: self.rejection_count,
+            first_tracked_timestamp: from_unix_millis(self.first_tracked_unix_millis),
+        };
+        (
+            self.notarized_transaction_hash,
+            self.transaction_intent_hash,
+            self.subintent_hashes,
+            record,
+        )
+    }
+}
+
+impl PersistedTransactionAttempt {
+    fn capture(attempt: &TransactionAttempt) -> Option<Self> {
+        let rejection = match &attempt.rejection {
+            None => None,
+            Some(MempoolRejectionReason::TransactionIntentAlreadyCommitted(error)) => Some(
+                PersistedRejectionReason::TransactionIntentAlreadyCommitted(error.clone()),
+            ),
+            Some(MempoolRejectionReason::SubintentAlreadyFinalized(error)) => Some(
+                PersistedRejectionReason::SubintentAlreadyFinalized(error.clone()),
+            ),
+            Some(MempoolRejectionReason::FromExecution(reason)) => Some(
+                PersistedRejectionReason::FromExecution(reason.as_ref().clone()),
+            ),
+            Some(MempoolRejectionReason::ValidationError(_)) => return None,
+        };
+        Some(Self {
+            rejection,
+            against_state: attempt.against_state.clone(),
+            unix_millis: to_unix_millis(&attempt.timestamp),
+        })
+    }
+
+    fn restore(self) -> TransactionAttempt {
+        TransactionAttempt {
+            rejection: self.rejection.map(|rejection| match rejection {
+                PersistedRejectionReason::TransactionIntentAlreadyCommitted(error) => {
+                    MempoolRejectionReason::TransactionIntentAlreadyCommitted(error)
+                }
+                PersistedRejectionReason::SubintentAlreadyFinalized(error) => {
+                    MempoolRejectionReason::SubintentAlreadyFinalized(error)
+                }
+                PersistedRejectionReason::FromExecution(reason) => {
+                    MempoolRejectionReason::FromExecution(Box::new(reason))
+                }
+            }),
+            against_state: self.against_state,
+            timestamp: from_unix_millis(self.unix_millis),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MempoolSnapshotError {
+    Io(std::io::Error),
+    Format(ChecksummedRecordError),
+    RecordCountMismatch { declared: u64, actual: u64 },
+}
+
+impl From<std::io::Error> for MempoolSnapshotError {
+    fn from(error: std::io::Error) -> Self {
+        MempoolSnapshotError::Io(error)
+    }
+}
+
+impl From<ChecksummedRecordError> for MempoolSnapshotError {
+    fn from(error: ChecksummedRecordError) -> Self {
+        MempoolSnapshotError::Format(error)
+    }
+}
+
+/// Atomically replaces the given file with the given snapshot.
+pub fn write_mempool_snapshot(
+    file_path: &Path,
+    snapshot: MempoolSnapshot,
+) -> Result<(), MempoolSnapshotError> {
+    let mut temporary_path = file_path.as_os_str().to_owned();
+    temporary_path.push(".tmp");
+    let file = fs::File::create(&temporary_path)?;
+    let mut writer = ChecksummedRecordWriter::new(BufWriter::new(file), MEMPOOL_SNAPSHOT_MAGIC)?;
+    let mut record_count = 0;
+    let content_records = snapshot
+        .transactions
+        .into_iter()
+        .map(MempoolSnapshotRecordV1::Transaction)
+        .chain(
+            snapshot
+                .pending_transaction_records
+                .into_iter()
+                .map(MempoolSnapshotRecordV1::PendingTransactionRecord),
+        );
+    for record in content_records {
+        writer.write(&VersionedMempoolSnapshotRecord::from(record))?;
+        record_count += 1;
+    }
+    writer.write(&VersionedMempoolSnapshotRecord::from(
+        MempoolSnapshotRecordV1::End(MempoolSnapshotEnd { record_count }),
+    ))?;
+    writer
+        .finish()?
+        .into_inner()
+        .map_err(|error| error.into_error())?
+        .sync_all()?;
+    fs::rename(temporary_path, file_path)?;
+    Ok(())
+}
+
+/// Reads the snapshot from the given file, or returns [`None`] if the file does not exist.
+pub fn read_mempool_snapshot(
+    file_path: &Path,
+) -> Result<Option<MempoolSnapshot>, MempoolSnapshotError> {
+    let file = match fs::File::open(file_path) {
+        Ok(file) => file,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    let mut reader = ChecksummedRecordReader::new(BufReader::new(file), MEMPOOL_SNAPSHOT_MAGIC)?;
+    let mut snapshot = MempoolSnapshot::default();
+    loop {
+        let record_index = reader.next_record_index();
+        match reader
+            .read::<VersionedMempoolSnapshotRecord>()?
+            .fully_update_and_into_latest_version()
+        {
+            MempoolSnapshotRecordV1::Transaction(transaction) => {
+                snapshot.transactions.push(transaction)
+            }
+            MempoolSnapshotRecordV1::PendingTransactionRecord(record) => {
+                snapshot.pending_transaction_records.push(record)
+            }
+            MempoolSnapshotRecordV1::End(end) => {
+                if end.record_count != record_index {
+                    return Err(MempoolSnapshotError::RecordCountMismatch {
+                        declared: end.record_count,
+                        actual: record_index,
+                    });
+                }
+                return Ok(Some(snapshot));
+            }
+        }
+    }
+}
+
+/// A saver (and restorer) of the [`MempoolManager`]'s contents, making them survive Node restarts.
+/// The implementation is suited for being driven by an external scheduler.
+pub struct MempoolPersister {
+    mempool_manager: Arc<MempoolManager>,
+    file_path: PathBuf,
+    interval: Duration,
+    /// Whether the initial [`Self::restore()`] has already happened (before which nothing is saved,
+    /// not to overwrite the persisted contents with an empty mempool).
+    /// Also prevents the scheduled and the shutdown-triggered runs from interleaving.
+    restored: Mutex<bool>,
+}
+
+impl MempoolPersister {
+    pub fn new(
+        mempool_manager: Arc<MempoolManager>,
+        config: MempoolPersistenceConfig,
+        lock_factory: &LockFactory,
+    ) -> Self {
+        Self {
+            mempool_manager,
+            file_path: PathBuf::from(config.file_path),
+            interval: Duration::from_secs(u64::from(config.interval_sec)),
+            restored: lock_factory.named("restored").new_mutex(false),
+        }
+    }
+
+    /// An interval between [`run()`]s, to be used by this instance's scheduler.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Restores the previously saved contents on the first run (see [`Self::restore()`]), and saves
+    /// the current contents of the mempool on every subsequent one.
+    /// This way, the potentially lengthy restore does not block the Node's startup.
+    pub fn run(&self) {
+        let mut restored = self.restored.lock();
+        if *restored {
+            self.save();
+        } else {
+            self.restore();
+            *restored = true;
+        }
+    }
+
+    /// Saves the current contents of the mempool (unless they were not restored yet - in which case
+    /// the previously saved contents are left intact).
+    /// This is meant to be called on a graceful shutdown.
+    pub fn save_if_restored(&self) {
+        let restored = self.restored.lock();
+        if *restored {
+            self.save();
+        }
+    }
+
+    /// Saves the current contents of the mempool, logging any problems.
+    fn save(&self) {
+        let snapshot = self.mempool_manager.capture_snapshot();
+        let transaction_count = snapshot.transactions.len();
+        match write_mempool_snapshot(&self.file_path, snapshot) {
+            Ok(()) => debug!(
+                "Saved {} mempool transactions to {}",
+                transaction_count,
+                self.file_path.display()
+            ),
+            Err(error) => error!("Could not save the mempool: {:?}", error),
+        }
+    }
+
+    /// Restores the previously saved contents (if any) into the mempool, re-checking the
+    /// transactions' committability against the current state (see
+    /// [`MempoolManager::restore_snapshot()`]).
+    /// Any problem with the saved file is only logged, since the mempool is not critical.
+    fn restore(&self) {
+        match read_mempool_snapshot(&self.file_path) {
+            Ok(Some(snapshot)) => {
+                let summary = self.mempool_manager.restore_snapshot(snapshot);
+                info!(
+                    "Restored {} pending transaction records and {} mempool transactions ({} no longer committable)",
+                    summary.restored_pending_transaction_record_count,
+                    summary.restored_transaction_count,
+                    summary.dropped_transaction_count
+                );
+            }
+            Ok(None) => {}
+            Err(error) => warn!(
+                "Could not restore the mempool from {}: {:?}; starting empty",
+                self.file_path.display(),
+                error
+            ),
+        }
+    }
+}
+
+fn to_unix_millis(time: &SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
+fn from_unix_millis(millis: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        build_faucet_transaction, commit_round_updates_until_epoch,
+        create_bootstrapped_state_manager_with_rounds_per_epoch,
+        prepare_and_commit_round_with_transactions,
+    };
+
+    #[test]
+    fn snapshot_survives_file_round_trip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let file_path = tmp.path().join("mempool.bin");
+        let first_tracked = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let record = PendingTransactionRecord::new(
+            Some(Epoch::of(7)),
+            TransactionAttempt {
+                rejection: None,
+                against_state: AtState::Static,
+                timestamp: first_tracked,
+            },
+        );
+        let notarized_transaction_hash = NotarizedTransactionHash::from(blake2b_256_hash([0u8]));
+        let transaction_intent_hash = TransactionIntentHash::from(blake2b_256_hash([1u8]));
+        let subintent_hash = SubintentHash::from(blake2b_256_hash([2u8]));
+        let captured_record = PersistedPendingTransactionRecord::capture(
+            &notarized_transaction_hash,
+            &record,
+            &transaction_intent_hash,
+            &[subintent_hash],
+        )
+        .unwrap();
+
+        write_mempool_snapshot(
+            &file_path,
+            MempoolSnapshot {
+                transactions: vec![PersistedMempoolTransaction {
+                    raw: RawNotarizedTransaction::from_vec(vec![1, 2, 3]),
+                    source: MempoolAddSource::MempoolSync,
+                }],
+                pending_transaction_records: vec![captured_record],
+            },
+        )
+        .unwrap();
+        let snapshot = read_mempool_snapshot(&file_path).unwrap().unwrap();
+
+        assert_eq!(snapshot.transactions.len(), 1);
+        assert_eq!(snapshot.transactions[0].raw.as_slice(), &[1, 2, 3]);
+        let (restored_notarized, restored_intent, restored_subintents, restored_record) = snapshot
+            .pending_transaction_records
+            .into_iter()
+            .exactly_one()
+            .unwrap()
+            .restore();
+        assert_eq!(restored_notarized, notarized_transaction_hash);
+        assert_eq!(restored_intent, transaction_intent_hash);
+        assert_eq!(restored_subintents, vec![subintent_hash]);
+        assert_eq!(
+            restored_record.intent_invalid_from_epoch,
+            Some(Epoch::of(7))
+        );
+        assert_eq!(restored_record.first_tracked_timestamp, first_tracked);
+    }
+
+    #[test]
+    fn missing_snapshot_file_reads_as_none() {
+        let tmp = tempfile::tempdir().unwrap();
+        let snapshot = read_mempool_snapshot(&tmp.path().join("absent.bin")).unwrap();
+        assert!(snapshot.is_none());
+    }
+
+    #[test]
+    fn snapshot_restores_committable_transactions_and_records_in_lru_order() {
+        let create_state_manager = || {
+            let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+                StateManagerConfig::new_for_testing_in_memory(),
+                10,
+            );
+            commit_round_updates_until_epoch(&state_manager, Epoch::of(2));
+            state_manager
+        };
+        let original = create_state_manager();
+        let transactions = (1..=3)
+            .map(|key| {
+                build_faucet_transaction(
+                    Epoch::of(2),
+                    ComponentAddress::preallocated_account_from_public_key(
+                        &Secp256k1PrivateKey::from_u64(key).unwrap().public_key(),
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        let notarized_transaction_hashes = transactions
+            .iter()
+            .map(|raw| {
+                original
+                    .mempool_manager
+                    .add_if_committable(MempoolAddSource::CoreApi, raw.clone(), false)
+                    .unwrap()
+                    .hashes
+                    .notarized_transaction_hash
+            })
+            .collect::<Vec<_>>();
+        let snapshot = original.mempool_manager.capture_snapshot();
+        assert_eq!(snapshot.transactions.len(), 3);
+        let record_hashes = |snapshot: &MempoolSnapshot| {
+            snapshot
+                .pending_transaction_records
+                .iter()
+                .map(|record| record.notarized_transaction_hash)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(record_hashes(&snapshot).len(), 3);
+
+        // The records are restored first, in their original LRU order:
+        let restored = create_state_manager();
+        let summary = restored.mempool_manager.restore_snapshot(MempoolSnapshot {
+            transactions: vec![],
+            pending_transaction_records: snapshot.pending_transaction_records.clone(),
+        });
+        assert_eq!(summary.restored_pending_transaction_record_count, 3);
+        assert_eq!(
+            record_hashes(&restored.mempool_manager.capture_snapshot()),
+            record_hashes(&snapshot)
+        );
+
+        // Then the transactions are re-added - except the one committed in the meantime:
+        prepare_and_commit_round_with_transactions(&restored, vec![transactions[0].clone()]);
+        let summary = restored.mempool_manager.restore_snapshot(MempoolSnapshot {
+            transactions: snapshot.transactions,
+            pending_transaction_records: vec![],
+        });
+        assert_eq!(summary.restored_transaction_count, 2);
+        assert_eq!(summary.dropped_transaction_count, 1);
+        let mempool_hashes = restored
+            .mempool_manager
+            .get_mempool_all_hashes()
+            .into_iter()
+            .map(|(_, notarized_transaction_hash)| notarized_transaction_hash)
+            .collect::<IndexSet<_>>();
+        assert_eq!(
+            mempool_hashes,
+            notarized_transaction_hashes[1..]
+                .iter()
+                .cloned()
+                .collect::<IndexSet<_>>()
+        );
+    }
+}
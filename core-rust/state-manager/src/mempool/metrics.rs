@@ -69,6 +69,8 @@ pub struct MempoolMetrics {
     pub current_transactions: IntGauge,
     pub current_total_transactions_size: IntGauge,
     pub submission_added: IntCounterVec,
+    pub submission_replaced: IntCounter,
+    pub submission_displaced: IntCounterVec,
 }
 
 pub struct MempoolManagerMetrics {
@@ -97,6 +99,19 @@ impl MempoolMetrics {
                 &["source"],
             )
             .registered_at(registry),
+            submission_replaced: IntCounter::with_opts(opts(
+                "mempool_submission_replaced_total",
+                "Count of transactions replaced by a higher-tip transaction for the same intent.",
+            ))
+            .registered_at(registry),
+            submission_displaced: IntCounterVec::new(
+                opts(
+                    "mempool_submission_displaced_total",
+                    "Count of transactions displaced by a higher-tip transaction of the same party exceeding its quota.",
+                ),
+                &["quota"],
+            )
+            .registered_at(registry),
         }
     }
 }
@@ -137,6 +152,17 @@ impl MetricLabel for MempoolAddSource {
     }
 }
 
+impl MetricLabel for MempoolQuota {
+    type StringReturnType = &'static str;
+
+    fn prometheus_label_name(&self) -> Self::StringReturnType {
+        match *self {
+            MempoolQuota::FeePayer => "FeePayer",
+            MempoolQuota::Notary => "Notary",
+        }
+    }
+}
+
 pub struct MempoolAddResult(Option<MempoolAddError>);
 
 impl MempoolAddResult {
@@ -152,6 +178,9 @@ impl MetricLabel for MempoolAddResult {
         match &self.0 {
             None => "Added",
             Some(MempoolAddError::PriorityThresholdNotMet { .. }) => "PriorityThresholdNotMet",
+            Some(MempoolAddError::ReplacementTipTooLow { .. }) => "ReplacementTipTooLow",
+            Some(MempoolAddError::FeePayerQuotaExceeded { .. }) => "FeePayerQuotaExceeded",
+            Some(MempoolAddError::NotaryQuotaExceeded { .. }) => "NotaryQuotaExceeded",
             Some(MempoolAddError::Rejected(rejection, _)) => match &rejection.reason {
                 MempoolRejectionReason::TransactionIntentAlreadyCommitted(_) => "AlreadyCommitted",
                 MempoolRejectionReason::SubintentAlreadyFinalized(_) => "SubintentAlreadyFinalized",
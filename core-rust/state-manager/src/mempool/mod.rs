@@ -64,19 +64,23 @@
 
 use crate::prelude::*;
 
+mod fee_estimator;
 mod mempool_manager;
+mod mempool_persistence;
 mod mempool_relay_dispatcher;
 mod metrics;
 mod pending_transaction_result_cache;
 mod priority_mempool;
 
+pub use fee_estimator::*;
 pub use mempool_manager::*;
+pub use mempool_persistence::*;
 pub use mempool_relay_dispatcher::*;
 pub use metrics::*;
 pub use pending_transaction_result_cache::*;
 pub use priority_mempool::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
 pub enum MempoolAddSource {
     CoreApi,
     MempoolSync,
@@ -89,6 +93,27 @@ pub enum MempoolAddError {
         min_tip_basis_points_required: Option<u32>,
         tip_basis_points: u32,
     },
+    /// A transaction submitting the same intent (or any of the same subintents) is already in the
+    /// mempool, and the new one's tip is not high enough to replace it.
+    ReplacementTipTooLow {
+        replaced_tip_basis_points: u32,
+        min_tip_basis_points_required: u32,
+        tip_basis_points: u32,
+    },
+    /// The transaction's fee payer already has [`MempoolConfig::max_transactions_per_fee_payer`]
+    /// transactions in the mempool, and the new one does not outrank any of them.
+    FeePayerQuotaExceeded {
+        max_transaction_count: u32,
+        min_tip_basis_points_required: Option<u32>,
+        tip_basis_points: u32,
+    },
+    /// The transaction's notary already has [`MempoolConfig::max_transactions_per_notary`]
+    /// transactions in the mempool, and the new one does not outrank any of them.
+    NotaryQuotaExceeded {
+        max_transaction_count: u32,
+        min_tip_basis_points_required: Option<u32>,
+        tip_basis_points: u32,
+    },
     Duplicate(NotarizedTransactionHash),
     Rejected(Box<MempoolAddRejection>, Option<NotarizedTransactionHash>),
 }
@@ -149,6 +174,35 @@ impl<'a> ContextualDisplay<ScryptoValueDisplayContext<'a>> for MempoolAddError {
                     write!(f, "Priority Threshold not met: tip is {tip_basis_points} basis points while min tip required {min_tip_percentage_required} basis points")
                 }
             },
+            MempoolAddError::ReplacementTipTooLow {
+                replaced_tip_basis_points,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => {
+                write!(f, "Replacement tip too low: tip is {tip_basis_points} basis points while replacing a transaction with tip {replaced_tip_basis_points} basis points requires at least {min_tip_basis_points_required} basis points")
+            }
+            MempoolAddError::FeePayerQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => write_quota_exceeded(
+                f,
+                "Fee payer",
+                *max_transaction_count,
+                *min_tip_basis_points_required,
+                *tip_basis_points,
+            ),
+            MempoolAddError::NotaryQuotaExceeded {
+                max_transaction_count,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            } => write_quota_exceeded(
+                f,
+                "Notary",
+                *max_transaction_count,
+                *min_tip_basis_points_required,
+                *tip_basis_points,
+            ),
             MempoolAddError::Duplicate(_) => write!(f, "Duplicate Entry"),
             MempoolAddError::Rejected(rejection, _) => {
                 rejection.reason.contextual_format(f, context)
@@ -156,3 +210,16 @@ impl<'a> ContextualDisplay<ScryptoValueDisplayContext<'a>> for MempoolAddError {
         }
     }
 }
+
+fn write_quota_exceeded(
+    f: &mut fmt::Formatter,
+    party: &str,
+    max_transaction_count: u32,
+    min_tip_basis_points_required: Option<u32>,
+    tip_basis_points: u32,
+) -> fmt::Result {
+    match min_tip_basis_points_required {
+        None => write!(f, "{party} quota exceeded: at most {max_transaction_count} transactions are allowed in the mempool"),
+        Some(min_tip_basis_points_required) => write!(f, "{party} quota exceeded: at most {max_transaction_count} transactions are allowed in the mempool, and tip is {tip_basis_points} basis points while displacing one of them requires at least {min_tip_basis_points_required} basis points"),
+    }
+}
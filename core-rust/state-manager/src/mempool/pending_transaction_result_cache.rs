@@ -18,13 +18,13 @@ pub enum MempoolRejectionReason {
     ValidationError(TransactionValidationError),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct AlreadyCommittedError {
     pub committed_state_version: StateVersion,
     pub committed_notarized_transaction_hash: NotarizedTransactionHash,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct SubintentAlreadyFinalizedError {
     pub subintent_hash: SubintentHash,
     pub committed_state_version: StateVersion,
@@ -288,7 +288,7 @@ impl TransactionAttempt {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AtState {
     // We might need this to be versioned by protocol update later...
     Static,
@@ -307,7 +307,7 @@ impl AtState {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AtSpecificState {
     Committed {
         state_version: StateVersion,
@@ -381,6 +381,8 @@ impl RetryFrom {
 pub struct PendingExecutedTransaction {
     pub executable: ExecutableTransaction,
     pub user_hashes: UserTransactionHashes,
+    /// See [`CommittabilityCheck::fee_payer`].
+    pub fee_payer: Option<GlobalAddress>,
     pub latest_attempt_against_state: AtSpecificState,
 }
 
@@ -388,6 +390,7 @@ impl PendingExecutedTransaction {
     pub fn new(
         executable: ExecutableTransaction,
         user_hashes: UserTransactionHashes,
+        fee_payer: Option<GlobalAddress>,
         against_state: AtState,
     ) -> Self {
         let AtState::Specific(latest_attempt_against_state) = against_state else {
@@ -396,6 +399,7 @@ impl PendingExecutedTransaction {
         Self {
             executable,
             user_hashes,
+            fee_payer,
             latest_attempt_against_state,
         }
     }
@@ -495,9 +499,11 @@ impl PendingTransactionRecord {
             CheckMetadata::Fresh(StaticValidation::Valid {
                 executable,
                 user_hashes,
+                fee_payer,
             }) => Ok(PendingExecutedTransaction::new(
                 executable,
                 user_hashes,
+                fee_payer,
                 self.latest_attempt.against_state,
             )),
             CheckMetadata::Fresh(StaticValidation::Invalid) => {
@@ -834,6 +840,64 @@ impl PendingTransactionResultCache {
         }
     }
 
+    /// Iterates over the pending transaction records (without affecting their LRU order), starting
+    /// from the least recently used one - i.e. in an order suitable for re-inserting them.
+    pub fn iter_pending_transaction_records(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            &NotarizedTransactionHash,
+            &PendingTransactionRecord,
+            &TransactionIntentHash,
+            &[SubintentHash],
+        ),
+    > + '_ {
+        self.pending_transaction_records.iter().rev().map(
+            |(notarized_transaction_hash, (record, transaction_intent_hash, subintent_hashes))| {
+                (
+                    notarized_transaction_hash,
+                    record,
+                    transaction_intent_hash,
+                    subintent_hashes.as_slice(),
+                )
+            },
+        )
+    }
+
+    /// Puts back a previously captured record (e.g. after a Node restart), unless the same payload
+    /// is already tracked.
+    pub fn restore_pending_transaction_record(
+        &mut self,
+        notarized_transaction_hash: NotarizedTransactionHash,
+        transaction_intent_hash: TransactionIntentHash,
+        subintent_hashes: Vec<SubintentHash>,
+        record: PendingTransactionRecord,
+    ) {
+        if self
+            .pending_transaction_records
+            .contains(&notarized_transaction_hash)
+        {
+            return;
+        }
+        self.handled_added(
+            transaction_intent_hash,
+            notarized_transaction_hash,
+            subintent_hashes.as_slice(),
+        );
+        let removed = self.pending_transaction_records.push(
+            notarized_transaction_hash,
+            (record, transaction_intent_hash, subintent_hashes),
+        );
+        if let Some((notarized_transaction_hash, removed)) = removed {
+            let (_, transaction_intent_hash, subintent_hashes) = removed;
+            self.handled_removed(
+                notarized_transaction_hash,
+                transaction_intent_hash,
+                subintent_hashes,
+            );
+        }
+    }
+
     fn handled_added(
         &mut self,
         intent_hash: TransactionIntentHash,
@@ -66,6 +66,7 @@ use crate::prelude::*;
 use rand::seq::index::sample;
 
 use std::cmp::{max, min, Ordering};
+use std::collections::BTreeSet;
 
 use node_common::indexing::SecondaryIndex;
 use std::ops::Index;
@@ -92,6 +93,9 @@ pub struct MempoolData {
     pub source: MempoolAddSource,
     /// The state version of this transaction's last successful execution.
     pub successfully_executed_at: StateVersion,
+    /// The execution cost units consumed by this transaction's latest full execution (i.e. as part
+    /// of a prepared proposal), if any.
+    pub execution_cost_units_consumed: Option<u32>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)] // (`Eq` for tests only)
@@ -99,6 +103,7 @@ pub struct MempoolTransaction {
     pub executable: ExecutableTransaction,
     pub hashes: UserTransactionHashes,
     pub raw: RawNotarizedTransaction,
+    pub parties: MempoolTransactionParties,
 }
 
 impl MempoolTransaction {
@@ -114,6 +119,39 @@ impl MempoolTransaction {
     }
 }
 
+/// The parties which a mempool transaction counts against, for the purpose of the per-party quotas
+/// (see [`MempoolConfig::max_transactions_per_fee_payer`] and
+/// [`MempoolConfig::max_transactions_per_notary`]).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MempoolTransactionParties {
+    /// The global entity which paid the fee during the committability check's execution (see
+    /// [`CommittabilityCheck::fee_payer`]).
+    /// Absent if it could not be resolved - all such transactions share a single quota.
+    pub fee_payer: Option<GlobalAddress>,
+    /// The notary's public key.
+    pub notary: PublicKey,
+}
+
+impl MempoolTransactionParties {
+    pub fn new(fee_payer: Option<GlobalAddress>, transaction: &UserTransaction) -> Self {
+        Self {
+            fee_payer,
+            notary: match transaction {
+                UserTransaction::V1(notarized) => {
+                    notarized.signed_intent.intent.header.notary_public_key
+                }
+                UserTransaction::V2(notarized) => {
+                    notarized
+                        .signed_transaction_intent
+                        .transaction_intent
+                        .transaction_header
+                        .notary_public_key
+                }
+            },
+        }
+    }
+}
+
 impl HasTransactionIntentHash for MempoolTransaction {
     fn transaction_intent_hash(&self) -> TransactionIntentHash {
         self.hashes.transaction_intent_hash
@@ -144,6 +182,7 @@ impl MempoolData {
             added_at,
             source,
             successfully_executed_at,
+            execution_cost_units_consumed: None,
         }
     }
 }
@@ -202,11 +241,50 @@ impl ExecutionStateVersion {
     }
 }
 
+/// The current usage of the mempool's capacity.
+#[derive(Debug, Clone)]
+pub struct MempoolOccupancy {
+    pub transaction_count: u32,
+    pub max_transaction_count: u32,
+    pub total_transactions_size: u64,
+    pub max_total_transactions_size: u64,
+}
+
+impl MempoolOccupancy {
+    /// Returns true if no new transaction can be added without evicting some existing one.
+    /// Note: the size limit is only considered exhausted when there is no space left at all, since
+    /// the size of the next transaction is unknown.
+    pub fn is_full(&self) -> bool {
+        self.transaction_count >= self.max_transaction_count
+            || self.total_transactions_size >= self.max_total_transactions_size
+    }
+}
+
+/// A per-party limit on the number of transactions present in the mempool.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MempoolQuota {
+    /// See [`MempoolConfig::max_transactions_per_fee_payer`].
+    FeePayer,
+    /// See [`MempoolConfig::max_transactions_per_notary`].
+    Notary,
+}
+
 pub struct PriorityMempool {
+    /// The configured [`MempoolConfig::max_transaction_count`].
+    max_transaction_count: u32,
+    /// The configured [`MempoolConfig::max_total_transactions_size`].
+    max_total_transactions_size: u64,
     /// Max number of different (by [`NotarizedTransactionHash`]) transactions that can live at any moment of time in the mempool.
     remaining_transaction_count: u32,
     /// Max sum of transactions size that can live in [`self.data`].
     remaining_total_transactions_size: u64,
+    /// A tip increase required from a transaction replacing the ones it conflicts with (see
+    /// [`MempoolConfig::replacement_min_tip_bump_basis_points`]).
+    replacement_min_tip_bump_basis_points: Option<u32>,
+    /// The configured [`MempoolConfig::max_transactions_per_fee_payer`].
+    max_transactions_per_fee_payer: Option<u32>,
+    /// The configured [`MempoolConfig::max_transactions_per_notary`].
+    max_transactions_per_notary: Option<u32>,
     /// Mapping from [`NotarizedTransactionHash`] to [`MempoolData`] containing [`MempoolTransaction`] with said payload hash.
     /// We use [`IndexMap`] for it's O(1) [`get_index`] needed for efficient random sampling.
     data: IndexMap<NotarizedTransactionHash, MempoolData>,
@@ -214,6 +292,11 @@ pub struct PriorityMempool {
     intent_lookup: HashMap<TransactionIntentHash, HashSet<NotarizedTransactionHash>>,
     /// Mapping from [`SubintentHash`] to all transactions ([`NotarizedTransactionHash`]) that contain said subintent.
     subintent_lookup: HashMap<SubintentHash, HashSet<NotarizedTransactionHash>>,
+    /// Mapping from a fee payer to all transactions ([`NotarizedTransactionHash`]) that pay the fee from it.
+    /// The transactions of unknown fee payers are all tracked under the [`None`] key.
+    fee_payer_lookup: HashMap<Option<GlobalAddress>, HashSet<NotarizedTransactionHash>>,
+    /// Mapping from a notary's public key to all transactions ([`NotarizedTransactionHash`]) that it notarized.
+    notary_lookup: HashMap<PublicKey, HashSet<NotarizedTransactionHash>>,
     /// Keeps ordering of the transactions by their [`ProposalPriority`].
     proposal_priority_index: SecondaryIndex<ProposalPriority, NotarizedTransactionHash>,
     /// Keeps ordering of the transactions by their validity end epoch.
@@ -227,11 +310,18 @@ pub struct PriorityMempool {
 impl PriorityMempool {
     pub fn new(config: MempoolConfig, metric_registry: &MetricRegistry) -> PriorityMempool {
         PriorityMempool {
+            max_transaction_count: config.max_transaction_count,
+            max_total_transactions_size: config.max_total_transactions_size,
             remaining_transaction_count: config.max_transaction_count,
             remaining_total_transactions_size: config.max_total_transactions_size,
+            replacement_min_tip_bump_basis_points: config.replacement_min_tip_bump_basis_points,
+            max_transactions_per_fee_payer: config.max_transactions_per_fee_payer,
+            max_transactions_per_notary: config.max_transactions_per_notary,
             data: IndexMap::new(),
             intent_lookup: HashMap::new(),
             subintent_lookup: HashMap::new(),
+            fee_payer_lookup: HashMap::new(),
+            notary_lookup: HashMap::new(),
             proposal_priority_index: SecondaryIndex::new(),
             end_epoch_exclusive_index: SecondaryIndex::new(),
             executed_state_version_index: SecondaryIndex::new(),
@@ -242,8 +332,10 @@ impl PriorityMempool {
 
 impl PriorityMempool {
     /// Tries to add a new transaction into the mempool.
-    /// Will return either a [`Vec`] of [`MempoolData`] that was evicted in order to fit the new transaction or an error
-    /// if the mempool is full and the new transaction proposal priority is not better than what already exists.
+    /// Will return either a [`Vec`] of [`MempoolData`] that was evicted in order to fit the new transaction (or replaced
+    /// by it, see [`Self::get_transactions_to_replace()`], or displaced by it, see
+    /// [`Self::get_transactions_to_displace()`]) or an error if the mempool is full and the new transaction
+    /// proposal priority is not better than what already exists.
     /// Returns an empty [`Vec`] if the transaction was already present in the mempool.
     pub fn add_transaction_if_not_present(
         &mut self,
@@ -258,18 +350,40 @@ impl PriorityMempool {
             return Ok(vec![]);
         }
 
+        let to_be_replaced = self.get_transactions_to_replace(&transaction)?;
+        let mut leaving_hashes = to_be_replaced
+            .iter()
+            .map(|data| data.transaction.notarized_transaction_hash())
+            .collect::<HashSet<_>>();
+
         let intent_hash = transaction.transaction_intent_hash();
         let transaction_size = transaction.raw.len() as u64;
 
         let transaction_data = MempoolData::new(transaction, added_at, source, executed_at);
         let new_proposal_priority = ProposalPriority::new(&transaction_data);
 
-        let mut total_transaction_size_free_space = self.remaining_total_transactions_size;
-        let mut total_transaction_count_free_space = self.remaining_transaction_count;
+        let to_be_displaced =
+            self.get_transactions_to_displace(&transaction_data, &leaving_hashes)?;
+        leaving_hashes.extend(
+            to_be_displaced
+                .iter()
+                .map(|(_, data)| data.transaction.notarized_transaction_hash()),
+        );
+
+        // The replaced and displaced transactions will be removed regardless of the priority-based eviction below.
+        let mut total_transaction_size_free_space = self.remaining_total_transactions_size
+            + to_be_replaced
+                .iter()
+                .chain(to_be_displaced.iter().map(|(_, data)| data))
+                .map(|data| data.transaction.raw.len() as u64)
+                .sum::<u64>();
+        let mut total_transaction_count_free_space = self.remaining_transaction_count
+            + (to_be_replaced.len() + to_be_displaced.len()) as u32;
         let mut to_be_removed = Vec::new();
         let mut priority_iter = self
             .proposal_priority_index
             .iter_values_from_least()
+            .filter(|hash| !leaving_hashes.contains(*hash))
             .map(|hash| self.data.index(hash));
         // Collect the lowest priority transactions that are required to be evicted in order to add the new one.
         // Note: worst-case scenario is the biggest transaction that will be rejected against a mempool full of smallest
@@ -314,6 +428,14 @@ impl PriorityMempool {
         }
 
         // Make room for new transaction
+        for data in to_be_replaced.iter() {
+            self.remove_data(data.clone());
+            self.metrics.submission_replaced.inc();
+        }
+        for (quota, data) in to_be_displaced.iter() {
+            self.remove_data(data.clone());
+            self.metrics.submission_displaced.with_label(*quota).inc();
+        }
         for data in to_be_removed.iter() {
             self.remove_data(data.clone());
         }
@@ -369,7 +491,22 @@ impl PriorityMempool {
                 .insert(notarized_transaction_hash);
         }
 
-        Ok(to_be_removed)
+        // Add party lookups
+        let parties = &transaction_data.transaction.parties;
+        self.fee_payer_lookup
+            .entry(parties.fee_payer)
+            .or_default()
+            .insert(notarized_transaction_hash);
+        self.notary_lookup
+            .entry(parties.notary)
+            .or_default()
+            .insert(notarized_transaction_hash);
+
+        Ok(to_be_replaced
+            .into_iter()
+            .chain(to_be_displaced.into_iter().map(|(_, data)| data))
+            .chain(to_be_removed)
+            .collect())
     }
 
     pub fn contains_transaction(
@@ -450,6 +587,34 @@ impl PriorityMempool {
         self.data.len()
     }
 
+    pub fn get_occupancy(&self) -> MempoolOccupancy {
+        MempoolOccupancy {
+            transaction_count: self.max_transaction_count - self.remaining_transaction_count,
+            max_transaction_count: self.max_transaction_count,
+            total_transactions_size: self.max_total_transactions_size
+                - self.remaining_total_transactions_size,
+            max_total_transactions_size: self.max_total_transactions_size,
+        }
+    }
+
+    /// Returns the tip of the transaction which would be evicted first.
+    pub fn get_lowest_priority_tip_basis_points(&self) -> Option<u32> {
+        self.proposal_priority_index
+            .iter_values_from_least()
+            .next()
+            .map(|hash| self.data.index(hash).transaction.tip_basis_points())
+    }
+
+    /// Returns the tips of (at most) the given number of the highest-priority transactions, in the
+    /// order of [`proposal_priority_index`].
+    pub fn get_highest_priority_tips_basis_points(&self, max_count: usize) -> Vec<u32> {
+        self.proposal_priority_index
+            .iter_values_from_greatest()
+            .take(max_count)
+            .map(|hash| self.data.index(hash).transaction.tip_basis_points())
+            .collect()
+    }
+
     pub fn get_notarized_transaction_hashes_for_intent(
         &self,
         intent_hash: &TransactionIntentHash,
@@ -508,38 +673,225 @@ impl PriorityMempool {
         .collect()
     }
 
-    /// Picks an subset of transactions to form the proposal.
-    /// Transactions are picked in the order of [`proposal_priority_index`].
-    /// Obeys the given count/size limits and explicit exclusions.
+    /// Picks a subset of transactions to form the proposal.
+    /// Obeys the given count/size/execution cost limits and explicit exclusions.
+    /// Out of the transactions submitting the same intent (or any of the same subintents), at most
+    /// one is picked - the others could not be committed after it anyway. The conflicts are resolved
+    /// in favour of the highest total tip:
+    /// - first, the transactions are picked greedily, in the order of [`proposal_priority_index`];
+    /// - then, each picked transaction is swapped for the conflicting ones it has blocked, if they
+    ///   offer a higher total tip (weighted by their estimated execution cost, when known) and
+    ///   still fit within the limits.
+    ///
+    /// The execution cost of a transaction is estimated by its latest full execution (see
+    /// [`Self::observe_execution_cost_units()`]); a transaction not yet executed in full is assumed to
+    /// take its fair share of the proposal's execution cost limit (i.e. as if the proposal was filled
+    /// with similar transactions up to its count limit). Note: the mempool's own committability
+    /// check cannot tell the cost, since it only executes a transaction up to its fee loan.
+    /// The returned transactions are ordered by their proposal priority.
     pub fn get_proposal_transactions(
         &self,
         max_count: usize,
         max_payload_size_bytes: u64,
+        max_execution_cost_units: u64,
         notarized_transaction_hashes_to_exclude: &HashSet<NotarizedTransactionHash>,
     ) -> Vec<Arc<MempoolTransaction>> {
         const MAX_TRANSACTIONS_TO_TRY: usize = 1000;
         let max_transactions_to_try = max(max_count, MAX_TRANSACTIONS_TO_TRY);
 
-        let mut payload_size_so_far = 0;
-        self.proposal_priority_index
+        let candidates = self
+            .proposal_priority_index
             .iter_values_from_greatest()
             .filter(|hash| !notarized_transaction_hashes_to_exclude.contains(*hash))
             .take(max_transactions_to_try)
-            .map(|hash| self.data.index(hash).transaction.clone())
-            .filter(|transaction| {
-                let increased_payload_size = payload_size_so_far + transaction.raw.len() as u64;
-                let fits = increased_payload_size <= max_payload_size_bytes;
-                if fits {
-                    payload_size_so_far = increased_payload_size;
-                }
-                fits
-            })
-            .take(max_count)
+            .map(|hash| self.data.index(hash))
+            .collect::<Vec<_>>();
+
+        let mut packing = ProposalPacking::new(
+            &candidates,
+            max_count,
+            max_payload_size_bytes,
+            max_execution_cost_units,
+        );
+        for index in 0..candidates.len() {
+            if packing.is_full() {
+                break;
+            }
+            packing.try_pick(index);
+        }
+        packing.swap_for_higher_total_tips();
+        packing
+            .into_picked_indices()
+            .map(|index| candidates[index].transaction.clone())
             .collect()
     }
 
+    /// Records the execution cost units actually consumed by the given transaction, to be used as
+    /// an estimate by [`Self::get_proposal_transactions()`].
+    pub fn observe_execution_cost_units(
+        &mut self,
+        notarized_transaction_hash: &NotarizedTransactionHash,
+        execution_cost_units: u32,
+    ) {
+        if let Some(data) = self.data.get_mut(notarized_transaction_hash) {
+            data.execution_cost_units_consumed = Some(execution_cost_units);
+        }
+    }
+
     // Only internals below:
 
+    /// Returns the transactions that the given one would replace, i.e. the ones submitting the same
+    /// intent or any of the same subintents.
+    /// Returns an error if the given transaction's tip does not exceed all of theirs by the configured
+    /// minimum bump. Without the replacement policy configured, nothing is ever replaced.
+    fn get_transactions_to_replace(
+        &self,
+        transaction: &MempoolTransaction,
+    ) -> Result<Vec<MempoolData>, MempoolAddError> {
+        let Some(min_tip_bump_basis_points) = self.replacement_min_tip_bump_basis_points else {
+            return Ok(vec![]);
+        };
+        let conflicting = self
+            .intent_lookup
+            .get(&transaction.transaction_intent_hash())
+            .into_iter()
+            .chain(
+                transaction
+                    .hashes
+                    .non_root_subintent_hashes
+                    .iter()
+                    .filter_map(|subintent_hash| self.subintent_lookup.get(subintent_hash)),
+            )
+            .flatten()
+            .unique()
+            .map(|notarized_transaction_hash| self.data.index(notarized_transaction_hash).clone())
+            .collect::<Vec<_>>();
+        let Some(replaced_tip_basis_points) = conflicting
+            .iter()
+            .map(|data| data.transaction.tip_basis_points())
+            .max()
+        else {
+            return Ok(vec![]);
+        };
+        let min_tip_basis_points_required =
+            replaced_tip_basis_points.saturating_add(min_tip_bump_basis_points);
+        let tip_basis_points = transaction.tip_basis_points();
+        if tip_basis_points < min_tip_basis_points_required {
+            return Err(MempoolAddError::ReplacementTipTooLow {
+                replaced_tip_basis_points,
+                min_tip_basis_points_required,
+                tip_basis_points,
+            });
+        }
+        Ok(conflicting)
+    }
+
+    /// Returns the transactions that the given one would displace in order to keep its fee payer
+    /// and its notary within their configured quotas, i.e. the lowest-priority transactions of that
+    /// party (ignoring the ones already leaving the mempool).
+    /// Returns an error if the given transaction does not outrank all of them. This way, a party
+    /// flooding the mempool can only ever displace its own transactions, while the others keep
+    /// competing for the capacity left.
+    fn get_transactions_to_displace(
+        &self,
+        transaction_data: &MempoolData,
+        leaving_hashes: &HashSet<NotarizedTransactionHash>,
+    ) -> Result<Vec<(MempoolQuota, MempoolData)>, MempoolAddError> {
+        let parties = &transaction_data.transaction.parties;
+        let tip_basis_points = transaction_data.transaction.tip_basis_points();
+        let mut leaving_hashes = leaving_hashes.clone();
+        let mut to_be_displaced = Vec::new();
+
+        if let Some(max_transaction_count) = self.max_transactions_per_fee_payer {
+            let displaced = self
+                .get_quota_excess(
+                    self.fee_payer_lookup.get(&parties.fee_payer),
+                    max_transaction_count,
+                    transaction_data,
+                    &leaving_hashes,
+                )
+                .map_err(|min_tip_basis_points_required| {
+                    MempoolAddError::FeePayerQuotaExceeded {
+                        max_transaction_count,
+                        min_tip_basis_points_required,
+                        tip_basis_points,
+                    }
+                })?;
+            leaving_hashes.extend(
+                displaced
+                    .iter()
+                    .map(|data| data.transaction.notarized_transaction_hash()),
+            );
+            to_be_displaced.extend(
+                displaced
+                    .into_iter()
+                    .map(|data| (MempoolQuota::FeePayer, data)),
+            );
+        }
+
+        if let Some(max_transaction_count) = self.max_transactions_per_notary {
+            let displaced = self
+                .get_quota_excess(
+                    self.notary_lookup.get(&parties.notary),
+                    max_transaction_count,
+                    transaction_data,
+                    &leaving_hashes,
+                )
+                .map_err(
+                    |min_tip_basis_points_required| MempoolAddError::NotaryQuotaExceeded {
+                        max_transaction_count,
+                        min_tip_basis_points_required,
+                        tip_basis_points,
+                    },
+                )?;
+            to_be_displaced.extend(
+                displaced
+                    .into_iter()
+                    .map(|data| (MempoolQuota::Notary, data)),
+            );
+        }
+
+        Ok(to_be_displaced)
+    }
+
+    /// Returns the lowest-priority transactions (out of the given party's ones, not already leaving)
+    /// which need to be removed for the given new one to fit within the party's quota.
+    /// Returns an error (holding the tip which would be sufficient, if any) if the new transaction
+    /// does not outrank them.
+    fn get_quota_excess(
+        &self,
+        party_transaction_hashes: Option<&HashSet<NotarizedTransactionHash>>,
+        max_transaction_count: u32,
+        transaction_data: &MempoolData,
+        leaving_hashes: &HashSet<NotarizedTransactionHash>,
+    ) -> Result<Vec<MempoolData>, Option<u32>> {
+        let mut party_transactions = party_transaction_hashes
+            .into_iter()
+            .flatten()
+            .filter(|hash| !leaving_hashes.contains(*hash))
+            .map(|hash| self.data.index(hash).clone())
+            .collect::<Vec<_>>();
+        let excess_count =
+            (party_transactions.len() + 1).saturating_sub(max_transaction_count as usize);
+        if excess_count == 0 {
+            return Ok(vec![]);
+        }
+        if excess_count > party_transactions.len() {
+            // Only possible with a zero quota - nothing could be displaced to make room.
+            return Err(None);
+        }
+        party_transactions.sort_by_cached_key(ProposalPriority::new);
+        party_transactions.truncate(excess_count);
+        let best_to_be_displaced = party_transactions.last().unwrap();
+        if ProposalPriority::new(transaction_data) < ProposalPriority::new(best_to_be_displaced) {
+            return Err(best_to_be_displaced
+                .transaction
+                .tip_basis_points()
+                .checked_add(1));
+        }
+        Ok(party_transactions)
+    }
+
     /// Removes the given item from the mempool's storage (and all indices).
     ///
     /// Note: assumes that the given transaction is currently in the mempool.
@@ -584,6 +936,28 @@ impl PriorityMempool {
             }
         }
 
+        let parties = &data.transaction.parties;
+        let fee_payer_lookup = self
+            .fee_payer_lookup
+            .get_mut(&parties.fee_payer)
+            .expect("Mempool fee payer lookup out of sync on remove");
+        if !fee_payer_lookup.remove(notarized_transaction_hash) {
+            panic!("Mempool fee payer lookup out of sync on remove");
+        }
+        if fee_payer_lookup.is_empty() {
+            self.fee_payer_lookup.remove(&parties.fee_payer);
+        }
+        let notary_lookup = self
+            .notary_lookup
+            .get_mut(&parties.notary)
+            .expect("Mempool notary lookup out of sync on remove");
+        if !notary_lookup.remove(notarized_transaction_hash) {
+            panic!("Mempool notary lookup out of sync on remove");
+        }
+        if notary_lookup.is_empty() {
+            self.notary_lookup.remove(&parties.notary);
+        }
+
         self.proposal_priority_index
             .remove_existing(ProposalPriority::new(&data), *notarized_transaction_hash);
         self.end_epoch_exclusive_index
@@ -625,6 +999,191 @@ impl PriorityMempool {
     }
 }
 
+/// A work-in-progress selection of mutually non-conflicting transactions for a proposal (see
+/// [`PriorityMempool::get_proposal_transactions()`]).
+/// The transactions are referenced by their index within the candidates, which are sorted by their
+/// proposal priority.
+struct ProposalPacking<'a> {
+    candidates: &'a [&'a MempoolData],
+    /// Indices of candidates submitting the given intent (or subintent).
+    candidates_by_intent: HashMap<IntentHash, Vec<usize>>,
+    max_count: usize,
+    max_payload_size_bytes: u64,
+    max_execution_cost_units: u64,
+    picked: BTreeSet<usize>,
+    /// The intents (and subintents) submitted by the picked transactions.
+    picked_intents: HashSet<IntentHash>,
+    payload_size_bytes: u64,
+    execution_cost_units: u64,
+    /// The execution cost units assumed for a candidate which was not yet executed in full.
+    unknown_execution_cost_units_estimate: u64,
+}
+
+impl<'a> ProposalPacking<'a> {
+    fn new(
+        candidates: &'a [&'a MempoolData],
+        max_count: usize,
+        max_payload_size_bytes: u64,
+        max_execution_cost_units: u64,
+    ) -> Self {
+        let mut candidates_by_intent = HashMap::<IntentHash, Vec<usize>>::new();
+        for (index, candidate) in candidates.iter().enumerate() {
+            for intent_hash in Self::intent_hashes(candidate) {
+                candidates_by_intent
+                    .entry(intent_hash)
+                    .or_default()
+                    .push(index);
+            }
+        }
+        Self {
+            candidates,
+            candidates_by_intent,
+            max_count,
+            max_payload_size_bytes,
+            max_execution_cost_units,
+            picked: BTreeSet::new(),
+            picked_intents: HashSet::new(),
+            payload_size_bytes: 0,
+            execution_cost_units: 0,
+            unknown_execution_cost_units_estimate: max_execution_cost_units
+                / max(max_count, 1) as u64,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.picked.len() >= self.max_count
+    }
+
+    /// Picks the given candidate, if it does not conflict with the already-picked ones and fits
+    /// within the limits.
+    fn try_pick(&mut self, index: usize) -> bool {
+        let candidate = self.candidates[index];
+        if self.is_full()
+            || self.picked.contains(&index)
+            || Self::intent_hashes(candidate).any(|hash| self.picked_intents.contains(&hash))
+        {
+            return false;
+        }
+        let payload_size_bytes = self.payload_size_bytes + candidate.transaction.raw.len() as u64;
+        let execution_cost_units = self
+            .execution_cost_units
+            .saturating_add(self.estimated_execution_cost_units(candidate));
+        if payload_size_bytes > self.max_payload_size_bytes
+            || execution_cost_units > self.max_execution_cost_units
+        {
+            return false;
+        }
+        self.payload_size_bytes = payload_size_bytes;
+        self.execution_cost_units = execution_cost_units;
+        self.picked_intents.extend(Self::intent_hashes(candidate));
+        self.picked.insert(index);
+        true
+    }
+
+    fn unpick(&mut self, index: usize) {
+        let candidate = self.candidates[index];
+        self.picked.remove(&index);
+        for intent_hash in Self::intent_hashes(candidate) {
+            self.picked_intents.remove(&intent_hash);
+        }
+        self.payload_size_bytes -= candidate.transaction.raw.len() as u64;
+        self.execution_cost_units -= self.estimated_execution_cost_units(candidate);
+    }
+
+    /// Goes through the picked transactions (starting from the lowest-priority one) and replaces
+    /// each of them with the conflicting candidates it has blocked, whenever these can be picked
+    /// together and their total tip is higher (see [`Self::outweighs()`]).
+    fn swap_for_higher_total_tips(&mut self) {
+        let picked_indices = self.picked.iter().rev().cloned().collect::<Vec<_>>();
+        for index in picked_indices {
+            let conflicting_indices = Self::intent_hashes(self.candidates[index])
+                .flat_map(|intent_hash| self.candidates_by_intent[&intent_hash].iter().cloned())
+                .filter(|conflicting_index| *conflicting_index != index)
+                .collect::<BTreeSet<_>>();
+            if conflicting_indices.is_empty() {
+                continue;
+            }
+            self.unpick(index);
+            let swapped_in = conflicting_indices
+                .into_iter()
+                .filter(|conflicting_index| self.try_pick(*conflicting_index))
+                .collect::<Vec<_>>();
+            if !self.outweighs(&swapped_in, index) {
+                for swapped_in_index in swapped_in {
+                    self.unpick(swapped_in_index);
+                }
+                let reverted = self.try_pick(index);
+                debug_assert!(reverted, "Reverting a swap should always succeed");
+                if !reverted {
+                    // The proposal can do without this transaction (it stays in the mempool):
+                    warn!(
+                        "Could not revert a proposal packing swap; leaving out transaction {:?}",
+                        self.candidates[index]
+                            .transaction
+                            .notarized_transaction_hash()
+                    );
+                }
+            }
+        }
+    }
+
+    fn into_picked_indices(self) -> impl Iterator<Item = usize> {
+        self.picked.into_iter()
+    }
+
+    /// Returns whether the total tip of the given candidates is higher than the given single one's.
+    /// The tip actually paid is proportional to the execution cost, so the tip basis points are
+    /// weighted by the estimated execution cost units - provided that all the involved candidates
+    /// have been executed in full. Otherwise, the tip basis points are compared directly, which is
+    /// only a heuristic (assuming similar execution costs).
+    fn outweighs(&self, indices: &[usize], other_index: usize) -> bool {
+        let involved = || indices.iter().cloned().chain(std::iter::once(other_index));
+        let tip_weight = if involved().all(|index| {
+            self.candidates[index]
+                .execution_cost_units_consumed
+                .is_some()
+        }) {
+            Self::estimated_tip_amount
+        } else {
+            Self::tip_basis_points
+        };
+        let total_tip_weight = indices
+            .iter()
+            .map(|index| tip_weight(self, *index))
+            .sum::<u64>();
+        total_tip_weight > tip_weight(self, other_index)
+    }
+
+    fn tip_basis_points(&self, index: usize) -> u64 {
+        self.candidates[index].transaction.tip_basis_points() as u64
+    }
+
+    /// Returns the tip basis points multiplied by the estimated execution cost units, i.e. a value
+    /// proportional to the tip actually paid (at the current cost unit price).
+    fn estimated_tip_amount(&self, index: usize) -> u64 {
+        self.tip_basis_points(index) * self.estimated_execution_cost_units(self.candidates[index])
+    }
+
+    fn intent_hashes(data: &MempoolData) -> impl Iterator<Item = IntentHash> + '_ {
+        std::iter::once(IntentHash::Transaction(
+            data.transaction.transaction_intent_hash(),
+        ))
+        .chain(
+            data.transaction
+                .hashes
+                .non_root_subintent_hashes
+                .iter()
+                .map(|subintent_hash| IntentHash::Subintent(*subintent_hash)),
+        )
+    }
+
+    fn estimated_execution_cost_units(&self, data: &MempoolData) -> u64 {
+        data.execution_cost_units_consumed
+            .map(|execution_cost_units| execution_cost_units as u64)
+            .unwrap_or(self.unknown_execution_cost_units_estimate)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -659,10 +1218,121 @@ mod tests {
         let hashes = validated.hashes();
         let executable = validated.create_executable();
 
+        let parties = MempoolTransactionParties::new(None, &raw.into_typed().unwrap());
+
         Arc::new(MempoolTransaction {
             executable,
             hashes,
             raw,
+            parties,
+        })
+    }
+
+    fn create_mempool_transaction_with_subintents(
+        intent_discriminator: u64,
+        subintent_discriminators: &[u64],
+        tip_percentage: u32,
+    ) -> Arc<MempoolTransaction> {
+        let intent_header = |intent_discriminator| IntentHeaderV2 {
+            network_id: 1,
+            start_epoch_inclusive: Epoch::of(1),
+            end_epoch_exclusive: Epoch::of(2),
+            min_proposer_timestamp_inclusive: None,
+            max_proposer_timestamp_exclusive: None,
+            intent_discriminator,
+        };
+        let notary = Ed25519PrivateKey::from_u64(999).unwrap();
+        let child_names = (0..subintent_discriminators.len())
+            .map(|index| format!("child{index}"))
+            .collect::<Vec<_>>();
+        let mut builder = TransactionV2Builder::new()
+            .transaction_header(TransactionHeaderV2 {
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_basis_points: tip_percentage * 100,
+            })
+            .intent_header(intent_header(intent_discriminator));
+        for (child_name, subintent_discriminator) in
+            child_names.iter().zip(subintent_discriminators)
+        {
+            let subintent = PartialTransactionV2Builder::new()
+                .intent_header(intent_header(*subintent_discriminator))
+                .manifest_builder(|builder| builder.yield_to_parent(()))
+                .build();
+            builder = builder.add_signed_child(child_name.as_str(), subintent);
+        }
+        let raw = builder
+            .manifest_builder(|builder| {
+                child_names
+                    .iter()
+                    .fold(builder.lock_fee_from_faucet(), |builder, child_name| {
+                        builder.yield_to_child(child_name.as_str(), ())
+                    })
+            })
+            .notarize(&notary)
+            .build_minimal()
+            .to_raw()
+            .unwrap();
+        let validated = raw
+            .validate(&TransactionValidator::new_with_latest_config_network_agnostic())
+            .unwrap();
+        let hashes = validated.hashes();
+        let executable = validated.create_executable();
+
+        let parties = MempoolTransactionParties::new(None, &raw.into_typed().unwrap());
+
+        Arc::new(MempoolTransaction {
+            executable,
+            hashes,
+            raw,
+            parties,
+        })
+    }
+
+    fn create_mempool_transaction_with_parties(
+        intent_discriminator: u32,
+        fee_payer_discriminator: u64,
+        notary_discriminator: u64,
+        tip_percentage: u16,
+    ) -> Arc<MempoolTransaction> {
+        let notary = Ed25519PrivateKey::from_u64(notary_discriminator).unwrap();
+        let fee_payer = ComponentAddress::preallocated_account_from_public_key(
+            &Ed25519PrivateKey::from_u64(fee_payer_discriminator)
+                .unwrap()
+                .public_key(),
+        );
+        let raw = TransactionV1Builder::new()
+            .header(TransactionHeaderV1 {
+                network_id: 1,
+                start_epoch_inclusive: Epoch::of(1),
+                end_epoch_exclusive: Epoch::of(2),
+                nonce: intent_discriminator,
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory: true,
+                tip_percentage,
+            })
+            .manifest(
+                ManifestBuilder::new_v1()
+                    .lock_fee(fee_payer, dec!(10))
+                    .build(),
+            )
+            .notarize(&notary)
+            .build()
+            .to_raw()
+            .unwrap();
+        let validated = raw
+            .validate(&TransactionValidator::new_with_latest_config_network_agnostic())
+            .unwrap();
+        let hashes = validated.hashes();
+        let executable = validated.create_executable();
+        let parties =
+            MempoolTransactionParties::new(Some(fee_payer.into()), &raw.into_typed().unwrap());
+
+        Arc::new(MempoolTransaction {
+            executable,
+            hashes,
+            raw,
+            parties,
         })
     }
 
@@ -679,6 +1349,10 @@ mod tests {
             MempoolConfig {
                 max_transaction_count: 5,
                 max_total_transactions_size: 2 * 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
             },
             &registry,
         );
@@ -749,6 +1423,10 @@ mod tests {
             MempoolConfig {
                 max_transaction_count: 10,
                 max_total_transactions_size: 2 * 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
             },
             &registry,
         );
@@ -934,6 +1612,10 @@ mod tests {
             MempoolConfig {
                 max_transaction_count: 4,
                 max_total_transactions_size: 2 * 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
             },
             &registry,
         );
@@ -1018,6 +1700,10 @@ mod tests {
             MempoolConfig {
                 max_transaction_count: 1,
                 max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
             },
             &registry,
         );
@@ -1037,4 +1723,370 @@ mod tests {
             .unwrap()
             .is_empty());
     }
+
+    #[test]
+    fn test_conflicting_transaction_replaced_only_with_sufficient_tip_bump() {
+        let original = create_mempool_transaction_with_subintents(1, &[7], 10);
+        let insufficient_bump = create_mempool_transaction_with_subintents(2, &[7], 15);
+        let replacement = create_mempool_transaction_with_subintents(3, &[7], 20);
+        let unrelated = create_mempool_transaction_with_subintents(4, &[8], 0);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: Some(1000),
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
+            },
+            &registry,
+        );
+
+        for transaction in [original.clone(), unrelated.clone()] {
+            assert!(mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap()
+                .is_empty());
+        }
+
+        let error = mempool
+            .add_transaction_if_not_present(insufficient_bump, MempoolAddSource::CoreApi, now, v1)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            MempoolAddError::ReplacementTipTooLow {
+                replaced_tip_basis_points: 1000,
+                min_tip_basis_points_required: 2000,
+                tip_basis_points: 1500,
+            }
+        ));
+        assert_eq!(mempool.get_count(), 2);
+
+        let replaced = mempool
+            .add_transaction_if_not_present(replacement.clone(), MempoolAddSource::CoreApi, now, v1)
+            .unwrap();
+        assert_eq!(replaced.len(), 1);
+        assert_eq!(replaced[0].transaction, original);
+        assert_eq!(mempool.get_count(), 2);
+        assert!(!mempool.contains_transaction(&original.notarized_transaction_hash()));
+    }
+
+    #[test]
+    fn test_proposal_prefers_highest_tip_among_conflicting_transactions() {
+        let lower_tip = create_mempool_transaction_with_subintents(1, &[7], 10);
+        let higher_tip = create_mempool_transaction_with_subintents(2, &[7], 20);
+        let unrelated = create_mempool_transaction_with_subintents(3, &[8], 0);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        // Without the replacement policy, the conflicting transactions co-exist in the mempool...
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
+            },
+            &registry,
+        );
+        for transaction in [lower_tip, higher_tip.clone(), unrelated.clone()] {
+            assert!(mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap()
+                .is_empty());
+        }
+        assert_eq!(mempool.get_count(), 3);
+
+        // ... but only the highest-tip one of them gets proposed.
+        let proposed = mempool.get_proposal_transactions(10, u64::MAX, u64::MAX, &HashSet::new());
+        assert_eq!(proposed, vec![higher_tip, unrelated]);
+    }
+
+    #[test]
+    fn test_parties_read_notary_from_header() {
+        let transaction = create_mempool_transaction_with_parties(1, 5, 6, 0);
+        assert_eq!(
+            transaction.parties.notary,
+            PublicKey::from(Ed25519PrivateKey::from_u64(6).unwrap().public_key())
+        );
+
+        let transaction = create_mempool_transaction_with_subintents(1, &[7], 0);
+        assert_eq!(
+            transaction.parties.notary,
+            PublicKey::from(Ed25519PrivateKey::from_u64(999).unwrap().public_key())
+        );
+    }
+
+    #[test]
+    fn test_fee_payer_quota_only_displaces_own_transactions() {
+        let flooder_low = create_mempool_transaction_with_parties(1, 1, 11, 0);
+        let flooder_high = create_mempool_transaction_with_parties(2, 1, 12, 20);
+        let flooder_same = create_mempool_transaction_with_parties(3, 1, 13, 0);
+        let flooder_better = create_mempool_transaction_with_parties(4, 1, 14, 10);
+        let other = create_mempool_transaction_with_parties(5, 2, 15, 0);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: Some(2),
+                max_transactions_per_notary: None,
+            },
+            &registry,
+        );
+
+        for transaction in [flooder_low.clone(), flooder_high.clone(), other.clone()] {
+            assert!(mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap()
+                .is_empty());
+        }
+        let later = now + Duration::from_secs(1);
+
+        // The fee payer is at its quota, and the new transaction does not outrank any of its own...
+        let error = mempool
+            .add_transaction_if_not_present(flooder_same, MempoolAddSource::CoreApi, later, v1)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            MempoolAddError::FeePayerQuotaExceeded {
+                max_transaction_count: 2,
+                min_tip_basis_points_required: Some(1),
+                tip_basis_points: 0,
+            }
+        ));
+
+        // ... while a higher-tip one displaces the fee payer's lowest-priority transaction only.
+        let displaced = mempool
+            .add_transaction_if_not_present(
+                flooder_better.clone(),
+                MempoolAddSource::CoreApi,
+                later,
+                v1,
+            )
+            .unwrap();
+        assert_eq!(displaced.len(), 1);
+        assert_eq!(displaced[0].transaction, flooder_low);
+        assert_eq!(mempool.get_count(), 3);
+        assert!(mempool.contains_transaction(&other.notarized_transaction_hash()));
+        assert!(mempool.contains_transaction(&flooder_high.notarized_transaction_hash()));
+        assert!(mempool.contains_transaction(&flooder_better.notarized_transaction_hash()));
+    }
+
+    #[test]
+    fn test_unknown_fee_payers_share_a_quota() {
+        let first_unknown = create_mempool_transaction(1, 0, 0);
+        let second_unknown = create_mempool_transaction(2, 0, 0);
+        let known = create_mempool_transaction_with_parties(3, 1, 11, 0);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: Some(1),
+                max_transactions_per_notary: None,
+            },
+            &registry,
+        );
+
+        mempool
+            .add_transaction_if_not_present(first_unknown, MempoolAddSource::CoreApi, now, v1)
+            .unwrap();
+        let error = mempool
+            .add_transaction_if_not_present(second_unknown, MempoolAddSource::CoreApi, now, v1)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            MempoolAddError::FeePayerQuotaExceeded {
+                max_transaction_count: 1,
+                ..
+            }
+        ));
+
+        // The transactions of known fee payers do not count against the shared quota.
+        mempool
+            .add_transaction_if_not_present(known, MempoolAddSource::CoreApi, now, v1)
+            .unwrap();
+        assert_eq!(mempool.get_count(), 2);
+    }
+
+    #[test]
+    fn test_notary_quota_rejects_flooding_notary() {
+        let first = create_mempool_transaction_with_parties(1, 1, 9, 0);
+        let second = create_mempool_transaction_with_parties(2, 2, 9, 0);
+        let other_notary = create_mempool_transaction_with_parties(3, 3, 10, 0);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: Some(1),
+            },
+            &registry,
+        );
+
+        mempool
+            .add_transaction_if_not_present(first.clone(), MempoolAddSource::CoreApi, now, v1)
+            .unwrap();
+        let error = mempool
+            .add_transaction_if_not_present(second, MempoolAddSource::CoreApi, now, v1)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            MempoolAddError::NotaryQuotaExceeded {
+                max_transaction_count: 1,
+                ..
+            }
+        ));
+        mempool
+            .add_transaction_if_not_present(other_notary, MempoolAddSource::CoreApi, now, v1)
+            .unwrap();
+        assert_eq!(mempool.get_count(), 2);
+
+        // Removing the notary's transaction also drops it from the lookup.
+        mempool.remove_by_intent_hash(&first.transaction_intent_hash());
+        assert_eq!(mempool.notary_lookup.len(), 1);
+    }
+
+    #[test]
+    fn test_proposal_swaps_transaction_for_higher_total_tip_combination() {
+        let spanning = create_mempool_transaction_with_subintents(1, &[7, 8], 30);
+        let first_part = create_mempool_transaction_with_subintents(2, &[7], 20);
+        let second_part = create_mempool_transaction_with_subintents(3, &[8], 20);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(
+            MempoolConfig {
+                max_transaction_count: 10,
+                max_total_transactions_size: 1024 * 1024,
+                persistence: None,
+                replacement_min_tip_bump_basis_points: None,
+                max_transactions_per_fee_payer: None,
+                max_transactions_per_notary: None,
+            },
+            &registry,
+        );
+        for transaction in [spanning, first_part.clone(), second_part.clone()] {
+            mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap();
+        }
+
+        // The highest-tip transaction alone pays less than the two it conflicts with.
+        let proposed = mempool.get_proposal_transactions(10, u64::MAX, u64::MAX, &HashSet::new());
+        assert_eq!(proposed.len(), 2);
+        assert!(proposed.contains(&first_part));
+        assert!(proposed.contains(&second_part));
+
+        // Unless there is no room for both of them.
+        let proposed = mempool.get_proposal_transactions(1, u64::MAX, u64::MAX, &HashSet::new());
+        assert_eq!(proposed.len(), 1);
+        assert_eq!(proposed[0].tip_basis_points(), 3000);
+    }
+
+    #[test]
+    fn test_proposal_swap_weighs_tips_by_estimated_execution_cost() {
+        let spanning = create_mempool_transaction_with_subintents(1, &[7, 8], 30);
+        let first_part = create_mempool_transaction_with_subintents(2, &[7], 20);
+        let second_part = create_mempool_transaction_with_subintents(3, &[8], 20);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(MempoolConfig::new_for_testing(), &registry);
+        for transaction in [spanning.clone(), first_part.clone(), second_part.clone()] {
+            mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap();
+        }
+
+        // Without the cost of the spanning transaction known, its tip basis points are compared.
+        mempool.observe_execution_cost_units(&first_part.notarized_transaction_hash(), 10);
+        mempool.observe_execution_cost_units(&second_part.notarized_transaction_hash(), 10);
+        let proposed = mempool.get_proposal_transactions(10, u64::MAX, u64::MAX, &HashSet::new());
+        assert_eq!(proposed.len(), 2);
+        assert!(proposed.contains(&first_part));
+        assert!(proposed.contains(&second_part));
+
+        // With all the costs known, the spanning transaction pays a higher tip in total.
+        mempool.observe_execution_cost_units(&spanning.notarized_transaction_hash(), 50);
+        let proposed = mempool.get_proposal_transactions(10, u64::MAX, u64::MAX, &HashSet::new());
+        assert_eq!(proposed, vec![spanning]);
+    }
+
+    #[test]
+    fn test_proposal_respects_estimated_execution_cost() {
+        let expensive_1 = create_mempool_transaction(1, 0, 30);
+        let expensive_2 = create_mempool_transaction(2, 0, 20);
+        let never_executed = create_mempool_transaction(3, 0, 10);
+        let another_never_executed = create_mempool_transaction(4, 0, 5);
+
+        let now = StdInstant::now();
+        let v1 = StateVersion::of(1);
+
+        let registry = MetricRegistry::new();
+
+        let mut mempool = PriorityMempool::new(MempoolConfig::new_for_testing(), &registry);
+        for transaction in [
+            expensive_1.clone(),
+            expensive_2.clone(),
+            never_executed.clone(),
+            another_never_executed.clone(),
+        ] {
+            mempool
+                .add_transaction_if_not_present(transaction, MempoolAddSource::CoreApi, now, v1)
+                .unwrap();
+        }
+        mempool.observe_execution_cost_units(&expensive_1.notarized_transaction_hash(), 60);
+        mempool.observe_execution_cost_units(&expensive_2.notarized_transaction_hash(), 60);
+
+        // A transaction not yet executed in full is assumed to take its fair share (i.e. 100 / 4):
+        let proposed = mempool.get_proposal_transactions(4, u64::MAX, 100, &HashSet::new());
+        assert_eq!(proposed, vec![expensive_1.clone(), never_executed.clone()]);
+
+        let proposed = mempool.get_proposal_transactions(10, u64::MAX, 100, &HashSet::new());
+        assert_eq!(
+            proposed,
+            vec![expensive_1, never_executed, another_never_executed]
+        );
+    }
 }
@@ -63,6 +63,7 @@
  */
 
 use crate::prelude::*;
+use crate::store::gc_control::GcKind;
 
 use std::time::UNIX_EPOCH;
 
@@ -107,6 +108,20 @@ pub struct RawDbMetrics {
     pub size: IntGaugeVec,
     pub files: IntGaugeVec,
     pub max_level: IntGaugeVec,
+    pub gc_reclaimed_bytes: IntCounterVec,
+}
+
+pub struct DatabaseMigrationMetrics {
+    pub schema_version: IntGauge,
+    pub pending_migrations: IntGauge,
+    pub migrated_batches: IntCounterVec,
+}
+
+pub struct GcMetrics {
+    pub paused: IntGaugeVec,
+    pub interval_sec: IntGaugeVec,
+    pub completed_runs: IntCounterVec,
+    pub progress: IntGaugeVec,
 }
 
 impl LedgerMetrics {
@@ -627,6 +642,22 @@ impl RawDbMetrics {
                 &["category"],
             )
             .registered_at(registry),
+            gc_reclaimed_bytes: IntCounterVec::new(
+                opts(
+                    "raw_db_gc_reclaimed_bytes",
+                    "An approximate total size of SST files reclaimed by the GCs' targeted compactions, by category.",
+                ),
+                &["category"],
+            )
+            .registered_at(registry),
+        }
+    }
+
+    pub fn record_reclaimed(&self, reclaimed: impl IntoIterator<Item = CategoryReclaimedBytes>) {
+        for category_reclaimed in reclaimed {
+            self.gc_reclaimed_bytes
+                .with_label(&category_reclaimed.category_name)
+                .inc_by(u64::try_from(category_reclaimed.reclaimed_bytes).unwrap_or_default());
         }
     }
 
@@ -651,6 +682,70 @@ impl RawDbMetrics {
     }
 }
 
+impl DatabaseMigrationMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            schema_version: IntGauge::with_opts(opts(
+                "db_schema_version",
+                "The schema version of the database (i.e. reached by the applied migrations).",
+            ))
+            .registered_at(registry),
+            pending_migrations: IntGauge::with_opts(opts(
+                "db_pending_migrations",
+                "A number of database migrations not yet completed (i.e. still running at start-up).",
+            ))
+            .registered_at(registry),
+            migrated_batches: IntCounterVec::new(
+                opts(
+                    "db_migrated_batches",
+                    "A number of batches committed by a specific database migration.",
+                ),
+                &["migration"],
+            )
+            .registered_at(registry),
+        }
+    }
+}
+
+impl GcMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            paused: IntGaugeVec::new(
+                opts(
+                    "gc_paused",
+                    "Whether a specific GC is currently paused (1) or not (0).",
+                ),
+                &["gc"],
+            )
+            .registered_at(registry),
+            interval_sec: IntGaugeVec::new(
+                opts(
+                    "gc_interval_sec",
+                    "A currently configured interval between periodic runs of a specific GC (0 means on-demand only).",
+                ),
+                &["gc"],
+            )
+            .registered_at(registry),
+            completed_runs: IntCounterVec::new(
+                opts(
+                    "gc_completed_runs",
+                    "A number of completed runs of a specific GC (both periodic and on-demand).",
+                ),
+                &["gc"],
+            )
+            .registered_at(registry),
+            progress: IntGaugeVec::new(
+                opts(
+                    "gc_progress",
+                    "A position reached by a specific GC's latest run: a state version for the StateTree GC, an epoch for the LedgerProofs GC.",
+                ),
+                &["gc"],
+            )
+            .registered_at(registry),
+        }
+    }
+}
+
 // Concrete types used for metric labels:
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConsensusRoundResolution {
@@ -699,6 +794,17 @@ impl MetricLabel for VertexPrepareStopReason {
     }
 }
 
+impl MetricLabel for GcKind {
+    type StringReturnType = &'static str;
+
+    fn prometheus_label_name(&self) -> Self::StringReturnType {
+        match self {
+            GcKind::StateTree => "StateTree",
+            GcKind::LedgerProofs => "LedgerProofs",
+        }
+    }
+}
+
 /// A number of most recent [`RoundSlot`]s of a single validator to track for metrics purposes.
 const PROPOSAL_HISTORY_LEN: usize = 100;
 
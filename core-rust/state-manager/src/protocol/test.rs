@@ -72,8 +72,7 @@ const CUSTOM_V2_PROTOCOL_VERSION: &str = "custom-v2";
 fn flash_protocol_update_test() {
     let custom_v2_protocol_version = ProtocolVersionName::of(CUSTOM_V2_PROTOCOL_VERSION).unwrap();
 
-    let mut state_manager_config =
-        StateManagerConfig::new_for_testing(tempfile::tempdir().unwrap().path().to_str().unwrap());
+    let mut state_manager_config = StateManagerConfig::new_for_testing_in_memory();
 
     // We're enacting an update after another transaction commit
     let protocol_update_epoch = Epoch::of(3);
@@ -0,0 +1,285 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+use std::time::UNIX_EPOCH;
+
+#[derive(Debug, Clone)]
+pub struct ReadReplicaConfig {
+    pub network_definition: NetworkDefinition,
+    /// A path of the live node's (i.e. primary) RocksDB directory.
+    pub primary_db_path: String,
+    /// A path of a directory private to this replica, where its secondary RocksDB instance keeps
+    /// its info logs.
+    pub secondary_db_path: String,
+    /// The protocol configuration of the primary.
+    pub protocol_config: ProtocolConfig,
+    /// An interval between the catch-ups with the primary.
+    pub catch_up_interval: Duration,
+}
+
+/// The replica's view of the ledger, as of its latest catch-up with the primary.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadReplicaSyncState {
+    /// The state version of the latest ledger proof visible to the replica.
+    pub state_version: StateVersion,
+    /// The proposer timestamp of the latest ledger proof visible to the replica.
+    pub proposer_timestamp_ms: i64,
+    /// The wall-clock time of the latest successful catch-up.
+    pub caught_up_at: SystemTime,
+}
+
+/// A status of a read replica, shared between its catch-up task and the API servers (which report
+/// the replica's staleness in their responses).
+pub struct ReadReplicaStatus {
+    sync_state: RwLock<Option<ReadReplicaSyncState>>,
+}
+
+impl ReadReplicaStatus {
+    pub fn new(lock_factory: &LockFactory) -> Self {
+        Self {
+            sync_state: lock_factory.named("sync_state").new_rwlock(None),
+        }
+    }
+
+    /// Returns the replica's view of the ledger, or [`None`] if it has not caught up yet.
+    pub fn sync_state(&self) -> Option<ReadReplicaSyncState> {
+        self.sync_state.read().clone()
+    }
+
+    /// Returns the time elapsed since the proposer timestamp of the latest ledger proof visible to
+    /// the replica.
+    /// Note: this includes the lag of the primary itself (which is what an API client cares about).
+    pub fn ledger_staleness(&self) -> Option<Duration> {
+        self.sync_state().map(|sync_state| {
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since_epoch| since_epoch.as_millis() as i64)
+                .unwrap_or(0);
+            Duration::from_millis(
+                now_ms
+                    .saturating_sub(sync_state.proposer_timestamp_ms)
+                    .max(0) as u64,
+            )
+        })
+    }
+
+    fn update(&self, sync_state: ReadReplicaSyncState) {
+        *self.sync_state.write() = Some(sync_state);
+    }
+}
+
+/// A periodic task of a read replica, catching up its secondary DB with the primary.
+///
+/// Apart from the DB itself, it also follows the protocol updates executed by the primary (which
+/// would otherwise only be picked up by the replica's services on restart).
+pub struct ReadReplicaCatchup {
+    state_manager: StateManager,
+    status: Arc<ReadReplicaStatus>,
+    interval: Duration,
+}
+
+impl ReadReplicaCatchup {
+    pub fn new(
+        state_manager: StateManager,
+        status: Arc<ReadReplicaStatus>,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            state_manager,
+            status,
+            interval,
+        }
+    }
+
+    /// An interval between [`Self::run()`]s.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Catches up with the primary and updates the shared [`ReadReplicaStatus`].
+    pub fn run(&self) {
+        let database = self.state_manager.database.lock();
+        database.try_catchup_with_primary();
+        let latest_proof = database.get_latest_proof();
+        let latest_protocol_version = database
+            .get_latest_protocol_update_execution_proof()
+            .and_then(|proof| match proof.origin {
+                LedgerProofOrigin::ProtocolUpdate {
+                    protocol_version_name,
+                    ..
+                } => Some(protocol_version_name),
+                LedgerProofOrigin::Consensus { .. } => None,
+            });
+        drop(database);
+
+        if let Some(latest_protocol_version) = latest_protocol_version {
+            if latest_protocol_version
+                != self
+                    .state_manager
+                    .protocol_manager
+                    .current_protocol_version()
+            {
+                info!(
+                    "Read replica observed protocol update to {}",
+                    latest_protocol_version
+                );
+                self.state_manager
+                    .enact_protocol_version(&latest_protocol_version);
+            }
+        }
+
+        if let Some(latest_proof) = latest_proof {
+            self.status.update(ReadReplicaSyncState {
+                state_version: latest_proof.ledger_header.state_version,
+                proposer_timestamp_ms: latest_proof.ledger_header.proposer_timestamp_ms,
+                caught_up_at: SystemTime::now(),
+            });
+        }
+    }
+}
+
+/// A [`ResolveGenesisData`] of a read replica, which never executes the genesis (since it only
+/// observes the ledger committed by the primary).
+pub(crate) struct UnavailableGenesisDataResolver;
+
+impl ResolveGenesisData for UnavailableGenesisDataResolver {
+    fn get_raw_genesis_data(&self) -> Vec<u8> {
+        panic!("a read replica does not execute genesis")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+    use node_common::scheduler::Scheduler;
+
+    #[test]
+    fn read_replica_catches_up_with_primary() {
+        let primary_tmp = tempfile::tempdir().unwrap();
+        let secondary_tmp = tempfile::tempdir().unwrap();
+        let primary = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing(primary_tmp.path().to_str().unwrap()),
+            10,
+        );
+        let primary_state_version = || {
+            primary
+                .database
+                .lock()
+                .get_latest_proof()
+                .unwrap()
+                .ledger_header
+                .state_version
+        };
+        commit_round_updates_until_epoch(&primary, Epoch::of(3));
+
+        // A bare scheduler does not run the periodic catch-up, so that we can trigger it explicitly:
+        let replica = StateManager::new_read_replica(
+            ReadReplicaConfig {
+                network_definition: NetworkDefinition::simulator(),
+                primary_db_path: primary_tmp.path().to_str().unwrap().to_string(),
+                secondary_db_path: secondary_tmp.path().to_str().unwrap().to_string(),
+                protocol_config: ProtocolConfig::new_with_no_updates(),
+                catch_up_interval: Duration::from_secs(1),
+            },
+            &LockFactory::new("testing"),
+            &MetricRegistry::new(),
+            &Scheduler::new("testing"),
+        )
+        .unwrap();
+        let status = replica.read_replica_status.clone().unwrap();
+        assert_eq!(
+            status.sync_state().unwrap().state_version,
+            primary_state_version()
+        );
+
+        commit_round_updates_until_epoch(&primary, Epoch::of(4));
+        assert_ne!(
+            status.sync_state().unwrap().state_version,
+            primary_state_version()
+        );
+
+        ReadReplicaCatchup::new(replica.clone(), status.clone(), Duration::from_secs(1)).run();
+        assert_eq!(
+            status.sync_state().unwrap().state_version,
+            primary_state_version()
+        );
+        assert_eq!(
+            replica
+                .database
+                .lock()
+                .get_latest_epoch_proof()
+                .unwrap()
+                .ledger_header
+                .next_epoch
+                .unwrap()
+                .epoch,
+            Epoch::of(4)
+        );
+    }
+}
@@ -92,7 +92,7 @@ impl From<(SubstateReference, SubstateChangeAction)> for SubstateChange {
 }
 
 /// An on-ledger change of an entire partition.
-#[derive(Debug, Clone, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum PartitionChangeAction {
     /// Deletion of an entire Partition.
     /// Note: contrary to [`SubstateChangeAction`]s, the previous contents of the Partition are not
@@ -101,7 +101,7 @@ pub enum PartitionChangeAction {
 }
 
 /// An on-ledger change of an individual substate.
-#[derive(Debug, Clone, ScryptoSbor)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum SubstateChangeAction {
     Create {
         /// A value after the transaction.
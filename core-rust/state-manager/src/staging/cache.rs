@@ -452,6 +452,10 @@ impl<S: ConfigurableDatabase> ConfigurableDatabase for StagedStore<'_, S> {
         self.root.is_account_change_index_enabled()
     }
 
+    fn is_resource_change_index_enabled(&self) -> bool {
+        self.root.is_resource_change_index_enabled()
+    }
+
     fn is_local_transaction_execution_index_enabled(&self) -> bool {
         self.root.is_local_transaction_execution_index_enabled()
     }
@@ -467,6 +471,11 @@ impl<S: ConfigurableDatabase> ConfigurableDatabase for StagedStore<'_, S> {
     fn get_first_stored_historical_state_version(&self) -> StateVersion {
         self.root.get_first_stored_historical_state_version()
     }
+
+    fn get_first_stored_local_transaction_execution_state_version(&self) -> StateVersion {
+        self.root
+            .get_first_stored_local_transaction_execution_state_version()
+    }
 }
 
 impl Delta for ProcessedTransactionReceipt {
@@ -127,6 +127,21 @@ impl EpochAwareAccuTreeFactory {
         collector.into_diff()
     }
 
+    /// Creates an accu tree prover of the scoped epoch's accu tree, as it was at the current version
+    /// (i.e. declared during this factory's construction).
+    pub fn create_prover<'s, S: SeekableAccuTreeStore<StateVersion, N>, N: IsMerklizableHash>(
+        &self,
+        store: &'s S,
+    ) -> AccuTreeProver<EpochScopedSeekableAccuTreeStore<'s, S>, N> {
+        AccuTreeProver::new(
+            EpochScopedSeekableAccuTreeStore {
+                store,
+                epoch_start_state_version: self.epoch_start_state_version,
+            },
+            self.current_accu_tree_leaf_count(),
+        )
+    }
+
     /// Returns the actual number of leaves in the epoch's accu tree.
     /// This takes into account the extra first leaf (i.e. previous epoch's tree root), and handles
     /// the "fresh epoch" (i.e. empty accu tree) case.
@@ -192,10 +207,9 @@ impl<S: ReadableAccuTreeStore<StateVersion, N>, N> ReadableAccuTreeStore<usize,
     for EpochScopedAccuTreeStore<'_, S>
 {
     fn get_tree_slice(&self, epoch_tree_size: &usize) -> Option<TreeSlice<N>> {
-        let end_state_version = self
-            .epoch_start_state_version
-            .relative(*epoch_tree_size as u64 - 1)
-            .expect("Invalid relative state version.");
+        // An empty tree has no slices (and does not end at any state version):
+        let end_state_version =
+            epoch_tree_end_state_version(self.epoch_start_state_version, *epoch_tree_size)?;
         self.store.get_tree_slice(&end_state_version)
     }
 }
@@ -204,10 +218,53 @@ impl<S: WriteableAccuTreeStore<StateVersion, N>, N> WriteableAccuTreeStore<usize
     for EpochScopedAccuTreeStore<'_, S>
 {
     fn put_tree_slice(&mut self, epoch_tree_size: usize, slice: TreeSlice<N>) {
-        let end_state_version = self
-            .epoch_start_state_version
-            .relative(epoch_tree_size as u64 - 1)
-            .expect("Invalid relative state version.");
+        let end_state_version =
+            epoch_tree_end_state_version(self.epoch_start_state_version, epoch_tree_size)
+                .expect("an empty accu tree is never persisted");
         self.store.put_tree_slice(end_state_version, slice)
     }
 }
+
+/// Resolves the state version at which the epoch-scoped accu tree reaches the given size.
+/// Returns [`None`] for an empty tree, which does not correspond to any state version.
+fn epoch_tree_end_state_version(
+    epoch_start_state_version: StateVersion,
+    epoch_tree_size: usize,
+) -> Option<StateVersion> {
+    let offset = (epoch_tree_size as u64).checked_sub(1)?;
+    Some(
+        epoch_start_state_version
+            .relative(offset)
+            .expect("Invalid relative state version."),
+    )
+}
+
+/// A read-only counterpart of [`EpochScopedAccuTreeStore`], used for proof generation.
+pub struct EpochScopedSeekableAccuTreeStore<'s, S> {
+    store: &'s S,
+    epoch_start_state_version: StateVersion,
+}
+
+impl<S: SeekableAccuTreeStore<StateVersion, N>, N> SeekableAccuTreeStore<usize, N>
+    for EpochScopedSeekableAccuTreeStore<'_, S>
+{
+    fn get_first_tree_slice_from(&self, epoch_tree_size: &usize) -> Option<(usize, TreeSlice<N>)> {
+        // Every non-empty tree is at least as large as an empty one, hence seeking from an empty
+        // tree means seeking from the very first slice of the epoch:
+        let from_state_version =
+            epoch_tree_end_state_version(self.epoch_start_state_version, *epoch_tree_size)
+                .unwrap_or(self.epoch_start_state_version);
+        self.store
+            .get_first_tree_slice_from(&from_state_version)
+            .map(|(end_state_version, slice)| {
+                let epoch_tree_size = StateVersion::calculate_progress(
+                    self.epoch_start_state_version,
+                    end_state_version,
+                )
+                .and_then(usize::try_from)
+                .unwrap()
+                    + 1;
+                (epoch_tree_size, slice)
+            })
+    }
+}
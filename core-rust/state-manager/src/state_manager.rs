@@ -66,8 +66,13 @@ use std::num::NonZeroUsize;
 
 use crate::jni::LedgerSyncLimitsConfig;
 use crate::jni_prelude::*;
+use crate::store::db_checkpoints::{DbCheckpointManager, DbCheckpointsConfig};
+use crate::store::gc_control::{GcControls, GC_CONTROL_POLL_INTERVAL};
 use crate::store::jmt_gc::*;
+use crate::store::ledger_transactions_gc::LedgerTransactionsGc;
 use crate::store::proofs_gc::{LedgerProofsGc, LedgerProofsGcConfig};
+use crate::store::resource_change_index::ResourceChangeIndexCatchup;
+use crate::store::secondary_indices::{SecondaryIndexRegistry, SecondaryIndicesCatchup};
 
 use node_common::scheduler::{Metrics, Scheduler, Spawner, Tracker};
 use radix_transaction_scenarios::scenarios::default_testnet_scenarios_at_version;
@@ -89,6 +94,7 @@ pub struct StateManagerConfig {
     pub logging_config: LoggingConfig,
     pub state_tree_gc_config: StateTreeGcConfig,
     pub ledger_proofs_gc_config: LedgerProofsGcConfig,
+    pub db_checkpoints_config: Option<DbCheckpointsConfig>,
     pub ledger_sync_limits_config: LedgerSyncLimitsConfig,
     pub protocol_config: ProtocolConfig,
     pub no_fees: bool,
@@ -143,11 +149,14 @@ impl StateManagerConfig {
             vertex_limits_config: None,
             database_backend_config: DatabaseBackendConfig {
                 rocks_db_path: rocks_db_path.into(),
+                tuning_profile: None,
+                in_memory: false,
             },
             database_config: DatabaseConfig::default(),
             logging_config: LoggingConfig::default(),
             state_tree_gc_config: StateTreeGcConfig::default(),
             ledger_proofs_gc_config: LedgerProofsGcConfig::default(),
+            db_checkpoints_config: None,
             ledger_sync_limits_config: LedgerSyncLimitsConfig::default(),
             protocol_config: ProtocolConfig::new_with_no_updates(),
             no_fees: false,
@@ -155,6 +164,13 @@ impl StateManagerConfig {
         }
     }
 
+    /// Creates a testing configuration using a non-persistent, in-memory database.
+    pub fn new_for_testing_in_memory() -> Self {
+        let mut config = Self::new_for_testing("");
+        config.database_backend_config.in_memory = true;
+        config
+    }
+
     /// Parses the [`Self`] (see [`StructFromJava`]) and performs static validation of all
     /// applicable configuration components.
     pub fn valid_from_java(data: &[u8]) -> JavaResult<Self> {
@@ -169,6 +185,7 @@ pub struct StateManager {
     pub network_definition: NetworkDefinition,
     pub database: Arc<DbLock<ActualStateManagerDatabase>>,
     pub mempool_manager: Arc<MempoolManager>,
+    pub fee_estimator: Arc<FeeEstimator>,
     pub transaction_validator: Arc<RwLock<TransactionValidator>>,
     pub committability_validator: Arc<CommittabilityValidator>,
     pub transaction_previewer: Arc<TransactionPreviewer>,
@@ -181,6 +198,12 @@ pub struct StateManager {
     pub protocol_update_executor: Arc<NodeProtocolUpdateExecutor>,
     pub ledger_metrics: Arc<LedgerMetrics>,
     pub formatter: Arc<Formatter>,
+    /// Present only on a read replica (see [`Self::new_read_replica()`]).
+    pub read_replica_status: Option<Arc<ReadReplicaStatus>>,
+    /// Absent on a read replica (which does not run any GC).
+    pub gc_controls: Option<GcControls>,
+    /// Present only if the mempool persistence is configured (see [`MempoolPersistenceConfig`]).
+    pub mempool_persister: Option<Arc<MempoolPersister>>,
 }
 
 impl StateManager {
@@ -191,6 +214,28 @@ impl StateManager {
         lock_factory: &LockFactory,
         metrics_registry: &MetricRegistry,
         scheduler: &Scheduler<impl Spawner, impl Tracker, impl Metrics>,
+    ) -> Self {
+        Self::new_with_secondary_index_registry(
+            config,
+            &SecondaryIndexRegistry::default(),
+            mempool_relay_dispatcher,
+            genesis_data_resolver,
+            lock_factory,
+            metrics_registry,
+            scheduler,
+        )
+    }
+
+    /// Creates a [`StateManager`] whose database may enable any [`SecondaryIndex`] from the given
+    /// registry (i.e. not only the built-in ones).
+    pub fn new_with_secondary_index_registry(
+        config: StateManagerConfig,
+        secondary_index_registry: &SecondaryIndexRegistry,
+        mempool_relay_dispatcher: Option<MempoolRelayDispatcher>,
+        genesis_data_resolver: Arc<dyn ResolveGenesisData>,
+        lock_factory: &LockFactory,
+        metrics_registry: &MetricRegistry,
+        scheduler: &Scheduler<impl Spawner, impl Tracker, impl Metrics>,
     ) -> Self {
         let StateManagerConfig {
             network_definition,
@@ -201,34 +246,344 @@ impl StateManager {
             logging_config,
             state_tree_gc_config,
             ledger_proofs_gc_config,
+            db_checkpoints_config,
             ledger_sync_limits_config,
             protocol_config,
             no_fees,
             scenarios_execution_config,
         } = config;
-        let db_path = PathBuf::from(database_backend_config.rocks_db_path);
-        let raw_db = match StateManagerDatabase::new(db_path, database_config, &network_definition) {
+        if let Err(error) = state_tree_gc_config.validate() {
+            panic!("Invalid state hash tree GC configuration: {error:?}");
+        }
+        let mempool_persistence_config = mempool_config
+            .as_ref()
+            .and_then(|mempool_config| mempool_config.persistence.clone());
+        let ledger_transaction_retention_epoch_count =
+            database_config.ledger_transaction_retention_epoch_count;
+        let raw_db_result = if database_backend_config.in_memory {
+            if db_checkpoints_config.is_some() {
+                panic!("DB checkpoints cannot be configured for an in-memory database.");
+            }
+            StateManagerDatabase::new_in_memory(
+                database_config,
+                secondary_index_registry,
+                &network_definition,
+            )
+        } else {
+            StateManagerDatabase::new_with_secondary_index_registry(
+                PathBuf::from(database_backend_config.rocks_db_path),
+                database_config,
+                database_backend_config.tuning_profile,
+                secondary_index_registry,
+                &DatabaseMigrationMetrics::new(metrics_registry),
+                &network_definition,
+            )
+        };
+        let raw_db = match raw_db_result {
             Ok(db) => db,
             Err(error) => {
                 match error {
                     DatabaseConfigValidationError::AccountChangeIndexRequiresLocalTransactionExecutionIndex => {
                         panic!("Local transaction execution index needs to be enabled in order for account change index to work.");
                     },
-                    DatabaseConfigValidationError::LocalTransactionExecutionIndexChanged => {
-                        panic!("Local transaction execution index can not be changed once configured.\nIf you need to change it, please wipe ledger data and resync.");
-                    }
+                    DatabaseConfigValidationError::ResourceChangeIndexRequiresLocalTransactionExecutionIndex => {
+                        panic!("Local transaction execution index needs to be enabled in order for resource change index to work.");
+                    },
+                    DatabaseConfigValidationError::SecondaryIndicesRequireLocalTransactionExecutionIndex => {
+                        panic!("Local transaction execution index needs to be enabled in order for secondary indices to work.");
+                    },
+                    DatabaseConfigValidationError::UnknownSecondaryIndex(name) => {
+                        panic!("Unknown secondary index: {name}");
+                    },
+                    DatabaseConfigValidationError::NewerSchemaVersion { persisted, supported } => {
+                        panic!("Database schema version {persisted} is newer than the latest version supported by this Node ({supported}); the database was written by a newer Node version.");
+                    },
                 }
             }
         };
 
         let database = Arc::new(lock_factory.named("database").new_db_lock(raw_db));
 
-        let formatter = Arc::new(Formatter::new(&network_definition));
+        let execution_configurator = Arc::new(ExecutionConfigurator::new(
+            &network_definition,
+            no_fees,
+            logging_config.engine_trace,
+        ));
+
+        let transaction_validator = Arc::new(lock_factory.named("validator").new_rwlock(
+            TransactionValidator::new(database.access_direct().deref(), &network_definition),
+        ));
+
+        // The GCs below delete in multiple batches, and must not interleave with DB checkpoints:
+        let db_maintenance_lock = Arc::new(lock_factory.named("db_maintenance").new_mutex(()));
+
+        // The DB checkpoints (if configured) are created at epoch boundaries, i.e. triggered by the
+        // committer:
+        let db_checkpoint_manager = db_checkpoints_config.map(|db_checkpoints_config| {
+            Arc::new(DbCheckpointManager::new(
+                database.clone(),
+                db_maintenance_lock.clone(),
+                db_checkpoints_config,
+                &lock_factory.named("db_checkpoints"),
+            ))
+        });
+
+        let mut state_manager = Self::assemble(
+            network_definition,
+            database.clone(),
+            execution_configurator.clone(),
+            transaction_validator,
+            mempool_config,
+            mempool_relay_dispatcher,
+            vertex_limits_config,
+            protocol_config,
+            scenarios_execution_config,
+            genesis_data_resolver,
+            None,
+            db_checkpoint_manager.clone(),
+            lock_factory,
+            metrics_registry,
+        );
+
+        // Register the periodic background task for collecting the costly raw DB metrics...
+        // (these metrics are also reported by the GCs, which compact the ranges they delete)
+        let raw_db_metrics = Arc::new(RawDbMetrics::new(metrics_registry));
+        let raw_db_metrics_collector =
+            RawDbMetricsCollector::new(database.clone(), raw_db_metrics.clone());
+        scheduler
+            .named("raw_db_measurement")
+            .start_periodic(RAW_DB_MEASUREMENT_INTERVAL, move || {
+                raw_db_metrics_collector.run()
+            });
+
+        // The GCs' configuration can be changed at runtime, so their schedules only poll the
+        // controls for a due run:
+        let gc_controls = GcControls::new(
+            state_tree_gc_config,
+            ledger_proofs_gc_config,
+            &lock_factory.named("gc_control"),
+            metrics_registry,
+        );
+
+        // ... and for deleting the stale state hash tree nodes (a.k.a. "JMT GC")...
+        let state_tree_gc_control = gc_controls.state_tree_gc.clone();
+        let state_tree_gc_database = database.clone();
+        let state_tree_gc_maintenance_lock = db_maintenance_lock.clone();
+        let state_tree_gc_raw_db_metrics = raw_db_metrics.clone();
+        scheduler
+            .named("state_tree_gc")
+            .start_periodic(GC_CONTROL_POLL_INTERVAL, move || {
+                state_tree_gc_control.run_if_due(|config| {
+                    let _maintenance_guard = state_tree_gc_maintenance_lock.lock();
+                    let state_tree_gc = StateTreeGc::new(
+                        state_tree_gc_database.clone(),
+                        config.clone(),
+                        state_tree_gc_raw_db_metrics.clone(),
+                    );
+                    Some(state_tree_gc.run().number())
+                })
+            });
+
+        // ... and for deleting the old, non-critical ledger proofs (a.k.a. "Proofs GC")...
+        // ... and (on non-archive nodes) for deleting the old ledger transactions' payloads and
+        // receipts (this GC follows the Proofs GC, so it shares its schedule and control)...
+        let ledger_transactions_gc =
+            ledger_transaction_retention_epoch_count.map(|retention_epoch_count| {
+                LedgerTransactionsGc::new(
+                    database.clone(),
+                    retention_epoch_count,
+                    raw_db_metrics.clone(),
+                )
+            });
+        let ledger_proofs_gc_control = gc_controls.ledger_proofs_gc.clone();
+        let ledger_proofs_gc_database = database.clone();
+        let ledger_proofs_gc_maintenance_lock = db_maintenance_lock.clone();
+        scheduler
+            .named("ledger_proofs_gc")
+            .start_periodic(GC_CONTROL_POLL_INTERVAL, move || {
+                ledger_proofs_gc_control.run_if_due(|config| {
+                    let _maintenance_guard = ledger_proofs_gc_maintenance_lock.lock();
+                    let ledger_proofs_gc = LedgerProofsGc::new(
+                        ledger_proofs_gc_database.clone(),
+                        config.clone(),
+                        ledger_sync_limits_config.clone(),
+                        raw_db_metrics.clone(),
+                    );
+                    ledger_proofs_gc.run();
+                    if let Some(ledger_transactions_gc) = &ledger_transactions_gc {
+                        ledger_transactions_gc.run();
+                    }
+                    ledger_proofs_gc_database
+                        .access_direct()
+                        .get_progress()
+                        .map(|progress| progress.last_pruned_epoch.number())
+                })
+            });
+        state_manager.gc_controls = Some(gc_controls);
+
+        // ... and (if configured) for creating the DB checkpoints missed at epoch boundaries...
+        if let Some(db_checkpoint_manager) = db_checkpoint_manager {
+            scheduler
+                .named("db_checkpoints")
+                .start_periodic(db_checkpoint_manager.interval(), move || {
+                    db_checkpoint_manager.run()
+                });
+        }
+
+        // ... and for re-executing the past transactions, in case the local transaction execution
+        // index was enabled on an already-populated ledger...
+        let local_execution_backfiller = LocalTransactionExecutionBackfiller::new(
+            database.clone(),
+            execution_configurator,
+            state_manager.network_definition.clone(),
+            &lock_factory.named("local_execution_backfill"),
+        );
+        scheduler
+            .named("local_execution_backfill")
+            .start_periodic(local_execution_backfiller.interval(), move || {
+                local_execution_backfiller.run()
+            });
+
+        // ... and for catching up the indices enabled on an already-populated ledger:
+        let resource_change_index_catchup = ResourceChangeIndexCatchup::new(database.clone());
+        scheduler
+            .named("resource_change_index_catchup")
+            .start_periodic(resource_change_index_catchup.interval(), move || {
+                resource_change_index_catchup.run()
+            });
+        let secondary_indices_catchup = SecondaryIndicesCatchup::new(database.clone());
+        scheduler
+            .named("secondary_indices_catchup")
+            .start_periodic(secondary_indices_catchup.interval(), move || {
+                secondary_indices_catchup.run()
+            });
+
+        state_manager.resume_protocol_updates_if_any();
+
+        // If configured, the mempool is restored by the first scheduled run (against the ledger
+        // state resulting from any resumed protocol update) and then saved periodically (and on
+        // shutdown, see `save_mempool_if_persisted()`):
+        if let Some(mempool_persistence_config) = mempool_persistence_config {
+            let mempool_persister = Arc::new(MempoolPersister::new(
+                state_manager.mempool_manager.clone(),
+                mempool_persistence_config,
+                &lock_factory.named("mempool_persistence"),
+            ));
+            let scheduled_mempool_persister = mempool_persister.clone();
+            scheduler
+                .named("mempool_persistence")
+                .start_periodic(mempool_persister.interval(), move || {
+                    scheduled_mempool_persister.run()
+                });
+            state_manager.mempool_persister = Some(mempool_persister);
+        }
+
+        state_manager
+    }
+
+    /// Saves the current mempool contents, if the mempool persistence is configured.
+    /// This is meant to be called on a graceful shutdown.
+    pub fn save_mempool_if_persisted(&self) {
+        if let Some(mempool_persister) = &self.mempool_persister {
+            mempool_persister.save_if_restored();
+        }
+    }
+
+    /// Creates a [`StateManager`] serving the (read-only) APIs from a secondary instance of a live
+    /// node's database.
+    ///
+    /// The replica does not run any of the primary's background tasks (GCs, backfills, protocol
+    /// updates' execution), but only periodically catches up with the primary's DB (see
+    /// [`ReadReplicaCatchup`]).
+    ///
+    /// Returns an error if the primary's database cannot be opened as a secondary instance (e.g.
+    /// when it was written by a newer schema than this Node supports).
+    pub fn new_read_replica(
+        config: ReadReplicaConfig,
+        lock_factory: &LockFactory,
+        metrics_registry: &MetricRegistry,
+        scheduler: &Scheduler<impl Spawner, impl Tracker, impl Metrics>,
+    ) -> Result<Self, DatabaseOpeningError> {
+        let ReadReplicaConfig {
+            network_definition,
+            primary_db_path,
+            secondary_db_path,
+            protocol_config,
+            catch_up_interval,
+        } = config;
+        let raw_db = StateManagerDatabase::try_new_as_secondary(
+            PathBuf::from(primary_db_path),
+            PathBuf::from(secondary_db_path),
+        )?;
+        let database = Arc::new(lock_factory.named("database").new_db_lock(raw_db));
+
+        let execution_configurator = Arc::new(ExecutionConfigurator::new(
+            &network_definition,
+            false,
+            false,
+        ));
 
         let transaction_validator = Arc::new(lock_factory.named("validator").new_rwlock(
             TransactionValidator::new(database.access_direct().deref(), &network_definition),
         ));
 
+        let read_replica_status = Arc::new(ReadReplicaStatus::new(
+            &lock_factory.named("read_replica_status"),
+        ));
+
+        let state_manager = Self::assemble(
+            network_definition,
+            database,
+            execution_configurator,
+            transaction_validator,
+            None,
+            None,
+            None,
+            protocol_config,
+            ScenariosExecutionConfig::default(),
+            Arc::new(UnavailableGenesisDataResolver),
+            Some(read_replica_status.clone()),
+            None,
+            lock_factory,
+            metrics_registry,
+        );
+
+        // Catch up once before serving any request, and then periodically:
+        let read_replica_catchup = ReadReplicaCatchup::new(
+            state_manager.clone(),
+            read_replica_status,
+            catch_up_interval,
+        );
+        read_replica_catchup.run();
+        scheduler
+            .named("read_replica_catchup")
+            .start_periodic(read_replica_catchup.interval(), move || {
+                read_replica_catchup.run()
+            });
+
+        Ok(state_manager)
+    }
+
+    /// Creates all the services operating on the given database.
+    #[allow(clippy::too_many_arguments)]
+    fn assemble(
+        network_definition: NetworkDefinition,
+        database: Arc<DbLock<ActualStateManagerDatabase>>,
+        execution_configurator: Arc<ExecutionConfigurator>,
+        transaction_validator: Arc<RwLock<TransactionValidator>>,
+        mempool_config: Option<MempoolConfig>,
+        mempool_relay_dispatcher: Option<MempoolRelayDispatcher>,
+        vertex_limits_config: Option<VertexLimitsConfig>,
+        protocol_config: ProtocolConfig,
+        scenarios_execution_config: ScenariosExecutionConfig,
+        genesis_data_resolver: Arc<dyn ResolveGenesisData>,
+        read_replica_status: Option<Arc<ReadReplicaStatus>>,
+        db_checkpoint_manager: Option<Arc<DbCheckpointManager>>,
+        lock_factory: &LockFactory,
+        metrics_registry: &MetricRegistry,
+    ) -> Self {
+        let formatter = Arc::new(Formatter::new(&network_definition));
+
         let protocol_manager = Arc::new(ProtocolManager::new(
             protocol_config.protocol_update_triggers,
             &protocol_config.protocol_update_content_overrides,
@@ -242,12 +597,6 @@ impl StateManager {
             metrics_registry,
         ));
 
-        let execution_configurator = Arc::new(ExecutionConfigurator::new(
-            &network_definition,
-            no_fees,
-            logging_config.engine_trace,
-        ));
-
         let mempool = lock_factory
             .named("mempool")
             .new_rwlock(PriorityMempool::new(
@@ -269,11 +618,13 @@ impl StateManager {
             transaction_validator.clone(),
             formatter.clone(),
         ));
+        let vertex_limits_config = vertex_limits_config.unwrap_or_default();
         let mempool_manager = Arc::new(match mempool_relay_dispatcher {
             None => MempoolManager::new_for_testing(
                 mempool,
                 pending_transaction_result_cache,
                 committability_validator.clone(),
+                vertex_limits_config,
                 metrics_registry,
             ),
             Some(mempool_relay_dispatcher) => MempoolManager::new(
@@ -281,6 +632,7 @@ impl StateManager {
                 mempool_relay_dispatcher,
                 pending_transaction_result_cache,
                 committability_validator.clone(),
+                vertex_limits_config,
                 metrics_registry,
             ),
         });
@@ -298,12 +650,17 @@ impl StateManager {
             execution_cache_manager.clone(),
             protocol_manager.clone(),
         ));
+        let fee_estimator = Arc::new(FeeEstimator::new(
+            mempool_manager.clone(),
+            &vertex_limits_config,
+            &lock_factory.named("fee_estimator"),
+        ));
         let preparator = Arc::new(Preparator::new(
             database.clone(),
             transaction_executor_factory.clone(),
             mempool_manager.clone(),
             transaction_validator.clone(),
-            vertex_limits_config.unwrap_or_default(),
+            vertex_limits_config,
             metrics_registry,
             formatter.clone(),
         ));
@@ -321,10 +678,12 @@ impl StateManager {
             transaction_executor_factory.clone(),
             transaction_validator.clone(),
             mempool_manager.clone(),
+            fee_estimator.clone(),
             execution_cache_manager.clone(),
             protocol_manager.clone(),
             ledger_metrics.clone(),
             formatter.clone(),
+            db_checkpoint_manager,
         ));
 
         let system_executor = Arc::new(SystemExecutor::new(
@@ -344,38 +703,11 @@ impl StateManager {
             genesis_data_resolver,
         ));
 
-        // Register the periodic background task for collecting the costly raw DB metrics...
-        let raw_db_metrics_collector =
-            RawDbMetricsCollector::new(database.clone(), metrics_registry);
-        scheduler
-            .named("raw_db_measurement")
-            .start_periodic(RAW_DB_MEASUREMENT_INTERVAL, move || {
-                raw_db_metrics_collector.run()
-            });
-
-        // ... and for deleting the stale state hash tree nodes (a.k.a. "JMT GC")...
-        let state_tree_gc = StateTreeGc::new(database.clone(), state_tree_gc_config);
-        scheduler
-            .named("state_tree_gc")
-            .start_periodic(state_tree_gc.interval(), move || state_tree_gc.run());
-
-        // ... and for deleting the old, non-critical ledger proofs (a.k.a. "Proofs GC"):
-        let ledger_proofs_gc = LedgerProofsGc::new(
-            database.clone(),
-            ledger_proofs_gc_config,
-            ledger_sync_limits_config,
-        );
-        scheduler.named("ledger_proofs_gc").start_periodic(
-            ledger_proofs_gc.interval(),
-            move || {
-                ledger_proofs_gc.run();
-            },
-        );
-
-        let state_manager = Self {
+        Self {
             network_definition,
             database,
             mempool_manager,
+            fee_estimator,
             transaction_validator,
             committability_validator,
             transaction_previewer,
@@ -388,11 +720,10 @@ impl StateManager {
             protocol_update_executor,
             ledger_metrics,
             formatter,
-        };
-
-        state_manager.resume_protocol_updates_if_any();
-
-        state_manager
+            read_replica_status,
+            gc_controls: None,
+            mempool_persister: None,
+        }
     }
 
     /// Executes the actual protocol update transactions (on-ledger) and performs any changes to the
@@ -422,8 +753,22 @@ impl StateManager {
         &self,
         resultant_version: ProtocolVersionName,
     ) -> ProtocolUpdateResult {
+        self.enact_protocol_version(&resultant_version);
+
+        ProtocolUpdateResult {
+            post_update_proof: self
+                .database
+                .lock()
+                .get_latest_proof()
+                .expect("Missing post protocol update proof"),
+        }
+    }
+
+    /// Performs the changes to the services (off-ledger) affected by a (just executed) protocol
+    /// update.
+    pub(crate) fn enact_protocol_version(&self, protocol_version: &ProtocolVersionName) {
         self.protocol_manager
-            .set_current_protocol_version(&resultant_version);
+            .set_current_protocol_version(protocol_version);
 
         // Protocol update might change transaction execution rules, so we need to update our
         // validator and clear our tree-based execution caches
@@ -432,13 +777,5 @@ impl StateManager {
         self.execution_cache_manager.clear();
         // We could also clear the mempool and the pending tranasction result cache here
         // ... but that doesn't guarantee it's still accurate, so it's not required.
-
-        ProtocolUpdateResult {
-            post_update_proof: self
-                .database
-                .lock()
-                .get_latest_proof()
-                .expect("Missing post protocol update proof"),
-        }
     }
 }
@@ -0,0 +1,332 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+pub use crate::accumulator_tree::{AccuTreeConsistencyProof, AccuTreeInclusionProof};
+
+/// Merkle proofs of a committed transaction's leaves in both accumulator trees of its epoch (i.e.
+/// of its ledger transaction hash in the transaction tree, and of its [`ConsensusReceipt`]'s hash
+/// in the receipt tree), against the roots recorded by a particular ledger header.
+///
+/// Please note that each epoch has its own pair of accumulator trees (with the previous epoch's
+/// roots inserted as their first leaves), so only a ledger header of the transaction's epoch can be
+/// used as a root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerTransactionInclusionProof {
+    pub transaction_leaf: TransactionTreeHash,
+    pub transaction_tree_proof: AccuTreeInclusionProof<TransactionTreeHash>,
+    pub receipt_leaf: ReceiptTreeHash,
+    pub receipt_tree_proof: AccuTreeInclusionProof<ReceiptTreeHash>,
+}
+
+impl LedgerTransactionInclusionProof {
+    /// Verifies both proofs against the roots from the given hashes.
+    pub fn verify(&self, root_hashes: &LedgerHashes) -> bool {
+        self.transaction_tree_proof
+            .verify(&self.transaction_leaf, &root_hashes.transaction_root)
+            && self
+                .receipt_tree_proof
+                .verify(&self.receipt_leaf, &root_hashes.receipt_root)
+    }
+}
+
+/// Merkle proofs of both accumulator trees at an older ledger header being prefixes of the
+/// respective trees at a newer ledger header (of the same epoch).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerConsistencyProof {
+    pub transaction_tree_proof: AccuTreeConsistencyProof<TransactionTreeHash>,
+    pub receipt_tree_proof: AccuTreeConsistencyProof<ReceiptTreeHash>,
+}
+
+impl LedgerConsistencyProof {
+    /// Verifies both proofs against the roots from the given older and newer hashes.
+    pub fn verify(&self, old_hashes: &LedgerHashes, new_hashes: &LedgerHashes) -> bool {
+        self.transaction_tree_proof
+            .verify(&old_hashes.transaction_root, &new_hashes.transaction_root)
+            && self
+                .receipt_tree_proof
+                .verify(&old_hashes.receipt_root, &new_hashes.receipt_root)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccuTreeProofError {
+    /// The transaction is not committed under the given root (i.e. it comes after the root's state
+    /// version, or it belongs to a different epoch's accumulator trees).
+    TransactionNotUnderRoot,
+    /// The given ledger headers are not in order, or they belong to different epochs' accumulator
+    /// trees.
+    HeadersNotConsistentInEpoch,
+    /// The accumulator tree slices required for the proof are not stored (e.g. the database was
+    /// bootstrapped from a state snapshot taken later).
+    TreeSlicesNotAvailable,
+}
+
+/// Generates proofs of the transaction committed at the given state version being included under
+/// the given ledger header's roots.
+pub fn prove_ledger_transaction_inclusion<S>(
+    database: &S,
+    state_version: StateVersion,
+    root_header: &LedgerHeader,
+) -> Result<LedgerTransactionInclusionProof, AccuTreeProofError>
+where
+    S: QueryableProofStore
+        + SeekableAccuTreeStore<StateVersion, TransactionTreeHash>
+        + SeekableAccuTreeStore<StateVersion, ReceiptTreeHash>,
+{
+    let epoch_start_state_version =
+        get_epoch_start_state_version(database, root_header.state_version);
+    if state_version <= epoch_start_state_version || state_version > root_header.state_version {
+        return Err(AccuTreeProofError::TransactionNotUnderRoot);
+    }
+    let leaf_index = epoch_tree_leaf_index(epoch_start_state_version, state_version);
+    let epoch_accu_trees =
+        EpochAwareAccuTreeFactory::new(epoch_start_state_version, root_header.state_version);
+    let transaction_prover = epoch_accu_trees.create_prover::<_, TransactionTreeHash>(database);
+    let receipt_prover = epoch_accu_trees.create_prover::<_, ReceiptTreeHash>(database);
+    Ok(LedgerTransactionInclusionProof {
+        transaction_leaf: transaction_prover
+            .get_leaf(leaf_index)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+        transaction_tree_proof: transaction_prover
+            .prove_inclusion(leaf_index)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+        receipt_leaf: receipt_prover
+            .get_leaf(leaf_index)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+        receipt_tree_proof: receipt_prover
+            .prove_inclusion(leaf_index)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+    })
+}
+
+/// Generates proofs of the accumulator trees under the older ledger header being prefixes of the
+/// ones under the newer ledger header.
+pub fn prove_ledger_consistency<S>(
+    database: &S,
+    old_header: &LedgerHeader,
+    new_header: &LedgerHeader,
+) -> Result<LedgerConsistencyProof, AccuTreeProofError>
+where
+    S: QueryableProofStore
+        + SeekableAccuTreeStore<StateVersion, TransactionTreeHash>
+        + SeekableAccuTreeStore<StateVersion, ReceiptTreeHash>,
+{
+    let epoch_start_state_version =
+        get_epoch_start_state_version(database, new_header.state_version);
+    if old_header.state_version <= epoch_start_state_version
+        || old_header.state_version > new_header.state_version
+    {
+        return Err(AccuTreeProofError::HeadersNotConsistentInEpoch);
+    }
+    let old_tree_size =
+        epoch_tree_leaf_index(epoch_start_state_version, old_header.state_version) + 1;
+    let epoch_accu_trees =
+        EpochAwareAccuTreeFactory::new(epoch_start_state_version, new_header.state_version);
+    Ok(LedgerConsistencyProof {
+        transaction_tree_proof: epoch_accu_trees
+            .create_prover::<_, TransactionTreeHash>(database)
+            .prove_consistency(old_tree_size)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+        receipt_tree_proof: epoch_accu_trees
+            .create_prover::<_, ReceiptTreeHash>(database)
+            .prove_consistency(old_tree_size)
+            .ok_or(AccuTreeProofError::TreeSlicesNotAvailable)?,
+    })
+}
+
+/// Returns the latest stored ledger proof which can serve as a root for the transaction committed
+/// at the given state version, i.e. the proof ending the transaction's epoch (if the epoch is
+/// already over), or the latest proof.
+pub fn get_latest_proof_of_same_epoch<S>(
+    database: &S,
+    state_version: StateVersion,
+) -> Option<LedgerProof>
+where
+    S: QueryableProofStore + IterableProofStore,
+{
+    let epoch = state_version
+        .previous()
+        .ok()
+        .and_then(|parent_state_version| {
+            database.get_closest_epoch_proof_on_or_before(parent_state_version)
+        })
+        .and_then(|epoch_proof| epoch_proof.ledger_header.next_epoch)
+        .map(|next_epoch| next_epoch.epoch)
+        .unwrap_or(Epoch::of(0));
+    database
+        .get_next_epoch_proof_iter(epoch)
+        .find(|epoch_proof| epoch_proof.ledger_header.state_version >= state_version)
+        .or_else(|| database.get_latest_proof())
+        .filter(|proof| proof.ledger_header.state_version >= state_version)
+}
+
+/// Returns the state version of the epoch change transaction which started the accumulator trees
+/// containing the given state version's root.
+fn get_epoch_start_state_version(
+    database: &impl QueryableProofStore,
+    root_state_version: StateVersion,
+) -> StateVersion {
+    root_state_version
+        .previous()
+        .ok()
+        .and_then(|parent_state_version| {
+            database.get_closest_epoch_proof_on_or_before(parent_state_version)
+        })
+        .map(|epoch_proof| epoch_proof.ledger_header.state_version)
+        .unwrap_or_else(StateVersion::pre_genesis)
+}
+
+/// Returns the index of the given state version's leaf in its epoch's accumulator trees (where the
+/// leaf at index 0 holds the previous epoch's root).
+fn epoch_tree_leaf_index(
+    epoch_start_state_version: StateVersion,
+    state_version: StateVersion,
+) -> usize {
+    StateVersion::calculate_progress(epoch_start_state_version, state_version)
+        .and_then(usize::try_from)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    #[test]
+    fn proofs_of_committed_transactions_verify_against_ledger_proofs_of_their_epoch() {
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+
+        let database = state_manager.database.lock();
+        let epoch_proof = database.get_epoch_proof(Epoch::of(3)).unwrap();
+        let epoch_end_proof = database.get_epoch_proof(Epoch::of(4)).unwrap();
+        let epoch_proofs = database
+            .get_proof_iter(epoch_proof.ledger_header.state_version.next().unwrap())
+            .take_while(|proof| {
+                proof.ledger_header.state_version <= epoch_end_proof.ledger_header.state_version
+            })
+            .collect::<Vec<_>>();
+
+        for root_proof in epoch_proofs.iter() {
+            let root_header = &root_proof.ledger_header;
+            let mut state_version = epoch_proof.ledger_header.state_version.next().unwrap();
+            while state_version <= root_header.state_version {
+                let identifiers = database
+                    .get_committed_transaction_identifiers(state_version)
+                    .unwrap();
+                let proof = prove_ledger_transaction_inclusion(
+                    database.deref(),
+                    state_version,
+                    root_header,
+                )
+                .unwrap();
+                assert_eq!(
+                    proof.transaction_leaf,
+                    TransactionTreeHash::from(
+                        identifiers.transaction_hashes.ledger_transaction_hash
+                    )
+                );
+                assert!(proof.verify(&root_header.hashes));
+                assert!(!proof.verify(&epoch_proof.ledger_header.hashes));
+                state_version = state_version.next().unwrap();
+            }
+
+            for old_root_proof in epoch_proofs.iter() {
+                let old_header = &old_root_proof.ledger_header;
+                let result = prove_ledger_consistency(database.deref(), old_header, root_header);
+                if old_header.state_version <= root_header.state_version {
+                    assert!(result
+                        .unwrap()
+                        .verify(&old_header.hashes, &root_header.hashes));
+                } else {
+                    assert_eq!(result, Err(AccuTreeProofError::HeadersNotConsistentInEpoch));
+                }
+            }
+        }
+
+        // A transaction of the previous epoch is not under this epoch's roots:
+        assert_eq!(
+            prove_ledger_transaction_inclusion(
+                database.deref(),
+                epoch_proof.ledger_header.state_version,
+                &epoch_end_proof.ledger_header
+            ),
+            Err(AccuTreeProofError::TransactionNotUnderRoot)
+        );
+        assert_eq!(
+            get_latest_proof_of_same_epoch(
+                database.deref(),
+                epoch_proof.ledger_header.state_version.next().unwrap()
+            ),
+            Some(epoch_end_proof)
+        );
+    }
+}
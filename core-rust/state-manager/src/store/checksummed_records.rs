@@ -0,0 +1,188 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use std::io::{ErrorKind, Read, Write};
+
+/// A version of the framing, i.e. of the byte layout *around* the SBOR-encoded records.
+/// The records themselves are expected to be versioned independently (by their users).
+const FRAMING_VERSION: u8 = 1;
+
+#[derive(Debug)]
+pub enum ChecksummedRecordError {
+    Io(std::io::Error),
+    UnexpectedMagic,
+    UnsupportedFramingVersion(u8),
+    Truncated,
+    ChecksumMismatch {
+        record_index: u64,
+    },
+    DecodingFailed {
+        record_index: u64,
+        error: DecodeError,
+    },
+}
+
+impl From<std::io::Error> for ChecksummedRecordError {
+    fn from(error: std::io::Error) -> Self {
+        ChecksummedRecordError::Io(error)
+    }
+}
+
+/// A writer of a simple, checksummed framing of SBOR-encoded records.
+///
+/// The stream starts with a preamble (an 8-byte magic identifying the file kind, followed by a
+/// [`FRAMING_VERSION`] byte). Each record is then framed as: a big-endian `u32` length, the
+/// SBOR-encoded record bytes, and a hash of these bytes.
+pub struct ChecksummedRecordWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> ChecksummedRecordWriter<W> {
+    /// Writes the preamble with the given magic.
+    pub fn new(mut output: W, magic: &[u8; 8]) -> Result<Self, ChecksummedRecordError> {
+        output.write_all(magic)?;
+        output.write_all(&[FRAMING_VERSION])?;
+        Ok(Self { output })
+    }
+
+    pub fn write<T: ScryptoEncode>(&mut self, record: &T) -> Result<(), ChecksummedRecordError> {
+        let bytes = scrypto_encode(record).unwrap();
+        let length = u32::try_from(bytes.len()).expect("record exceeds 4 GiB");
+        self.output.write_all(&length.to_be_bytes())?;
+        self.output.write_all(&bytes)?;
+        self.output.write_all(&hash(&bytes).0)?;
+        Ok(())
+    }
+
+    /// Flushes and returns the underlying output.
+    pub fn finish(mut self) -> Result<W, ChecksummedRecordError> {
+        self.output.flush()?;
+        Ok(self.output)
+    }
+}
+
+/// A reader of the format produced by [`ChecksummedRecordWriter`].
+pub struct ChecksummedRecordReader<R: Read> {
+    input: R,
+    next_record_index: u64,
+}
+
+impl<R: Read> ChecksummedRecordReader<R> {
+    /// Reads and validates the preamble (which must contain the given magic).
+    pub fn new(mut input: R, magic: &[u8; 8]) -> Result<Self, ChecksummedRecordError> {
+        let mut actual_magic = [0u8; 8];
+        read_exact_or_truncated(&mut input, &mut actual_magic)?;
+        if &actual_magic != magic {
+            return Err(ChecksummedRecordError::UnexpectedMagic);
+        }
+        let mut framing_version = [0u8; 1];
+        read_exact_or_truncated(&mut input, &mut framing_version)?;
+        if framing_version[0] != FRAMING_VERSION {
+            return Err(ChecksummedRecordError::UnsupportedFramingVersion(
+                framing_version[0],
+            ));
+        }
+        Ok(Self {
+            input,
+            next_record_index: 0,
+        })
+    }
+
+    /// Returns the index that the next [`Self::read()`] record will have (starting from 0).
+    pub fn next_record_index(&self) -> u64 {
+        self.next_record_index
+    }
+
+    /// Reads the next record and verifies its checksum.
+    pub fn read<T: ScryptoDecode>(&mut self) -> Result<T, ChecksummedRecordError> {
+        let record_index = self.next_record_index;
+        let mut length = [0u8; 4];
+        read_exact_or_truncated(&mut self.input, &mut length)?;
+        let mut bytes = vec![0u8; u32::from_be_bytes(length) as usize];
+        read_exact_or_truncated(&mut self.input, &mut bytes)?;
+        let mut checksum = [0u8; Hash::LENGTH];
+        read_exact_or_truncated(&mut self.input, &mut checksum)?;
+        if hash(&bytes) != Hash(checksum) {
+            return Err(ChecksummedRecordError::ChecksumMismatch { record_index });
+        }
+        self.next_record_index += 1;
+        scrypto_decode::<T>(&bytes).map_err(|error| ChecksummedRecordError::DecodingFailed {
+            record_index,
+            error,
+        })
+    }
+}
+
+fn read_exact_or_truncated(
+    input: &mut impl Read,
+    buffer: &mut [u8],
+) -> Result<(), ChecksummedRecordError> {
+    input
+        .read_exact(buffer)
+        .map_err(|error| match error.kind() {
+            ErrorKind::UnexpectedEof => ChecksummedRecordError::Truncated,
+            _ => ChecksummedRecordError::Io(error),
+        })
+}
@@ -294,6 +294,73 @@ impl<S, SC: DbCodec<S> + OrderPreservingDbCodec> OrderPreservingDbCodec
 {
 }
 
+pub struct PrefixResourceAddressDbCodec<S, SC: DbCodec<S>> {
+    suffix_codec: SC,
+    type_parameters_phantom: PhantomData<S>,
+}
+
+impl<S, SC: DbCodec<S>> PrefixResourceAddressDbCodec<S, SC> {
+    pub fn new(suffix_codec: SC) -> Self {
+        Self {
+            suffix_codec,
+            type_parameters_phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, SC: DbCodec<S>> DbCodec<(ResourceAddress, S)> for PrefixResourceAddressDbCodec<S, SC> {
+    fn encode(&self, (resource_address, suffix): &(ResourceAddress, S)) -> Vec<u8> {
+        let mut encoding = resource_address.to_vec();
+        encoding.extend_from_slice(self.suffix_codec.encode(suffix).as_slice());
+        encoding
+    }
+
+    fn decode(&self, bytes: &[u8]) -> (ResourceAddress, S) {
+        let resource_address =
+            ResourceAddress::new_or_panic(copy_u8_array(&bytes[..NodeId::LENGTH]));
+        let suffix = self.suffix_codec.decode(&bytes[NodeId::LENGTH..]);
+        (resource_address, suffix)
+    }
+}
+
+impl<S, SC: DbCodec<S> + OrderPreservingDbCodec> OrderPreservingDbCodec
+    for PrefixResourceAddressDbCodec<S, SC>
+{
+}
+
+pub struct PrefixHashDbCodec<S, SC: DbCodec<S>> {
+    suffix_codec: SC,
+    type_parameters_phantom: PhantomData<S>,
+}
+
+impl<S, SC: DbCodec<S>> PrefixHashDbCodec<S, SC> {
+    pub fn new(suffix_codec: SC) -> Self {
+        Self {
+            suffix_codec,
+            type_parameters_phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, SC: DbCodec<S>> DbCodec<(Hash, S)> for PrefixHashDbCodec<S, SC> {
+    fn encode(&self, (hash, suffix): &(Hash, S)) -> Vec<u8> {
+        let mut encoding = hash.to_vec();
+        encoding.extend_from_slice(self.suffix_codec.encode(suffix).as_slice());
+        encoding
+    }
+
+    fn decode(&self, bytes: &[u8]) -> (Hash, S) {
+        let hash = Hash(copy_u8_array(&bytes[..Hash::LENGTH]));
+        let suffix = self.suffix_codec.decode(&bytes[Hash::LENGTH..]);
+        (hash, suffix)
+    }
+}
+
+impl<S, SC: DbCodec<S> + OrderPreservingDbCodec> OrderPreservingDbCodec
+    for PrefixHashDbCodec<S, SC>
+{
+}
+
 #[derive(Default)]
 pub struct NodeIdDbCodec {}
 
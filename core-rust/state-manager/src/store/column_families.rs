@@ -231,6 +231,34 @@ impl VersionedCf for StaleStateTreePartsCf {
     type VersionedValue = VersionedStaleTreeParts;
 }
 
+/// Parts of the Substate database's hash tree which became stale at a specific state version, but
+/// are still reachable from some sparse historical state checkpoint (and thus cannot be deleted).
+/// Schema: `StateVersion.to_bytes()` -> `scrypto_encode(VersionedStaleTreeParts)`.
+/// Note: these are only held here so that they can be handed back to the GC if the state history
+/// feature gets disabled.
+pub struct RetainedStaleStateTreePartsCf;
+impl VersionedCf for RetainedStaleStateTreePartsCf {
+    type Key = StateVersion;
+    type Value = StaleTreeParts;
+
+    const NAME_SOURCE: &'static str = "retained_stale_state_tree_parts";
+    type KeyCodec = StateVersionDbCodec;
+    type VersionedValue = VersionedStaleTreeParts;
+}
+
+/// State versions of the sparse historical state checkpoints (i.e. epoch boundaries at which the
+/// historical Substate values remain available beyond the full-resolution state history window).
+/// Schema: `StateVersion.to_bytes()` -> `[]`.
+pub struct StateHistoryCheckpointsCf;
+impl DefaultCf for StateHistoryCheckpointsCf {
+    type Key = StateVersion;
+    type Value = ();
+
+    const NAME_SOURCE: &'static str = "state_history_checkpoints";
+    type KeyCodec = StateVersionDbCodec;
+    type ValueCodec = UnitDbCodec;
+}
+
 /// Transaction accumulator tree slices added at a specific state version.
 /// Schema: `StateVersion.to_bytes()` -> `scrypto_encode(VersionedTransactionAccuTreeSlice)`.
 pub struct TransactionAccuTreeSlicesCf;
@@ -264,6 +292,11 @@ pub enum ExtensionsDataKey {
     December2023LostSubstatesRestored,
     StateTreeAssociatedValuesStatus,
     EntityListingIndicesLastProcessedStateVersion,
+    LocalTransactionExecutionIndexStatus,
+    SecondaryIndicesProgress,
+    ResourceChangeIndexLastProcessedStateVersion,
+    LedgerTransactionsStatus,
+    DatabaseSchemaStatus,
 }
 
 // IMPORTANT NOTE: the strings defined below are used as database identifiers. Any change would
@@ -284,6 +317,15 @@ impl fmt::Display for ExtensionsDataKey {
             Self::EntityListingIndicesLastProcessedStateVersion => {
                 "entity_listing_indices_last_processed_state_version"
             }
+            Self::LocalTransactionExecutionIndexStatus => {
+                "local_transaction_execution_index_status"
+            }
+            Self::SecondaryIndicesProgress => "secondary_indices_progress",
+            Self::ResourceChangeIndexLastProcessedStateVersion => {
+                "resource_change_index_last_processed_state_version"
+            }
+            Self::LedgerTransactionsStatus => "ledger_transactions_status",
+            Self::DatabaseSchemaStatus => "database_schema_status",
         };
         write!(f, "{str}")
     }
@@ -311,6 +353,11 @@ impl TypedCf for ExtensionsDataCf {
             ExtensionsDataKey::December2023LostSubstatesRestored,
             ExtensionsDataKey::StateTreeAssociatedValuesStatus,
             ExtensionsDataKey::EntityListingIndicesLastProcessedStateVersion,
+            ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+            ExtensionsDataKey::SecondaryIndicesProgress,
+            ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
+            ExtensionsDataKey::LedgerTransactionsStatus,
+            ExtensionsDataKey::DatabaseSchemaStatus,
         ])
     }
 
@@ -342,6 +389,73 @@ impl TypedCf for AccountChangeStateVersionsCf {
     }
 }
 
+/// Resource addresses and state versions at which their balances were changed (in any global
+/// entity, as captured by the [`GlobalBalanceSummary`]).
+/// Schema: `[ResourceAddress.0, StateVersion.to_bytes()].concat() -> []`.
+/// Note: This is a key-only table, emulating a `Map<Resource, Set<StateVersion>>` (exactly like
+/// the [`AccountChangeStateVersionsCf`]).
+pub struct ResourceChangeStateVersionsCf;
+impl TypedCf for ResourceChangeStateVersionsCf {
+    type Key = (ResourceAddress, StateVersion);
+    type Value = ();
+
+    type KeyCodec = PrefixResourceAddressDbCodec<StateVersion, StateVersionDbCodec>;
+    type ValueCodec = UnitDbCodec;
+
+    const NAME: &'static str = "resource_change_state_versions";
+
+    fn key_codec(&self) -> PrefixResourceAddressDbCodec<StateVersion, StateVersionDbCodec> {
+        PrefixResourceAddressDbCodec::new(StateVersionDbCodec::default())
+    }
+
+    fn value_codec(&self) -> UnitDbCodec {
+        UnitDbCodec::default()
+    }
+}
+
+/// State versions of committed transactions, indexed by keys extracted by a specific
+/// [`SecondaryIndex`].
+/// Schema: `[hash(index_key), StateVersion.to_bytes()].concat() -> []`.
+/// Note: This is a "family" of column families: each enabled [`SecondaryIndex`] has its own column
+/// family, named by appending the index's name to the [`SecondaryIndexCf::NAME`] prefix.
+/// Similar to [`AccountChangeStateVersionsCf`], this is a key-only table emulating a
+/// `Map<IndexKey, Set<StateVersion>>` (where the arbitrary-length index keys are hashed, to allow
+/// for a fast prefix iteration).
+pub struct SecondaryIndexCf {
+    name: String,
+}
+
+impl SecondaryIndexCf {
+    /// Creates an instance representing the column family of the given [`SecondaryIndex`].
+    pub fn of(index_name: &str) -> Self {
+        Self {
+            name: format!("{}{}", Self::NAME, index_name),
+        }
+    }
+}
+
+impl TypedCf for SecondaryIndexCf {
+    type Key = (Hash, StateVersion);
+    type Value = ();
+
+    type KeyCodec = PrefixHashDbCodec<StateVersion, StateVersionDbCodec>;
+    type ValueCodec = UnitDbCodec;
+
+    const NAME: &'static str = "secondary_index_";
+
+    fn key_codec(&self) -> PrefixHashDbCodec<StateVersion, StateVersionDbCodec> {
+        PrefixHashDbCodec::new(StateVersionDbCodec::default())
+    }
+
+    fn value_codec(&self) -> UnitDbCodec {
+        UnitDbCodec::default()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 /// Additional details of "Scenarios" (and their transactions) executed as part of Genesis,
 /// keyed by their sequence number (i.e. their index in the list of Scenarios to execute).
 /// Schema: `ScenarioSequenceNumber.to_be_bytes()` -> `scrypto_encode(VersionedExecutedScenario)`
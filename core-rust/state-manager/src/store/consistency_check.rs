@@ -0,0 +1,630 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::historical_state::StateTreeBasedSubstateDatabase;
+
+/// A configuration of the [`check_database_consistency()`].
+#[derive(Debug, Clone)]
+pub struct ConsistencyCheckConfig {
+    /// A maximum number of violations to be captured in the [`ConsistencyCheckReport`].
+    /// The check always runs to completion and counts all violations; this limit only protects
+    /// against an unbounded memory use in case of a massive corruption.
+    pub max_reported_violations: usize,
+}
+
+impl Default for ConsistencyCheckConfig {
+    fn default() -> Self {
+        Self {
+            max_reported_violations: 1000,
+        }
+    }
+}
+
+/// A single broken invariant found by the [`check_database_consistency()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsistencyViolation {
+    /// A Substate exists in the `SubstatesCf`, but has no leaf in the current state tree.
+    SubstateMissingInStateTree { substate_key: DbSubstateKey },
+    /// A leaf of the current state tree has no corresponding entry in the `SubstatesCf`.
+    StateTreeLeafWithoutSubstate { substate_key: DbSubstateKey },
+    /// A leaf of the current state tree holds a different hash than the `SubstatesCf` value.
+    SubstateValueHashMismatch {
+        substate_key: DbSubstateKey,
+        leaf_value_hash: Hash,
+        substate_value_hash: Hash,
+    },
+    /// A current state tree's leaf key points at a missing (or non-leaf) node.
+    StateTreeLeafNodeMissing { leaf_key: StoredTreeNodeKey },
+    /// A transaction hash index points at a state version which does not contain a transaction
+    /// of that hash.
+    DanglingTransactionHashIndexEntry {
+        hash: IndexedTransactionHash,
+        state_version: StateVersion,
+    },
+    /// A committed transaction's hash cannot be resolved (back to its state version) using the
+    /// transaction hash index.
+    MissingTransactionHashIndexEntry {
+        hash: IndexedTransactionHash,
+        state_version: StateVersion,
+    },
+    /// A state version (within the range of committed transactions available in this database)
+    /// has no transaction identifiers or ledger receipt.
+    MissingTransaction { state_version: StateVersion },
+    /// An accumulator tree slice is not stored at the given state version.
+    MissingAccuTreeSlice {
+        tree: AccuTreeKind,
+        state_version: StateVersion,
+    },
+    /// The root of an accumulator tree slice stored at the given state version differs from the
+    /// ledger hashes recorded in the transaction's identifiers.
+    StoredAccuTreeRootMismatch {
+        tree: AccuTreeKind,
+        state_version: StateVersion,
+    },
+    /// The root of an accumulator tree recomputed from the transaction (or receipt) at the given
+    /// state version differs from the ledger hashes recorded in the transaction's identifiers.
+    RecomputedAccuTreeRootMismatch {
+        tree: AccuTreeKind,
+        state_version: StateVersion,
+    },
+    /// A non-global entity which owns some Substates has no ancestry record.
+    MissingNodeAncestryRecord { node_id: NodeId },
+    /// An ancestry record names a non-global entity as its root.
+    NonGlobalAncestryRoot { node_id: NodeId, root: NodeId },
+    /// An ancestry record names a different root than the ancestry record of its parent.
+    InconsistentAncestryRoot {
+        node_id: NodeId,
+        root: NodeId,
+        parent_root: NodeId,
+    },
+}
+
+/// A transaction hash, tagged with the index which stores it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexedTransactionHash {
+    Intent(TransactionIntentHash),
+    Notarized(NotarizedTransactionHash),
+    Ledger(LedgerTransactionHash),
+}
+
+/// An accumulator tree kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccuTreeKind {
+    Transaction,
+    Receipt,
+}
+
+/// A result of the [`check_database_consistency()`].
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyCheckReport {
+    /// The first [`ConsistencyCheckConfig::max_reported_violations`] found violations.
+    pub violations: Vec<ConsistencyViolation>,
+    /// The total number of found violations (may exceed the size of [`Self::violations`]).
+    pub violation_count: usize,
+    pub checked_substate_count: usize,
+    pub checked_state_tree_leaf_count: usize,
+    pub checked_transaction_count: usize,
+    pub checked_transaction_hash_index_entry_count: usize,
+    pub checked_node_ancestry_record_count: usize,
+}
+
+impl ConsistencyCheckReport {
+    pub fn is_consistent(&self) -> bool {
+        self.violation_count == 0
+    }
+
+    fn report(&mut self, violation: ConsistencyViolation, config: &ConsistencyCheckConfig) {
+        warn!("Database consistency violation: {:?}", violation);
+        self.violation_count += 1;
+        if self.violations.len() < config.max_reported_violations {
+            self.violations.push(violation);
+        }
+    }
+}
+
+/// Walks the entire given database and cross-checks the invariants between its column families:
+/// - the current Substate values vs the current state tree's leaves,
+/// - the transaction hash indices vs the committed transactions,
+/// - the stored accumulator tree slices vs the committed transactions and receipts,
+/// - the Substate node ancestry records vs each other and the Substates' owners.
+///
+/// This is a long-running, read-only operation, which assumes that the database does not change
+/// while it is being checked. Hence, an online check should be run against a consistent view:
+/// either a [`Snapshottable::snapshot()`] of it, or a checkpoint (see
+/// [`StateManagerDatabase::create_checkpoint()`]) opened with
+/// [`StateManagerDatabase::new_read_only()`].
+pub fn check_database_consistency<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+) -> ConsistencyCheckReport {
+    let mut report = ConsistencyCheckReport::default();
+    info!("Checking the Substates against the state tree");
+    check_substates(database, config, &mut report);
+    info!("Checking the state tree against the Substates");
+    check_state_tree_leaves(database, config, &mut report);
+    info!("Checking the committed transactions and accumulator trees");
+    check_transactions(database, config, &mut report);
+    info!("Checking the transaction hash indices");
+    check_transaction_hash_indices(database, config, &mut report);
+    info!("Checking the Substate node ancestry records");
+    check_node_ancestry_records(database, config, &mut report);
+    info!(
+        "Database consistency check finished with {} violation(s)",
+        report.violation_count
+    );
+    report
+}
+
+fn check_substates<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+) {
+    let state_tree = StateTreeBasedSubstateDatabase::new(database, database.max_state_version());
+    for partition_key in database.list_partition_keys() {
+        let (node_id, _) = SpreadPrefixKeyMapper::from_db_partition_key(&partition_key);
+        if !node_id.is_global() && database.get_ancestry(&node_id).is_none() {
+            report.report(
+                ConsistencyViolation::MissingNodeAncestryRecord { node_id },
+                config,
+            );
+        }
+        for (sort_key, _) in database.list_raw_values_from_db_key(&partition_key, None) {
+            report.checked_substate_count += 1;
+            if state_tree
+                .get_substate_leaf_key(&partition_key, &sort_key)
+                .is_none()
+            {
+                report.report(
+                    ConsistencyViolation::SubstateMissingInStateTree {
+                        substate_key: (partition_key.clone(), sort_key),
+                    },
+                    config,
+                );
+            }
+        }
+    }
+}
+
+fn check_state_tree_leaves<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+) {
+    let state_tree = StateTreeBasedSubstateDatabase::new(database, database.max_state_version());
+    for (leaf_key, substate_key) in state_tree.iter_substate_leaf_keys() {
+        report.checked_state_tree_leaf_count += 1;
+        let Some(TreeNode::Leaf(leaf)) = database.get_node(&leaf_key) else {
+            report.report(
+                ConsistencyViolation::StateTreeLeafNodeMissing { leaf_key },
+                config,
+            );
+            continue;
+        };
+        let (partition_key, sort_key) = &substate_key;
+        let Some(value) = database.get_raw_substate_by_db_key(partition_key, sort_key) else {
+            report.report(
+                ConsistencyViolation::StateTreeLeafWithoutSubstate { substate_key },
+                config,
+            );
+            continue;
+        };
+        let substate_value_hash = hash(value);
+        if leaf.value_hash != substate_value_hash {
+            report.report(
+                ConsistencyViolation::SubstateValueHashMismatch {
+                    substate_key,
+                    leaf_value_hash: leaf.value_hash,
+                    substate_value_hash,
+                },
+                config,
+            );
+        }
+    }
+}
+
+fn check_transactions<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+) {
+    let max_state_version = database.max_state_version();
+    // A database bootstrapped from a state snapshot only has the transactions after its first
+    // proof (except for a sparse history of epoch changes, which we cannot check here):
+    let first_state_version = StateVersion::pre_genesis().next().unwrap();
+    let start_state_version = if database
+        .get_committed_transaction_identifiers(first_state_version)
+        .is_some()
+    {
+        first_state_version
+    } else {
+        match database.get_first_proof() {
+            Some(first_proof) => first_proof.ledger_header.state_version.next().unwrap(),
+            None => return,
+        }
+    };
+
+    // The receipts of transactions pruned by the `LedgerTransactionsGc` may be missing (but their
+    // identifiers and accu tree slices are retained):
+    let first_stored_receipt_state_version =
+        database.get_first_stored_ledger_transaction_state_version();
+
+    let mut epoch_proofs = database.get_next_epoch_proof_iter(Epoch::of(0)).peekable();
+    let mut epoch_identifiers = EpochTransactionIdentifiers::pre_genesis();
+    // Whether the accu tree slices of the previous state version were found consistent (i.e.
+    // whether an incremental recomputation of the current state version is meaningful):
+    let mut previous_slices_consistent = (true, true);
+    let mut state_version = start_state_version;
+    while state_version <= max_state_version {
+        let parent_state_version = state_version.previous().unwrap();
+        while let Some(epoch_proof) =
+            epoch_proofs.next_if(|proof| proof.ledger_header.state_version <= parent_state_version)
+        {
+            epoch_identifiers = EpochTransactionIdentifiers::from(&epoch_proof.ledger_header);
+        }
+        if epoch_identifiers.state_version == parent_state_version {
+            // A fresh epoch does not depend on any previous slices
+            previous_slices_consistent = (true, true);
+        }
+
+        report.checked_transaction_count += 1;
+        let identifiers = database.get_committed_transaction_identifiers(state_version);
+        let receipt = database.get_committed_ledger_transaction_receipt(state_version);
+        let Some(identifiers) = identifiers
+            .filter(|_| receipt.is_some() || state_version < first_stored_receipt_state_version)
+        else {
+            report.report(
+                ConsistencyViolation::MissingTransaction { state_version },
+                config,
+            );
+            previous_slices_consistent = (false, false);
+            state_version = state_version.next().unwrap();
+            continue;
+        };
+
+        check_transaction_hash_index_entries(
+            database,
+            config,
+            report,
+            state_version,
+            &identifiers.transaction_hashes,
+        );
+
+        let epoch_accu_trees =
+            EpochAwareAccuTreeFactory::new(epoch_identifiers.state_version, parent_state_version);
+        let ledger_hashes = &identifiers.resultant_ledger_hashes;
+        previous_slices_consistent = (
+            check_accu_tree_slice(
+                database,
+                config,
+                report,
+                AccuTreeKind::Transaction,
+                state_version,
+                &ledger_hashes.transaction_root,
+                previous_slices_consistent.0.then(|| {
+                    *epoch_accu_trees
+                        .compute_tree_diff(
+                            epoch_identifiers.transaction_root,
+                            database,
+                            vec![TransactionTreeHash::from(
+                                identifiers.transaction_hashes.ledger_transaction_hash,
+                            )],
+                        )
+                        .slice
+                        .root()
+                }),
+            ),
+            check_accu_tree_slice(
+                database,
+                config,
+                report,
+                AccuTreeKind::Receipt,
+                state_version,
+                &ledger_hashes.receipt_root,
+                receipt
+                    .filter(|_| previous_slices_consistent.1)
+                    .map(|receipt| {
+                        *epoch_accu_trees
+                            .compute_tree_diff(
+                                epoch_identifiers.receipt_root,
+                                database,
+                                vec![ReceiptTreeHash::from(
+                                    receipt.get_consensus_receipt().get_hash(),
+                                )],
+                            )
+                            .slice
+                            .root()
+                    }),
+            ),
+        );
+        state_version = state_version.next().unwrap();
+    }
+}
+
+/// Checks the stored slice (and, if given, the recomputed root) against the recorded root.
+/// Returns `true` if the stored slice is consistent.
+fn check_accu_tree_slice<N, S: ReadableAccuTreeStore<StateVersion, N>>(
+    database: &S,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+    tree: AccuTreeKind,
+    state_version: StateVersion,
+    recorded_root: &N,
+    recomputed_root: Option<N>,
+) -> bool
+where
+    N: PartialEq,
+{
+    if let Some(recomputed_root) = recomputed_root {
+        if &recomputed_root != recorded_root {
+            report.report(
+                ConsistencyViolation::RecomputedAccuTreeRootMismatch {
+                    tree,
+                    state_version,
+                },
+                config,
+            );
+        }
+    }
+    let Some(slice) = database.get_tree_slice(&state_version) else {
+        report.report(
+            ConsistencyViolation::MissingAccuTreeSlice {
+                tree,
+                state_version,
+            },
+            config,
+        );
+        return false;
+    };
+    if slice.root() != recorded_root {
+        report.report(
+            ConsistencyViolation::StoredAccuTreeRootMismatch {
+                tree,
+                state_version,
+            },
+            config,
+        );
+        return false;
+    }
+    true
+}
+
+fn check_transaction_hash_index_entries<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+    state_version: StateVersion,
+    transaction_hashes: &LedgerTransactionHashes,
+) {
+    let mut indexed_hashes = vec![(
+        IndexedTransactionHash::Ledger(transaction_hashes.ledger_transaction_hash),
+        database.get_txn_state_version_by_identifier(&transaction_hashes.ledger_transaction_hash),
+    )];
+    if let Some(user_hashes) = transaction_hashes.as_user() {
+        indexed_hashes.push((
+            IndexedTransactionHash::Intent(user_hashes.transaction_intent_hash),
+            database.get_txn_state_version_by_identifier(&user_hashes.transaction_intent_hash),
+        ));
+        indexed_hashes.push((
+            IndexedTransactionHash::Notarized(user_hashes.notarized_transaction_hash),
+            database.get_txn_state_version_by_identifier(&user_hashes.notarized_transaction_hash),
+        ));
+    }
+    for (hash, indexed_state_version) in indexed_hashes {
+        if indexed_state_version != Some(state_version) {
+            report.report(
+                ConsistencyViolation::MissingTransactionHashIndexEntry {
+                    hash,
+                    state_version,
+                },
+                config,
+            );
+        }
+    }
+}
+
+fn check_transaction_hash_indices<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+) {
+    let entries =
+        database
+            .get_ledger_transaction_hash_index_iter()
+            .map(|(hash, state_version)| (IndexedTransactionHash::Ledger(hash), state_version))
+            .chain(
+                database
+                    .get_intent_hash_index_iter()
+                    .map(|(hash, state_version)| {
+                        (IndexedTransactionHash::Intent(hash), state_version)
+                    }),
+            )
+            .chain(database.get_notarized_transaction_hash_index_iter().map(
+                |(hash, state_version)| (IndexedTransactionHash::Notarized(hash), state_version),
+            ));
+    // Only the identifiers of transactions pruned by the `LedgerTransactionsGc` remain:
+    let first_stored_transaction_state_version =
+        database.get_first_stored_ledger_transaction_state_version();
+    for (hash, state_version) in entries {
+        report.checked_transaction_hash_index_entry_count += 1;
+        let points_at_transaction = database
+            .get_committed_transaction_identifiers(state_version)
+            .map(|identifiers| identifiers.transaction_hashes)
+            .is_some_and(|hashes| match &hash {
+                IndexedTransactionHash::Ledger(hash) => &hashes.ledger_transaction_hash == hash,
+                IndexedTransactionHash::Intent(hash) => hashes
+                    .as_user()
+                    .is_some_and(|user_hashes| &user_hashes.transaction_intent_hash == hash),
+                IndexedTransactionHash::Notarized(hash) => hashes
+                    .as_user()
+                    .is_some_and(|user_hashes| &user_hashes.notarized_transaction_hash == hash),
+            });
+        let transaction_missing = state_version >= first_stored_transaction_state_version
+            && database.get_committed_transaction(state_version).is_none();
+        if !points_at_transaction || transaction_missing {
+            report.report(
+                ConsistencyViolation::DanglingTransactionHashIndexEntry {
+                    hash,
+                    state_version,
+                },
+                config,
+            );
+        }
+    }
+}
+
+fn check_node_ancestry_records<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+    config: &ConsistencyCheckConfig,
+    report: &mut ConsistencyCheckReport,
+) {
+    for (node_id, record) in database.get_substate_node_ancestry_record_iter() {
+        report.checked_node_ancestry_record_count += 1;
+        let SubstateNodeAncestryRecord { parent, root } = record;
+        let root_node_id = root.0;
+        if !root_node_id.is_global() {
+            report.report(
+                ConsistencyViolation::NonGlobalAncestryRoot {
+                    node_id,
+                    root: root_node_id,
+                },
+                config,
+            );
+        }
+        let parent_root_node_id = database
+            .get_ancestry(&parent.0)
+            .map(|parent_record| parent_record.root.0)
+            // A parent without a record must be the (global) root itself:
+            .unwrap_or(parent.0);
+        if parent_root_node_id != root_node_id {
+            report.report(
+                ConsistencyViolation::InconsistentAncestryRoot {
+                    node_id,
+                    root: root_node_id,
+                    parent_root: parent_root_node_id,
+                },
+                config,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager() -> StateManager {
+        let config = StateManagerConfig::new_for_testing_in_memory();
+        create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+    }
+
+    #[test]
+    fn healthy_ledger_is_consistent() {
+        let state_manager = create_state_manager();
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.access_direct();
+        let report = check_database_consistency(database.deref(), &Default::default());
+        assert_eq!(report.violations, vec![]);
+        assert!(report.is_consistent());
+        assert!(report.checked_substate_count > 0);
+        assert_eq!(
+            report.checked_substate_count,
+            report.checked_state_tree_leaf_count
+        );
+        assert_eq!(
+            report.checked_transaction_count as u64,
+            database.max_state_version().number()
+        );
+    }
+
+    #[test]
+    fn substate_outside_state_tree_is_reported() {
+        let state_manager = create_state_manager();
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(2));
+
+        let database = state_manager.database.access_direct();
+        let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+        );
+        let sort_key = DbSortKey(vec![0xFF; 3]);
+        database.put_snapshot_substates(vec![(
+            (partition_key.clone(), sort_key.clone()),
+            vec![1, 2, 3],
+        )]);
+
+        let report = check_database_consistency(database.deref(), &Default::default());
+        assert_eq!(
+            report.violations,
+            vec![ConsistencyViolation::SubstateMissingInStateTree {
+                substate_key: (partition_key, sort_key),
+            }]
+        );
+    }
+}
@@ -0,0 +1,487 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::checksummed_records::*;
+use std::fs;
+use std::io::{BufReader, BufWriter, ErrorKind};
+use std::path::Path;
+
+/// A magic prefix of the checkpoints' manifest file.
+const MANIFEST_MAGIC: &[u8; 8] = b"RDXCKPTM";
+
+/// A name of the manifest file, kept directly in the checkpoints' directory.
+const MANIFEST_FILE_NAME: &str = "manifest.bin";
+
+/// A configuration for [`DbCheckpointManager`].
+#[derive(Debug, Clone, Sbor)]
+pub struct DbCheckpointsConfig {
+    /// A directory in which the checkpoints (and their manifest) are kept.
+    pub checkpoints_path: String,
+    /// How often to check whether a checkpoint is due, in seconds.
+    /// Apart from these periodic checks, a check is also performed right after each committed epoch
+    /// change (see [`DbCheckpointManager::run()`]) - the periodic ones only
+    /// serve as a fallback (e.g. when a GC was in progress at the epoch change).
+    pub interval_sec: u32,
+    /// A number of epochs between consecutive checkpoints (`1` means "every epoch").
+    pub epoch_interval: u64,
+    /// How many most recent checkpoints should be retained (older ones are deleted).
+    pub retained_checkpoint_count: u32,
+}
+
+define_single_versioned! {
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub VersionedDbCheckpointsManifest(DbCheckpointsManifestVersions) => DbCheckpointsManifest = DbCheckpointsManifestV1
+}
+
+/// A list of the checkpoints currently retained in the checkpoints' directory.
+#[derive(Debug, Clone, Default, ScryptoSbor)]
+pub struct DbCheckpointsManifestV1 {
+    /// Checkpoints in their creation order (i.e. the oldest first).
+    pub checkpoints: Vec<DbCheckpointMetadata>,
+}
+
+/// A description of a single, verified checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct DbCheckpointMetadata {
+    /// A name of the checkpoint's directory (relative to the checkpoints' directory).
+    pub directory_name: String,
+    /// The epoch in progress at the checkpoint's top-of-ledger.
+    pub epoch: Epoch,
+    /// The state version of the latest ledger proof contained in the checkpoint.
+    pub state_version: StateVersion,
+    /// The ledger hashes of the latest ledger proof contained in the checkpoint.
+    pub ledger_hashes: LedgerHashes,
+    /// The proposer timestamp of the latest ledger proof contained in the checkpoint.
+    pub proposer_timestamp_ms: i64,
+}
+
+#[derive(Debug)]
+pub enum DbCheckpointError {
+    Io(std::io::Error),
+    Manifest(ChecksummedRecordError),
+    CreationFailed(String),
+    OpeningFailed(DatabaseOpeningError),
+    UnexpectedTopOfLedger {
+        expected_state_version: StateVersion,
+        actual_state_version: Option<StateVersion>,
+    },
+}
+
+impl From<std::io::Error> for DbCheckpointError {
+    fn from(error: std::io::Error) -> Self {
+        DbCheckpointError::Io(error)
+    }
+}
+
+impl From<ChecksummedRecordError> for DbCheckpointError {
+    fn from(error: ChecksummedRecordError) -> Self {
+        DbCheckpointError::Manifest(error)
+    }
+}
+
+/// A creator of periodic database checkpoints, retaining a configured number of the most recent
+/// ones and describing them in a manifest.
+/// The implementation is suited for being driven by an external scheduler.
+pub struct DbCheckpointManager {
+    database: Arc<DbLock<ActualStateManagerDatabase>>,
+    maintenance_lock: Arc<Mutex<()>>,
+    run_lock: Mutex<()>,
+    checkpoints_path: PathBuf,
+    interval: Duration,
+    epoch_interval: u64,
+    retained_checkpoint_count: usize,
+}
+
+impl DbCheckpointManager {
+    /// Creates a new manager.
+    /// The given `maintenance_lock` must be held by every other background process which mutates
+    /// the database in multiple batches (i.e. the GCs), so that a checkpoint never captures such
+    /// process' partial results.
+    pub fn new(
+        database: Arc<DbLock<ActualStateManagerDatabase>>,
+        maintenance_lock: Arc<Mutex<()>>,
+        config: DbCheckpointsConfig,
+        lock_factory: &LockFactory,
+    ) -> Self {
+        Self {
+            database,
+            maintenance_lock,
+            run_lock: lock_factory.named("run").new_mutex(()),
+            checkpoints_path: PathBuf::from(config.checkpoints_path),
+            interval: Duration::from_secs(u64::from(config.interval_sec)),
+            epoch_interval: config.epoch_interval.max(1),
+            retained_checkpoint_count: usize::try_from(config.retained_checkpoint_count)
+                .unwrap()
+                .max(1),
+        }
+    }
+
+    /// An interval between [`run()`]s, to be used by this instance's scheduler.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Performs a single run, which creates a new checkpoint if it is due (see
+    /// [`Self::create_checkpoint_if_due()`]), logging any problems.
+    ///
+    /// This is called by the committer right after committing an epoch change proof (after it
+    /// releases the [`DbLock::lock()`]; since the commits are sequential, no other commit can
+    /// interleave with the checkpoint then), and also periodically (as a fallback).
+    pub fn run(&self) {
+        match self.create_checkpoint_if_due() {
+            Ok(Some(metadata)) => info!(
+                "Created DB checkpoint {} (epoch {}, state version {})",
+                metadata.directory_name,
+                metadata.epoch.number(),
+                metadata.state_version
+            ),
+            Ok(None) => {}
+            Err(error) => error!("Could not create a scheduled DB checkpoint: {:?}", error),
+        }
+    }
+
+    /// Creates a new checkpoint if the top-of-ledger is an epoch change proof, and if at least the
+    /// configured number of epochs has started since the most recent checkpoint (or if there is no
+    /// checkpoint yet).
+    /// The new checkpoint is verified (see [`verify_db_checkpoint()`]) before being added to the
+    /// manifest, and then the checkpoints beyond the configured retention are deleted.
+    /// Returns the new checkpoint's metadata, or [`None`] if no checkpoint was due (or possible).
+    ///
+    /// *Note on concurrent database access:*
+    /// The checkpoint is captured under the maintenance lock only (so that no GC is in progress),
+    /// i.e. without blocking the commits. Hence a commit may interleave between reading the
+    /// top-of-ledger epoch proof and capturing the data - in such case, the checkpoint's
+    /// verification detects it and the checkpoint is discarded (to be re-attempted at a later run).
+    /// The runs never wait for a lock: a run which finds a GC (or another run) in progress does
+    /// nothing.
+    pub fn create_checkpoint_if_due(
+        &self,
+    ) -> Result<Option<DbCheckpointMetadata>, DbCheckpointError> {
+        let Some(_run_guard) = self.run_lock.try_lock() else {
+            return Ok(None);
+        };
+        let mut manifest = read_db_checkpoints_manifest(&self.checkpoints_path)?;
+        let Some(epoch_proof) = self.database.access_direct().get_latest_proof() else {
+            // Not even a genesis was committed yet.
+            return Ok(None);
+        };
+        let ledger_header = epoch_proof.ledger_header;
+        let Some(next_epoch) = ledger_header.next_epoch else {
+            // The checkpoints are only captured exactly at epoch boundaries.
+            return Ok(None);
+        };
+        let epoch = next_epoch.epoch;
+        if let Some(last_checkpoint) = manifest.checkpoints.last() {
+            if epoch.number() < last_checkpoint.epoch.number() + self.epoch_interval {
+                return Ok(None);
+            }
+        }
+
+        let metadata = DbCheckpointMetadata {
+            directory_name: format!(
+                "epoch_{}_state_version_{}",
+                epoch.number(),
+                ledger_header.state_version
+            ),
+            epoch,
+            state_version: ledger_header.state_version,
+            ledger_hashes: ledger_header.hashes,
+            proposer_timestamp_ms: ledger_header.proposer_timestamp_ms,
+        };
+        let checkpoint_path = self.checkpoints_path.join(&metadata.directory_name);
+        fs::create_dir_all(&self.checkpoints_path)?;
+        {
+            let Some(_maintenance_guard) = self.maintenance_lock.try_lock() else {
+                debug!("Postponing the DB checkpoint, since a DB maintenance is in progress");
+                return Ok(None);
+            };
+            // A leftover of an interrupted run (i.e. not listed in the manifest) is not usable:
+            remove_dir_if_exists(&checkpoint_path)?;
+            self.database
+                .access_direct()
+                .create_checkpoint(checkpoint_path.to_string_lossy().into_owned())
+                .map_err(DbCheckpointError::CreationFailed)?;
+        }
+
+        match verify_db_checkpoint(&checkpoint_path, &metadata) {
+            Ok(()) => {}
+            Err(DbCheckpointError::UnexpectedTopOfLedger { .. }) => {
+                debug!("Discarding the DB checkpoint, since a commit interleaved with it");
+                remove_dir_if_exists(&checkpoint_path)?;
+                return Ok(None);
+            }
+            Err(error) => {
+                remove_dir_if_exists(&checkpoint_path)?;
+                return Err(error);
+            }
+        }
+
+        manifest.checkpoints.push(metadata.clone());
+        let excess_count = manifest
+            .checkpoints
+            .len()
+            .saturating_sub(self.retained_checkpoint_count);
+        let deleted_checkpoints = manifest
+            .checkpoints
+            .drain(..excess_count)
+            .collect::<Vec<_>>();
+        // The manifest is written first, so that it never lists a deleted checkpoint:
+        write_db_checkpoints_manifest(&self.checkpoints_path, manifest)?;
+        for deleted_checkpoint in deleted_checkpoints {
+            remove_dir_if_exists(
+                &self
+                    .checkpoints_path
+                    .join(deleted_checkpoint.directory_name),
+            )?;
+        }
+        Ok(Some(metadata))
+    }
+}
+
+/// Verifies that the checkpoint at the given path opens cleanly (as a read-only database) and
+/// that its top-of-ledger proof matches the given metadata.
+pub fn verify_db_checkpoint(
+    checkpoint_path: &Path,
+    metadata: &DbCheckpointMetadata,
+) -> Result<(), DbCheckpointError> {
+    let database = StateManagerDatabase::try_new_read_only(checkpoint_path.to_path_buf())
+        .map_err(DbCheckpointError::OpeningFailed)?;
+    let ledger_header = database.get_latest_proof().map(|proof| proof.ledger_header);
+    match ledger_header {
+        Some(ledger_header)
+            if ledger_header.state_version == metadata.state_version
+                && ledger_header.hashes == metadata.ledger_hashes =>
+        {
+            Ok(())
+        }
+        other => Err(DbCheckpointError::UnexpectedTopOfLedger {
+            expected_state_version: metadata.state_version,
+            actual_state_version: other.map(|ledger_header| ledger_header.state_version),
+        }),
+    }
+}
+
+/// Reads the manifest kept in the given checkpoints' directory (or returns an empty one, if the
+/// directory does not contain a manifest yet).
+pub fn read_db_checkpoints_manifest(
+    checkpoints_path: &Path,
+) -> Result<DbCheckpointsManifest, DbCheckpointError> {
+    let file = match fs::File::open(checkpoints_path.join(MANIFEST_FILE_NAME)) {
+        Ok(file) => file,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            return Ok(DbCheckpointsManifest::default())
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let mut reader = ChecksummedRecordReader::new(BufReader::new(file), MANIFEST_MAGIC)?;
+    Ok(reader
+        .read::<VersionedDbCheckpointsManifest>()?
+        .fully_update_and_into_latest_version())
+}
+
+/// Atomically replaces the manifest kept in the given checkpoints' directory.
+fn write_db_checkpoints_manifest(
+    checkpoints_path: &Path,
+    manifest: DbCheckpointsManifest,
+) -> Result<(), DbCheckpointError> {
+    let temporary_path = checkpoints_path.join(format!("{}.tmp", MANIFEST_FILE_NAME));
+    let file = fs::File::create(&temporary_path)?;
+    let mut writer = ChecksummedRecordWriter::new(BufWriter::new(file), MANIFEST_MAGIC)?;
+    writer.write(&VersionedDbCheckpointsManifest::from(manifest))?;
+    writer
+        .finish()?
+        .into_inner()
+        .map_err(|error| error.into_error())?
+        .sync_all()?;
+    fs::rename(temporary_path, checkpoints_path.join(MANIFEST_FILE_NAME))?;
+    Ok(())
+}
+
+fn remove_dir_if_exists(path: &Path) -> Result<(), std::io::Error> {
+    match fs::remove_dir_all(path) {
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+        prepare_and_commit_round_update,
+    };
+    use node_common::locks::LockFactory;
+
+    fn create_manager(
+        state_manager: &StateManager,
+        checkpoints_path: &Path,
+        maintenance_lock: Arc<Mutex<()>>,
+    ) -> DbCheckpointManager {
+        DbCheckpointManager::new(
+            state_manager.database.clone(),
+            maintenance_lock,
+            DbCheckpointsConfig {
+                checkpoints_path: checkpoints_path.to_str().unwrap().to_string(),
+                interval_sec: 0,
+                epoch_interval: 2,
+                retained_checkpoint_count: 2,
+            },
+            &LockFactory::new("testing"),
+        )
+    }
+
+    #[test]
+    fn checkpoints_are_created_at_epoch_intervals_and_retained_up_to_limit() {
+        let db_tmp = tempfile::tempdir().unwrap();
+        let checkpoints_tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing(db_tmp.path().to_str().unwrap()),
+            10,
+        );
+        let manager = create_manager(
+            &state_manager,
+            checkpoints_tmp.path(),
+            Arc::new(LockFactory::new("testing").new_mutex(())),
+        );
+
+        let mut created_epochs = vec![];
+        for epoch in 3..=9 {
+            commit_round_updates_until_epoch(&state_manager, Epoch::of(epoch));
+            if let Some(metadata) = manager.create_checkpoint_if_due().unwrap() {
+                created_epochs.push(metadata.epoch.number());
+            }
+        }
+        // The first checkpoint is created immediately, and then every 2 epochs:
+        assert_eq!(created_epochs, vec![3, 5, 7, 9]);
+
+        let manifest = read_db_checkpoints_manifest(checkpoints_tmp.path()).unwrap();
+        let retained_epochs = manifest
+            .checkpoints
+            .iter()
+            .map(|metadata| metadata.epoch.number())
+            .collect::<Vec<_>>();
+        assert_eq!(retained_epochs, vec![7, 9]);
+        for metadata in manifest.checkpoints.iter() {
+            let checkpoint_path = checkpoints_tmp.path().join(&metadata.directory_name);
+            verify_db_checkpoint(&checkpoint_path, metadata).unwrap();
+        }
+        let checkpoint_directory_count = fs::read_dir(checkpoints_tmp.path())
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().path().is_dir())
+            .count();
+        assert_eq!(checkpoint_directory_count, 2);
+
+        // The retained checkpoint is exactly the top-of-ledger at the time of its creation:
+        let latest = manifest.checkpoints.last().unwrap();
+        let database = state_manager.database.lock();
+        let top_proof = database.get_latest_proof().unwrap();
+        assert_eq!(latest.state_version, top_proof.ledger_header.state_version);
+        assert_eq!(latest.ledger_hashes, top_proof.ledger_header.hashes);
+    }
+
+    #[test]
+    fn checkpoints_are_only_created_at_epoch_proofs_and_outside_of_maintenance() {
+        let db_tmp = tempfile::tempdir().unwrap();
+        let checkpoints_tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing(db_tmp.path().to_str().unwrap()),
+            10,
+        );
+        let maintenance_lock = Arc::new(LockFactory::new("testing").new_mutex(()));
+        let manager = create_manager(
+            &state_manager,
+            checkpoints_tmp.path(),
+            maintenance_lock.clone(),
+        );
+
+        // A proof in the middle of an epoch is not a checkpoint boundary:
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        prepare_and_commit_round_update(&state_manager);
+        assert!(manager.create_checkpoint_if_due().unwrap().is_none());
+
+        // An epoch proof is, but not while some DB maintenance (e.g. a GC) is in progress:
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+        {
+            let _maintenance_guard = maintenance_lock.lock();
+            assert!(manager.create_checkpoint_if_due().unwrap().is_none());
+        }
+        let metadata = manager.create_checkpoint_if_due().unwrap().unwrap();
+        let epoch_proof = state_manager
+            .database
+            .access_direct()
+            .get_latest_epoch_proof()
+            .unwrap();
+        assert_eq!(metadata.epoch.number(), 4);
+        assert_eq!(
+            metadata.state_version,
+            epoch_proof.ledger_header.state_version
+        );
+        assert!(read_db_checkpoints_manifest(checkpoints_tmp.path())
+            .unwrap()
+            .checkpoints
+            .contains(&metadata));
+    }
+}
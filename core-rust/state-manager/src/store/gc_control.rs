@@ -0,0 +1,408 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::jmt_gc::StateTreeGcConfig;
+use crate::store::proofs_gc::LedgerProofsGcConfig;
+
+use std::time::Instant;
+
+/// An interval at which the scheduled GC tasks poll their [`GcControl`] for a due run.
+/// This bounds the delay of applying a changed interval, a resume or an on-demand run request.
+pub const GC_CONTROL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// An identifier of a GC which can be controlled at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sbor)]
+pub enum GcKind {
+    StateTree,
+    LedgerProofs,
+}
+
+/// A GC configuration which can be changed at runtime (see [`GcControl::reconfigure()`]).
+pub trait ControlledGcConfig: Clone + Debug + Send + 'static {
+    /// Returns an interval between periodic runs, or [`None`] if the GC should only run on demand.
+    fn periodic_interval(&self) -> Option<Duration>;
+}
+
+impl ControlledGcConfig for StateTreeGcConfig {
+    fn periodic_interval(&self) -> Option<Duration> {
+        (self.interval_sec > 0).then(|| Duration::from_secs(u64::from(self.interval_sec)))
+    }
+}
+
+impl ControlledGcConfig for LedgerProofsGcConfig {
+    fn periodic_interval(&self) -> Option<Duration> {
+        (self.interval_sec > 0).then(|| Duration::from_secs(u64::from(self.interval_sec)))
+    }
+}
+
+/// A runtime status of a GC (see [`GcControl::status()`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Sbor)]
+pub struct GcRunStatus {
+    pub is_paused: bool,
+    pub is_run_requested: bool,
+    pub is_running: bool,
+    pub completed_runs: u64,
+    /// A GC-specific position reached by the latest completed run (i.e. the state version up to
+    /// which the state hash tree is pruned, or the last epoch of pruned ledger proofs).
+    pub progress: Option<u64>,
+}
+
+/// A runtime control of a single GC, allowing to change its configuration, pause and resume it,
+/// and request its immediate run.
+///
+/// The GC's scheduled task is expected to call [`Self::run_if_due()`] every
+/// [`GC_CONTROL_POLL_INTERVAL`]; the control decides whether an actual run should happen.
+pub struct GcControl<C> {
+    kind: GcKind,
+    state: Mutex<GcControlState<C>>,
+    run_lock: Mutex<()>,
+    metrics: Arc<GcMetrics>,
+}
+
+struct GcControlState<C> {
+    config: C,
+    status: GcRunStatus,
+    last_run_started_at: Option<Instant>,
+}
+
+impl<C: ControlledGcConfig> GcControl<C> {
+    /// Creates a control of a GC, initially configured with the given configuration (not paused).
+    pub fn new(
+        kind: GcKind,
+        config: C,
+        lock_factory: &LockFactory,
+        metrics: Arc<GcMetrics>,
+    ) -> Self {
+        Self::update_interval_metric(&metrics, kind, &config);
+        metrics.paused.with_label(kind).set(0);
+        Self {
+            kind,
+            state: lock_factory.named("state").new_mutex(GcControlState {
+                config,
+                status: GcRunStatus::default(),
+                last_run_started_at: None,
+            }),
+            run_lock: lock_factory.named("run").new_mutex(()),
+            metrics,
+        }
+    }
+
+    /// Returns the kind of the controlled GC.
+    pub fn kind(&self) -> GcKind {
+        self.kind
+    }
+
+    /// Returns the currently effective configuration.
+    pub fn config(&self) -> C {
+        self.state.lock().config.clone()
+    }
+
+    /// Returns the current runtime status.
+    pub fn status(&self) -> GcRunStatus {
+        self.state.lock().status.clone()
+    }
+
+    /// Replaces the configuration, effective from the next run (a run already in progress is not
+    /// affected).
+    pub fn reconfigure(&self, config: C) {
+        info!("Reconfiguring the {:?} GC: {:?}", self.kind, config);
+        Self::update_interval_metric(&self.metrics, self.kind, &config);
+        self.state.lock().config = config;
+    }
+
+    /// Pauses the GC.
+    /// This method returns only after a run already in progress (if any) has finished, so that the
+    /// caller can rely on the GC's data being frozen (e.g. for the duration of a backup).
+    pub fn pause(&self) {
+        info!("Pausing the {:?} GC", self.kind);
+        self.state.lock().status.is_paused = true;
+        self.metrics.paused.with_label(self.kind).set(1);
+        // Wait for any in-progress run (a new one will not start, since we are paused already):
+        drop(self.run_lock.lock());
+    }
+
+    /// Resumes the paused GC.
+    /// A periodic run which became due during the pause (or a run requested during the pause)
+    /// happens right after resuming.
+    pub fn resume(&self) {
+        info!("Resuming the {:?} GC", self.kind);
+        self.state.lock().status.is_paused = false;
+        self.metrics.paused.with_label(self.kind).set(0);
+    }
+
+    /// Requests a run at the nearest poll, regardless of the configured interval (but only when the
+    /// GC is not paused).
+    pub fn request_run(&self) {
+        info!("Requesting an immediate run of the {:?} GC", self.kind);
+        self.state.lock().status.is_run_requested = true;
+    }
+
+    /// Executes a single run of the GC (using the given function, which should return the progress
+    /// reached by the run, if known) if one is due.
+    pub fn run_if_due(&self, run: impl FnOnce(&C) -> Option<u64>) {
+        let _run_guard = self.run_lock.lock();
+        let config = {
+            let mut state = self.state.lock();
+            if state.status.is_paused {
+                return;
+            }
+            let is_periodic_run_due = match state.config.periodic_interval() {
+                Some(interval) => state
+                    .last_run_started_at
+                    .map(|started_at| started_at.elapsed() >= interval)
+                    .unwrap_or(true),
+                None => false,
+            };
+            if !is_periodic_run_due && !state.status.is_run_requested {
+                return;
+            }
+            state.status.is_run_requested = false;
+            state.status.is_running = true;
+            state.last_run_started_at = Some(Instant::now());
+            state.config.clone()
+        };
+
+        let progress = run(&config);
+
+        let mut state = self.state.lock();
+        state.status.is_running = false;
+        state.status.completed_runs += 1;
+        self.metrics.completed_runs.with_label(self.kind).inc();
+        if let Some(progress) = progress {
+            state.status.progress = Some(progress);
+            self.metrics
+                .progress
+                .with_label(self.kind)
+                .set(i64::try_from(progress).unwrap_or(i64::MAX));
+        }
+    }
+
+    fn update_interval_metric(metrics: &GcMetrics, kind: GcKind, config: &C) {
+        metrics.interval_sec.with_label(kind).set(
+            config
+                .periodic_interval()
+                .map(|interval| interval.as_secs() as i64)
+                .unwrap_or(0),
+        );
+    }
+}
+
+/// A summary of configurations and statuses of all the controllable GCs.
+#[derive(Debug, Clone, Sbor)]
+pub struct GcControlsSummary {
+    pub state_tree_gc_config: StateTreeGcConfig,
+    pub state_tree_gc_status: GcRunStatus,
+    pub ledger_proofs_gc_config: LedgerProofsGcConfig,
+    pub ledger_proofs_gc_status: GcRunStatus,
+}
+
+/// The runtime controls of all the Node's controllable GCs.
+#[derive(Clone)]
+pub struct GcControls {
+    pub state_tree_gc: Arc<GcControl<StateTreeGcConfig>>,
+    pub ledger_proofs_gc: Arc<GcControl<LedgerProofsGcConfig>>,
+}
+
+impl GcControls {
+    /// Creates the controls, initially configured with the given configurations.
+    pub fn new(
+        state_tree_gc_config: StateTreeGcConfig,
+        ledger_proofs_gc_config: LedgerProofsGcConfig,
+        lock_factory: &LockFactory,
+        metrics_registry: &MetricRegistry,
+    ) -> Self {
+        let metrics = Arc::new(GcMetrics::new(metrics_registry));
+        Self {
+            state_tree_gc: Arc::new(GcControl::new(
+                GcKind::StateTree,
+                state_tree_gc_config,
+                &lock_factory.named("state_tree_gc"),
+                metrics.clone(),
+            )),
+            ledger_proofs_gc: Arc::new(GcControl::new(
+                GcKind::LedgerProofs,
+                ledger_proofs_gc_config,
+                &lock_factory.named("ledger_proofs_gc"),
+                metrics,
+            )),
+        }
+    }
+
+    /// Returns the current configurations and statuses of all the GCs.
+    pub fn summary(&self) -> GcControlsSummary {
+        GcControlsSummary {
+            state_tree_gc_config: self.state_tree_gc.config(),
+            state_tree_gc_status: self.state_tree_gc.status(),
+            ledger_proofs_gc_config: self.ledger_proofs_gc.config(),
+            ledger_proofs_gc_status: self.ledger_proofs_gc.status(),
+        }
+    }
+
+    /// Returns the status of the given GC.
+    pub fn status(&self, kind: GcKind) -> GcRunStatus {
+        match kind {
+            GcKind::StateTree => self.state_tree_gc.status(),
+            GcKind::LedgerProofs => self.ledger_proofs_gc.status(),
+        }
+    }
+
+    /// Pauses the given GC (see [`GcControl::pause()`]).
+    pub fn pause(&self, kind: GcKind) {
+        match kind {
+            GcKind::StateTree => self.state_tree_gc.pause(),
+            GcKind::LedgerProofs => self.ledger_proofs_gc.pause(),
+        }
+    }
+
+    /// Resumes the given GC (see [`GcControl::resume()`]).
+    pub fn resume(&self, kind: GcKind) {
+        match kind {
+            GcKind::StateTree => self.state_tree_gc.resume(),
+            GcKind::LedgerProofs => self.ledger_proofs_gc.resume(),
+        }
+    }
+
+    /// Requests an immediate run of the given GC (see [`GcControl::request_run()`]).
+    pub fn request_run(&self, kind: GcKind) {
+        match kind {
+            GcKind::StateTree => self.state_tree_gc.request_run(),
+            GcKind::LedgerProofs => self.ledger_proofs_gc.request_run(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_control(interval_sec: u32) -> GcControl<LedgerProofsGcConfig> {
+        GcControl::new(
+            GcKind::LedgerProofs,
+            LedgerProofsGcConfig {
+                interval_sec,
+                most_recent_full_resolution_epoch_count: 10,
+            },
+            &LockFactory::new("testing"),
+            Arc::new(GcMetrics::new(&MetricRegistry::new())),
+        )
+    }
+
+    #[test]
+    fn periodic_runs_follow_the_reconfigured_interval() {
+        let control = test_control(3600);
+        let mut runs = Vec::new();
+
+        // The first periodic run is due immediately, but the next one only after the interval:
+        control.run_if_due(|config| {
+            runs.push(config.most_recent_full_resolution_epoch_count);
+            Some(5)
+        });
+        control.run_if_due(|_| panic!("not due yet"));
+        assert_eq!(runs, vec![10]);
+
+        // An interval of 0 means "on-demand only":
+        control.reconfigure(LedgerProofsGcConfig {
+            interval_sec: 0,
+            most_recent_full_resolution_epoch_count: 20,
+        });
+        control.run_if_due(|_| panic!("only on demand"));
+        control.request_run();
+        control.run_if_due(|config| {
+            runs.push(config.most_recent_full_resolution_epoch_count);
+            None
+        });
+        assert_eq!(runs, vec![10, 20]);
+        assert_eq!(
+            control.status(),
+            GcRunStatus {
+                is_paused: false,
+                is_run_requested: false,
+                is_running: false,
+                completed_runs: 2,
+                progress: Some(5),
+            }
+        );
+    }
+
+    #[test]
+    fn paused_gc_does_not_run_until_resumed() {
+        let control = test_control(0);
+        control.pause();
+        control.request_run();
+        control.run_if_due(|_| panic!("paused"));
+        assert!(control.status().is_paused);
+        assert!(control.status().is_run_requested);
+
+        control.resume();
+        let mut run_count = 0;
+        control.run_if_due(|_| {
+            run_count += 1;
+            Some(1)
+        });
+        control.run_if_due(|_| panic!("the request was already served"));
+        assert_eq!(run_count, 1);
+        assert_eq!(control.status().completed_runs, 1);
+    }
+}
@@ -153,6 +153,20 @@ impl<'s, S: ReadableTreeStore + 's, DS: Deref<Target = S>> StateTreeBasedSubstat
                     })
             })
     }
+
+    /// Returns the leaf key of the given Substate, or [`None`] if the Substate is not accessible
+    /// from the scoped version.
+    pub fn get_substate_leaf_key(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<StoredTreeNodeKey> {
+        self.create_entity_tier()
+            .get_entity_partition_tier(partition_key.node_key.clone())
+            .get_partition_substate_tier(partition_key.partition_num)
+            .get_substate_summary(sort_key)
+            .map(|substate| substate.state_tree_leaf_key)
+    }
 }
 
 impl<'s, S: ReadableTreeStore + LeafSubstateValueStore + 's, DS: Deref<Target = S>> SubstateDatabase
@@ -214,6 +228,17 @@ impl<'s, S: LeafSubstateValueStore + 's, DS: Deref<Target = S>>
     }
 }
 
+impl<'s, S: ReadableTreeStore + LeafSubstateValueStore + 's, DS: Deref<Target = S>>
+    StateTreeBasedSubstateDatabase<'s, DS>
+{
+    /// Returns an iterator over *all* Substates (i.e. their full keys and values) accessible from
+    /// the scoped version.
+    pub fn iter_substates(&self) -> impl Iterator<Item = (DbSubstateKey, DbSubstateValue)> + '_ {
+        self.iter_substate_leaf_keys()
+            .map(|(leaf_key, substate_key)| (substate_key, self.get_value(&leaf_key)))
+    }
+}
+
 /// A [`SubstateDatabase`] aware of its state version.
 ///
 /// The implementation enum-dispatches either to the runtime store (when at current version), or to
@@ -255,7 +280,9 @@ impl<'s, R: ReadableRocks + 's, DS: Deref<Target = StateManagerDatabase<R>>>
             return Err(StateHistoryError::StateHistoryDisabled);
         };
         let first_available_version = database.get_first_stored_historical_state_version();
-        if requested_version < first_available_version {
+        if requested_version < first_available_version
+            && !database.is_state_history_checkpoint(requested_version)
+        {
             return Err(StateHistoryError::StateVersionInTooDistantPast {
                 first_available_version,
             });
@@ -398,6 +425,10 @@ impl<'s, R: ReadableRocks + 's, DS: Deref<Target = StateManagerDatabase<R>>> Con
         self.underlying().is_account_change_index_enabled()
     }
 
+    fn is_resource_change_index_enabled(&self) -> bool {
+        self.underlying().is_resource_change_index_enabled()
+    }
+
     fn is_local_transaction_execution_index_enabled(&self) -> bool {
         self.underlying()
             .is_local_transaction_execution_index_enabled()
@@ -415,6 +446,11 @@ impl<'s, R: ReadableRocks + 's, DS: Deref<Target = StateManagerDatabase<R>>> Con
         self.underlying()
             .get_first_stored_historical_state_version()
     }
+
+    fn get_first_stored_local_transaction_execution_state_version(&self) -> StateVersion {
+        self.underlying()
+            .get_first_stored_local_transaction_execution_state_version()
+    }
 }
 
 impl<'s, R: ReadableRocks + 's, DS: Deref<Target = StateManagerDatabase<R>>>
@@ -75,52 +75,132 @@ pub struct StateTreeGcConfig {
     /// How often to run the GC, in seconds.
     /// This should be at least an order of magnitude shorter than an expected duration over which
     /// the [`state_version_history_length`] spans (to honour the precision of these settings).
+    /// A value of 0 disables the periodic runs (i.e. the GC then only runs on demand - see
+    /// `GcControl::request_run()`).
     pub interval_sec: u32,
     /// How many most recent state versions to keep in the state hash tree.
     pub state_version_history_length: usize,
+    /// How many most recent *completed* epochs of historical Substate values to keep at full
+    /// resolution (only applicable if the state history feature is enabled).
+    /// This retention may only extend the [`state_version_history_length`] (which then applies to
+    /// the state hash tree as well, since the historical values are useless without it).
+    pub historical_state_full_resolution_epoch_count: Option<u64>,
+    /// How long (in seconds, as measured by the ledger's proposer timestamps) to keep historical
+    /// Substate values at full resolution. Rounded to the epoch boundary, and applied just like the
+    /// [`historical_state_full_resolution_epoch_count`] (the longer of the two wins, if both set).
+    pub historical_state_full_resolution_duration_sec: Option<u64>,
+    /// If set, the historical Substate values are retained (indefinitely) at the beginning of every
+    /// epoch having a number divisible by this interval, even after the full-resolution history
+    /// moves past it. Such a sparse checkpoint can be queried just like the full-resolution state
+    /// history.
+    pub historical_state_checkpoint_epoch_interval: Option<u64>,
+}
+
+/// A reason for rejecting a [`StateTreeGcConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateTreeGcConfigError {
+    StateVersionHistoryLengthTooLarge(usize),
+    FullResolutionDurationTooLarge(u64),
+}
+
+impl StateTreeGcConfig {
+    /// Validates that the configured values are representable in the GC's internal units.
+    pub fn validate(&self) -> Result<(), StateTreeGcConfigError> {
+        if StateVersionDelta::try_from(self.state_version_history_length).is_err() {
+            return Err(StateTreeGcConfigError::StateVersionHistoryLengthTooLarge(
+                self.state_version_history_length,
+            ));
+        }
+        if let Some(duration_sec) = self.historical_state_full_resolution_duration_sec {
+            if sec_to_ms(duration_sec).is_none() {
+                return Err(StateTreeGcConfigError::FullResolutionDurationTooLarge(
+                    duration_sec,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn sec_to_ms(duration_sec: u64) -> Option<i64> {
+    duration_sec
+        .checked_mul(1000)
+        .and_then(|duration_ms| i64::try_from(duration_ms).ok())
 }
 
 /// A garbage collector of sufficiently-old stale state hash tree nodes.
 /// The implementation is suited for being driven by an external scheduler.
+///
+/// Each run ends with a targeted compaction of the affected DB ranges, so that the reads do not
+/// have to skip over the tombstones of the deleted nodes until a regular compaction happens.
 pub struct StateTreeGc {
     database: Arc<DbLock<ActualStateManagerDatabase>>,
-    interval: Duration,
+    raw_db_metrics: Arc<RawDbMetrics>,
     history_len: StateVersionDelta,
+    full_resolution_epoch_count: Option<u64>,
+    full_resolution_duration_ms: Option<i64>,
+    checkpoint_epoch_interval: Option<u64>,
 }
 
 impl StateTreeGc {
     /// Creates a new GC.
+    /// The given config must be valid (see [`StateTreeGcConfig::validate()`]).
     pub fn new(
         database: Arc<DbLock<ActualStateManagerDatabase>>,
         config: StateTreeGcConfig,
+        raw_db_metrics: Arc<RawDbMetrics>,
     ) -> Self {
         Self {
             database,
-            interval: Duration::from_secs(u64::from(config.interval_sec)),
-            history_len: StateVersionDelta::try_from(config.state_version_history_length).unwrap(),
+            raw_db_metrics,
+            history_len: StateVersionDelta::try_from(config.state_version_history_length)
+                .expect("validated state version history length"),
+            full_resolution_epoch_count: config.historical_state_full_resolution_epoch_count,
+            full_resolution_duration_ms: config.historical_state_full_resolution_duration_sec.map(
+                |duration_sec| sec_to_ms(duration_sec).expect("validated full resolution duration"),
+            ),
+            checkpoint_epoch_interval: config
+                .historical_state_checkpoint_epoch_interval
+                .filter(|interval| *interval > 0),
         }
     }
 
-    /// An interval between [`run()`]s, to be used by this instance's scheduler.
-    pub fn interval(&self) -> Duration {
-        self.interval
-    }
-
     /// Performs a single GC run, which is supposed to permanently delete *all* old-enough state
     /// hash tree nodes marked as stale.
+    /// Returns the state version up to which (exclusive) the stale nodes were pruned.
     ///
     /// *Note on concurrent database access:*
     /// The JMT's GC process, by its nature, only accesses "old" (i.e. not "top-of-ledger" new)
     /// JMT DB rows. For this reason, it can use the direct [`DbLock::access_direct()`] and
     /// effectively own these rows (for reads and deletes), without locking the database.
-    pub fn run(&self) {
+    pub fn run(&self) -> StateVersion {
         let direct_database = self.database.access_direct();
         let database = direct_database.deref();
         let current_state_version = database.max_state_version();
-        let to_state_version = current_state_version
+        let mut to_state_version = current_state_version
             .relative(-self.history_len)
             .unwrap_or(StateVersion::pre_genesis());
 
+        if database.is_state_history_enabled() {
+            if let Some(full_resolution_history_start) =
+                self.resolve_full_resolution_history_start(database)
+            {
+                to_state_version = to_state_version.min(full_resolution_history_start);
+            }
+            if let Some(checkpoint_epoch_interval) = self.checkpoint_epoch_interval {
+                record_state_history_checkpoints(
+                    database,
+                    checkpoint_epoch_interval,
+                    to_state_version,
+                );
+            }
+        }
+        let checkpoints = StateHistoryCheckpoints(
+            database
+                .get_state_history_checkpoint_iter(StateVersion::pre_genesis())
+                .collect(),
+        );
+
         info!(
             "Starting a GC run: current state version is {:?}; pruning JMT up to version {:?} (exclusive)",
             current_state_version.number(),
@@ -132,9 +212,13 @@ impl StateTreeGc {
             .get_stale_tree_parts_iter()
             .take_while(|(state_version, _)| state_version < &to_state_version);
 
-        // Collect the stale node keys into a "delete buffer":
+        // Collect the stale node keys into a "delete buffer" (and the ones still reachable from
+        // some historical state checkpoint into a "retain buffer"):
         let mut deleted_nodes = Vec::new();
+        let mut retained_parts = Vec::new();
+        let mut first_processed_state_version = None; // for targeting the compaction
         for (state_version, StaleTreePartsV1(stale_tree_parts)) in stale_entries {
+            first_processed_state_version.get_or_insert(state_version);
             // Periodically rotate the collected buffer of node keys to delete:
             if deleted_nodes.len() >= DELETED_NODE_BUFFER_MAX_LEN {
                 // The flush is handled before the processing, so that we always operate on exclusive right bound of state versions.
@@ -142,12 +226,26 @@ impl StateTreeGc {
                     "Flushing a full delete buffer up to version {} (exclusive)",
                     state_version
                 );
-                delete_nodes_and_update_state_history(database, &deleted_nodes, state_version);
+                delete_nodes_and_update_state_history(
+                    database,
+                    &deleted_nodes,
+                    retained_parts.drain(..),
+                    state_version,
+                );
                 deleted_nodes.clear();
             }
+            let mut retained_tree_parts = Vec::new();
             for stale_tree_part in stale_tree_parts {
                 let part_keys: Box<dyn Iterator<Item = StoredTreeNodeKey>> = match stale_tree_part {
                     StaleTreePart::Node(key) => Box::new(iter::once(key)),
+                    // A subtree's nodes are never newer than its root - so, if the root is reachable
+                    // from some checkpoint, then the entire subtree is:
+                    StaleTreePart::Subtree(subtree_root_key)
+                        if checkpoints.reach(&subtree_root_key, state_version) =>
+                    {
+                        retained_tree_parts.push(StaleTreePart::Subtree(subtree_root_key));
+                        continue;
+                    }
                     // In case of "delete partition", we have to traverse its entire subtree:
                     // Note: it is critical to do a post-order DFS here (i.e. to delete a parent
                     // only after its children, in case this process is interrupted half-way
@@ -156,22 +254,179 @@ impl StateTreeGc {
                         Box::new(iterate_dfs_post_order(database, subtree_root_key))
                     }
                 };
-                deleted_nodes.extend(part_keys);
+                for key in part_keys {
+                    if checkpoints.reach(&key, state_version) {
+                        retained_tree_parts.push(StaleTreePart::Node(key));
+                    } else {
+                        deleted_nodes.push(key);
+                    }
+                }
+            }
+            if !retained_tree_parts.is_empty() {
+                retained_parts.push((state_version, StaleTreePartsV1(retained_tree_parts)));
             }
         }
 
         // Delete the last collected batch of keys:
-        info!("Flushing the last buffer ({} deletes)", deleted_nodes.len());
-        delete_nodes_and_update_state_history(database, &deleted_nodes, to_state_version);
+        info!(
+            "Flushing the last buffer ({} deletes, {} retained stale versions)",
+            deleted_nodes.len(),
+            retained_parts.len()
+        );
+        delete_nodes_and_update_state_history(
+            database,
+            &deleted_nodes,
+            retained_parts,
+            to_state_version,
+        );
+
+        // Reclaim the space (and get rid of the tombstones) right away - but only within the range
+        // of state versions processed by this run:
+        // Note: the deleted nodes may have been created at any (arbitrarily old) version, but the
+        // ones created within the processed range are the vast majority (most nodes go stale soon
+        // after their creation). The sparse tombstones of the older ones are left to the regular
+        // RocksDB compactions, so that we do not compact almost the entire tree on every run.
+        if let Some(from_state_version) = first_processed_state_version {
+            let reclaimed = database.compact_state_tree_range(from_state_version, to_state_version);
+            info!(
+                "Compacted the pruned JMT range; reclaimed {} bytes",
+                reclaimed
+                    .iter()
+                    .map(|category| category.reclaimed_bytes)
+                    .sum::<usize>()
+            );
+            self.raw_db_metrics.record_reclaimed(reclaimed);
+        }
+        to_state_version
+    }
+
+    /// Resolves the first state version of the configured full-resolution state history window
+    /// (i.e. the epoch boundary from which all historical Substate values must be kept), or
+    /// [`None`] if no such window is configured.
+    fn resolve_full_resolution_history_start(
+        &self,
+        database: &ActualStateManagerDatabase,
+    ) -> Option<StateVersion> {
+        let by_epoch_count = self.full_resolution_epoch_count.map(|epoch_count| {
+            database
+                .max_completed_epoch()
+                .and_then(|max_completed_epoch| {
+                    max_completed_epoch.number().checked_sub(epoch_count)
+                })
+                .and_then(|last_pruned_epoch| {
+                    database.get_epoch_proof(Epoch::of(last_pruned_epoch + 1))
+                })
+                .map(|proof| proof.ledger_header.state_version)
+                .unwrap_or(StateVersion::pre_genesis())
+        });
+        let by_duration = self.full_resolution_duration_ms.map(|duration_ms| {
+            let Some(latest_proof) = database.get_latest_proof() else {
+                return StateVersion::pre_genesis();
+            };
+            let threshold_timestamp_ms = latest_proof
+                .ledger_header
+                .proposer_timestamp_ms
+                .saturating_sub(duration_ms);
+            find_last_epoch_boundary(database, |header| {
+                header.proposer_timestamp_ms <= threshold_timestamp_ms
+            })
+            .map(|header| header.state_version)
+            .unwrap_or(StateVersion::pre_genesis())
+        });
+        by_epoch_count.into_iter().chain(by_duration).min()
+    }
+}
+
+/// Records (as historical state checkpoints) all the matching epoch boundaries within the part of
+/// the state history that is about to be pruned (i.e. from its current start up to the given
+/// version, exclusive).
+fn record_state_history_checkpoints(
+    database: &ActualStateManagerDatabase,
+    checkpoint_epoch_interval: u64,
+    to_state_version: StateVersion,
+) {
+    let from_state_version = database.get_first_stored_historical_state_version();
+    let from_epoch = database
+        .get_closest_epoch_proof_on_or_before(from_state_version)
+        .and_then(|proof| proof.ledger_header.next_epoch)
+        .map(|next_epoch| next_epoch.epoch)
+        .unwrap_or(Epoch::of(0));
+    let epoch_boundaries = database
+        .get_next_epoch_proof_iter(from_epoch)
+        .map(|proof| proof.ledger_header)
+        .take_while(|header| header.state_version < to_state_version)
+        .filter(|header| header.state_version >= from_state_version);
+    for header in epoch_boundaries {
+        let epoch = header
+            .next_epoch
+            .expect("epoch proof must initiate the next epoch")
+            .epoch;
+        if epoch.number() % checkpoint_epoch_interval == 0 {
+            info!(
+                "Recording a historical state checkpoint at the beginning of epoch {} (state version {})",
+                epoch.number(),
+                header.state_version
+            );
+            database.record_state_history_checkpoint(header.state_version);
+        }
     }
 }
 
+/// Binary-searches the epoch boundaries (i.e. the headers of ledger proofs which initiate each
+/// epoch) for the last one satisfying the given predicate (which must be monotonic, i.e. all its
+/// `true` results have to precede all `false` ones).
+fn find_last_epoch_boundary(
+    database: &ActualStateManagerDatabase,
+    predicate: impl Fn(&LedgerHeader) -> bool,
+) -> Option<LedgerHeader> {
+    let boundary_of = |epoch_number: u64| {
+        database
+            .get_epoch_proof(Epoch::of(epoch_number))
+            .map(|proof| proof.ledger_header)
+    };
+    let first_header = database.get_post_genesis_epoch_proof()?.ledger_header;
+    let last_header = database.get_latest_epoch_proof()?.ledger_header;
+    if !predicate(&first_header) {
+        return None;
+    }
+    let mut low = first_header.next_epoch?.epoch.number(); // satisfies the predicate
+    let mut high = last_header.next_epoch?.epoch.number() + 1; // does not exist
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if boundary_of(middle).filter(&predicate).is_some() {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    boundary_of(low)
+}
+
+/// Sorted state versions of the historical state checkpoints.
+struct StateHistoryCheckpoints(Vec<StateVersion>);
+
+impl StateHistoryCheckpoints {
+    /// Returns true if the given tree node (which became stale at the given state version) is
+    /// reachable from some checkpoint, i.e. if the node existed at the checkpoint's state version.
+    fn reach(&self, node_key: &StoredTreeNodeKey, stale_at: StateVersion) -> bool {
+        let created_at = StateVersion::of(node_key.version());
+        let first_candidate_index = self
+            .0
+            .partition_point(|checkpoint| checkpoint < &created_at);
+        self.0
+            .get(first_candidate_index)
+            .is_some_and(|checkpoint| checkpoint < &stale_at)
+    }
+}
+
+/// Returns the minimum of the given version and the creation versions of the given node keys.
 /// A helper method for flushing the GC's partial DB update batches.
 ///
 /// Note: the order of the operations is important for non-trivial reasons - see comments.
 fn delete_nodes_and_update_state_history(
     database: &ActualStateManagerDatabase,
     deleted_nodes: &[StoredTreeNodeKey],
+    retained_parts: impl IntoIterator<Item = (StateVersion, StaleTreeParts)>,
     state_version: StateVersion,
 ) {
     // First, bump the minimum state version from which the state history is available:
@@ -179,6 +434,10 @@ fn delete_nodes_and_update_state_history(
     database.progress_historical_substate_values_availability(state_version);
     // Delete the actual tree nodes:
     database.batch_delete_node(deleted_nodes.iter());
+    // Record the tree parts kept for the checkpoints:
+    // (this must happen after the deletes: the retained parts may be handed back to the GC later, and
+    // their record must not replace a still-unprocessed stale parts' entry of the same version)
+    database.batch_retain_stale_tree_parts(retained_parts);
     // Finally, remove the processed items from the GC's work queue:
     // (it is safe to do it last: even if the Node restarts right before this point, it will simply
     // re-process these stale tree parts, performing a couple of no-op deletes)
@@ -461,6 +720,101 @@ mod tests {
         assert!(iterator.next().is_none());
     }
 
+    #[test]
+    fn config_with_unrepresentable_duration_is_rejected() {
+        let config = StateTreeGcConfig {
+            historical_state_full_resolution_duration_sec: Some(u64::MAX / 10),
+            ..StateTreeGcConfig::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(StateTreeGcConfigError::FullResolutionDurationTooLarge(
+                u64::MAX / 10
+            ))
+        );
+
+        let config = StateTreeGcConfig {
+            historical_state_full_resolution_duration_sec: Some(30 * 24 * 3600),
+            ..StateTreeGcConfig::default()
+        };
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn checkpoint_reaches_nodes_alive_at_its_version() {
+        let checkpoints = StateHistoryCheckpoints(vec![StateVersion::of(10), StateVersion::of(20)]);
+        let node_created_at = |version| StoredTreeNodeKey::new(version, nibbles("c0ffee"));
+
+        // Alive between 5 and 14 (inclusive), so existed at checkpoint 10:
+        assert!(checkpoints.reach(&node_created_at(5), StateVersion::of(15)));
+        // Created exactly at checkpoint 20:
+        assert!(checkpoints.reach(&node_created_at(20), StateVersion::of(21)));
+        // Became stale exactly at checkpoint 10 (i.e. did not exist there anymore):
+        assert!(!checkpoints.reach(&node_created_at(5), StateVersion::of(10)));
+        // Lived entirely between the checkpoints:
+        assert!(!checkpoints.reach(&node_created_at(11), StateVersion::of(19)));
+        // Created after the last checkpoint:
+        assert!(!checkpoints.reach(&node_created_at(21), StateVersion::of(30)));
+    }
+
+    #[test]
+    fn sparse_checkpoints_remain_queryable_beyond_full_resolution_history() {
+        use crate::store::historical_state::{
+            StateHistoryError, VersionScopedDatabase, VersionScopingSupport,
+        };
+        use crate::test::{
+            commit_round_updates_until_epoch,
+            create_bootstrapped_state_manager_with_rounds_per_epoch,
+        };
+
+        let mut config = StateManagerConfig::new_for_testing_in_memory();
+        config.database_config.enable_historical_substate_values = true;
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(7));
+
+        let gc = StateTreeGc::new(
+            state_manager.database.clone(),
+            StateTreeGcConfig {
+                interval_sec: 1,
+                state_version_history_length: 0,
+                historical_state_full_resolution_epoch_count: Some(1),
+                historical_state_full_resolution_duration_sec: None,
+                historical_state_checkpoint_epoch_interval: Some(2),
+            },
+            Arc::new(RawDbMetrics::new(&MetricRegistry::new())),
+        );
+        gc.run();
+
+        let database = state_manager.database.access_direct();
+        let epoch_start = |epoch| {
+            database
+                .get_epoch_proof(Epoch::of(epoch))
+                .unwrap()
+                .ledger_header
+                .state_version
+        };
+        // Only the most recent completed epoch (and the current one) remain at full resolution:
+        assert_eq!(
+            database.get_first_stored_historical_state_version(),
+            epoch_start(6)
+        );
+        // The even epochs' beginnings remain readable (i.e. all their values are retained):
+        for checkpoint_epoch in [2, 4] {
+            let Ok(VersionScopedDatabase::Historical(historical)) = database
+                .deref()
+                .scoped_at(Some(epoch_start(checkpoint_epoch)))
+            else {
+                panic!("checkpoint at epoch {} not available", checkpoint_epoch);
+            };
+            assert!(historical.iter_substates().count() > 0);
+        }
+        // ... but the odd ones are gone:
+        assert!(matches!(
+            database.deref().scoped_at(Some(epoch_start(5))),
+            Err(StateHistoryError::StateVersionInTooDistantPast { .. })
+        ));
+    }
+
     fn bytes(string: &str) -> Vec<u8> {
         hex::decode(string.replace(' ', "")).unwrap()
     }
@@ -0,0 +1,639 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::checksummed_records::*;
+use std::io::{Read, Write};
+
+/// A magic prefix of every ledger archive file.
+const ARCHIVE_MAGIC: &[u8; 8] = b"RDXLEDGA";
+
+/// A default (approximate) number of transactions to put in a single archive chunk.
+/// The actual chunk may be larger, since it always ends at a ledger proof.
+pub const DEFAULT_ARCHIVE_CHUNK_TRANSACTION_COUNT: usize = 10000;
+
+define_single_versioned! {
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub VersionedLedgerArchiveRecord(LedgerArchiveRecordVersions) => LedgerArchiveRecord = LedgerArchiveRecordV1
+}
+
+/// A single, checksummed unit of the ledger archive.
+/// A valid archive consists of a [`LedgerArchiveRecordV1::Header`], followed by any number of
+/// [`LedgerArchiveRecordV1::Chunk`]s, and terminated by a [`LedgerArchiveRecordV1::End`] (which
+/// allows to detect a truncated archive).
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum LedgerArchiveRecordV1 {
+    Header(LedgerArchiveHeader),
+    Chunk(LedgerArchiveChunk),
+    End(LedgerArchiveEnd),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerArchiveHeader {
+    /// The network of the exported ledger.
+    pub network_definition: NetworkDefinition,
+    /// The highest state version known to the source database at the beginning of the export.
+    /// Informational only (the archive may end at a slightly lower version, i.e. at the last proof).
+    pub source_max_state_version: StateVersion,
+}
+
+/// A consecutive range of committed transactions, together with all the ledger proofs that
+/// these transactions are covered by. A chunk always ends at the state version of its last proof.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerArchiveChunk {
+    /// Transactions in their state version order.
+    pub transactions: Vec<ArchivedTransaction>,
+    /// Proofs (from the `LedgerProofsCf`) in their state version order.
+    pub proofs: Vec<VersionedLedgerProof>,
+    /// Epoch proofs (from the `EpochLedgerProofsCf`) of the epochs ending within this chunk.
+    pub epoch_proofs: Vec<VersionedLedgerProof>,
+}
+
+/// A complete [`CommittedTransactionBundle`], in its archived form.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct ArchivedTransaction {
+    pub state_version: StateVersion,
+    pub raw: RawLedgerTransaction,
+    pub ledger_receipt: VersionedLedgerTransactionReceipt,
+    pub local_execution: VersionedLocalTransactionExecution,
+    pub identifiers: VersionedCommittedTransactionIdentifiers,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerArchiveEnd {
+    pub chunk_count: u64,
+    pub last_state_version: StateVersion,
+}
+
+#[derive(Debug)]
+pub enum LedgerArchiveError {
+    Format(ChecksummedRecordError),
+    UnexpectedRecord {
+        record_index: u64,
+    },
+    ChunkCountMismatch {
+        declared: u64,
+        actual: u64,
+    },
+    NetworkMismatch {
+        archive_network: String,
+        local_network: String,
+    },
+    MissingTransaction {
+        state_version: StateVersion,
+    },
+    TransactionsPruned(TransactionsPrunedError),
+    TransactionDiscontinuity {
+        expected: StateVersion,
+        actual: StateVersion,
+    },
+    CommitRejected {
+        state_version: StateVersion,
+        error: InvalidCommitRequestError,
+    },
+    ProtocolUpdateNotReproduced {
+        state_version: StateVersion,
+    },
+    LedgerHashesMismatch {
+        state_version: StateVersion,
+        archived: LedgerHashes,
+        local: Option<LedgerHashes>,
+    },
+}
+
+impl From<ChecksummedRecordError> for LedgerArchiveError {
+    fn from(error: ChecksummedRecordError) -> Self {
+        LedgerArchiveError::Format(error)
+    }
+}
+
+impl From<TransactionsPrunedError> for LedgerArchiveError {
+    fn from(error: TransactionsPrunedError) -> Self {
+        LedgerArchiveError::TransactionsPruned(error)
+    }
+}
+
+/// A low-level writer of the archive file format (see [`ChecksummedRecordWriter`]).
+pub struct LedgerArchiveWriter<W: Write> {
+    records: ChecksummedRecordWriter<W>,
+    chunk_count: u64,
+    last_state_version: StateVersion,
+}
+
+impl<W: Write> LedgerArchiveWriter<W> {
+    /// Writes the archive preamble and the given header.
+    pub fn new(output: W, header: LedgerArchiveHeader) -> Result<Self, LedgerArchiveError> {
+        let mut writer = Self {
+            records: ChecksummedRecordWriter::new(output, ARCHIVE_MAGIC)?,
+            chunk_count: 0,
+            last_state_version: StateVersion::pre_genesis(),
+        };
+        writer.write_record(LedgerArchiveRecordV1::Header(header))?;
+        Ok(writer)
+    }
+
+    pub fn write_chunk(&mut self, chunk: LedgerArchiveChunk) -> Result<(), LedgerArchiveError> {
+        if let Some(last_transaction) = chunk.transactions.last() {
+            self.last_state_version = last_transaction.state_version;
+        }
+        self.chunk_count += 1;
+        self.write_record(LedgerArchiveRecordV1::Chunk(chunk))
+    }
+
+    /// Terminates the archive and returns the underlying output.
+    pub fn finish(mut self) -> Result<W, LedgerArchiveError> {
+        self.write_record(LedgerArchiveRecordV1::End(LedgerArchiveEnd {
+            chunk_count: self.chunk_count,
+            last_state_version: self.last_state_version,
+        }))?;
+        Ok(self.records.finish()?)
+    }
+
+    fn write_record(&mut self, record: LedgerArchiveRecord) -> Result<(), LedgerArchiveError> {
+        Ok(self
+            .records
+            .write(&VersionedLedgerArchiveRecord::from(record))?)
+    }
+}
+
+/// A low-level reader of the archive file format (see [`LedgerArchiveWriter`]).
+pub struct LedgerArchiveReader<R: Read> {
+    records: ChecksummedRecordReader<R>,
+    header: LedgerArchiveHeader,
+    finished: bool,
+}
+
+impl<R: Read> LedgerArchiveReader<R> {
+    /// Reads and validates the archive preamble and header.
+    pub fn new(input: R) -> Result<Self, LedgerArchiveError> {
+        let mut records = ChecksummedRecordReader::new(input, ARCHIVE_MAGIC)?;
+        let LedgerArchiveRecordV1::Header(header) = read_record(&mut records)? else {
+            return Err(LedgerArchiveError::UnexpectedRecord { record_index: 0 });
+        };
+        Ok(Self {
+            records,
+            header,
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &LedgerArchiveHeader {
+        &self.header
+    }
+
+    /// Reads the next chunk, or returns `None` after reaching the (validated) end of the archive.
+    pub fn next_chunk(&mut self) -> Result<Option<LedgerArchiveChunk>, LedgerArchiveError> {
+        if self.finished {
+            return Ok(None);
+        }
+        let record_index = self.records.next_record_index();
+        match read_record(&mut self.records)? {
+            LedgerArchiveRecordV1::Chunk(chunk) => Ok(Some(chunk)),
+            LedgerArchiveRecordV1::End(end) => {
+                // Every record except the header and this end marker is a chunk:
+                let actual = record_index - 1;
+                if end.chunk_count != actual {
+                    return Err(LedgerArchiveError::ChunkCountMismatch {
+                        declared: end.chunk_count,
+                        actual,
+                    });
+                }
+                self.finished = true;
+                Ok(None)
+            }
+            LedgerArchiveRecordV1::Header(_) => {
+                Err(LedgerArchiveError::UnexpectedRecord { record_index })
+            }
+        }
+    }
+}
+
+fn read_record(
+    records: &mut ChecksummedRecordReader<impl Read>,
+) -> Result<LedgerArchiveRecord, LedgerArchiveError> {
+    Ok(records
+        .read::<VersionedLedgerArchiveRecord>()?
+        .fully_update_and_into_latest_version())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LedgerArchiveExportSummary {
+    pub chunk_count: u64,
+    pub transaction_count: u64,
+    pub proof_count: u64,
+}
+
+/// Streams the entire ledger history (i.e. all committed transaction bundles and the proofs which
+/// cover them) from the given database into an archive.
+///
+/// The database must have the local transaction execution index enabled (since the
+/// [`CommittedTransactionBundle`]s are read in their entirety) and must not have pruned any of its
+/// ledger transactions. Any transactions above the last stored proof are not exported.
+pub fn export_ledger_archive<D, W>(
+    database: &D,
+    network_definition: &NetworkDefinition,
+    output: W,
+    chunk_transaction_count: usize,
+) -> Result<(W, LedgerArchiveExportSummary), LedgerArchiveError>
+where
+    D: IterableTransactionStore + IterableProofStore + QueryableProofStore,
+    W: Write,
+{
+    let first_state_version = StateVersion::pre_genesis().next().unwrap();
+    let mut writer = LedgerArchiveWriter::new(
+        output,
+        LedgerArchiveHeader {
+            network_definition: network_definition.clone(),
+            source_max_state_version: database.max_state_version(),
+        },
+    )?;
+    let mut summary = LedgerArchiveExportSummary::default();
+
+    let mut bundles = database.get_committed_transaction_bundle_iter(first_state_version)?;
+    let mut epoch_proofs = database.get_next_epoch_proof_iter(Epoch::of(0)).peekable();
+    let mut next_state_version = first_state_version;
+    let mut chunk = LedgerArchiveChunk::empty();
+    for proof in database.get_proof_iter(first_state_version) {
+        let proof_state_version = proof.ledger_header.state_version;
+        while next_state_version <= proof_state_version {
+            let bundle = bundles
+                .next()
+                .ok_or(LedgerArchiveError::MissingTransaction {
+                    state_version: next_state_version,
+                })?;
+            chunk.transactions.push(ArchivedTransaction::from(bundle));
+            next_state_version = next_state_version.next().unwrap();
+        }
+        while let Some(epoch_proof) = epoch_proofs
+            .next_if(|epoch_proof| epoch_proof.ledger_header.state_version <= proof_state_version)
+        {
+            chunk
+                .epoch_proofs
+                .push(VersionedLedgerProof::from(epoch_proof));
+        }
+        chunk.proofs.push(VersionedLedgerProof::from(proof));
+        summary.proof_count += 1;
+
+        if chunk.transactions.len() >= chunk_transaction_count {
+            summary.transaction_count += chunk.transactions.len() as u64;
+            summary.chunk_count += 1;
+            writer.write_chunk(std::mem::replace(&mut chunk, LedgerArchiveChunk::empty()))?;
+        }
+    }
+    if !chunk.proofs.is_empty() {
+        summary.transaction_count += chunk.transactions.len() as u64;
+        summary.chunk_count += 1;
+        writer.write_chunk(chunk)?;
+    }
+
+    Ok((writer.finish()?, summary))
+}
+
+impl LedgerArchiveChunk {
+    fn empty() -> Self {
+        Self {
+            transactions: Vec::new(),
+            proofs: Vec::new(),
+            epoch_proofs: Vec::new(),
+        }
+    }
+}
+
+impl From<CommittedTransactionBundle> for ArchivedTransaction {
+    fn from(bundle: CommittedTransactionBundle) -> Self {
+        let CommittedTransactionBundle {
+            state_version,
+            raw,
+            receipt,
+            identifiers,
+        } = bundle;
+        Self {
+            state_version,
+            raw,
+            ledger_receipt: receipt.on_ledger.into(),
+            local_execution: receipt.local_execution.into(),
+            identifiers: identifiers.into(),
+        }
+    }
+}
+
+impl From<ArchivedTransaction> for CommittedTransactionBundle {
+    fn from(transaction: ArchivedTransaction) -> Self {
+        let ArchivedTransaction {
+            state_version,
+            raw,
+            ledger_receipt,
+            local_execution,
+            identifiers,
+        } = transaction;
+        Self {
+            state_version,
+            raw,
+            receipt: LocalTransactionReceipt {
+                on_ledger: ledger_receipt.fully_update_and_into_latest_version(),
+                local_execution: local_execution.fully_update_and_into_latest_version(),
+            },
+            identifiers: identifiers.fully_update_and_into_latest_version(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LedgerArchiveImportSummary {
+    /// Number of archived proofs committed by this import (i.e. together with their transactions).
+    pub committed_proof_count: u64,
+    /// Number of archived proofs which were already present in the local ledger (e.g. because
+    /// of a resumed import, or because they were generated locally by genesis or protocol updates)
+    /// and thus were only verified.
+    pub verified_proof_count: u64,
+    pub resultant_state_version: StateVersion,
+}
+
+/// Replays the given archive into the given [`StateManager`]'s ledger.
+///
+/// Transactions covered by consensus proofs are committed through the regular [`Committer`], i.e.
+/// they are fully re-executed. Genesis and protocol update transactions are not replayed: the
+/// local node generates them by itself (as on regular ledger sync), and the import only verifies
+/// their results against the archive. The [`LedgerHashes`] recorded locally (i.e. derived from the
+/// local transaction and receipt accumulator trees, and the state tree) are compared against the
+/// archived proofs after each proof.
+///
+/// The import is resumable: any archived proofs which already exist in the local ledger are only
+/// verified (not committed again).
+pub fn import_ledger_archive<R: Read>(
+    state_manager: &StateManager,
+    reader: &mut LedgerArchiveReader<R>,
+) -> Result<LedgerArchiveImportSummary, LedgerArchiveError> {
+    let archive_network = &reader.header().network_definition;
+    if archive_network.id != state_manager.network_definition.id {
+        return Err(LedgerArchiveError::NetworkMismatch {
+            archive_network: archive_network.logical_name.to_string(),
+            local_network: state_manager.network_definition.logical_name.to_string(),
+        });
+    }
+    let mut summary = LedgerArchiveImportSummary::default();
+    while let Some(chunk) = reader.next_chunk()? {
+        import_chunk(state_manager, chunk, &mut summary)?;
+    }
+    summary.resultant_state_version = state_manager.database.access_direct().max_state_version();
+    Ok(summary)
+}
+
+fn import_chunk(
+    state_manager: &StateManager,
+    chunk: LedgerArchiveChunk,
+    summary: &mut LedgerArchiveImportSummary,
+) -> Result<(), LedgerArchiveError> {
+    let LedgerArchiveChunk {
+        transactions,
+        proofs,
+        epoch_proofs,
+    } = chunk;
+    let mut transactions = transactions.into_iter().peekable();
+    for proof in proofs {
+        let proof = proof.fully_update_and_into_latest_version();
+        let proof_state_version = proof.ledger_header.state_version;
+        let mut proof_transactions = Vec::new();
+        while let Some(transaction) =
+            transactions.next_if(|transaction| transaction.state_version <= proof_state_version)
+        {
+            proof_transactions.push(transaction);
+        }
+
+        let local_max_state_version = state_manager.database.access_direct().max_state_version();
+        if proof_state_version > local_max_state_version {
+            let LedgerProofOrigin::Consensus { .. } = &proof.origin else {
+                // Protocol update transactions are expected to be already generated locally, after
+                // committing the previous (consensus) proof which initiated the update.
+                return Err(LedgerArchiveError::ProtocolUpdateNotReproduced {
+                    state_version: proof_state_version,
+                });
+            };
+            let expected_state_version = local_max_state_version.next().unwrap();
+            let first_state_version = proof_transactions
+                .first()
+                .map(|transaction| transaction.state_version)
+                .unwrap_or(proof_state_version);
+            if first_state_version != expected_state_version {
+                return Err(LedgerArchiveError::TransactionDiscontinuity {
+                    expected: expected_state_version,
+                    actual: first_state_version,
+                });
+            }
+            let initiates_protocol_update = proof.ledger_header.next_protocol_version.is_some();
+            state_manager
+                .committer
+                .commit(CommitRequest {
+                    transactions: proof_transactions
+                        .into_iter()
+                        .map(|transaction| transaction.raw)
+                        .collect(),
+                    proof: proof.clone(),
+                    vertex_store: None,
+                    self_validator_id: None,
+                })
+                .map_err(|error| LedgerArchiveError::CommitRejected {
+                    state_version: proof_state_version,
+                    error,
+                })?;
+            if initiates_protocol_update {
+                state_manager.apply_known_pending_protocol_updates();
+            }
+            summary.committed_proof_count += 1;
+        } else {
+            summary.verified_proof_count += 1;
+        }
+
+        let local_ledger_hashes = state_manager
+            .database
+            .access_direct()
+            .get_committed_transaction_identifiers(proof_state_version)
+            .map(|identifiers| identifiers.resultant_ledger_hashes);
+        verify_ledger_hashes(
+            proof_state_version,
+            &proof.ledger_header.hashes,
+            local_ledger_hashes,
+        )?;
+    }
+
+    for epoch_proof in epoch_proofs {
+        let epoch_proof = epoch_proof.fully_update_and_into_latest_version();
+        let next_epoch = epoch_proof
+            .ledger_header
+            .next_epoch
+            .as_ref()
+            .expect("epoch proof must initiate the next epoch")
+            .epoch;
+        let local_ledger_hashes = state_manager
+            .database
+            .access_direct()
+            .get_epoch_proof(next_epoch)
+            .map(|local_epoch_proof| local_epoch_proof.ledger_header.hashes);
+        verify_ledger_hashes(
+            epoch_proof.ledger_header.state_version,
+            &epoch_proof.ledger_header.hashes,
+            local_ledger_hashes,
+        )?;
+    }
+    Ok(())
+}
+
+fn verify_ledger_hashes(
+    state_version: StateVersion,
+    archived: &LedgerHashes,
+    local: Option<LedgerHashes>,
+) -> Result<(), LedgerArchiveError> {
+    if local.as_ref() == Some(archived) {
+        return Ok(());
+    }
+    Err(LedgerArchiveError::LedgerHashesMismatch {
+        state_version,
+        archived: *archived,
+        local,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager() -> StateManager {
+        create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        )
+    }
+
+    #[test]
+    fn exported_archive_imports_into_identical_ledger() {
+        let source = create_state_manager();
+        commit_round_updates_until_epoch(&source, Epoch::of(4));
+
+        let (archive, export_summary) = export_ledger_archive(
+            source.database.access_direct().deref(),
+            &source.network_definition,
+            Vec::new(),
+            7,
+        )
+        .unwrap();
+        assert!(export_summary.chunk_count > 1);
+
+        let target = create_state_manager();
+        let mut reader = LedgerArchiveReader::new(archive.as_slice()).unwrap();
+        let import_summary = import_ledger_archive(&target, &mut reader).unwrap();
+
+        let source_top = source
+            .database
+            .access_direct()
+            .get_top_transaction_identifiers()
+            .unwrap();
+        let target_top = target
+            .database
+            .access_direct()
+            .get_top_transaction_identifiers()
+            .unwrap();
+        assert_eq!(import_summary.resultant_state_version, source_top.0);
+        assert_eq!(target_top.0, source_top.0);
+        assert_eq!(
+            target_top.1.resultant_ledger_hashes,
+            source_top.1.resultant_ledger_hashes
+        );
+        assert!(import_summary.committed_proof_count > 0);
+        // The genesis proofs were generated locally and only verified:
+        assert!(import_summary.verified_proof_count > 0);
+
+        // A repeated import is a no-op verification:
+        let mut reader = LedgerArchiveReader::new(archive.as_slice()).unwrap();
+        let repeated_summary = import_ledger_archive(&target, &mut reader).unwrap();
+        assert_eq!(repeated_summary.committed_proof_count, 0);
+    }
+
+    #[test]
+    fn corrupted_archive_is_detected() {
+        let state_manager = create_state_manager();
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let (mut archive, _) = export_ledger_archive(
+            state_manager.database.access_direct().deref(),
+            &state_manager.network_definition,
+            Vec::new(),
+            DEFAULT_ARCHIVE_CHUNK_TRANSACTION_COUNT,
+        )
+        .unwrap();
+        let middle = archive.len() / 2;
+        archive[middle] ^= 0xFF;
+
+        let mut reader = LedgerArchiveReader::new(archive.as_slice()).unwrap();
+        let result = std::iter::from_fn(|| reader.next_chunk().transpose()).find(Result::is_err);
+        assert!(matches!(
+            result,
+            Some(Err(LedgerArchiveError::Format(
+                ChecksummedRecordError::ChecksumMismatch { .. }
+            )))
+        ));
+    }
+}
@@ -0,0 +1,571 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::state_tree_proofs::{from_nibbles, nested_tier_prefix, to_nibble_path};
+
+/// The first state version at which two ledgers' [`LedgerHashes`] differ.
+#[derive(Debug, Clone)]
+pub struct LedgerDivergence {
+    pub state_version: StateVersion,
+    pub left: CommittedTransactionIdentifiers,
+    pub right: CommittedTransactionIdentifiers,
+}
+
+/// A difference between the [`LedgerStateChanges`] of the same transaction on two ledgers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateChangeDifference {
+    Partition {
+        reference: PartitionReference,
+        left: Option<PartitionChangeAction>,
+        right: Option<PartitionChangeAction>,
+    },
+    Substate {
+        reference: SubstateReference,
+        left: Option<SubstateChangeAction>,
+        right: Option<SubstateChangeAction>,
+    },
+}
+
+/// A Substate which differs between two state trees of the same version.
+/// A [`None`] value hash means that the Substate does not exist in the respective tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstateTreeDifference {
+    pub partition_key: DbPartitionKey,
+    pub sort_key: DbSortKey,
+    pub left_value_hash: Option<Hash>,
+    pub right_value_hash: Option<Hash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateTreeDiffError {
+    /// The state tree nodes of the requested version are not available in one of the databases
+    /// (see [`StateTreeGcConfig::state_version_history_length`]).
+    StateVersionNotRetained,
+}
+
+/// Finds the first state version at which the given databases' committed transactions have
+/// different [`LedgerHashes`], or returns [`None`] if one ledger is a prefix of the other.
+///
+/// Since the transaction and receipt roots are accumulators, the ledgers never re-converge after
+/// diverging - which allows for a binary search over the versions stored in both databases. If the
+/// databases do not start at the same version (e.g. one was loaded from a state snapshot), the
+/// search starts from the later of the first stored versions, and a divergence reported there only
+/// means that the ledgers diverged at (or before) it.
+pub fn find_first_divergence<L: ReadableRocks, R: ReadableRocks>(
+    left: &StateManagerDatabase<L>,
+    right: &StateManagerDatabase<R>,
+) -> Option<LedgerDivergence> {
+    let (Some(left_first), Some(right_first)) = (
+        first_identified_state_version(left),
+        first_identified_state_version(right),
+    ) else {
+        return None;
+    };
+    let low = left_first.max(right_first);
+    let high = left.max_state_version().min(right.max_state_version());
+    if low > high {
+        return None;
+    }
+    let diverges_at = |state_version: StateVersion| {
+        let left_identifiers = left
+            .get_committed_transaction_identifiers(state_version)
+            .expect("left ledger's identifiers");
+        let right_identifiers = right
+            .get_committed_transaction_identifiers(state_version)
+            .expect("right ledger's identifiers");
+        if left_identifiers.resultant_ledger_hashes == right_identifiers.resultant_ledger_hashes {
+            None
+        } else {
+            Some(LedgerDivergence {
+                state_version,
+                left: left_identifiers,
+                right: right_identifiers,
+            })
+        }
+    };
+    if let Some(divergence) = diverges_at(low) {
+        return Some(divergence);
+    }
+    let mut divergence = diverges_at(high)?;
+    // Invariant: the ledgers agree at `agreed` and disagree at `divergence.state_version`.
+    let mut agreed = low.number();
+    while divergence.state_version.number() - agreed > 1 {
+        let middle = StateVersion::of(agreed + (divergence.state_version.number() - agreed) / 2);
+        match diverges_at(middle) {
+            Some(earlier_divergence) => divergence = earlier_divergence,
+            None => agreed = middle.number(),
+        }
+    }
+    Some(divergence)
+}
+
+/// Lists the state changes which are present in only one of the given [`LedgerStateChanges`], or
+/// which have different actions in each of them.
+pub fn diff_state_changes(
+    left: &LedgerStateChanges,
+    right: &LedgerStateChanges,
+) -> Vec<StateChangeDifference> {
+    let mut differences = Vec::new();
+    let mut right_partition_changes = right
+        .partition_level_changes
+        .iter()
+        .collect::<IndexMap<_, _>>();
+    for (reference, left_action) in left.partition_level_changes.iter() {
+        let right_action = right_partition_changes.swap_remove(&reference);
+        if right_action != Some(left_action) {
+            differences.push(StateChangeDifference::Partition {
+                reference,
+                left: Some(left_action.clone()),
+                right: right_action.cloned(),
+            });
+        }
+    }
+    for (reference, right_action) in right_partition_changes {
+        differences.push(StateChangeDifference::Partition {
+            reference,
+            left: None,
+            right: Some(right_action.clone()),
+        });
+    }
+    let mut right_substate_changes = right
+        .substate_level_changes
+        .iter()
+        .collect::<IndexMap<_, _>>();
+    for (reference, left_action) in left.substate_level_changes.iter() {
+        let right_action = right_substate_changes.swap_remove(&reference);
+        if right_action != Some(left_action) {
+            differences.push(StateChangeDifference::Substate {
+                reference,
+                left: Some(left_action.clone()),
+                right: right_action.cloned(),
+            });
+        }
+    }
+    for (reference, right_action) in right_substate_changes {
+        differences.push(StateChangeDifference::Substate {
+            reference,
+            left: None,
+            right: Some(right_action.clone()),
+        });
+    }
+    differences
+}
+
+/// Lists the Substates which differ between the given state trees at the given version.
+///
+/// Within each tier of the state tree, only the subtrees having different hashes are descended
+/// into, so the cost is proportional to the number of differences (rather than to the state size).
+pub fn diff_state_trees<L: ReadableTreeStore, R: ReadableTreeStore>(
+    left: &L,
+    right: &R,
+    state_version: StateVersion,
+) -> Result<Vec<SubstateTreeDifference>, StateTreeDiffError> {
+    let root_version = Some(state_version.number());
+    let mut differences = Vec::new();
+    for entity in diff_tier(left, right, &[], root_version, root_version)? {
+        let partition_tier_prefix = nested_tier_prefix(&[], &entity.key);
+        for partition in diff_tier(
+            left,
+            right,
+            &partition_tier_prefix,
+            entity.left.map(|leaf| leaf.version),
+            entity.right.map(|leaf| leaf.version),
+        )? {
+            let substate_tier_prefix = nested_tier_prefix(&partition_tier_prefix, &partition.key);
+            for substate in diff_tier(
+                left,
+                right,
+                &substate_tier_prefix,
+                partition.left.map(|leaf| leaf.version),
+                partition.right.map(|leaf| leaf.version),
+            )? {
+                differences.push(SubstateTreeDifference {
+                    partition_key: DbPartitionKey {
+                        node_key: entity.key.clone(),
+                        partition_num: partition.key[0],
+                    },
+                    sort_key: DbSortKey(substate.key),
+                    left_value_hash: substate.left.map(|leaf| leaf.value_hash),
+                    right_value_hash: substate.right.map(|leaf| leaf.value_hash),
+                });
+            }
+        }
+    }
+    Ok(differences)
+}
+
+fn first_identified_state_version<R: ReadableRocks>(
+    database: &StateManagerDatabase<R>,
+) -> Option<StateVersion> {
+    let genesis_state_version = StateVersion::pre_genesis().next().unwrap();
+    if database
+        .get_committed_transaction_identifiers(genesis_state_version)
+        .is_some()
+    {
+        return Some(genesis_state_version);
+    }
+    database
+        .get_first_proof()
+        .map(|proof| proof.ledger_header.state_version)
+}
+
+/// A leaf of a single state tree tier.
+#[derive(Debug, Clone, Copy)]
+struct TierLeaf {
+    value_hash: Hash,
+    version: Version,
+}
+
+/// A leaf key of a single state tree tier, with its leaf in each tree (if present).
+struct TierLeafDifference {
+    key: Vec<u8>,
+    left: Option<TierLeaf>,
+    right: Option<TierLeaf>,
+}
+
+/// Compares a single tier (whose nodes are stored under the given nibble prefix) of two trees, from
+/// their roots at the given versions ([`None`] meaning an absent tier).
+fn diff_tier<L: ReadableTreeStore, R: ReadableTreeStore>(
+    left: &L,
+    right: &R,
+    tier_prefix: &[u8],
+    left_root_version: Option<Version>,
+    right_root_version: Option<Version>,
+) -> Result<Vec<TierLeafDifference>, StateTreeDiffError> {
+    let mut differences = Vec::new();
+    let mut pending = vec![(tier_prefix.to_vec(), left_root_version, right_root_version)];
+    while let Some((path, left_version, right_version)) = pending.pop() {
+        let left_node = get_tier_node(left, &path, left_version)?;
+        let right_node = get_tier_node(right, &path, right_version)?;
+        if let (Some(TreeNode::Internal(left_internal)), Some(TreeNode::Internal(right_internal))) =
+            (&left_node, &right_node)
+        {
+            let left_children = index_children(left_internal);
+            let right_children = index_children(right_internal);
+            for nibble in 0..16 {
+                let left_child = left_children.get(&nibble);
+                let right_child = right_children.get(&nibble);
+                if let (Some(left_child), Some(right_child)) = (left_child, right_child) {
+                    if left_child.hash == right_child.hash {
+                        continue;
+                    }
+                }
+                if left_child.is_none() && right_child.is_none() {
+                    continue;
+                }
+                let mut child_path = path.clone();
+                child_path.push(nibble);
+                pending.push((
+                    child_path,
+                    left_child.map(|child| child.version),
+                    right_child.map(|child| child.version),
+                ));
+            }
+            continue;
+        }
+        // The subtrees' shapes differ - we simply compare all their leaves:
+        let mut right_leaves = collect_tier_leaves(right, tier_prefix, &path, right_node)?;
+        for (key, left_leaf) in collect_tier_leaves(left, tier_prefix, &path, left_node)? {
+            let right_leaf = right_leaves.swap_remove(&key);
+            if right_leaf.map(|leaf| leaf.value_hash) != Some(left_leaf.value_hash) {
+                differences.push(TierLeafDifference {
+                    key,
+                    left: Some(left_leaf),
+                    right: right_leaf,
+                });
+            }
+        }
+        for (key, right_leaf) in right_leaves {
+            differences.push(TierLeafDifference {
+                key,
+                left: None,
+                right: Some(right_leaf),
+            });
+        }
+    }
+    Ok(differences)
+}
+
+fn get_tier_node<S: ReadableTreeStore>(
+    tree_store: &S,
+    path: &[u8],
+    version: Option<Version>,
+) -> Result<Option<TreeNode>, StateTreeDiffError> {
+    let Some(version) = version else {
+        return Ok(None);
+    };
+    tree_store
+        .get_node(&StoredTreeNodeKey::new(version, to_nibble_path(path)))
+        .map(Some)
+        .ok_or(StateTreeDiffError::StateVersionNotRetained)
+}
+
+fn index_children(internal: &TreeInternalNode) -> IndexMap<u8, &TreeChildEntry> {
+    internal
+        .children
+        .iter()
+        .map(|child| (u8::from(child.nibble), child))
+        .collect()
+}
+
+/// Collects all leaves of a subtree (rooted at the given node, located at the given path) of a
+/// single tier, keyed by their full leaf keys.
+fn collect_tier_leaves<S: ReadableTreeStore>(
+    tree_store: &S,
+    tier_prefix: &[u8],
+    path: &[u8],
+    node: Option<TreeNode>,
+) -> Result<IndexMap<Vec<u8>, TierLeaf>, StateTreeDiffError> {
+    let mut leaves = index_map_new();
+    let mut pending = node
+        .map(|node| (path.to_vec(), node))
+        .into_iter()
+        .collect::<Vec<_>>();
+    while let Some((path, node)) = pending.pop() {
+        match node {
+            TreeNode::Internal(internal) => {
+                for child in internal.children {
+                    let mut child_path = path.clone();
+                    child_path.push(u8::from(child.nibble));
+                    let child_node = get_tier_node(tree_store, &child_path, Some(child.version))?
+                        .expect("requested node");
+                    pending.push((child_path, child_node));
+                }
+            }
+            TreeNode::Leaf(leaf) => {
+                let mut key_nibbles = path[tier_prefix.len()..].to_vec();
+                key_nibbles.extend(leaf.key_suffix.nibbles().map(u8::from));
+                leaves.insert(
+                    from_nibbles(&key_nibbles),
+                    TierLeaf {
+                        value_hash: leaf.value_hash,
+                        version: leaf.last_hash_change_version,
+                    },
+                );
+            }
+            TreeNode::Null => {}
+        }
+    }
+    Ok(leaves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        build_faucet_transaction, commit_round_updates_until_epoch,
+        create_bootstrapped_state_manager_with_rounds_per_epoch, prepare_and_commit_round_update,
+        prepare_and_commit_round_with_transactions,
+    };
+
+    #[test]
+    fn ledger_lagging_behind_does_not_diverge() {
+        let left = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&left, Epoch::of(4));
+        let right = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&right, Epoch::of(3));
+
+        let left_database = left.database.lock();
+        let right_database = right.database.lock();
+        assert!(right_database.max_state_version() < left_database.max_state_version());
+        assert!(find_first_divergence(left_database.deref(), right_database.deref()).is_none());
+        assert!(find_first_divergence(right_database.deref(), left_database.deref()).is_none());
+    }
+
+    #[test]
+    fn diverging_ledgers_are_reported_at_first_differing_transaction() {
+        let left = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&left, Epoch::of(3));
+        let right = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&right, Epoch::of(3));
+        let common_state_version = left.database.lock().max_state_version();
+        assert_eq!(
+            right.database.lock().max_state_version(),
+            common_state_version
+        );
+
+        // The same round is committed with a user transaction on the left only (i.e. its round
+        // update transaction is still common), and then both ledgers continue:
+        let account = ComponentAddress::preallocated_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        prepare_and_commit_round_with_transactions(
+            &left,
+            vec![build_faucet_transaction(Epoch::of(3), account)],
+        );
+        prepare_and_commit_round_update(&right);
+        commit_round_updates_until_epoch(&left, Epoch::of(4));
+        commit_round_updates_until_epoch(&right, Epoch::of(4));
+
+        let left_database = left.database.lock();
+        let right_database = right.database.lock();
+        let expected_state_version = common_state_version.next().unwrap().next().unwrap();
+        let divergence =
+            find_first_divergence(left_database.deref(), right_database.deref()).unwrap();
+        assert_eq!(divergence.state_version, expected_state_version);
+        assert!(divergence.left.transaction_hashes.as_user().is_some());
+        assert!(divergence.right.transaction_hashes.as_user().is_none());
+        assert_eq!(
+            divergence.left.resultant_ledger_hashes,
+            left_database
+                .get_committed_transaction_identifiers(expected_state_version)
+                .unwrap()
+                .resultant_ledger_hashes
+        );
+        let reversed_divergence =
+            find_first_divergence(right_database.deref(), left_database.deref()).unwrap();
+        assert_eq!(reversed_divergence.state_version, expected_state_version);
+    }
+
+    #[test]
+    fn state_tree_diff_finds_exactly_the_differing_substates() {
+        let left = TypedInMemoryTreeStore::new();
+        let right = TypedInMemoryTreeStore::new();
+        let common_substates = vec![
+            (db_key("c0ffee", 7, "dec0"), vec![1]),
+            (db_key("c0ffee", 7, "beef"), vec![2]),
+            (db_key("c0ffee", 64, "af"), vec![3]),
+            (db_key("deadbeef", 1, "00"), vec![4]),
+        ];
+        put_at_next_version(&left, None, &database_updates(&common_substates));
+        put_at_next_version(&right, None, &database_updates(&common_substates));
+        put_at_next_version(
+            &left,
+            Some(1),
+            &database_updates(&[(db_key("c0ffee", 7, "beef"), vec![5])]),
+        );
+        put_at_next_version(
+            &right,
+            Some(1),
+            &database_updates(&[
+                (db_key("c0ffee", 7, "beef"), vec![6]),
+                (db_key("c0fefe", 2, "aa"), vec![7]),
+            ]),
+        );
+
+        assert_eq!(
+            diff_state_trees(&left, &right, StateVersion::of(1)),
+            Ok(vec![])
+        );
+        let mut differences = diff_state_trees(&left, &right, StateVersion::of(2)).unwrap();
+        differences.sort_by(|a, b| a.partition_key.cmp(&b.partition_key));
+        let (beef_partition_key, beef_sort_key) = db_key("c0ffee", 7, "beef");
+        let (aa_partition_key, aa_sort_key) = db_key("c0fefe", 2, "aa");
+        assert_eq!(
+            differences,
+            vec![
+                SubstateTreeDifference {
+                    partition_key: aa_partition_key,
+                    sort_key: aa_sort_key,
+                    left_value_hash: None,
+                    right_value_hash: Some(hash([7u8])),
+                },
+                SubstateTreeDifference {
+                    partition_key: beef_partition_key,
+                    sort_key: beef_sort_key,
+                    left_value_hash: Some(hash([5u8])),
+                    right_value_hash: Some(hash([6u8])),
+                },
+            ]
+        );
+        assert_eq!(
+            diff_state_trees(&left, &right, StateVersion::of(3)),
+            Err(StateTreeDiffError::StateVersionNotRetained)
+        );
+    }
+
+    fn db_key(node_key: &str, partition_num: u8, sort_key: &str) -> DbSubstateKey {
+        (
+            DbPartitionKey {
+                node_key: hex::decode(node_key).unwrap(),
+                partition_num,
+            },
+            DbSortKey(hex::decode(sort_key).unwrap()),
+        )
+    }
+
+    fn database_updates(substates: &[(DbSubstateKey, DbSubstateValue)]) -> DatabaseUpdates {
+        let mut delta_maps = index_map_new::<DbPartitionKey, IndexMap<DbSortKey, DatabaseUpdate>>();
+        for ((partition_key, sort_key), value) in substates {
+            delta_maps
+                .entry(partition_key.clone())
+                .or_default()
+                .insert(sort_key.clone(), DatabaseUpdate::Set(value.clone()));
+        }
+        DatabaseUpdates::from_delta_maps(delta_maps)
+    }
+}
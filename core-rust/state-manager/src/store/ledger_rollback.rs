@@ -0,0 +1,366 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::historical_state::{StateHistoryError, VersionScopingSupport};
+use crate::store::migrations::DatabaseMigrationRegistry;
+
+/// A number of reverted Substates (or Partitions) written in a single batch.
+const REVERT_BATCH_SIZE: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerRollbackError {
+    EpochProofNotFound {
+        epoch: Epoch,
+    },
+    TargetNotInPast {
+        target_state_version: StateVersion,
+        current_state_version: StateVersion,
+    },
+    StateUnavailable {
+        state_version: StateVersion,
+        error: StateHistoryError,
+    },
+    MissingTransaction {
+        state_version: StateVersion,
+    },
+    UnsupportedSchema(DatabaseConfigValidationError),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LedgerRollbackSummary {
+    pub target_state_version: StateVersion,
+    pub rolled_back_transaction_count: u64,
+    pub reverted_partition_count: u64,
+    pub reverted_substate_count: u64,
+}
+
+/// Truncates the given database back to the epoch change proof which started the given epoch.
+///
+/// The Substates touched by the rolled-back transactions (as listed by their receipts) are reverted
+/// to their values at the target state version, read from the state history - which thus has to
+/// cover the target version. Then, the index entries of the rolled-back transactions are deleted,
+/// and finally all the ledger data above the target proof is atomically truncated (which includes
+/// resetting the vertex store).
+///
+/// The database must not be used by a running Node, and must not be written by a newer schema
+/// than this Node supports (which is checked before any write). An interrupted rollback can be
+/// simply re-run: all the steps before the final truncation only depend on the (still intact)
+/// rolled-back transactions and the state history.
+pub fn rollback_ledger<R: WriteableRocks>(
+    database: &StateManagerDatabase<R>,
+    target_epoch: Epoch,
+) -> Result<LedgerRollbackSummary, LedgerRollbackError> {
+    database
+        .check_schema_version_supported(DatabaseMigrationRegistry::<R>::default().schema_version())
+        .map_err(LedgerRollbackError::UnsupportedSchema)?;
+    let target_proof =
+        database
+            .get_epoch_proof(target_epoch)
+            .ok_or(LedgerRollbackError::EpochProofNotFound {
+                epoch: target_epoch,
+            })?;
+    let target_state_version = target_proof.ledger_header.state_version;
+    let current_state_version = database.max_state_version();
+    if target_state_version >= current_state_version {
+        return Err(LedgerRollbackError::TargetNotInPast {
+            target_state_version,
+            current_state_version,
+        });
+    }
+    let target_state = database
+        .scoped_at(Some(target_state_version))
+        .map_err(|error| LedgerRollbackError::StateUnavailable {
+            state_version: target_state_version,
+            error,
+        })?;
+
+    let mut summary = LedgerRollbackSummary {
+        target_state_version,
+        ..Default::default()
+    };
+    let mut partition_keys = index_set_new::<DbPartitionKey>();
+    let mut substate_keys = index_set_new::<DbSubstateKey>();
+    let mut state_version = target_state_version.next().unwrap();
+    while state_version <= current_state_version {
+        let receipt = database
+            .get_committed_ledger_transaction_receipt(state_version)
+            .ok_or(LedgerRollbackError::MissingTransaction { state_version })?;
+        let state_changes = receipt.state_changes;
+        for (PartitionReference(node_id, partition_num), _) in
+            state_changes.partition_level_changes.iter()
+        {
+            partition_keys.insert(SpreadPrefixKeyMapper::to_db_partition_key(
+                &node_id,
+                partition_num,
+            ));
+        }
+        for (SubstateReference(node_id, partition_num, substate_key), _) in
+            state_changes.substate_level_changes.iter()
+        {
+            substate_keys.insert((
+                SpreadPrefixKeyMapper::to_db_partition_key(&node_id, partition_num),
+                SpreadPrefixKeyMapper::to_db_sort_key(&substate_key),
+            ));
+        }
+        summary.rolled_back_transaction_count += 1;
+        state_version = state_version.next().unwrap();
+    }
+    info!(
+        "Reverting {} partitions and {} substates touched by {} transactions",
+        partition_keys.len(),
+        substate_keys.len(),
+        summary.rolled_back_transaction_count
+    );
+
+    for batch in partition_keys
+        .into_iter()
+        .chunks(REVERT_BATCH_SIZE)
+        .into_iter()
+    {
+        let partitions = batch
+            .map(|partition_key| {
+                let values = target_state
+                    .list_raw_values_from_db_key(&partition_key, None)
+                    .collect::<Vec<_>>();
+                (partition_key, values)
+            })
+            .collect::<Vec<_>>();
+        summary.reverted_partition_count += partitions.len() as u64;
+        database.put_reverted_substates(partitions, vec![]);
+    }
+    for batch in substate_keys
+        .into_iter()
+        .chunks(REVERT_BATCH_SIZE)
+        .into_iter()
+    {
+        let substates = batch
+            .map(|(partition_key, sort_key)| {
+                let value = target_state.get_raw_substate_by_db_key(&partition_key, &sort_key);
+                ((partition_key, sort_key), value)
+            })
+            .collect::<Vec<_>>();
+        summary.reverted_substate_count += substates.len() as u64;
+        database.put_reverted_substates(vec![], substates);
+    }
+
+    info!(
+        "Deleting index entries of transactions above state version {}",
+        target_state_version
+    );
+    database.delete_index_entries_after(target_state_version);
+
+    info!("Truncating the ledger to {:?}", target_proof.ledger_header);
+    database.truncate_ledger_after(&target_proof);
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::consistency_check::check_database_consistency;
+    use crate::store::historical_state::VersionScopedDatabase;
+    use crate::store::secondary_indices::ChangedEntityIndex;
+    use crate::test::{
+        build_faucet_transaction, commit_round_updates_until_epoch,
+        create_bootstrapped_state_manager_with_rounds_per_epoch,
+        prepare_and_commit_round_with_transactions,
+    };
+
+    #[test]
+    fn rolled_back_ledger_matches_historical_state_at_target_epoch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap());
+        config.database_config.enable_historical_substate_values = true;
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(5));
+
+        let database = state_manager.database.lock();
+        let target_proof = database.get_epoch_proof(Epoch::of(3)).unwrap();
+        let target_state_version = target_proof.ledger_header.state_version;
+        let Ok(VersionScopedDatabase::Historical(historical)) =
+            database.deref().scoped_at(Some(target_state_version))
+        else {
+            panic!("state at {} not available", target_state_version);
+        };
+        let historical_substates = historical.iter_substates().collect::<Vec<_>>();
+        let rolled_back_transaction_count =
+            database.max_state_version().number() - target_state_version.number();
+
+        let summary = rollback_ledger(database.deref(), Epoch::of(3)).unwrap();
+
+        assert_eq!(summary.target_state_version, target_state_version);
+        assert_eq!(
+            summary.rolled_back_transaction_count,
+            rolled_back_transaction_count
+        );
+        assert!(summary.reverted_substate_count > 0);
+        assert_eq!(database.max_state_version(), target_state_version);
+        assert_eq!(database.get_latest_proof(), Some(target_proof.clone()));
+        assert_eq!(database.get_latest_epoch_proof(), Some(target_proof));
+        assert_eq!(database.get_epoch_proof(Epoch::of(4)), None);
+        for ((partition_key, sort_key), value) in historical_substates {
+            assert_eq!(
+                database.get_raw_substate_by_db_key(&partition_key, &sort_key),
+                Some(value)
+            );
+        }
+        let report = check_database_consistency(database.deref(), &Default::default());
+        assert_eq!(report.violations, vec![]);
+    }
+
+    #[test]
+    fn rolled_back_user_transactions_are_removed_from_indices() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap());
+        config.database_config.enable_historical_substate_values = true;
+        config.database_config.enable_resource_change_index = true;
+        config.database_config.enabled_secondary_indices =
+            vec![ChangedEntityIndex.name().to_string()];
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        let account = ComponentAddress::preallocated_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        prepare_and_commit_round_with_transactions(
+            &state_manager,
+            vec![build_faucet_transaction(Epoch::of(3), account)],
+        );
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+
+        let database = state_manager.database.lock();
+        let target_state_version = database
+            .get_epoch_proof(Epoch::of(3))
+            .unwrap()
+            .ledger_header
+            .state_version;
+        let first_rolled_back_state_version = target_state_version.next().unwrap();
+        let rolled_back_intent_hashes = (first_rolled_back_state_version.number()
+            ..=database.max_state_version().number())
+            .filter_map(|state_version| {
+                database
+                    .get_committed_transaction_identifiers(StateVersion::of(state_version))
+                    .unwrap()
+                    .transaction_hashes
+                    .as_user()
+                    .map(|user_hashes| user_hashes.transaction_intent_hash)
+            })
+            .collect::<Vec<_>>();
+        let account_state_versions = |database: &ActualStateManagerDatabase| {
+            database
+                .get_state_versions_for_account_iter(
+                    account.into(),
+                    first_rolled_back_state_version,
+                )
+                .collect::<Vec<_>>()
+        };
+        let xrd_state_versions = |database: &ActualStateManagerDatabase| {
+            database
+                .get_state_versions_for_resource_iter(XRD, first_rolled_back_state_version)
+                .collect::<Vec<_>>()
+        };
+        let changed_account_state_versions = |database: &ActualStateManagerDatabase| {
+            database
+                .get_state_versions_for_secondary_index_key_iter(
+                    ChangedEntityIndex.name(),
+                    account.as_node_id().0.as_slice(),
+                    StateVersion::pre_genesis(),
+                )
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rolled_back_intent_hashes.len(), 1);
+        assert!(!account_state_versions(database.deref()).is_empty());
+        assert!(!xrd_state_versions(database.deref()).is_empty());
+        assert!(!changed_account_state_versions(database.deref()).is_empty());
+
+        rollback_ledger(database.deref(), Epoch::of(3)).unwrap();
+
+        for intent_hash in &rolled_back_intent_hashes {
+            assert_eq!(
+                database.get_txn_state_version_by_identifier(intent_hash),
+                None
+            );
+        }
+        assert_eq!(account_state_versions(database.deref()), vec![]);
+        assert_eq!(xrd_state_versions(database.deref()), vec![]);
+        assert_eq!(changed_account_state_versions(database.deref()), vec![]);
+        let report = check_database_consistency(database.deref(), &Default::default());
+        assert_eq!(report.violations, vec![]);
+    }
+
+    #[test]
+    fn rollback_to_unknown_epoch_is_refused() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut config = StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap());
+        config.database_config.enable_historical_substate_values = true;
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.lock();
+        assert!(matches!(
+            rollback_ledger(database.deref(), Epoch::of(7)),
+            Err(LedgerRollbackError::EpochProofNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+/// A garbage collector of the full data (i.e. payloads, receipts and local executions) of the
+/// ledger transactions older than a configured number of epochs.
+///
+/// Only the data which is not needed for verifying the ledger is pruned: the transactions'
+/// identifiers, hash indices and accumulator tree slices are retained, and so are the epoch change
+/// transactions (required e.g. for resolving the protocol state on boot).
+///
+/// To keep the [`LedgerProofsGc`](crate::store::proofs_gc::LedgerProofsGc) operational, this GC never prunes an epoch which was not yet
+/// processed by the former (i.e. the effective retention is the larger of both GCs' settings).
+/// For the same reason, it is driven by the Proofs GC's schedule (i.e. it runs right after it).
+///
+/// The data is deleted with a range delete per epoch, and each run ends with a targeted compaction
+/// of the pruned range.
+pub struct LedgerTransactionsGc {
+    database: Arc<DbLock<ActualStateManagerDatabase>>,
+    retention_epoch_count: u64,
+    raw_db_metrics: Arc<RawDbMetrics>,
+}
+
+impl LedgerTransactionsGc {
+    /// Creates a new GC, retaining the transactions of the given number of most recent *completed*
+    /// epochs (please note that the current epoch is never GC-ed).
+    pub fn new(
+        database: Arc<DbLock<ActualStateManagerDatabase>>,
+        retention_epoch_count: u64,
+        raw_db_metrics: Arc<RawDbMetrics>,
+    ) -> Self {
+        Self {
+            database,
+            retention_epoch_count,
+            raw_db_metrics,
+        }
+    }
+
+    /// Performs a single GC run, which is supposed to permanently delete the full data of *all*
+    /// transactions of configured old-enough epochs.
+    ///
+    /// *Note on concurrent database access:*
+    /// Similar to the Proofs GC, this process only accesses "old" DB rows, and thus can
+    /// use the direct [`DbLock::access_direct()`]. The readers (e.g. the Core API's streams) are
+    /// expected to use a [`DbLock::snapshot()`] and check the transactions' availability first.
+    pub fn run(&self) {
+        let database = self.database.access_direct();
+        let Some(max_completed_epoch) = database.max_completed_epoch() else {
+            // Nothing to GC ever, yet.
+            return;
+        };
+        let to_epoch = max_completed_epoch
+            .number()
+            .checked_sub(self.retention_epoch_count)
+            .map(Epoch::of);
+        let proofs_gc_epoch = database
+            .get_progress()
+            .map(|progress| progress.last_pruned_epoch);
+        let Some(to_epoch) = to_epoch.zip(proofs_gc_epoch).map(|(a, b)| a.min(b)) else {
+            // Nothing to GC during this run.
+            return;
+        };
+
+        let available_from = database.get_first_stored_ledger_transaction_state_version();
+        // The genesis transactions (up to the first epoch proof) are always retained:
+        let Some(first_retained_proof) = database
+            .get_closest_epoch_proof_on_or_before(available_from)
+            .or_else(|| database.get_post_genesis_epoch_proof())
+        else {
+            return;
+        };
+        if first_retained_proof.ledger_header.epoch >= to_epoch {
+            // Nothing to GC during this run.
+            return;
+        }
+
+        info!(
+            "Starting a GC run: pruning ledger transactions up to epoch {}; currently available from state version {}",
+            to_epoch.number(),
+            available_from
+        );
+        let epoch_proofs = database
+            .get_next_epoch_proof_iter(first_retained_proof.ledger_header.epoch)
+            .skip_while(|proof| {
+                proof.ledger_header.state_version < first_retained_proof.ledger_header.state_version
+            });
+        let mut previous_epoch_proof: Option<LedgerProof> = None;
+        for epoch_proof in epoch_proofs {
+            if epoch_proof.ledger_header.epoch > to_epoch {
+                break;
+            }
+            if let Some(previous_epoch_proof) = previous_epoch_proof {
+                // The epoch change transaction itself (i.e. at the proof's version) is retained:
+                let from = previous_epoch_proof
+                    .ledger_header
+                    .state_version
+                    .next()
+                    .expect("state version overflow");
+                let to = epoch_proof.ledger_header.state_version;
+                database.progress_ledger_transactions_availability(to);
+                database.delete_ledger_transactions_range(from, to);
+            }
+            previous_epoch_proof = Some(epoch_proof);
+        }
+
+        let now_available_from = database.get_first_stored_ledger_transaction_state_version();
+        let reclaimed =
+            database.compact_ledger_transactions_range(available_from, now_available_from);
+        info!(
+            "Ledger transactions' GC run finished; available from state version {}; compaction reclaimed {} bytes",
+            now_available_from,
+            reclaimed
+                .iter()
+                .map(|category| category.reclaimed_bytes)
+                .sum::<usize>()
+        );
+        self.raw_db_metrics.record_reclaimed(reclaimed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::consistency_check::check_database_consistency;
+    use crate::store::proofs_gc::{LedgerProofsGc, LedgerProofsGcConfig};
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    #[test]
+    fn transactions_availability_only_progresses_forward() {
+        let config = StateManagerConfig::new_for_testing_in_memory();
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.lock();
+        database.progress_ledger_transactions_availability(StateVersion::of(20));
+        assert_eq!(
+            database.get_first_stored_ledger_transaction_state_version(),
+            StateVersion::of(20)
+        );
+        database.progress_ledger_transactions_availability(StateVersion::of(10));
+        assert_eq!(
+            database.get_first_stored_ledger_transaction_state_version(),
+            StateVersion::of(20)
+        );
+    }
+
+    #[test]
+    fn old_transactions_are_pruned_while_identifiers_and_epoch_changes_are_retained() {
+        let mut config = StateManagerConfig::new_for_testing_in_memory();
+        config
+            .database_config
+            .ledger_transaction_retention_epoch_count = Some(2);
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(8));
+
+        let raw_db_metrics = Arc::new(RawDbMetrics::new(&MetricRegistry::new()));
+        let proofs_gc = LedgerProofsGc::new(
+            state_manager.database.clone(),
+            LedgerProofsGcConfig {
+                interval_sec: 0,
+                most_recent_full_resolution_epoch_count: 0,
+            },
+            LedgerSyncLimitsConfig {
+                max_txns_for_responses_spanning_more_than_one_proof: 8,
+                max_txn_bytes_for_single_response: 1_000_000,
+            },
+            raw_db_metrics.clone(),
+        );
+        let gc = LedgerTransactionsGc::new(state_manager.database.clone(), 2, raw_db_metrics);
+        proofs_gc.run();
+        gc.run();
+
+        let database = state_manager.database.lock();
+        // We are in epoch 8, so the transactions of epochs 6 and 7 (and later) are retained:
+        let first_retained_epoch_start = database
+            .get_epoch_proof(Epoch::of(6))
+            .unwrap()
+            .ledger_header
+            .state_version;
+        assert_eq!(
+            database.get_first_stored_ledger_transaction_state_version(),
+            first_retained_epoch_start
+        );
+        assert!(matches!(
+            database.get_committed_transaction_bundle_iter(StateVersion::of(1)),
+            Err(TransactionsPrunedError { first_available_state_version })
+                if first_available_state_version == first_retained_epoch_start
+        ));
+        assert!(database
+            .get_committed_transaction_bundle_iter(first_retained_epoch_start)
+            .is_ok());
+
+        let post_genesis_state_version = database
+            .get_post_genesis_epoch_proof()
+            .unwrap()
+            .ledger_header
+            .state_version;
+        let epoch_change_state_versions = database
+            .get_next_epoch_proof_iter(Epoch::of(0))
+            .map(|proof| proof.ledger_header.state_version)
+            .collect::<Vec<_>>();
+        let mut state_version = StateVersion::pre_genesis().next().unwrap();
+        while state_version <= database.max_state_version() {
+            assert!(database
+                .get_committed_transaction_identifiers(state_version)
+                .is_some());
+            let expected_retained = state_version <= post_genesis_state_version
+                || state_version >= first_retained_epoch_start
+                || epoch_change_state_versions.contains(&state_version);
+            assert_eq!(
+                database.get_committed_transaction(state_version).is_some(),
+                expected_retained
+            );
+            assert_eq!(
+                database
+                    .get_committed_ledger_transaction_receipt(state_version)
+                    .is_some(),
+                expected_retained
+            );
+            state_version = state_version.next().unwrap();
+        }
+
+        let report = check_database_consistency(database.deref(), &Default::default());
+        assert_eq!(report.violations, vec![]);
+    }
+}
@@ -0,0 +1,322 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+/// The schema version of a database which does not have any [`DatabaseMigration`] applied (i.e.
+/// also of every database created before the migrations were introduced).
+pub const BASELINE_DATABASE_SCHEMA_VERSION: u32 = 1;
+
+/// A single, named step bringing an already-populated database to the next schema version.
+///
+/// The migration is performed in batches, each of which is committed atomically together with
+/// the migration's cursor (i.e. an interrupted migration is resumed from its last committed batch
+/// on the next start-up, and a completed one is never run again).
+pub trait DatabaseMigration<R: WriteableRocks> {
+    /// The unique name of this migration.
+    ///
+    /// IMPORTANT NOTE: this is a database identifier (recorded among the applied migrations).
+    /// Any change would make the Node refuse to start on the already-migrated databases.
+    fn name(&self) -> &'static str;
+
+    /// Performs the next batch of this migration, starting from the given cursor (`None` on the
+    /// first batch), by writing to the given context (which is flushed by the caller).
+    fn migrate_batch(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        cursor: Option<Vec<u8>>,
+    ) -> DatabaseMigrationProgress;
+}
+
+/// A result of a single [`DatabaseMigration::migrate_batch()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseMigrationProgress {
+    /// More batches are needed; the next one should start from the given (migration-specific)
+    /// cursor.
+    InProgress { cursor: Vec<u8> },
+    /// The migration is complete.
+    Completed,
+}
+
+/// An ordered list of [`DatabaseMigration`]s, where the N-th migration upgrades the database to
+/// the schema version `BASELINE_DATABASE_SCHEMA_VERSION + N`.
+pub struct DatabaseMigrationRegistry<R: WriteableRocks> {
+    migrations: Vec<Box<dyn DatabaseMigration<R>>>,
+}
+
+impl<R: WriteableRocks> DatabaseMigrationRegistry<R> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self { migrations: vec![] }
+    }
+
+    /// Registers the given migration as the last one.
+    ///
+    /// Panics if a migration of the same name was already registered.
+    pub fn register(mut self, migration: impl DatabaseMigration<R> + 'static) -> Self {
+        if self.names().contains(&migration.name()) {
+            panic!("duplicate database migration: {}", migration.name());
+        }
+        self.migrations.push(Box::new(migration));
+        self
+    }
+
+    /// Returns the schema version reached after applying all the registered migrations.
+    pub fn schema_version(&self) -> u32 {
+        BASELINE_DATABASE_SCHEMA_VERSION + u32::try_from(self.migrations.len()).unwrap()
+    }
+
+    /// Returns the names of all the registered migrations, in their application order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.migrations
+            .iter()
+            .map(|migration| migration.name())
+            .collect()
+    }
+
+    /// Returns all the registered migrations, in their application order.
+    pub fn migrations(&self) -> &[Box<dyn DatabaseMigration<R>>] {
+        &self.migrations
+    }
+}
+
+impl<R: WriteableRocks> Default for DatabaseMigrationRegistry<R> {
+    /// Creates a registry of all the built-in migrations.
+    fn default() -> Self {
+        // Note: the built-in migrations must only ever be appended here (see the struct's docs).
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::rocks_db::ActualStateManagerDatabase;
+    use crate::store::secondary_indices::SecondaryIndexRegistry;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// A migration which only records the cursors it was called with, and completes after the
+    /// given number of batches (optionally "crashing" at one of them).
+    struct RecordingMigration {
+        batch_count: u8,
+        crash_at_batch: Option<u8>,
+        observed_cursors: Arc<std::sync::Mutex<Vec<Option<Vec<u8>>>>>,
+    }
+
+    impl<R: WriteableRocks> DatabaseMigration<R> for RecordingMigration {
+        fn name(&self) -> &'static str {
+            "recording_migration"
+        }
+
+        fn migrate_batch(
+            &self,
+            _db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+            cursor: Option<Vec<u8>>,
+        ) -> DatabaseMigrationProgress {
+            self.observed_cursors.lock().unwrap().push(cursor.clone());
+            let batch = cursor.map_or(1, |cursor| cursor[0] + 1);
+            if self.crash_at_batch == Some(batch) {
+                panic!("simulated crash at batch {}", batch);
+            }
+            if batch == self.batch_count {
+                DatabaseMigrationProgress::Completed
+            } else {
+                DatabaseMigrationProgress::InProgress {
+                    cursor: vec![batch],
+                }
+            }
+        }
+    }
+
+    fn create_database() -> ActualStateManagerDatabase {
+        ActualStateManagerDatabase::new_in_memory(
+            DatabaseConfig::default(),
+            &SecondaryIndexRegistry::default(),
+            &NetworkDefinition::simulator(),
+        )
+        .unwrap()
+    }
+
+    fn create_metrics() -> DatabaseMigrationMetrics {
+        DatabaseMigrationMetrics::new(&MetricRegistry::new())
+    }
+
+    #[test]
+    fn pending_migration_runs_in_batches_exactly_once() {
+        let database = create_database();
+        let observed_cursors = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let registry = DatabaseMigrationRegistry::new().register(RecordingMigration {
+            batch_count: 3,
+            crash_at_batch: None,
+            observed_cursors: observed_cursors.clone(),
+        });
+        let metrics = create_metrics();
+
+        database.run_migrations(&registry, &metrics).unwrap();
+        database.run_migrations(&registry, &metrics).unwrap();
+
+        assert_eq!(
+            *observed_cursors.lock().unwrap(),
+            vec![None, Some(vec![1]), Some(vec![2])]
+        );
+        assert_eq!(metrics.schema_version.get(), 2);
+        assert_eq!(metrics.pending_migrations.get(), 0);
+    }
+
+    #[test]
+    fn interrupted_migration_resumes_from_last_committed_batch() {
+        let database = create_database();
+        let observed_cursors = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let crashing_registry = DatabaseMigrationRegistry::new().register(RecordingMigration {
+            batch_count: 4,
+            crash_at_batch: Some(3),
+            observed_cursors: observed_cursors.clone(),
+        });
+        let crash = catch_unwind(AssertUnwindSafe(|| {
+            database.run_migrations(&crashing_registry, &create_metrics())
+        }));
+        assert!(crash.is_err());
+
+        observed_cursors.lock().unwrap().clear();
+        let registry = DatabaseMigrationRegistry::new().register(RecordingMigration {
+            batch_count: 4,
+            crash_at_batch: None,
+            observed_cursors: observed_cursors.clone(),
+        });
+        database
+            .run_migrations(&registry, &create_metrics())
+            .unwrap();
+
+        assert_eq!(
+            *observed_cursors.lock().unwrap(),
+            vec![Some(vec![2]), Some(vec![3])]
+        );
+    }
+
+    #[test]
+    fn database_of_newer_schema_is_refused() {
+        let database = create_database();
+        let registry = DatabaseMigrationRegistry::new().register(RecordingMigration {
+            batch_count: 1,
+            crash_at_batch: None,
+            observed_cursors: Arc::new(std::sync::Mutex::new(Vec::new())),
+        });
+        database
+            .run_migrations(&registry, &create_metrics())
+            .unwrap();
+
+        let result = database.run_migrations(&DatabaseMigrationRegistry::new(), &create_metrics());
+
+        assert!(matches!(
+            result,
+            Err(DatabaseConfigValidationError::NewerSchemaVersion {
+                persisted: 2,
+                supported: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn database_of_newer_schema_is_refused_by_special_opening_modes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let db_path = tmp.path().join("db");
+        {
+            let database = ActualStateManagerDatabase::new(
+                db_path.clone(),
+                DatabaseConfig::default(),
+                &NetworkDefinition::simulator(),
+            )
+            .unwrap();
+            let registry = DatabaseMigrationRegistry::new().register(RecordingMigration {
+                batch_count: 1,
+                crash_at_batch: None,
+                observed_cursors: Arc::new(std::sync::Mutex::new(Vec::new())),
+            });
+            database
+                .run_migrations(&registry, &create_metrics())
+                .unwrap();
+        }
+
+        let is_newer_schema_refusal = |result: Result<_, DatabaseOpeningError>| {
+            matches!(
+                result,
+                Err(DatabaseOpeningError::ConfigValidation(
+                    DatabaseConfigValidationError::NewerSchemaVersion {
+                        persisted: 2,
+                        supported: 1
+                    }
+                ))
+            )
+        };
+        assert!(is_newer_schema_refusal(
+            ActualStateManagerDatabase::try_new_read_only(db_path.clone())
+        ));
+        assert!(is_newer_schema_refusal(
+            ActualStateManagerDatabase::try_new_for_maintenance(db_path.clone())
+        ));
+        assert!(is_newer_schema_refusal(
+            ActualStateManagerDatabase::try_new_as_secondary(db_path, tmp.path().join("secondary"))
+        ));
+    }
+}
@@ -64,37 +64,61 @@
 
 use crate::prelude::*;
 
-pub use rocks_db::{ActualStateManagerDatabase, StateManagerDatabase};
+pub use rocks_db::{ActualStateManagerDatabase, DatabaseOpeningError, StateManagerDatabase};
 pub use traits::*;
+use tuning::DatabaseTuningProfile;
 
+pub mod accu_tree_proofs;
+pub mod checksummed_records;
 mod codecs;
 pub mod column_families;
+pub mod consistency_check;
+pub mod db_checkpoints;
+pub mod gc_control;
 pub mod historical_state;
 pub mod jmt_gc;
+pub mod ledger_archive;
+pub mod ledger_diff;
+pub mod ledger_rollback;
+pub mod ledger_transactions_gc;
+pub mod migrations;
 pub mod proofs_gc;
+pub mod resource_change_index;
 pub mod rocks_db;
+pub mod secondary_indices;
+pub mod state_snapshot;
+pub mod state_tree_proofs;
 mod traits;
+pub mod tuning;
 
 #[derive(Debug, Clone, Sbor)]
 pub struct DatabaseBackendConfig {
     pub rocks_db_path: String,
+    /// A per-column-family tuning of the RocksDB options (if not set, the RocksDB's defaults are
+    /// used for all column families).
+    pub tuning_profile: Option<DatabaseTuningProfile>,
+    /// Whether to use a non-persistent, in-memory database instead of a RocksDB (in which case
+    /// the [`Self::rocks_db_path`] and [`Self::tuning_profile`] are ignored).
+    /// This is meant only for tests and ephemeral nodes (e.g. it does not support DB checkpoints).
+    pub in_memory: bool,
 }
 
 /// A synchronous collector of costly (I/O-intensive) raw DB metrics.
 pub struct RawDbMetricsCollector {
     database: Arc<DbLock<ActualStateManagerDatabase>>,
-    raw_db_metrics: RawDbMetrics,
+    raw_db_metrics: Arc<RawDbMetrics>,
 }
 
 impl RawDbMetricsCollector {
-    /// Creates a collector measuring the given DB and updating the metrics in the given registry.
+    /// Creates a collector measuring the given DB and updating the given metrics (which are shared
+    /// with the GCs, reporting their reclaimed space).
     pub fn new(
         database: Arc<DbLock<ActualStateManagerDatabase>>,
-        metric_registry: &MetricRegistry,
+        raw_db_metrics: Arc<RawDbMetrics>,
     ) -> Self {
         Self {
             database,
-            raw_db_metrics: RawDbMetrics::new(metric_registry),
+            raw_db_metrics,
         }
     }
 
@@ -73,6 +73,8 @@ pub struct LedgerProofsGcConfig {
     // TODO(after having some event-driven Rust infra): The entire `LedgerProofsGc` could be
     // migrated away from `Scheduler` into `EventListener<EpochChangeCommittedEvent>` (as noted
     // above - it wants to run async exactly once after each epoch).
+    /// A value of 0 disables the periodic runs (i.e. the GC then only runs on demand - see
+    /// `GcControl::request_run()`).
     pub interval_sec: u32,
     /// How many most recent *completed* epochs should be left not GC-ed.
     /// Please note that the current epoch is never GC-ed.
@@ -83,9 +85,13 @@ pub struct LedgerProofsGcConfig {
 /// A ledger proof is "non-critical" when it is not listed by our "get transactions with their
 /// proof" logic (used e.g. for ledger-sync responses).
 /// The implementation is suited for being driven by an external scheduler.
+///
+/// The proofs are deleted with a single range delete per epoch (see
+/// [`LedgerProofsGcStore::delete_ledger_proofs_ranges()`]), and each run ends with a targeted
+/// compaction of the pruned range.
 pub struct LedgerProofsGc {
     database: Arc<DbLock<ActualStateManagerDatabase>>,
-    interval: Duration,
+    raw_db_metrics: Arc<RawDbMetrics>,
     most_recent_full_resolution_epoch_count: u64,
     limits_config: LedgerSyncLimitsConfig,
 }
@@ -96,23 +102,18 @@ impl LedgerProofsGc {
         database: Arc<DbLock<ActualStateManagerDatabase>>,
         gc_config: LedgerProofsGcConfig,
         limits_config: LedgerSyncLimitsConfig,
+        raw_db_metrics: Arc<RawDbMetrics>,
     ) -> Self {
         Self {
             database,
-            interval: Duration::from_secs(u64::from(gc_config.interval_sec)),
-            most_recent_full_resolution_epoch_count: u64::try_from(
-                gc_config.most_recent_full_resolution_epoch_count,
-            )
-            .unwrap(),
+            raw_db_metrics,
+            most_recent_full_resolution_epoch_count: gc_config
+                .most_recent_full_resolution_epoch_count
+                as u64,
             limits_config,
         }
     }
 
-    /// An interval between [`run()`]s, to be used by this instance's scheduler.
-    pub fn interval(&self) -> Duration {
-        self.interval
-    }
-
     /// Performs a single GC run, which is supposed to permanently delete *all* non-critical ledger
     /// proofs of configured old-enough epochs.
     /// Returns proof ranges that have been pruned.
@@ -160,16 +161,17 @@ impl LedgerProofsGc {
         let mut last_pruned_state_version = progress_started_at.epoch_proof_state_version;
 
         let mut pruned_proof_ranges = vec![];
+        let mut epoch_delete_ranges = vec![]; // deleted at once, when the epoch is complete
         let mut retained_proofs = 0; // only for logging purposes
         while let Some(next_prune_range) = Self::locate_next_prune_range(
             database.deref(),
             last_pruned_state_version,
             &self.limits_config,
         ) {
-            database.delete_ledger_proofs_range(
+            epoch_delete_ranges.push((
                 next_prune_range.from_state_version_inclusive,
                 next_prune_range.to_state_version_exclusive(),
-            );
+            ));
             pruned_proof_ranges.push(next_prune_range.clone());
             last_pruned_state_version = next_prune_range.to_state_version_exclusive();
 
@@ -184,6 +186,8 @@ impl LedgerProofsGc {
                     retained_proofs
                 );
                 retained_proofs = 0;
+                database.delete_ledger_proofs_ranges(&epoch_delete_ranges);
+                epoch_delete_ranges.clear();
                 database.set_progress(LedgerProofsGcProgressV1 {
                     last_pruned_epoch: retained_header.epoch,
                     epoch_proof_state_version: last_pruned_state_version,
@@ -194,6 +198,25 @@ impl LedgerProofsGc {
             }
         }
 
+        // The ranges of a not-yet-complete epoch can be deleted too (their progress is simply not
+        // recorded, so a next run will re-process them as no-ops):
+        database.delete_ledger_proofs_ranges(&epoch_delete_ranges);
+
+        if last_pruned_state_version > progress_started_at.epoch_proof_state_version {
+            let reclaimed = database.compact_ledger_proofs_range(
+                progress_started_at.epoch_proof_state_version,
+                last_pruned_state_version,
+            );
+            info!(
+                "Compacted the pruned ledger proofs' range; reclaimed {} bytes",
+                reclaimed
+                    .iter()
+                    .map(|category| category.reclaimed_bytes)
+                    .sum::<usize>()
+            );
+            self.raw_db_metrics.record_reclaimed(reclaimed);
+        }
+
         info!("Ledger proofs' GC run finished");
         pruned_proof_ranges
     }
@@ -281,12 +304,61 @@ impl ProofPruneRange {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test::{commit_round_updates_until_epoch, create_bootstrapped_state_manager};
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager,
+        prepare_and_commit_round_update,
+    };
+
+    #[test]
+    fn delete_ledger_proofs_ranges_retains_proofs_between_ranges() {
+        let mut config = StateManagerConfig::new_for_testing_in_memory();
+        // Disable scheduled proof GC
+        config.ledger_proofs_gc_config = LedgerProofsGcConfig {
+            interval_sec: 0,
+            most_recent_full_resolution_epoch_count: 0,
+        };
+        let state_manager =
+            create_bootstrapped_state_manager(config, BabylonSettings::test_default());
+        for _ in 0..10 {
+            prepare_and_commit_round_update(&state_manager);
+        }
+
+        let database = state_manager.database.access_direct();
+        let all_proofs = database
+            .get_proof_iter(StateVersion::pre_genesis())
+            .collect::<Vec<_>>();
+        let state_versions = all_proofs
+            .iter()
+            .map(|proof| proof.ledger_header.state_version)
+            .collect::<Vec<_>>();
+        // Two ranges with proofs in between them (and after them), which a single, spanning
+        // range delete would also cover:
+        let ranges = [
+            (state_versions[1], state_versions[4]),
+            (state_versions[6], state_versions[9]),
+        ];
+
+        database.delete_ledger_proofs_ranges(&ranges);
+
+        let remaining_proofs = database
+            .get_proof_iter(StateVersion::pre_genesis())
+            .collect::<Vec<_>>();
+        let expected_proofs = all_proofs
+            .into_iter()
+            .filter(|proof| {
+                let state_version = proof.ledger_header.state_version;
+                !ranges
+                    .iter()
+                    .any(|(from, to)| *from <= state_version && state_version < *to)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(remaining_proofs.len(), state_versions.len() - 6);
+        assert_eq!(remaining_proofs, expected_proofs);
+    }
 
     #[test]
     fn test_retain_protocol_update_proofs() {
-        let tmp = tempfile::tempdir().unwrap();
-        let mut config = StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap());
+        let mut config = StateManagerConfig::new_for_testing_in_memory();
         // Disable scheduled proof GC
         config.ledger_proofs_gc_config = LedgerProofsGcConfig {
             interval_sec: 0,
@@ -330,7 +402,7 @@ mod tests {
         // We're using our own GC instance in this test
         let gc = LedgerProofsGc {
             database: db.clone(),
-            interval: Duration::MAX,
+            raw_db_metrics: Arc::new(RawDbMetrics::new(&MetricRegistry::new())),
             most_recent_full_resolution_epoch_count: 2,
             limits_config: sync_limits_config.clone(),
         };
@@ -0,0 +1,233 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+use std::time::Duration;
+
+/// A background process populating the resource change index after it was enabled on an
+/// already-populated ledger (which would otherwise block the Node's boot-up for a long time).
+/// The implementation is suited for being driven by an external scheduler.
+pub struct ResourceChangeIndexCatchup {
+    database: Arc<DbLock<ActualStateManagerDatabase>>,
+}
+
+impl ResourceChangeIndexCatchup {
+    const INTERVAL: Duration = Duration::from_secs(10);
+    const TRANSACTION_BATCH_SIZE: usize = 10_000;
+
+    /// Creates a new catch-up process.
+    pub fn new(database: Arc<DbLock<ActualStateManagerDatabase>>) -> Self {
+        Self { database }
+    }
+
+    /// An interval between [`run()`]s, to be used by this instance's scheduler.
+    pub fn interval(&self) -> Duration {
+        Self::INTERVAL
+    }
+
+    /// Performs a single catch-up run, which processes the locally-executed transactions in
+    /// batches, until the index reaches the top of the ledger.
+    ///
+    /// *Note on concurrent database access:*
+    /// Each batch is processed under the [`DbLock::lock()`], so that it cannot interleave with a
+    /// commit (which maintains the already caught-up index on its own).
+    pub fn run(&self) {
+        loop {
+            let caught_up = self
+                .database
+                .lock()
+                .catchup_resource_change_index(Self::TRANSACTION_BATCH_SIZE);
+            if caught_up {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager(
+        path: &std::path::Path,
+        enable_resource_change_index: bool,
+    ) -> StateManager {
+        let mut config = StateManagerConfig::new_for_testing(path.to_str().unwrap());
+        config.database_config.enable_resource_change_index = enable_resource_change_index;
+        create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+    }
+
+    fn assert_xrd_changes_indexed(database: &ActualStateManagerDatabase) {
+        let expected_state_versions = (1..=database.max_state_version().number())
+            .map(StateVersion::of)
+            .filter(|state_version| {
+                database
+                    .get_committed_local_transaction_execution(*state_version)
+                    .unwrap()
+                    .global_balance_summary
+                    .global_balance_changes
+                    .values()
+                    .any(|balance_changes| balance_changes.contains_key(&XRD))
+            })
+            .collect::<Vec<_>>();
+        let indexed_state_versions = database
+            .get_state_versions_for_resource_iter(XRD, StateVersion::pre_genesis())
+            .collect::<Vec<_>>();
+        assert!(!expected_state_versions.is_empty());
+        assert_eq!(indexed_state_versions, expected_state_versions);
+    }
+
+    #[test]
+    fn index_is_disabled_by_default() {
+        assert!(!DatabaseConfig::default().enable_resource_change_index);
+    }
+
+    #[test]
+    fn index_requires_local_transaction_execution_index() {
+        let config = DatabaseConfig {
+            enable_local_transaction_execution_index: false,
+            enable_account_change_index: false,
+            enable_resource_change_index: true,
+            ..DatabaseConfig::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(DatabaseConfigValidationError::ResourceChangeIndexRequiresLocalTransactionExecutionIndex)
+        ));
+
+        let config = DatabaseConfig {
+            enable_resource_change_index: true,
+            ..DatabaseConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn enabled_index_is_maintained_on_commit() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), true);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_resource_change_index_last_processed_state_version(),
+            Some(database.max_state_version())
+        );
+        assert_xrd_changes_indexed(database.deref());
+    }
+
+    #[test]
+    fn index_enabled_retroactively_is_caught_up_in_background() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), false);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        drop(state_manager);
+
+        let state_manager = create_state_manager(tmp.path(), true);
+        // The lagging index is not maintained on commit...
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+        assert_eq!(
+            state_manager
+                .database
+                .access_direct()
+                .get_resource_change_index_last_processed_state_version(),
+            Some(StateVersion::pre_genesis())
+        );
+
+        // ... until the background process catches it up:
+        ResourceChangeIndexCatchup::new(state_manager.database.clone()).run();
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(5));
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_resource_change_index_last_processed_state_version(),
+            Some(database.max_state_version())
+        );
+        assert_xrd_changes_indexed(database.deref());
+    }
+
+    #[test]
+    fn index_disabled_retroactively_is_pruned() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), true);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        drop(state_manager);
+
+        let state_manager = create_state_manager(tmp.path(), false);
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_resource_change_index_last_processed_state_version(),
+            None
+        );
+        assert_eq!(
+            database
+                .get_state_versions_for_resource_iter(XRD, StateVersion::pre_genesis())
+                .count(),
+            0
+        );
+    }
+}
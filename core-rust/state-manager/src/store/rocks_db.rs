@@ -66,7 +66,10 @@ use crate::prelude::*;
 
 use crate::store::column_families::*;
 use crate::store::historical_state::StateTreeBasedSubstateDatabase;
+use crate::store::migrations::*;
+use crate::store::secondary_indices::SecondaryIndexRegistry;
 use crate::store::traits::*;
+use crate::store::tuning::{ColumnFamilyOptionsFactory, DatabaseTuningProfile};
 use rocksdb::*;
 
 /// A listing of all column family names used by the Node.
@@ -93,7 +96,7 @@ use rocksdb::*;
 /// ## Ordering
 ///
 /// The order of the list is not significant.
-const ALL_STATE_MANAGER_COLUMN_FAMILIES: [&str; 26] = [
+const ALL_STATE_MANAGER_COLUMN_FAMILIES: [&str; 29] = [
     RawLedgerTransactionsCf::NAME,
     CommittedTransactionIdentifiersCf::NAME,
     TransactionReceiptsCf::NAME,
@@ -111,10 +114,13 @@ const ALL_STATE_MANAGER_COLUMN_FAMILIES: [&str; 26] = [
     VertexStoreCf::NAME,
     StateTreeNodesCf::NAME,
     StaleStateTreePartsCf::NAME,
+    RetainedStaleStateTreePartsCf::NAME,
+    StateHistoryCheckpointsCf::NAME,
     TransactionAccuTreeSlicesCf::NAME,
     ReceiptAccuTreeSlicesCf::NAME,
     ExtensionsDataCf::NAME,
     AccountChangeStateVersionsCf::NAME,
+    ResourceChangeStateVersionsCf::NAME,
     ExecutedScenariosCf::NAME,
     LedgerProofsGcProgressCf::NAME,
     AssociatedStateTreeValuesCf::NAME,
@@ -122,10 +128,18 @@ const ALL_STATE_MANAGER_COLUMN_FAMILIES: [&str; 26] = [
     BlueprintAndCreationIndexedObjectsCf::NAME,
 ];
 
-pub type ActualStateManagerDatabase = StateManagerDatabase<DirectRocks>;
+/// A minimum number of adjacent keys deleted by a GC which are worth a single range delete (rather
+/// than individual point deletes, which may leave a "tombstone storm" degrading the reads).
+const MIN_COALESCED_DELETE_RUN_LEN: usize = 16;
 
-impl<'db> Snapshottable<'db> for StateManagerDatabase<DirectRocks> {
-    type Snapshot = StateManagerDatabase<SnapshotRocks<'db>>;
+/// The [`StateManagerDatabase`] used by the Node's components.
+///
+/// Its backend is chosen at construction: a RocksDB (see e.g. [`Self::new()`]) or a non-persistent
+/// in-memory one (see [`Self::new_in_memory()`]).
+pub type ActualStateManagerDatabase = StateManagerDatabase<ConfigurableRocks>;
+
+impl<'db, R: WriteableRocks + 'db> Snapshottable<'db> for StateManagerDatabase<R> {
+    type Snapshot = StateManagerDatabase<R::Snapshot<'db>>;
 
     // TODO(potential performance gain): This is the place where we could use a cached snapshot
     // instead of creating a new one. There are a few options: e.g. cache on-demand (after
@@ -135,15 +149,20 @@ impl<'db> Snapshottable<'db> for StateManagerDatabase<DirectRocks> {
     // become a performance problem, but only at rates way above our use-cases (i.e. >10K snapshots
     // per second).
     fn snapshot(&'db self) -> Self::Snapshot {
-        let StateManagerDatabase { config, rocks } = self;
+        let StateManagerDatabase {
+            config,
+            secondary_indices,
+            rocks,
+        } = self;
         StateManagerDatabase {
             config: config.clone(),
+            secondary_indices: secondary_indices.clone(),
             rocks: rocks.snapshot(),
         }
     }
 }
 
-/// A RocksDB-backed persistence layer for state manager.
+/// A persistence layer for state manager, backed by a RocksDB (or an in-memory equivalent).
 pub struct StateManagerDatabase<R> {
     /// Database config.
     ///
@@ -151,39 +170,139 @@ pub struct StateManagerDatabase<R> {
     /// during the state manager's lifetime. This field only acts as a cache.
     config: DatabaseConfig,
 
+    /// The enabled user-defined indices, resolved from [`DatabaseConfig::enabled_secondary_indices`].
+    secondary_indices: Vec<Arc<dyn SecondaryIndex>>,
+
     /// Underlying RocksDB instance.
     rocks: R,
 }
 
+/// An error of opening an existing database in one of the special modes (i.e. read-only, as a
+/// secondary instance or for a maintenance).
+#[derive(Debug)]
+pub enum DatabaseOpeningError {
+    Rocks(rocksdb::Error),
+    ConfigValidation(DatabaseConfigValidationError),
+}
+
+impl From<rocksdb::Error> for DatabaseOpeningError {
+    fn from(error: rocksdb::Error) -> Self {
+        DatabaseOpeningError::Rocks(error)
+    }
+}
+
+impl From<DatabaseConfigValidationError> for DatabaseOpeningError {
+    fn from(error: DatabaseConfigValidationError) -> Self {
+        DatabaseOpeningError::ConfigValidation(error)
+    }
+}
+
+impl fmt::Display for DatabaseOpeningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseOpeningError::Rocks(error) => write!(f, "{error}"),
+            DatabaseOpeningError::ConfigValidation(error) => write!(f, "{error:?}"),
+        }
+    }
+}
+
 impl ActualStateManagerDatabase {
     pub fn new(
         root_path: PathBuf,
         config: DatabaseConfig,
         network: &NetworkDefinition,
     ) -> Result<Self, DatabaseConfigValidationError> {
+        Self::new_with_tuning_profile(
+            root_path,
+            config,
+            None,
+            &DatabaseMigrationMetrics::new(&MetricRegistry::new()),
+            network,
+        )
+    }
+
+    /// Creates a [`StateManagerDatabase`] with column families tuned according to the given
+    /// profile (or using the RocksDB's defaults, if `None`).
+    /// The progress of any [`DatabaseMigration`]s run at opening is reported to the given metrics.
+    pub fn new_with_tuning_profile(
+        root_path: PathBuf,
+        config: DatabaseConfig,
+        tuning_profile: Option<DatabaseTuningProfile>,
+        migration_metrics: &DatabaseMigrationMetrics,
+        network: &NetworkDefinition,
+    ) -> Result<Self, DatabaseConfigValidationError> {
+        Self::new_with_secondary_index_registry(
+            root_path,
+            config,
+            tuning_profile,
+            &SecondaryIndexRegistry::default(),
+            migration_metrics,
+            network,
+        )
+    }
+
+    /// Creates a [`StateManagerDatabase`] capable of maintaining any [`SecondaryIndex`] from the
+    /// given registry (i.e. not only the built-in ones).
+    pub fn new_with_secondary_index_registry(
+        root_path: PathBuf,
+        config: DatabaseConfig,
+        tuning_profile: Option<DatabaseTuningProfile>,
+        secondary_index_registry: &SecondaryIndexRegistry,
+        migration_metrics: &DatabaseMigrationMetrics,
+        network: &NetworkDefinition,
+    ) -> Result<Self, DatabaseConfigValidationError> {
+        let secondary_indices =
+            secondary_index_registry.resolve(&config.enabled_secondary_indices)?;
+
         let mut db_opts = Options::default();
         db_opts.create_if_missing(true);
         db_opts.create_missing_column_families(true);
 
-        let column_families: Vec<ColumnFamilyDescriptor> = ALL_STATE_MANAGER_COLUMN_FAMILIES
+        // RocksDB requires us to open all existing column families - including the ones of the
+        // secondary indices which got disabled (and which we then drop):
+        let enabled_secondary_index_cf_names = secondary_indices
             .iter()
-            .map(|cf| ColumnFamilyDescriptor::new(cf.to_string(), Options::default()))
+            .map(|index| SecondaryIndexCf::of(index.name()).name().to_string())
+            .collect::<IndexSet<_>>();
+        let disabled_secondary_index_cf_names = DB::list_cf(&db_opts, root_path.as_path())
+            .unwrap_or_default() // i.e. a fresh database
+            .into_iter()
+            .filter(|name| name.starts_with(SecondaryIndexCf::NAME))
+            .filter(|name| !enabled_secondary_index_cf_names.contains(name))
+            .collect::<Vec<_>>();
+
+        let cf_options_factory = ColumnFamilyOptionsFactory::new(tuning_profile);
+        let enabled_cf_names = ALL_STATE_MANAGER_COLUMN_FAMILIES
+            .iter()
+            .map(|cf| cf.to_string())
+            .chain(enabled_secondary_index_cf_names)
+            .collect::<Vec<_>>();
+        let column_families: Vec<ColumnFamilyDescriptor> = enabled_cf_names
+            .iter()
+            .cloned()
+            .chain(disabled_secondary_index_cf_names.iter().cloned())
+            .map(|cf| {
+                let options = cf_options_factory.create(&cf);
+                ColumnFamilyDescriptor::new(cf, options)
+            })
             .collect();
 
-        let db = DB::open_cf_descriptors(&db_opts, root_path.as_path(), column_families).unwrap();
+        let mut db =
+            DB::open_cf_descriptors(&db_opts, root_path.as_path(), column_families).unwrap();
+        for cf_name in disabled_secondary_index_cf_names {
+            info!(
+                "Dropping the column family of a disabled secondary index: {}",
+                cf_name
+            );
+            db.drop_cf(&cf_name).unwrap();
+        }
 
         let state_manager_database = StateManagerDatabase {
             config,
-            rocks: DirectRocks { db },
+            secondary_indices,
+            rocks: ConfigurableRocks::rocks_db(DirectRocks { db }, enabled_cf_names),
         };
-
-        state_manager_database.validate_and_persist_new_config()?;
-
-        state_manager_database.catchup_account_change_index();
-        state_manager_database.restore_december_2023_lost_substates(network);
-        state_manager_database.ensure_historical_substate_values();
-        state_manager_database.ensure_entity_listing_indices();
-
+        state_manager_database.initialize(network, migration_metrics)?;
         Ok(state_manager_database)
     }
 
@@ -198,6 +317,14 @@ impl ActualStateManagerDatabase {
     ///
     /// [`ledger-tools`]: https://github.com/radixdlt/ledger-tools
     pub fn new_read_only(root_path: PathBuf) -> Self {
+        Self::try_new_read_only(root_path).unwrap()
+    }
+
+    /// A fallible variant of [`Self::new_read_only()`], useful e.g. for verifying that a DB
+    /// checkpoint opens cleanly.
+    ///
+    /// Returns an error if the database was written by a newer schema than this Node supports.
+    pub fn try_new_read_only(root_path: PathBuf) -> Result<Self, DatabaseOpeningError> {
         let mut db_opts = Options::default();
         db_opts.create_if_missing(false);
         db_opts.create_missing_column_families(false);
@@ -212,32 +339,99 @@ impl ActualStateManagerDatabase {
             root_path.as_path(),
             column_families,
             false,
-        )
-        .unwrap();
+        )?;
 
-        StateManagerDatabase {
+        let mut state_manager_database = StateManagerDatabase {
             config: DatabaseConfig {
                 enable_local_transaction_execution_index: false,
                 enable_account_change_index: false,
                 enable_historical_substate_values: false,
                 enable_entity_listing_indices: false,
+                enable_resource_change_index: false,
+                enabled_secondary_indices: vec![],
+                ledger_transaction_retention_epoch_count: None,
             },
-            rocks: DirectRocks { db },
-        }
+            secondary_indices: vec![],
+            rocks: ConfigurableRocks::rocks_db(
+                DirectRocks { db },
+                ALL_STATE_MANAGER_COLUMN_FAMILIES,
+            ),
+        };
+
+        state_manager_database.check_schema_version_supported(
+            DatabaseMigrationRegistry::<ConfigurableRocks>::default().schema_version(),
+        )?;
+        state_manager_database.reflect_persisted_index_settings();
+
+        Ok(state_manager_database)
+    }
+
+    /// Opens an existing [`StateManagerDatabase`] for an offline maintenance (e.g. by the
+    /// `ledger_rollback` tool), which writes to it directly.
+    ///
+    /// Contrary to the regular constructors, this one does not initialize the database according
+    /// to any configuration (i.e. it does not touch any index); instead, it reflects the persisted
+    /// index settings (and maintains the secondary indices which have their progress persisted).
+    /// A database written by a newer schema than this Node supports is refused (before any write).
+    pub fn try_new_for_maintenance(root_path: PathBuf) -> Result<Self, DatabaseOpeningError> {
+        let mut db_opts = Options::default();
+        db_opts.create_if_missing(false);
+        db_opts.create_missing_column_families(false);
+
+        // RocksDB requires us to open all existing column families (e.g. of secondary indices):
+        let cf_names = DB::list_cf(&db_opts, root_path.as_path())?;
+        let column_families: Vec<ColumnFamilyDescriptor> = cf_names
+            .iter()
+            .map(|cf| ColumnFamilyDescriptor::new(cf, Options::default()))
+            .collect();
+        let db = DB::open_cf_descriptors(&db_opts, root_path.as_path(), column_families)?;
+
+        let mut state_manager_database = StateManagerDatabase {
+            config: DatabaseConfig {
+                enable_local_transaction_execution_index: false,
+                enable_account_change_index: false,
+                enable_historical_substate_values: false,
+                enable_entity_listing_indices: false,
+                enable_resource_change_index: false,
+                enabled_secondary_indices: vec![],
+                ledger_transaction_retention_epoch_count: None,
+            },
+            secondary_indices: vec![],
+            rocks: ConfigurableRocks::rocks_db(DirectRocks { db }, cf_names),
+        };
+
+        state_manager_database.check_schema_version_supported(
+            DatabaseMigrationRegistry::<ConfigurableRocks>::default().schema_version(),
+        )?;
+        state_manager_database.reflect_persisted_index_settings();
+        let secondary_index_registry = SecondaryIndexRegistry::default();
+        let secondary_index_names = state_manager_database
+            .read_secondary_indices_progress()
+            .last_processed_state_versions
+            .into_keys()
+            .filter(|name| secondary_index_registry.resolve(&[name.clone()]).is_ok())
+            .collect::<Vec<_>>();
+        state_manager_database.secondary_indices = secondary_index_registry
+            .resolve(&secondary_index_names)
+            .expect("only known indices");
+        state_manager_database.config.enabled_secondary_indices = secondary_index_names;
+
+        Ok(state_manager_database)
     }
 
-    /// Creates a [`StateManagerDatabase`] as a secondary instance which may catch up with the
-    /// primary.
-    pub fn new_as_secondary(
+    /// Opens a [`StateManagerDatabase`] as a secondary instance of the (live) primary DB at
+    /// `root_path`, which may catch up with the primary (see [`Self::try_catchup_with_primary()`]).
+    /// The `temp_path` is a directory private to this instance, where RocksDB keeps its info logs.
+    /// A primary DB written by a newer schema than this Node supports is refused.
+    pub fn try_new_as_secondary(
         root_path: PathBuf,
         temp_path: PathBuf,
-        column_families: Vec<&str>,
-    ) -> Self {
+    ) -> Result<Self, DatabaseOpeningError> {
         let mut db_opts = Options::default();
         db_opts.create_if_missing(false);
         db_opts.create_missing_column_families(false);
 
-        let column_families: Vec<ColumnFamilyDescriptor> = column_families
+        let column_families: Vec<ColumnFamilyDescriptor> = ALL_STATE_MANAGER_COLUMN_FAMILIES
             .iter()
             .map(|cf| ColumnFamilyDescriptor::new(cf.to_string(), Options::default()))
             .collect();
@@ -247,23 +441,68 @@ impl ActualStateManagerDatabase {
             root_path.as_path(),
             temp_path.as_path(),
             column_families,
-        )
-        .unwrap();
+        )?;
 
-        StateManagerDatabase {
+        let mut state_manager_database = StateManagerDatabase {
             config: DatabaseConfig {
                 enable_local_transaction_execution_index: false,
                 enable_account_change_index: false,
                 enable_historical_substate_values: false,
                 enable_entity_listing_indices: false,
+                enable_resource_change_index: false,
+                enabled_secondary_indices: vec![],
+                ledger_transaction_retention_epoch_count: None,
             },
-            rocks: DirectRocks { db },
-        }
+            secondary_indices: vec![],
+            rocks: ConfigurableRocks::rocks_db(
+                DirectRocks { db },
+                ALL_STATE_MANAGER_COLUMN_FAMILIES,
+            ),
+        };
+
+        state_manager_database.check_schema_version_supported(
+            DatabaseMigrationRegistry::<ConfigurableRocks>::default().schema_version(),
+        )?;
+        state_manager_database.reflect_persisted_index_settings();
+
+        Ok(state_manager_database)
     }
 
     pub fn try_catchup_with_primary(&self) {
         self.rocks.try_catchup_with_primary();
     }
+
+    /// Creates an empty, non-persistent [`StateManagerDatabase`], capable of maintaining any
+    /// [`SecondaryIndex`] from the given registry.
+    ///
+    /// Apart from the lack of durability (and of RocksDB-specific features, like checkpoints or
+    /// raw file measurements), it behaves exactly like the RocksDB-backed one.
+    pub fn new_in_memory(
+        config: DatabaseConfig,
+        secondary_index_registry: &SecondaryIndexRegistry,
+        network: &NetworkDefinition,
+    ) -> Result<Self, DatabaseConfigValidationError> {
+        let secondary_indices =
+            secondary_index_registry.resolve(&config.enabled_secondary_indices)?;
+        let column_families = ALL_STATE_MANAGER_COLUMN_FAMILIES
+            .iter()
+            .map(|cf| cf.to_string())
+            .chain(
+                secondary_indices
+                    .iter()
+                    .map(|index| SecondaryIndexCf::of(index.name()).name().to_string()),
+            );
+        let state_manager_database = StateManagerDatabase {
+            config,
+            secondary_indices,
+            rocks: ConfigurableRocks::in_memory(column_families),
+        };
+        state_manager_database.initialize(
+            network,
+            &DatabaseMigrationMetrics::new(&MetricRegistry::new()),
+        )?;
+        Ok(state_manager_database)
+    }
 }
 
 impl<R: ReadableRocks> StateManagerDatabase<R> {
@@ -271,21 +510,30 @@ impl<R: ReadableRocks> StateManagerDatabase<R> {
     fn open_read_context(&self) -> TypedDbContext<R, NoWriteSupport> {
         TypedDbContext::new(&self.rocks, NoWriteSupport)
     }
-}
-
-impl<R: WriteableRocks> StateManagerDatabase<R> {
-    /// Starts a read/buffered-write interaction with the DB through per-CF type-safe APIs.
-    fn open_rw_context(&self) -> TypedDbContext<R, BufferedWriteSupport<R>> {
-        TypedDbContext::new(&self.rocks, BufferedWriteSupport::new(&self.rocks))
-    }
-}
 
-impl<R: WriteableRocks> StateManagerDatabase<R> {
-    fn validate_and_persist_new_config(&self) -> Result<(), DatabaseConfigValidationError> {
+    /// Reflects the persisted index settings in the [`DatabaseConfig`], so that a non-writing
+    /// reader may rely on the indices which are actually maintained (e.g. iterate over entire
+    /// committed transaction bundles).
+    fn reflect_persisted_index_settings(&mut self) {
         let stored_config_state = self.read_config_state();
-        self.config.validate(&stored_config_state)?;
-        self.write_config();
-        Ok(())
+        self.config.enable_local_transaction_execution_index = stored_config_state
+            .local_transaction_execution_index_enabled
+            .unwrap_or(false);
+        self.config.enable_account_change_index = stored_config_state
+            .account_change_index_enabled
+            .unwrap_or(false);
+        // The resource change index's progress marker only exists while the index is enabled:
+        self.config.enable_resource_change_index = self
+            .open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion)
+            .is_some();
+        // Similarly, the historical Substate values are readable only if their backfill succeeded:
+        self.config.enable_historical_substate_values = self
+            .open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::StateTreeAssociatedValuesStatus)
+            .is_some();
     }
 
     fn read_config_state(&self) -> DatabaseConfigState {
@@ -303,6 +551,235 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
         }
     }
 
+    fn read_database_schema_status(&self) -> Option<DatabaseSchemaStatus> {
+        self.open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::DatabaseSchemaStatus)
+            .map(|bytes| {
+                scrypto_decode_with_nice_error::<VersionedDatabaseSchemaStatus>(&bytes)
+                    .unwrap()
+                    .fully_update_and_into_latest_version()
+            })
+    }
+
+    /// Refuses a database written by a newer schema (i.e. with more migrations applied) than the
+    /// given supported one.
+    /// Contrary to [`Self::run_migrations()`], this never writes anything.
+    pub(crate) fn check_schema_version_supported(
+        &self,
+        supported_schema_version: u32,
+    ) -> Result<(), DatabaseConfigValidationError> {
+        let Some(status) = self.read_database_schema_status() else {
+            return Ok(()); // a fresh database, or one older than the migrations
+        };
+        let persisted_schema_version = BASELINE_DATABASE_SCHEMA_VERSION
+            + u32::try_from(status.applied_migrations.len()).unwrap();
+        if persisted_schema_version > supported_schema_version {
+            return Err(DatabaseConfigValidationError::NewerSchemaVersion {
+                persisted: persisted_schema_version,
+                supported: supported_schema_version,
+            });
+        }
+        Ok(())
+    }
+
+    fn read_secondary_indices_progress(&self) -> SecondaryIndicesProgress {
+        self.open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::SecondaryIndicesProgress)
+            .map(|bytes| {
+                scrypto_decode_with_nice_error::<VersionedSecondaryIndicesProgress>(&bytes)
+                    .unwrap()
+                    .fully_update_and_into_latest_version()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl<R: WriteableRocks> StateManagerDatabase<R> {
+    /// Starts a read/buffered-write interaction with the DB through per-CF type-safe APIs.
+    fn open_rw_context(&self) -> TypedDbContext<R, BufferedWriteSupport<R>> {
+        TypedDbContext::new(&self.rocks, BufferedWriteSupport::new(&self.rocks))
+    }
+}
+
+impl<R: WriteableRocks> StateManagerDatabase<R> {
+    /// Brings a freshly-opened database in line with its configuration (i.e. migrates it to the
+    /// current schema, validates and persists the config, catches up or prunes the optional
+    /// indices, and applies fixups).
+    fn initialize(
+        &self,
+        network: &NetworkDefinition,
+        migration_metrics: &DatabaseMigrationMetrics,
+    ) -> Result<(), DatabaseConfigValidationError> {
+        self.run_migrations(&DatabaseMigrationRegistry::default(), migration_metrics)?;
+        self.validate_and_persist_new_config()?;
+
+        self.catchup_account_change_index();
+        self.ensure_resource_change_index();
+        self.restore_december_2023_lost_substates(network);
+        self.ensure_historical_substate_values();
+        self.ensure_entity_listing_indices();
+        self.ensure_secondary_indices();
+        Ok(())
+    }
+
+    /// Applies all the pending migrations from the given registry, in order (first resuming the one
+    /// interrupted in the middle, if any).
+    ///
+    /// A fresh database is simply marked as being in the registry's latest schema, and a database
+    /// written by a newer schema (i.e. with more migrations applied) is refused.
+    pub fn run_migrations(
+        &self,
+        registry: &DatabaseMigrationRegistry<R>,
+        metrics: &DatabaseMigrationMetrics,
+    ) -> Result<(), DatabaseConfigValidationError> {
+        let mut status = match self.read_database_schema_status() {
+            Some(status) => status,
+            None if self.get_latest_proof().is_none() => {
+                let status = DatabaseSchemaStatus {
+                    applied_migrations: registry.names().into_iter().map(String::from).collect(),
+                    in_progress_migration: None,
+                };
+                self.write_database_schema_status(&self.open_rw_context(), &status);
+                metrics
+                    .schema_version
+                    .set(i64::from(registry.schema_version()));
+                return Ok(());
+            }
+            None => DatabaseSchemaStatus::default(), // a database older than the migrations
+        };
+
+        self.check_schema_version_supported(registry.schema_version())?;
+        let applied_count = status.applied_migrations.len();
+        let persisted_schema_version =
+            BASELINE_DATABASE_SCHEMA_VERSION + u32::try_from(applied_count).unwrap();
+        for (applied, registered) in status.applied_migrations.iter().zip(registry.names()) {
+            if applied != registered {
+                panic!(
+                    "database migration history diverged: {} was applied instead of {}",
+                    applied, registered
+                );
+            }
+        }
+
+        let pending_migrations = &registry.migrations()[applied_count..];
+        metrics
+            .schema_version
+            .set(i64::from(persisted_schema_version));
+        metrics
+            .pending_migrations
+            .set(i64::try_from(pending_migrations.len()).unwrap());
+        for migration in pending_migrations {
+            let name = migration.name();
+            let mut cursor = status.in_progress_migration.take().map(|in_progress| {
+                if in_progress.name != name {
+                    panic!(
+                        "interrupted database migration {} is not the next one ({})",
+                        in_progress.name, name
+                    );
+                }
+                in_progress.cursor
+            });
+            info!(
+                "{} database migration {}",
+                if cursor.is_some() {
+                    "Resuming"
+                } else {
+                    "Starting"
+                },
+                name
+            );
+            loop {
+                let db_context = self.open_rw_context();
+                let progress = migration.migrate_batch(&db_context, cursor);
+                // The progress is committed atomically with the batch's own writes:
+                cursor = match progress {
+                    DatabaseMigrationProgress::InProgress { cursor } => {
+                        status.in_progress_migration = Some(InProgressDatabaseMigration {
+                            name: name.to_string(),
+                            cursor: cursor.clone(),
+                        });
+                        Some(cursor)
+                    }
+                    DatabaseMigrationProgress::Completed => {
+                        status.in_progress_migration = None;
+                        status.applied_migrations.push(name.to_string());
+                        None
+                    }
+                };
+                self.write_database_schema_status(&db_context, &status);
+                db_context.flush();
+                metrics.migrated_batches.with_label(name).inc();
+                if cursor.is_none() {
+                    break;
+                }
+            }
+            info!("Finished database migration {}", name);
+            metrics.schema_version.inc();
+            metrics.pending_migrations.dec();
+        }
+        Ok(())
+    }
+
+    fn write_database_schema_status(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        status: &DatabaseSchemaStatus,
+    ) {
+        db_context.cf(ExtensionsDataCf).put(
+            &ExtensionsDataKey::DatabaseSchemaStatus,
+            &scrypto_encode(&VersionedDatabaseSchemaStatus::from(status.clone())).unwrap(),
+        );
+    }
+
+    fn validate_and_persist_new_config(&self) -> Result<(), DatabaseConfigValidationError> {
+        self.config.validate()?;
+        // Note: the index's metadata must be updated before the new config is persisted, so that
+        // an interrupted boot-up is correctly handled on the next attempt.
+        self.ensure_local_transaction_execution_index(&self.read_config_state());
+        self.write_config();
+        Ok(())
+    }
+
+    /// Ensures that the local transaction execution index is consistent with its configured flag,
+    /// when that flag changes on an already-populated database:
+    /// - If the index gets disabled, all its data and metadata is pruned.
+    /// - If the index gets enabled, it becomes available only from the next committed transaction;
+    ///   the older executions have to be backfilled by re-executing them (which is not a concern
+    ///   of this database layer - see [`LocalTransactionExecutionBackfillStore`]).
+    fn ensure_local_transaction_execution_index(&self, stored_config_state: &DatabaseConfigState) {
+        let db_context = self.open_rw_context();
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        let was_enabled = stored_config_state.local_transaction_execution_index_enabled;
+
+        if !self.config.enable_local_transaction_execution_index {
+            if was_enabled == Some(true) {
+                info!("Disabling local transaction execution index");
+            }
+            // Similar to the entity listing indices, we delete everything in a cheap write batch:
+            db_context.cf(LocalTransactionExecutionsCf).delete_all();
+            extension_data_cf.delete(&ExtensionsDataKey::LocalTransactionExecutionIndexStatus);
+            return;
+        }
+
+        if was_enabled == Some(false) {
+            let available_from = self.max_state_version().next().unwrap();
+            info!(
+                "Enabling local transaction execution index from {:?} (past transactions will be backfilled)",
+                available_from
+            );
+            let status = LocalTransactionExecutionIndexStatusV1 {
+                local_transaction_executions_available_from: available_from,
+            };
+            extension_data_cf.put(
+                &ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+                &scrypto_encode(&VersionedLocalTransactionExecutionIndexStatus::from(status))
+                    .unwrap(),
+            );
+        }
+    }
+
     fn write_config(&self) {
         let db_context = self.open_rw_context();
         let extension_data_cf = db_context.cf(ExtensionsDataCf);
@@ -387,6 +864,16 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
             //   in the DB (unreachable, yet never GCed). It is cheap enough to simply ensure that
             //   this table is empty on every history-disabled boot-up.
             db_context.cf(AssociatedStateTreeValuesCf).delete_all();
+            // Similarly, the tree parts retained for the historical state checkpoints (if any) are
+            // handed back to the GC (they were stale before any not-yet-GCed part, so there is
+            // no risk of overwriting anything), and the checkpoints are forgotten:
+            let retained_parts_cf = db_context.cf(RetainedStaleStateTreePartsCf);
+            let stale_parts_cf = db_context.cf(StaleStateTreePartsCf);
+            for (state_version, stale_tree_parts) in retained_parts_cf.iterate(Direction::Forward) {
+                stale_parts_cf.put(&state_version, &stale_tree_parts);
+            }
+            retained_parts_cf.delete_all();
+            db_context.cf(StateHistoryCheckpointsCf).delete_all();
         }
     }
 
@@ -426,6 +913,10 @@ impl<R: ReadableRocks> ConfigurableDatabase for StateManagerDatabase<R> {
         self.config.enable_account_change_index
     }
 
+    fn is_resource_change_index_enabled(&self) -> bool {
+        self.config.enable_resource_change_index
+    }
+
     fn is_local_transaction_execution_index_enabled(&self) -> bool {
         self.config.enable_local_transaction_execution_index
     }
@@ -450,6 +941,25 @@ impl<R: ReadableRocks> ConfigurableDatabase for StateManagerDatabase<R> {
             .expect("state history feature metadata not found")
             .historical_substate_values_available_from
     }
+
+    fn get_first_stored_local_transaction_execution_state_version(&self) -> StateVersion {
+        if !self.config.enable_local_transaction_execution_index {
+            panic!("local transaction execution index is disabled");
+        }
+        self.open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::LocalTransactionExecutionIndexStatus)
+            .map(|bytes| {
+                scrypto_decode_with_nice_error::<VersionedLocalTransactionExecutionIndexStatus>(
+                    &bytes,
+                )
+                .unwrap()
+                .fully_update_and_into_latest_version()
+                .local_transaction_executions_available_from
+            })
+            // The index was enabled since genesis:
+            .unwrap_or_else(|| StateVersion::pre_genesis().next().unwrap())
+    }
 }
 
 impl MeasurableDatabase for ActualStateManagerDatabase {
@@ -463,10 +973,13 @@ impl MeasurableDatabase for ActualStateManagerDatabase {
                 )
             })
             .collect::<IndexMap<_, _>>();
-        let live_files = self.rocks.db.live_files().unwrap_or_else(|err| {
-            warn!("could not get DB live files; returning 0: {:?}", err);
-            Vec::new()
-        });
+        let live_files = match self.rocks.as_rocks_db() {
+            Some(rocks) => rocks.db.live_files().unwrap_or_else(|err| {
+                warn!("could not get DB live files; returning 0: {:?}", err);
+                Vec::new()
+            }),
+            None => Vec::new(), // an in-memory database has no files to measure
+        };
 
         for live_file in live_files {
             let Some(statistic) = statistics.get_mut(&live_file.column_family_name) else {
@@ -503,6 +1016,10 @@ impl<R: WriteableRocks> CommitStore for StateManagerDatabase<R> {
         let commit_ledger_header = &commit_bundle.proof.ledger_header;
         let commit_state_version = commit_ledger_header.state_version;
 
+        let mut secondary_indices_progress = self.read_secondary_indices_progress();
+        let mut resource_change_index_last_processed_state_version = self
+            .is_resource_change_index_enabled()
+            .then(|| self.resource_change_index_last_processed_state_version());
         for transaction_bundle in commit_bundle.transactions {
             let hashes = &transaction_bundle.identifiers.transaction_hashes;
             if let Some(user_hashes) = hashes.as_user() {
@@ -510,8 +1027,25 @@ impl<R: WriteableRocks> CommitStore for StateManagerDatabase<R> {
                 user_transactions_count += 1;
             }
             processed_ledger_transaction_hashes.insert(hashes.ledger_transaction_hash);
+            self.batch_update_secondary_indices(
+                &db_context,
+                &mut secondary_indices_progress,
+                &transaction_bundle,
+            );
+            if let Some(last_processed_state_version) =
+                resource_change_index_last_processed_state_version.as_mut()
+            {
+                self.batch_update_resource_change_index_from_committed_transaction(
+                    &db_context,
+                    last_processed_state_version,
+                    &transaction_bundle,
+                );
+            }
             self.add_transaction_to_write_batch(&db_context, transaction_bundle);
         }
+        if !self.secondary_indices.is_empty() {
+            self.write_secondary_indices_progress(&db_context, secondary_indices_progress);
+        }
 
         if processed_intent_hashes.len() != user_transactions_count {
             panic!("Commit request contains duplicate intent hashes");
@@ -821,19 +1355,42 @@ impl<R: ReadableRocks> IterableTransactionStore for StateManagerDatabase<R> {
     fn get_committed_transaction_bundle_iter(
         &self,
         from_state_version: StateVersion,
-    ) -> Box<dyn Iterator<Item = CommittedTransactionBundle> + '_> {
+    ) -> Result<Box<dyn Iterator<Item = CommittedTransactionBundle> + '_>, TransactionsPrunedError>
+    {
         // This should not happen. This interface should be used after checking (e.g. `core-api-server/src/core-api/handlers/`).
         // However, with or without this debug_assert there would still be a panic if LocalTransactionExecution is missing.
         debug_assert!(self.is_local_transaction_execution_index_enabled());
 
-        Box::new(RocksDBCommittedTransactionBundleIterator::new(
+        let first_available_state_version =
+            self.get_first_stored_ledger_transaction_state_version();
+        if from_state_version < first_available_state_version {
+            return Err(TransactionsPrunedError {
+                first_available_state_version,
+            });
+        }
+
+        Ok(Box::new(RocksDBCommittedTransactionBundleIterator::new(
             from_state_version,
             self.open_read_context(),
-        ))
+        )))
     }
 }
 
 impl<R: ReadableRocks> QueryableTransactionStore for StateManagerDatabase<R> {
+    fn get_first_stored_ledger_transaction_state_version(&self) -> StateVersion {
+        self.open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::LedgerTransactionsStatus)
+            .map(|bytes| {
+                scrypto_decode_with_nice_error::<VersionedLedgerTransactionsStatus>(&bytes)
+                    .unwrap()
+                    .fully_update_and_into_latest_version()
+                    .ledger_transactions_available_from
+            })
+            // Nothing was pruned yet:
+            .unwrap_or_else(|| StateVersion::pre_genesis().next().unwrap())
+    }
+
     fn get_committed_transaction(
         &self,
         state_version: StateVersion,
@@ -885,7 +1442,10 @@ impl<R: ReadableRocks> QueryableTransactionStore for StateManagerDatabase<R> {
             }),
             (None, Some(_)) => panic!("missing ledger receipt at state version {state_version}"),
             (Some(_), None) => {
-                if self.is_local_transaction_execution_index_enabled() {
+                if self.is_local_transaction_execution_index_enabled()
+                    && state_version
+                        >= self.get_first_stored_local_transaction_execution_state_version()
+                {
                     panic!("missing local execution at state version {state_version}")
                 }
                 None
@@ -1012,6 +1572,7 @@ impl<R: ReadableRocks> QueryableProofStore for StateManagerDatabase<R> {
             .open_read_context()
             .cf(RawLedgerTransactionsCf)
             .iterate_from(&start_state_version_inclusive, Direction::Forward);
+        let mut expected_txn_state_version = start_state_version_inclusive;
 
         // A few flags used to be able to provide an accurate error response
         let mut encountered_protocol_update_proof = None;
@@ -1057,6 +1618,18 @@ impl<R: ReadableRocks> QueryableProofStore for StateManagerDatabase<R> {
                     {
                         match txns_iter.next() {
                             Some((next_txn_state_version, next_txn)) => {
+                                if next_txn_state_version != expected_txn_state_version {
+                                    // Only a sparse history is available below the anchor of a
+                                    // DB bootstrapped from a state snapshot, or below the retention
+                                    // window of a node pruning its ledger transactions; we cannot
+                                    // serve it
+                                    warn!("The DB has no transaction at state version {} (the next one is at {})",
+                                        expected_txn_state_version, next_txn_state_version);
+                                    break 'proof_loop;
+                                }
+                                expected_txn_state_version = next_txn_state_version
+                                    .next()
+                                    .expect("state version overflow");
                                 payload_size_including_next_proof_txns += next_txn.len() as u32;
                                 next_proof_txns.push(next_txn);
 
@@ -1190,9 +1763,7 @@ impl<R: ReadableRocks> QueryableProofStore for StateManagerDatabase<R> {
 impl<R: CheckpointableRocks> StateManagerDatabase<R> {
     /// Creates a checkpoint in `path`
     pub fn create_checkpoint(&self, path: String) -> Result<(), String> {
-        self.rocks
-            .create_checkpoint(PathBuf::from(path))
-            .map_err(|err| err.to_string())
+        self.rocks.create_checkpoint(PathBuf::from(path))
     }
 }
 
@@ -1242,32 +1813,66 @@ impl<R: ReadableRocks> SubstateNodeAncestryStore for StateManagerDatabase<R> {
     }
 }
 
-impl<R: ReadableRocks> ReadableTreeStore for StateManagerDatabase<R> {
-    fn get_node(&self, key: &StoredTreeNodeKey) -> Option<TreeNode> {
-        self.open_read_context().cf(StateTreeNodesCf).get(key)
+impl<R: ReadableRocks> ConsistencyCheckStore for StateManagerDatabase<R> {
+    fn get_intent_hash_index_iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (TransactionIntentHash, StateVersion)> + '_> {
+        self.open_read_context()
+            .cf(IntentHashesCf)
+            .iterate(Direction::Forward)
     }
-}
 
-impl<R: WriteableRocks> StateTreeGcStore for StateManagerDatabase<R> {
-    fn get_stale_tree_parts_iter(
+    fn get_notarized_transaction_hash_index_iter(
         &self,
-    ) -> Box<dyn Iterator<Item = (StateVersion, StaleTreeParts)> + '_> {
+    ) -> Box<dyn Iterator<Item = (NotarizedTransactionHash, StateVersion)> + '_> {
         self.open_read_context()
-            .cf(StaleStateTreePartsCf)
+            .cf(NotarizedTransactionHashesCf)
             .iterate(Direction::Forward)
     }
 
-    fn progress_historical_substate_values_availability(&self, available_from: StateVersion) {
-        let db_context = self.open_rw_context();
-        let extension_data_cf = db_context.cf(ExtensionsDataCf);
-        let status = extension_data_cf
-            .get(&ExtensionsDataKey::StateTreeAssociatedValuesStatus)
-            .map(|bytes| {
-                scrypto_decode_with_nice_error::<VersionedStateTreeAssociatedValuesStatus>(&bytes)
-                    .unwrap()
-                    .fully_update_and_into_latest_version()
-            });
-        let Some(mut status) = status else {
+    fn get_ledger_transaction_hash_index_iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (LedgerTransactionHash, StateVersion)> + '_> {
+        self.open_read_context()
+            .cf(LedgerTransactionHashesCf)
+            .iterate(Direction::Forward)
+    }
+
+    fn get_substate_node_ancestry_record_iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (NodeId, SubstateNodeAncestryRecord)> + '_> {
+        self.open_read_context()
+            .cf(SubstateNodeAncestryRecordsCf)
+            .iterate(Direction::Forward)
+    }
+}
+
+impl<R: ReadableRocks> ReadableTreeStore for StateManagerDatabase<R> {
+    fn get_node(&self, key: &StoredTreeNodeKey) -> Option<TreeNode> {
+        self.open_read_context().cf(StateTreeNodesCf).get(key)
+    }
+}
+
+impl<R: WriteableRocks> StateTreeGcStore for StateManagerDatabase<R> {
+    fn get_stale_tree_parts_iter(
+        &self,
+    ) -> Box<dyn Iterator<Item = (StateVersion, StaleTreeParts)> + '_> {
+        self.open_read_context()
+            .cf(StaleStateTreePartsCf)
+            .iterate(Direction::Forward)
+    }
+
+    fn progress_historical_substate_values_availability(&self, available_from: StateVersion) {
+        let db_context = self.open_rw_context();
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        let status = extension_data_cf
+            .get(&ExtensionsDataKey::StateTreeAssociatedValuesStatus)
+            .map(|bytes| {
+                scrypto_decode_with_nice_error::<VersionedStateTreeAssociatedValuesStatus>(&bytes)
+                    .unwrap()
+                    .fully_update_and_into_latest_version()
+            });
+        let Some(mut status) = status else {
             // The state history feature is simply not enabled.
             return;
         };
@@ -1283,16 +1888,19 @@ impl<R: WriteableRocks> StateTreeGcStore for StateManagerDatabase<R> {
     }
 
     fn batch_delete_node<'a>(&self, keys: impl IntoIterator<Item = &'a StoredTreeNodeKey>) {
+        // Note: coalescing the deletes into ranges is safe here, since the node keys start with the
+        // version at which the node was created - i.e. no new node can land between the old ones.
+        let keys = keys.into_iter().collect::<Vec<_>>();
         let db_context = self.open_rw_context();
-        let tree_nodes_cf = db_context.cf(StateTreeNodesCf);
-        let associated_values_cf = db_context.cf(AssociatedStateTreeValuesCf);
-        for key in keys {
-            tree_nodes_cf.delete(key);
-            if self.config.enable_historical_substate_values {
-                // Note: not every key represents a Substate. But majority does, so we simply accept
-                // some fraction of no-op deletes here, in the name of simplicity.
-                associated_values_cf.delete(key);
-            }
+        db_context
+            .cf(StateTreeNodesCf)
+            .delete_many_coalesced(keys.iter().copied(), MIN_COALESCED_DELETE_RUN_LEN);
+        if self.config.enable_historical_substate_values {
+            // Note: not every key represents a Substate. But majority does, so we simply accept
+            // some fraction of no-op deletes here, in the name of simplicity.
+            db_context
+                .cf(AssociatedStateTreeValuesCf)
+                .delete_many_coalesced(keys.iter().copied(), MIN_COALESCED_DELETE_RUN_LEN);
         }
     }
 
@@ -1301,6 +1909,70 @@ impl<R: WriteableRocks> StateTreeGcStore for StateManagerDatabase<R> {
             .cf(StaleStateTreePartsCf)
             .delete_range(&StateVersion::pre_genesis(), &to_state_version);
     }
+
+    fn compact_state_tree_range(
+        &self,
+        from: StateVersion,
+        to: StateVersion,
+    ) -> Vec<CategoryReclaimedBytes> {
+        let db_context = self.open_rw_context();
+        let from_node_key = StoredTreeNodeKey::new(from.number(), NibblePath::new_even(vec![]));
+        let to_node_key = StoredTreeNodeKey::new(to.number(), NibblePath::new_even(vec![]));
+        let mut reclaimed = vec![
+            CategoryReclaimedBytes {
+                category_name: StateTreeNodesCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(StateTreeNodesCf)
+                    .compact_range(&from_node_key, &to_node_key),
+            },
+            CategoryReclaimedBytes {
+                category_name: StaleStateTreePartsCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(StaleStateTreePartsCf)
+                    .compact_range(&from, &to),
+            },
+        ];
+        if self.config.enable_historical_substate_values {
+            reclaimed.push(CategoryReclaimedBytes {
+                category_name: AssociatedStateTreeValuesCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(AssociatedStateTreeValuesCf)
+                    .compact_range(&from_node_key, &to_node_key),
+            });
+        }
+        reclaimed
+    }
+
+    fn record_state_history_checkpoint(&self, state_version: StateVersion) {
+        self.open_rw_context()
+            .cf(StateHistoryCheckpointsCf)
+            .put(&state_version, &());
+    }
+
+    fn batch_retain_stale_tree_parts(
+        &self,
+        retained_parts: impl IntoIterator<Item = (StateVersion, StaleTreeParts)>,
+    ) {
+        let db_context = self.open_rw_context();
+        let retained_parts_cf = db_context.cf(RetainedStaleStateTreePartsCf);
+        for (state_version, stale_tree_parts) in retained_parts {
+            retained_parts_cf.put(&state_version, &stale_tree_parts);
+        }
+    }
+}
+
+impl<R: ReadableRocks> StateHistoryCheckpointStore for StateManagerDatabase<R> {
+    fn get_state_history_checkpoint_iter(
+        &self,
+        from_state_version: StateVersion,
+    ) -> Box<dyn Iterator<Item = StateVersion> + '_> {
+        Box::new(
+            self.open_read_context()
+                .cf(StateHistoryCheckpointsCf)
+                .iterate_from(&from_state_version, Direction::Forward)
+                .map(|(state_version, _)| state_version),
+        )
+    }
 }
 
 impl<R: WriteableRocks> LedgerProofsGcStore for StateManagerDatabase<R> {
@@ -1316,10 +1988,498 @@ impl<R: WriteableRocks> LedgerProofsGcStore for StateManagerDatabase<R> {
             .put(&(), &progress);
     }
 
-    fn delete_ledger_proofs_range(&self, from: StateVersion, to: StateVersion) {
-        self.open_rw_context()
-            .cf(LedgerProofsCf)
+    fn delete_ledger_proofs_ranges(&self, ranges: &[(StateVersion, StateVersion)]) {
+        let (Some((from, _)), Some((_, to))) = (ranges.first(), ranges.last()) else {
+            return;
+        };
+        let db_context = self.open_rw_context();
+        let proofs_cf = db_context.cf(LedgerProofsCf);
+        let retained_proofs = proofs_cf
+            .iterate_from(from, Direction::Forward)
+            .take_while(|(state_version, _)| state_version < to)
+            .filter(|(state_version, _)| {
+                let preceding_range_index =
+                    ranges.partition_point(|(range_from, _)| range_from <= state_version);
+                preceding_range_index == 0 || ranges[preceding_range_index - 1].1 <= *state_version
+            })
+            .collect::<Vec<_>>();
+        // Note: within a single write batch, the puts following a range delete take precedence.
+        proofs_cf.delete_range(from, to);
+        for (state_version, proof) in retained_proofs {
+            proofs_cf.put(&state_version, &proof);
+        }
+    }
+
+    fn compact_ledger_proofs_range(
+        &self,
+        from: StateVersion,
+        to: StateVersion,
+    ) -> Vec<CategoryReclaimedBytes> {
+        vec![CategoryReclaimedBytes {
+            category_name: LedgerProofsCf::NAME.to_string(),
+            reclaimed_bytes: self
+                .open_rw_context()
+                .cf(LedgerProofsCf)
+                .compact_range(&from, &to),
+        }]
+    }
+}
+
+impl<R: WriteableRocks> LedgerTransactionsGcStore for StateManagerDatabase<R> {
+    fn progress_ledger_transactions_availability(&self, available_from: StateVersion) {
+        if available_from <= self.get_first_stored_ledger_transaction_state_version() {
+            return; // the availability never regresses
+        }
+        let db_context = self.open_rw_context();
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        let status = LedgerTransactionsStatusV1 {
+            ledger_transactions_available_from: available_from,
+        };
+        extension_data_cf.put(
+            &ExtensionsDataKey::LedgerTransactionsStatus,
+            &scrypto_encode(&VersionedLedgerTransactionsStatus::from(status)).unwrap(),
+        );
+        if self.config.enable_local_transaction_execution_index
+            && self.get_first_stored_local_transaction_execution_state_version() < available_from
+        {
+            // The local executions of pruned transactions go away together with them:
+            let status = LocalTransactionExecutionIndexStatusV1 {
+                local_transaction_executions_available_from: available_from,
+            };
+            extension_data_cf.put(
+                &ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+                &scrypto_encode(&VersionedLocalTransactionExecutionIndexStatus::from(status))
+                    .unwrap(),
+            );
+        }
+    }
+
+    fn delete_ledger_transactions_range(&self, from: StateVersion, to: StateVersion) {
+        let db_context = self.open_rw_context();
+        db_context
+            .cf(RawLedgerTransactionsCf)
             .delete_range(&from, &to);
+        db_context
+            .cf(TransactionReceiptsCf)
+            .delete_range(&from, &to);
+        db_context
+            .cf(LocalTransactionExecutionsCf)
+            .delete_range(&from, &to);
+    }
+
+    fn compact_ledger_transactions_range(
+        &self,
+        from: StateVersion,
+        to: StateVersion,
+    ) -> Vec<CategoryReclaimedBytes> {
+        let db_context = self.open_rw_context();
+        vec![
+            CategoryReclaimedBytes {
+                category_name: RawLedgerTransactionsCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(RawLedgerTransactionsCf)
+                    .compact_range(&from, &to),
+            },
+            CategoryReclaimedBytes {
+                category_name: TransactionReceiptsCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(TransactionReceiptsCf)
+                    .compact_range(&from, &to),
+            },
+            CategoryReclaimedBytes {
+                category_name: LocalTransactionExecutionsCf::NAME.to_string(),
+                reclaimed_bytes: db_context
+                    .cf(LocalTransactionExecutionsCf)
+                    .compact_range(&from, &to),
+            },
+        ]
+    }
+}
+
+impl<R: WriteableRocks> StateSnapshotLoadingStore for StateManagerDatabase<R> {
+    fn put_snapshot_substates(&self, substates: Vec<(DbSubstateKey, DbSubstateValue)>) {
+        let db_context = self.open_rw_context();
+        let substates_cf = db_context.cf(SubstatesCf);
+        for (substate_key, value) in substates {
+            substates_cf.put(&substate_key, &value);
+        }
+    }
+
+    fn put_snapshot_node_ancestry_records(
+        &self,
+        records: Vec<(NodeId, SubstateNodeAncestryRecord)>,
+    ) {
+        let db_context = self.open_rw_context();
+        let ancestry_cf = db_context.cf(SubstateNodeAncestryRecordsCf);
+        for (node_id, record) in records {
+            ancestry_cf.put(&node_id, &record);
+        }
+    }
+
+    fn put_snapshot_tree_nodes(&self, nodes: Vec<(StoredTreeNodeKey, TreeNode)>) {
+        let db_context = self.open_rw_context();
+        let tree_nodes_cf = db_context.cf(StateTreeNodesCf);
+        for (key, node) in nodes {
+            tree_nodes_cf.put(&key, &node);
+        }
+    }
+
+    fn put_snapshot_ledger_history(
+        &self,
+        epoch_changes: Vec<(CommittedTransactionBundle, LedgerProof)>,
+        protocol_update_proofs: Vec<LedgerProof>,
+    ) {
+        let db_context = self.open_rw_context();
+        for (transaction_bundle, epoch_proof) in epoch_changes {
+            let CommittedTransactionBundle {
+                state_version,
+                raw,
+                receipt,
+                identifiers,
+            } = transaction_bundle;
+            // Note: the transaction is deliberately not fed to any of the indices (e.g. entity
+            // listing); they are all considered "caught up" to the snapshot's anchor proof.
+            db_context.cf(LedgerTransactionHashesCf).put(
+                &identifiers.transaction_hashes.ledger_transaction_hash,
+                &state_version,
+            );
+            db_context
+                .cf(RawLedgerTransactionsCf)
+                .put(&state_version, &raw);
+            db_context
+                .cf(CommittedTransactionIdentifiersCf)
+                .put(&state_version, &identifiers);
+            db_context
+                .cf(TransactionReceiptsCf)
+                .put(&state_version, &receipt.on_ledger);
+            if self.is_local_transaction_execution_index_enabled() {
+                db_context
+                    .cf(LocalTransactionExecutionsCf)
+                    .put(&state_version, &receipt.local_execution);
+            }
+            let next_epoch = epoch_proof
+                .ledger_header
+                .next_epoch
+                .as_ref()
+                .expect("epoch proof must initiate the next epoch")
+                .epoch;
+            db_context
+                .cf(EpochLedgerProofsCf)
+                .put(&next_epoch, &epoch_proof);
+        }
+        for proof in protocol_update_proofs {
+            let state_version = proof.ledger_header.state_version;
+            if proof.ledger_header.next_protocol_version.is_some() {
+                db_context
+                    .cf(ProtocolUpdateInitLedgerProofsCf)
+                    .put(&state_version, &proof);
+            }
+            if let LedgerProofOrigin::ProtocolUpdate { .. } = &proof.origin {
+                db_context
+                    .cf(ProtocolUpdateExecutionLedgerProofsCf)
+                    .put(&state_version, &proof);
+            }
+        }
+    }
+
+    fn put_snapshot_anchor_proof(&self, proof: LedgerProof) {
+        let db_context = self.open_rw_context();
+        let state_version = proof.ledger_header.state_version;
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        extension_data_cf.put(
+            &ExtensionsDataKey::AccountChangeIndexLastProcessedStateVersion,
+            &state_version.to_be_bytes().to_vec(),
+        );
+        extension_data_cf.put(
+            &ExtensionsDataKey::EntityListingIndicesLastProcessedStateVersion,
+            &state_version.to_be_bytes().to_vec(),
+        );
+        extension_data_cf.put(
+            &ExtensionsDataKey::December2023LostSubstatesRestored,
+            &vec![],
+        );
+        // The enabled indices have nothing to process below the anchor (their markers' presence
+        // also means "enabled", so the disabled ones must stay unmarked):
+        if self.config.enable_resource_change_index {
+            extension_data_cf.put(
+                &ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
+                &state_version.to_be_bytes().to_vec(),
+            );
+        }
+        let mut secondary_indices_progress = SecondaryIndicesProgress::default();
+        for index in &self.secondary_indices {
+            secondary_indices_progress.skip(
+                index.name(),
+                StateVersion::pre_genesis().next().unwrap(),
+                state_version,
+            );
+        }
+        self.write_secondary_indices_progress(&db_context, secondary_indices_progress);
+        // The loaded data is written in the current schema, i.e. needs no migrations:
+        self.write_database_schema_status(
+            &db_context,
+            &DatabaseSchemaStatus {
+                applied_migrations: DatabaseMigrationRegistry::<R>::default()
+                    .names()
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                in_progress_migration: None,
+            },
+        );
+        // Neither the transactions nor their local executions are available up to the anchor:
+        let status = LedgerTransactionsStatusV1 {
+            ledger_transactions_available_from: state_version.next().unwrap(),
+        };
+        extension_data_cf.put(
+            &ExtensionsDataKey::LedgerTransactionsStatus,
+            &scrypto_encode(&VersionedLedgerTransactionsStatus::from(status)).unwrap(),
+        );
+        let status = LocalTransactionExecutionIndexStatusV1 {
+            local_transaction_executions_available_from: state_version.next().unwrap(),
+        };
+        extension_data_cf.put(
+            &ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+            &scrypto_encode(&VersionedLocalTransactionExecutionIndexStatus::from(status)).unwrap(),
+        );
+        db_context.cf(LedgerProofsGcProgressCf).put(
+            &(),
+            &LedgerProofsGcProgress::new(proof.ledger_header.clone()),
+        );
+        db_context.cf(LedgerProofsCf).put(&state_version, &proof);
+    }
+}
+
+impl<R: WriteableRocks> LedgerRollbackStore for StateManagerDatabase<R> {
+    fn put_reverted_substates(
+        &self,
+        partitions: Vec<(DbPartitionKey, Vec<(DbSortKey, DbSubstateValue)>)>,
+        substates: Vec<(DbSubstateKey, Option<DbSubstateValue>)>,
+    ) {
+        let db_context = self.open_rw_context();
+        let substates_cf = db_context.cf(SubstatesCf);
+        for (partition_key, values) in partitions {
+            substates_cf.delete_group(&partition_key);
+            for (sort_key, value) in values {
+                substates_cf.put(&(partition_key.clone(), sort_key), &value);
+            }
+        }
+        for (substate_key, value) in substates {
+            match value {
+                Some(value) => substates_cf.put(&substate_key, &value),
+                None => substates_cf.delete(&substate_key),
+            }
+        }
+    }
+
+    fn delete_index_entries_after(&self, state_version: StateVersion) {
+        const TXN_FLUSH_INTERVAL: u64 = 10_000;
+
+        let db_context = self.open_rw_context();
+        let identifiers_iter = db_context
+            .cf(CommittedTransactionIdentifiersCf)
+            .iterate_from(&state_version.next().unwrap(), Direction::Forward);
+        for (state_version, identifiers) in identifiers_iter {
+            let hashes = &identifiers.transaction_hashes;
+            if let Some(user_hashes) = hashes.as_user() {
+                db_context
+                    .cf(IntentHashesCf)
+                    .delete(&user_hashes.transaction_intent_hash);
+                db_context
+                    .cf(NotarizedTransactionHashesCf)
+                    .delete(&user_hashes.notarized_transaction_hash);
+            }
+            db_context
+                .cf(LedgerTransactionHashesCf)
+                .delete(&hashes.ledger_transaction_hash);
+
+            // The optional indices are derived from the data of the transaction itself (and are
+            // thus available whenever the index could have been maintained):
+            let receipt = db_context.cf(TransactionReceiptsCf).get(&state_version);
+            if let Some(receipt) = &receipt {
+                self.batch_delete_entity_listing_index_entries(
+                    &db_context,
+                    state_version,
+                    &receipt.state_changes.substate_level_changes,
+                );
+            }
+            if let Some(execution) = db_context
+                .cf(LocalTransactionExecutionsCf)
+                .get(&state_version)
+            {
+                let balance_changes = &execution.global_balance_summary.global_balance_changes;
+                for (address, resource_changes) in balance_changes {
+                    if address.is_account() {
+                        db_context
+                            .cf(AccountChangeStateVersionsCf)
+                            .delete(&(*address, state_version));
+                    }
+                    for resource in resource_changes.keys() {
+                        db_context
+                            .cf(ResourceChangeStateVersionsCf)
+                            .delete(&(*resource, state_version));
+                    }
+                }
+                if let (Some(raw), Some(receipt)) = (
+                    db_context.cf(RawLedgerTransactionsCf).get(&state_version),
+                    &receipt,
+                ) {
+                    let input = SecondaryIndexInput {
+                        state_version,
+                        raw: &raw,
+                        ledger_receipt: receipt,
+                        local_execution: &execution,
+                    };
+                    for index in &self.secondary_indices {
+                        let index_cf = db_context.cf(SecondaryIndexCf::of(index.name()));
+                        for key in index.extract_keys(&input) {
+                            index_cf.delete(&(hash(key), state_version));
+                        }
+                    }
+                }
+            }
+
+            if state_version.number() % TXN_FLUSH_INTERVAL == 0 {
+                db_context.flush();
+            }
+        }
+
+        // The finalized subintents are only known to the local executions (which may be absent),
+        // so we find them by their (much less numerous) entries instead:
+        let finalized_subintent_hashes_cf = db_context.cf(FinalizedSubintentHashesCf);
+        for (subintent_hash, finalized_at) in
+            finalized_subintent_hashes_cf.iterate(Direction::Forward)
+        {
+            if finalized_at > state_version {
+                finalized_subintent_hashes_cf.delete(&subintent_hash);
+            }
+        }
+    }
+
+    fn truncate_ledger_after(&self, epoch_proof: &LedgerProof) {
+        let header = &epoch_proof.ledger_header;
+        let state_version = header.state_version;
+        let next_epoch = header
+            .next_epoch
+            .as_ref()
+            .expect("epoch change proof")
+            .epoch;
+        let from_state_version = state_version.next().unwrap();
+        let to_state_version = StateVersion::of(u64::MAX);
+        let from_node_key =
+            StoredTreeNodeKey::new(from_state_version.number(), NibblePath::new_even(vec![]));
+        let to_node_key = StoredTreeNodeKey::new(Version::MAX, NibblePath::new_even(vec![]));
+
+        // All the writes below are buffered, and thus applied atomically:
+        let db_context = self.open_rw_context();
+        db_context
+            .cf(RawLedgerTransactionsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(CommittedTransactionIdentifiersCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(TransactionReceiptsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(LocalTransactionExecutionsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(LedgerProofsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(EpochLedgerProofsCf)
+            .delete_range(&next_epoch.next().unwrap(), &Epoch::of(u64::MAX));
+        db_context
+            .cf(ProtocolUpdateInitLedgerProofsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(ProtocolUpdateExecutionLedgerProofsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(TransactionAccuTreeSlicesCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(ReceiptAccuTreeSlicesCf)
+            .delete_range(&from_state_version, &to_state_version);
+        // The tree nodes (and their associated values) are keyed by their state version first:
+        db_context
+            .cf(StateTreeNodesCf)
+            .delete_range(&from_node_key, &to_node_key);
+        db_context
+            .cf(AssociatedStateTreeValuesCf)
+            .delete_range(&from_node_key, &to_node_key);
+        // The parts which became stale only after the target version are live again:
+        db_context
+            .cf(StaleStateTreePartsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(RetainedStaleStateTreePartsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context
+            .cf(StateHistoryCheckpointsCf)
+            .delete_range(&from_state_version, &to_state_version);
+        db_context.cf(VertexStoreCf).delete(&());
+
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        for key in [
+            ExtensionsDataKey::AccountChangeIndexLastProcessedStateVersion,
+            ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
+            ExtensionsDataKey::EntityListingIndicesLastProcessedStateVersion,
+        ] {
+            if let Some(bytes) = extension_data_cf.get(&key) {
+                if StateVersion::from_be_bytes(bytes) > state_version {
+                    extension_data_cf.put(&key, &state_version.to_be_bytes().to_vec());
+                }
+            }
+        }
+        let mut secondary_indices_progress = self.read_secondary_indices_progress();
+        for last_processed_state_version in secondary_indices_progress
+            .last_processed_state_versions
+            .values_mut()
+        {
+            *last_processed_state_version = (*last_processed_state_version).min(state_version);
+        }
+        self.write_secondary_indices_progress(&db_context, secondary_indices_progress);
+        if let Some(bytes) =
+            extension_data_cf.get(&ExtensionsDataKey::StateTreeAssociatedValuesStatus)
+        {
+            let mut status =
+                scrypto_decode_with_nice_error::<VersionedStateTreeAssociatedValuesStatus>(&bytes)
+                    .unwrap()
+                    .fully_update_and_into_latest_version();
+            status.historical_substate_values_available_from = status
+                .historical_substate_values_available_from
+                .min(state_version);
+            extension_data_cf.put(
+                &ExtensionsDataKey::StateTreeAssociatedValuesStatus,
+                &scrypto_encode(&VersionedStateTreeAssociatedValuesStatus::from(status)).unwrap(),
+            );
+        }
+        if let Some(bytes) =
+            extension_data_cf.get(&ExtensionsDataKey::LocalTransactionExecutionIndexStatus)
+        {
+            let mut status = scrypto_decode_with_nice_error::<
+                VersionedLocalTransactionExecutionIndexStatus,
+            >(&bytes)
+            .unwrap()
+            .fully_update_and_into_latest_version();
+            status.local_transaction_executions_available_from = status
+                .local_transaction_executions_available_from
+                .min(from_state_version);
+            extension_data_cf.put(
+                &ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+                &scrypto_encode(&VersionedLocalTransactionExecutionIndexStatus::from(status))
+                    .unwrap(),
+            );
+        }
+
+        let proofs_gc_progress_cf = db_context.cf(LedgerProofsGcProgressCf);
+        if let Some(progress) = proofs_gc_progress_cf.get(&()) {
+            if progress.epoch_proof_state_version > state_version {
+                proofs_gc_progress_cf.put(&(), &LedgerProofsGcProgress::new(header.clone()));
+            }
+        }
     }
 }
 
@@ -1348,6 +2508,36 @@ impl<R: ReadableRocks> ReadableAccuTreeStore<StateVersion, ReceiptTreeHash>
     }
 }
 
+impl<R: ReadableRocks> SeekableAccuTreeStore<StateVersion, TransactionTreeHash>
+    for StateManagerDatabase<R>
+{
+    fn get_first_tree_slice_from(
+        &self,
+        state_version: &StateVersion,
+    ) -> Option<(StateVersion, TreeSlice<TransactionTreeHash>)> {
+        self.open_read_context()
+            .cf(TransactionAccuTreeSlicesCf)
+            .iterate_from(state_version, Direction::Forward)
+            .next()
+            .map(|(state_version, slice)| (state_version, slice.0))
+    }
+}
+
+impl<R: ReadableRocks> SeekableAccuTreeStore<StateVersion, ReceiptTreeHash>
+    for StateManagerDatabase<R>
+{
+    fn get_first_tree_slice_from(
+        &self,
+        state_version: &StateVersion,
+    ) -> Option<(StateVersion, TreeSlice<ReceiptTreeHash>)> {
+        self.open_read_context()
+            .cf(ReceiptAccuTreeSlicesCf)
+            .iterate_from(state_version, Direction::Forward)
+            .next()
+            .map(|(state_version, slice)| (state_version, slice.0))
+    }
+}
+
 impl<R: WriteableRocks> WriteableVertexStore for StateManagerDatabase<R> {
     fn save_vertex_store(&self, blob: VertexStoreBlob) {
         self.open_rw_context().cf(VertexStoreCf).put(&(), &blob)
@@ -1374,25 +2564,68 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
             .filter(|address| address.is_account())
         {
             db_context
-                .cf(AccountChangeStateVersionsCf)
-                .put(&(*address, state_version), &());
+                .cf(AccountChangeStateVersionsCf)
+                .put(&(*address, state_version), &());
+        }
+    }
+
+    fn batch_update_account_change_index_from_committed_transaction(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        transaction_bundle: &CommittedTransactionBundle,
+    ) {
+        let state_version = transaction_bundle.state_version;
+        self.batch_update_account_change_index_from_receipt(
+            db_context,
+            state_version,
+            &transaction_bundle.receipt.local_execution,
+        );
+
+        db_context.cf(ExtensionsDataCf).put(
+            &ExtensionsDataKey::AccountChangeIndexLastProcessedStateVersion,
+            &state_version.to_be_bytes().to_vec(),
+        );
+    }
+
+    fn batch_update_resource_change_index_from_receipt(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        state_version: StateVersion,
+        execution: &LocalTransactionExecution,
+    ) {
+        let resources = execution
+            .global_balance_summary
+            .global_balance_changes
+            .values()
+            .flat_map(|balance_changes| balance_changes.keys())
+            .collect::<IndexSet<_>>();
+        for resource in resources {
+            db_context
+                .cf(ResourceChangeStateVersionsCf)
+                .put(&(*resource, state_version), &());
         }
     }
 
-    fn batch_update_account_change_index_from_committed_transaction(
+    fn batch_update_resource_change_index_from_committed_transaction(
         &self,
         db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        last_processed_state_version: &mut StateVersion,
         transaction_bundle: &CommittedTransactionBundle,
     ) {
         let state_version = transaction_bundle.state_version;
-        self.batch_update_account_change_index_from_receipt(
+        // A lagging index is left to the background catch-up:
+        if last_processed_state_version.next().unwrap() != state_version {
+            return;
+        }
+        *last_processed_state_version = state_version;
+        self.batch_update_resource_change_index_from_receipt(
             db_context,
             state_version,
             &transaction_bundle.receipt.local_execution,
         );
 
         db_context.cf(ExtensionsDataCf).put(
-            &ExtensionsDataKey::AccountChangeIndexLastProcessedStateVersion,
+            &ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
             &state_version.to_be_bytes().to_vec(),
         );
     }
@@ -1446,37 +2679,10 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
         state_version: StateVersion,
         substate_changes: &BySubstate<SubstateChangeAction>,
     ) {
-        for (index_within_txn, node_id) in substate_changes.iter_node_ids().enumerate() {
-            let type_info_change = substate_changes.get(
-                node_id,
-                &TYPE_INFO_FIELD_PARTITION,
-                &TypeInfoField::TypeInfo.into(),
-            );
-            let Some(type_info_change) = type_info_change else {
-                continue;
-            };
-            let created_type_info_value = match type_info_change {
-                SubstateChangeAction::Create { new } => new,
-                SubstateChangeAction::Update { .. } => {
-                    // Even if TypeInfo is updated (e.g. its blueprint version bumped), the fields
-                    // that we care about (package address and blueprint name) are effectively
-                    // immutable - we can thus safely ignore all updates to this substate.
-                    continue;
-                }
-                SubstateChangeAction::Delete { .. } => {
-                    panic!(
-                        "type info substate should not be deleted: {:?}",
-                        type_info_change
-                    )
-                }
-            };
-            let type_info =
-                scrypto_decode_with_nice_error::<TypeInfoSubstate>(created_type_info_value)
-                    .expect("decode type info");
-
+        for (node_id, creation_id, type_info) in
+            Self::created_entities(state_version, substate_changes)
+        {
             let entity_type = node_id.entity_type().expect("type of upserted Entity");
-            let creation_id = CreationId::new(state_version, index_within_txn);
-
             match type_info {
                 TypeInfoSubstate::Object(object_info) => {
                     let blueprint_id = object_info.blueprint_info.blueprint_id;
@@ -1486,12 +2692,12 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
                     } = blueprint_id.clone();
                     db_context.cf(TypeAndCreationIndexedEntitiesCf).put(
                         &(entity_type, creation_id.clone()),
-                        &EntityBlueprintId::of_object(*node_id, blueprint_id),
+                        &EntityBlueprintId::of_object(node_id, blueprint_id),
                     );
                     db_context.cf(BlueprintAndCreationIndexedObjectsCf).put(
                         &(package_address, hash(&blueprint_name), creation_id),
                         &ObjectBlueprintNameV1 {
-                            node_id: *node_id,
+                            node_id,
                             blueprint_name,
                         },
                     );
@@ -1499,7 +2705,7 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
                 TypeInfoSubstate::KeyValueStore(_kv_store_info) => {
                     db_context.cf(TypeAndCreationIndexedEntitiesCf).put(
                         &(entity_type, creation_id),
-                        &EntityBlueprintId::of_kv_store(*node_id),
+                        &EntityBlueprintId::of_kv_store(node_id),
                     );
                 }
                 TypeInfoSubstate::GlobalAddressReservation(_)
@@ -1510,6 +2716,74 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
         }
     }
 
+    fn batch_delete_entity_listing_index_entries(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        state_version: StateVersion,
+        substate_changes: &BySubstate<SubstateChangeAction>,
+    ) {
+        for (node_id, creation_id, type_info) in
+            Self::created_entities(state_version, substate_changes)
+        {
+            let entity_type = node_id.entity_type().expect("type of upserted Entity");
+            if let TypeInfoSubstate::Object(object_info) = type_info {
+                let BlueprintId {
+                    package_address,
+                    blueprint_name,
+                } = object_info.blueprint_info.blueprint_id;
+                db_context
+                    .cf(BlueprintAndCreationIndexedObjectsCf)
+                    .delete(&(package_address, hash(&blueprint_name), creation_id.clone()));
+            }
+            db_context
+                .cf(TypeAndCreationIndexedEntitiesCf)
+                .delete(&(entity_type, creation_id));
+        }
+    }
+
+    /// Returns the Entities created by a transaction of the given changes (i.e. the ones having
+    /// their type info Substate created), together with their [`CreationId`]s.
+    fn created_entities(
+        state_version: StateVersion,
+        substate_changes: &BySubstate<SubstateChangeAction>,
+    ) -> Vec<(NodeId, CreationId, TypeInfoSubstate)> {
+        let mut created_entities = Vec::new();
+        for (index_within_txn, node_id) in substate_changes.iter_node_ids().enumerate() {
+            let type_info_change = substate_changes.get(
+                node_id,
+                &TYPE_INFO_FIELD_PARTITION,
+                &TypeInfoField::TypeInfo.into(),
+            );
+            let Some(type_info_change) = type_info_change else {
+                continue;
+            };
+            let created_type_info_value = match type_info_change {
+                SubstateChangeAction::Create { new } => new,
+                SubstateChangeAction::Update { .. } => {
+                    // Even if TypeInfo is updated (e.g. its blueprint version bumped), the fields
+                    // that we care about (package address and blueprint name) are effectively
+                    // immutable - we can thus safely ignore all updates to this substate.
+                    continue;
+                }
+                SubstateChangeAction::Delete { .. } => {
+                    panic!(
+                        "type info substate should not be deleted: {:?}",
+                        type_info_change
+                    )
+                }
+            };
+            let type_info =
+                scrypto_decode_with_nice_error::<TypeInfoSubstate>(created_type_info_value)
+                    .expect("decode type info");
+            created_entities.push((
+                *node_id,
+                CreationId::new(state_version, index_within_txn),
+                type_info,
+            ));
+        }
+        created_entities
+    }
+
     fn ensure_entity_listing_indices(&self) {
         const TXN_FLUSH_INTERVAL: u64 = 10_000;
         const PROGRESS_LOG_INTERVAL: u64 = 1_000_000;
@@ -1562,6 +2836,127 @@ impl<R: WriteableRocks> StateManagerDatabase<R> {
         }
         info!("Caught up Entity listing indices.");
     }
+
+    /// Prunes the resource change index if it was disabled, or marks it as enabled (so that it
+    /// gets populated in the background - see
+    /// [`ResourceChangeIndexExtension::catchup_resource_change_index()`]).
+    fn ensure_resource_change_index(&self) {
+        let db_context = self.open_rw_context();
+        if !self.config.enable_resource_change_index {
+            // Similar to the entity listing indices, we delete everything in a cheap write batch:
+            db_context.cf(ResourceChangeStateVersionsCf).delete_all();
+            db_context
+                .cf(ExtensionsDataCf)
+                .delete(&ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion);
+            return;
+        }
+
+        // The index can only be computed from the available local executions (the older ones will
+        // be indexed when they get backfilled):
+        let last_processed_state_version = self
+            .resource_change_index_last_processed_state_version()
+            .max(
+                self.get_first_stored_local_transaction_execution_state_version()
+                    .previous()
+                    .unwrap(),
+            );
+        info!(
+            "Resource change index is enabled (processed up to {}).",
+            last_processed_state_version
+        );
+        db_context.cf(ExtensionsDataCf).put(
+            &ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
+            &last_processed_state_version.to_be_bytes().to_vec(),
+        );
+    }
+
+    /// Initializes the progress of newly-enabled secondary indices (which will then be caught up
+    /// in the background - see [`SecondaryIndexExtension::compute_secondary_indices_catchup()`]) and
+    /// forgets the progress of the disabled ones (whose column families are already dropped).
+    fn ensure_secondary_indices(&self) {
+        let mut progress = self.read_secondary_indices_progress();
+        progress
+            .last_processed_state_versions
+            .retain(|name, _| self.is_secondary_index_enabled(name));
+        progress
+            .skipped_state_version_ranges
+            .retain(|name, _| self.is_secondary_index_enabled(name));
+        for index in &self.secondary_indices {
+            progress
+                .last_processed_state_versions
+                .entry(index.name().to_string())
+                .or_insert_with(|| {
+                    info!(
+                        "Secondary index {} enabled; it will be caught up in the background",
+                        index.name()
+                    );
+                    self.get_first_stored_local_transaction_execution_state_version()
+                        .previous()
+                        .unwrap()
+                });
+        }
+        self.write_secondary_indices_progress(&self.open_rw_context(), progress);
+    }
+
+    fn is_secondary_index_enabled(&self, index_name: &str) -> bool {
+        self.secondary_indices
+            .iter()
+            .any(|index| index.name() == index_name)
+    }
+
+    fn write_secondary_indices_progress(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        progress: SecondaryIndicesProgress,
+    ) {
+        let extension_data_cf = db_context.cf(ExtensionsDataCf);
+        if progress.last_processed_state_versions.is_empty() {
+            extension_data_cf.delete(&ExtensionsDataKey::SecondaryIndicesProgress);
+        } else {
+            extension_data_cf.put(
+                &ExtensionsDataKey::SecondaryIndicesProgress,
+                &scrypto_encode(&VersionedSecondaryIndicesProgress::from(progress)).unwrap(),
+            );
+        }
+    }
+
+    fn batch_update_secondary_indices(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        progress: &mut SecondaryIndicesProgress,
+        transaction_bundle: &CommittedTransactionBundle,
+    ) {
+        let input = SecondaryIndexInput {
+            state_version: transaction_bundle.state_version,
+            raw: &transaction_bundle.raw,
+            ledger_receipt: &transaction_bundle.receipt.on_ledger,
+            local_execution: &transaction_bundle.receipt.local_execution,
+        };
+        for index in &self.secondary_indices {
+            let last_processed_state_version = progress
+                .last_processed_state_versions
+                .get_mut(index.name())
+                .expect("enabled secondary index has no progress");
+            // A lagging index is left to the background catch-up:
+            if last_processed_state_version.next().unwrap() != input.state_version {
+                continue;
+            }
+            self.batch_update_secondary_index(db_context, index.as_ref(), &input);
+            *last_processed_state_version = input.state_version;
+        }
+    }
+
+    fn batch_update_secondary_index(
+        &self,
+        db_context: &TypedDbContext<R, BufferedWriteSupport<R>>,
+        index: &dyn SecondaryIndex,
+        input: &SecondaryIndexInput,
+    ) {
+        let index_cf = db_context.cf(SecondaryIndexCf::of(index.name()));
+        for key in index.extract_keys(input) {
+            index_cf.put(&(hash(key), input.state_version), &());
+        }
+    }
 }
 
 impl<R: WriteableRocks> AccountChangeIndexExtension for StateManagerDatabase<R> {
@@ -1583,8 +2978,15 @@ impl<R: WriteableRocks> AccountChangeIndexExtension for StateManagerDatabase<R>
         info!("Account Change Index is enabled!");
 
         let last_state_version = self.max_state_version();
-        let mut last_processed_state_version =
-            self.account_change_index_last_processed_state_version();
+        // The index can only be computed from the available local executions (the older ones will
+        // be indexed when they get backfilled):
+        let mut last_processed_state_version = self
+            .account_change_index_last_processed_state_version()
+            .max(
+                self.get_first_stored_local_transaction_execution_state_version()
+                    .previous()
+                    .unwrap(),
+            );
 
         if last_processed_state_version == last_state_version {
             return;
@@ -1606,6 +3008,290 @@ impl<R: WriteableRocks> AccountChangeIndexExtension for StateManagerDatabase<R>
     }
 }
 
+impl<R: WriteableRocks> ResourceChangeIndexExtension for StateManagerDatabase<R> {
+    fn resource_change_index_last_processed_state_version(&self) -> StateVersion {
+        self.get_resource_change_index_last_processed_state_version()
+            .unwrap_or(StateVersion::pre_genesis())
+    }
+
+    fn catchup_resource_change_index(&self, max_transaction_count: usize) -> bool {
+        if !self.config.enable_resource_change_index {
+            return true;
+        }
+        let max_state_version = self.max_state_version();
+        let last_processed_state_version =
+            self.resource_change_index_last_processed_state_version();
+        if last_processed_state_version >= max_state_version {
+            return true;
+        }
+
+        let db_context = self.open_rw_context();
+        let executions = db_context
+            .cf(LocalTransactionExecutionsCf)
+            .iterate_from(
+                &last_processed_state_version.next().unwrap(),
+                Direction::Forward,
+            )
+            .take(max_transaction_count)
+            .collect::<Vec<_>>();
+        let reached_ledger_end = executions.len() < max_transaction_count;
+        let mut last_processed_state_version = executions
+            .last()
+            .map(|(state_version, _)| *state_version)
+            .unwrap_or(last_processed_state_version);
+        for (state_version, execution) in executions {
+            self.batch_update_resource_change_index_from_receipt(
+                &db_context,
+                state_version,
+                &execution,
+            );
+        }
+        if reached_ledger_end {
+            // The remaining state versions (if any) have no local executions to index:
+            last_processed_state_version = max_state_version;
+        }
+        db_context.cf(ExtensionsDataCf).put(
+            &ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion,
+            &last_processed_state_version.to_be_bytes().to_vec(),
+        );
+        info!(
+            "Resource change index processed up to {} (caught up: {})",
+            last_processed_state_version, reached_ledger_end
+        );
+        reached_ledger_end
+    }
+}
+
+impl<R: WriteableRocks> LocalTransactionExecutionBackfillStore for StateManagerDatabase<R> {
+    fn put_backfilled_local_transaction_executions(
+        &self,
+        executions: Vec<(StateVersion, LocalTransactionExecution)>,
+    ) {
+        let db_context = self.open_rw_context();
+        let mut available_from = self.get_first_stored_local_transaction_execution_state_version();
+        for (state_version, execution) in executions {
+            if state_version.next().unwrap() != available_from {
+                panic!(
+                    "backfilled local execution at {} does not directly precede {}",
+                    state_version, available_from
+                );
+            }
+            if self.config.enable_account_change_index {
+                self.batch_update_account_change_index_from_receipt(
+                    &db_context,
+                    state_version,
+                    &execution,
+                );
+            }
+            if self.config.enable_resource_change_index {
+                self.batch_update_resource_change_index_from_receipt(
+                    &db_context,
+                    state_version,
+                    &execution,
+                );
+            }
+            db_context
+                .cf(LocalTransactionExecutionsCf)
+                .put(&state_version, &execution);
+            available_from = state_version;
+        }
+        let status = LocalTransactionExecutionIndexStatusV1 {
+            local_transaction_executions_available_from: available_from,
+        };
+        db_context.cf(ExtensionsDataCf).put(
+            &ExtensionsDataKey::LocalTransactionExecutionIndexStatus,
+            &scrypto_encode(&VersionedLocalTransactionExecutionIndexStatus::from(status)).unwrap(),
+        );
+    }
+}
+
+impl<R: ReadableRocks> SecondaryIndexExtension for StateManagerDatabase<R> {
+    fn compute_secondary_indices_catchup(
+        &self,
+        max_transaction_count: usize,
+    ) -> Option<SecondaryIndicesCatchupBatch> {
+        let mut progress = self.read_secondary_indices_progress();
+        let max_state_version = self.max_state_version();
+        let lagging_indices = self
+            .secondary_indices
+            .iter()
+            .map(|index| index.deref())
+            .filter(|index| {
+                progress.last_processed_state_versions[index.name()] < max_state_version
+            })
+            .collect::<Vec<&dyn SecondaryIndex>>();
+        let min_last_processed_state_version = lagging_indices
+            .iter()
+            .map(|index| progress.last_processed_state_versions[index.name()])
+            .min()?;
+        let base_last_processed_state_versions = lagging_indices
+            .iter()
+            .map(|index| {
+                (
+                    index.name().to_string(),
+                    progress.last_processed_state_versions[index.name()],
+                )
+            })
+            .collect::<IndexMap<_, _>>();
+
+        // The transactions pruned in the meantime (e.g. by the ledger transactions GC) cannot be
+        // indexed - we start from the first available one, and record the skipped gap:
+        let first_available_state_version =
+            self.get_first_stored_ledger_transaction_state_version();
+        let db_context = self.open_read_context();
+        if min_last_processed_state_version.next().unwrap() < first_available_state_version {
+            let last_skipped_state_version = first_available_state_version.previous().unwrap();
+            for index in &lagging_indices {
+                let last_processed_state_version =
+                    progress.last_processed_state_versions[index.name()];
+                if last_processed_state_version < last_skipped_state_version {
+                    let first_skipped_state_version = last_processed_state_version.next().unwrap();
+                    warn!(
+                        "Secondary index {} skips the pruned transactions from {} to {}",
+                        index.name(),
+                        first_skipped_state_version,
+                        last_skipped_state_version
+                    );
+                    progress.skip(
+                        index.name(),
+                        first_skipped_state_version,
+                        last_skipped_state_version,
+                    );
+                }
+            }
+        }
+        let from_state_version = lagging_indices
+            .iter()
+            .map(|index| progress.last_processed_state_versions[index.name()])
+            .min()
+            .unwrap()
+            .next()
+            .unwrap();
+
+        let executions = db_context
+            .cf(LocalTransactionExecutionsCf)
+            .iterate_from(&from_state_version, Direction::Forward)
+            .take(max_transaction_count)
+            .collect::<Vec<_>>();
+        let reached_ledger_end = executions.len() < max_transaction_count;
+        let mut entries = Vec::new();
+        for (state_version, local_execution) in executions {
+            let transactions_cf = db_context.cf(RawLedgerTransactionsCf);
+            let receipts_cf = db_context.cf(TransactionReceiptsCf);
+            let (Some(raw), Some(ledger_receipt)) = (
+                transactions_cf.get(&state_version),
+                receipts_cf.get(&state_version),
+            ) else {
+                warn!(
+                    "Secondary indices skip the unavailable transaction at {}",
+                    state_version
+                );
+                for index in &lagging_indices {
+                    if progress.last_processed_state_versions[index.name()] < state_version {
+                        progress.skip(index.name(), state_version, state_version);
+                    }
+                }
+                continue;
+            };
+            let input = SecondaryIndexInput {
+                state_version,
+                raw: &raw,
+                ledger_receipt: &ledger_receipt,
+                local_execution: &local_execution,
+            };
+            for index in &lagging_indices {
+                let last_processed_state_version = progress
+                    .last_processed_state_versions
+                    .get_mut(index.name())
+                    .unwrap();
+                if *last_processed_state_version < state_version {
+                    for key in index.extract_keys(&input) {
+                        entries.push((index.name().to_string(), key, state_version));
+                    }
+                    *last_processed_state_version = state_version;
+                }
+            }
+        }
+        if reached_ledger_end {
+            // The remaining state versions (if any) have no local executions to index:
+            for index in &lagging_indices {
+                progress
+                    .last_processed_state_versions
+                    .insert(index.name().to_string(), max_state_version);
+            }
+        }
+
+        Some(SecondaryIndicesCatchupBatch {
+            base_last_processed_state_versions,
+            progress,
+            entries,
+        })
+    }
+}
+
+impl<R: WriteableRocks> SecondaryIndicesCatchupStore for StateManagerDatabase<R> {
+    fn apply_secondary_indices_catchup(&self, batch: SecondaryIndicesCatchupBatch) -> bool {
+        let SecondaryIndicesCatchupBatch {
+            base_last_processed_state_versions,
+            progress: batch_progress,
+            entries,
+        } = batch;
+        let mut progress = self.read_secondary_indices_progress();
+        for (index_name, base_last_processed_state_version) in &base_last_processed_state_versions {
+            if progress.last_processed_state_versions.get(index_name)
+                != Some(base_last_processed_state_version)
+            {
+                // Someone else progressed the index in the meantime (should not happen in practice):
+                return true;
+            }
+        }
+
+        // Only the lagging indices' progress is taken from the batch (the caught-up indices may
+        // have been progressed by the commits in the meantime):
+        for index_name in base_last_processed_state_versions.keys() {
+            progress.last_processed_state_versions.insert(
+                index_name.clone(),
+                batch_progress.last_processed_state_versions[index_name],
+            );
+            match batch_progress.skipped_state_version_ranges.get(index_name) {
+                Some(ranges) => {
+                    progress
+                        .skipped_state_version_ranges
+                        .insert(index_name.clone(), ranges.clone());
+                }
+                None => {
+                    progress
+                        .skipped_state_version_ranges
+                        .swap_remove(index_name);
+                }
+            }
+        }
+
+        let db_context = self.open_rw_context();
+        for (index_name, key, state_version) in entries {
+            db_context
+                .cf(SecondaryIndexCf::of(&index_name))
+                .put(&(hash(key), state_version), &());
+        }
+        let max_state_version = self.max_state_version();
+        let caught_up = progress
+            .last_processed_state_versions
+            .values()
+            .all(|last_processed_state_version| *last_processed_state_version == max_state_version);
+        info!(
+            "Secondary indices processed up to {} (caught up: {})",
+            progress
+                .last_processed_state_versions
+                .values()
+                .min()
+                .unwrap(),
+            caught_up
+        );
+        self.write_secondary_indices_progress(&db_context, progress);
+        caught_up
+    }
+}
+
 impl<R: WriteableRocks> RestoreDecember2023LostSubstates for StateManagerDatabase<R> {
     fn restore_december_2023_lost_substates(&self, network: &NetworkDefinition) {
         let db_context = self.open_rw_context();
@@ -1712,13 +3398,74 @@ impl<R: ReadableRocks> IterableAccountChangeIndex for StateManagerDatabase<R> {
         Box::new(
             self.open_read_context()
                 .cf(AccountChangeStateVersionsCf)
-                .iterate_from(&(account, from_state_version), Direction::Forward)
+                .iterate_prefix_from(&(account, from_state_version))
                 .take_while(move |((next_account, _), _)| next_account == &account)
                 .map(|((_, state_version), _)| state_version),
         )
     }
 }
 
+impl<R: ReadableRocks> IterableResourceChangeIndex for StateManagerDatabase<R> {
+    fn get_resource_change_index_last_processed_state_version(&self) -> Option<StateVersion> {
+        self.open_read_context()
+            .cf(ExtensionsDataCf)
+            .get(&ExtensionsDataKey::ResourceChangeIndexLastProcessedStateVersion)
+            .map(StateVersion::from_be_bytes)
+    }
+
+    fn get_state_versions_for_resource_iter(
+        &self,
+        resource: ResourceAddress,
+        from_state_version: StateVersion,
+    ) -> Box<dyn Iterator<Item = StateVersion> + '_> {
+        Box::new(
+            self.open_read_context()
+                .cf(ResourceChangeStateVersionsCf)
+                .iterate_prefix_from(&(resource, from_state_version))
+                .take_while(move |((next_resource, _), _)| next_resource == &resource)
+                .map(|((_, state_version), _)| state_version),
+        )
+    }
+}
+
+impl<R: ReadableRocks> IterableSecondaryIndex for StateManagerDatabase<R> {
+    fn get_secondary_index_last_processed_state_version(
+        &self,
+        index_name: &str,
+    ) -> Option<StateVersion> {
+        self.read_secondary_indices_progress()
+            .last_processed_state_versions
+            .get(index_name)
+            .copied()
+    }
+
+    fn get_secondary_index_skipped_state_version_ranges(
+        &self,
+        index_name: &str,
+    ) -> Vec<(StateVersion, StateVersion)> {
+        self.read_secondary_indices_progress()
+            .skipped_state_version_ranges
+            .swap_remove(index_name)
+            .unwrap_or_default()
+    }
+
+    fn get_state_versions_for_secondary_index_key_iter(
+        &self,
+        index_name: &str,
+        key: &[u8],
+        from_state_version: StateVersion,
+    ) -> Box<dyn Iterator<Item = StateVersion> + '_> {
+        let key_hash = hash(key);
+        Box::new(
+            self.open_read_context()
+                .cf(SecondaryIndexCf::of(index_name))
+                .iterate_from(&(key_hash, from_state_version), Direction::Forward)
+                .take_while(move |((next_key_hash, _), _)| next_key_hash == &key_hash)
+                .map(|((_, state_version), _)| state_version),
+        )
+    }
+}
+
 impl<R: ReadableRocks> EntityListingIndex for StateManagerDatabase<R> {
     fn get_created_entity_iter(
         &self,
@@ -0,0 +1,428 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+/// A set of [`SecondaryIndex`]es which may be enabled by their names (see
+/// [`DatabaseConfig::enabled_secondary_indices`]).
+#[derive(Clone)]
+pub struct SecondaryIndexRegistry {
+    indices: IndexMap<&'static str, Arc<dyn SecondaryIndex>>,
+}
+
+impl SecondaryIndexRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            indices: index_map_new(),
+        }
+    }
+
+    /// Registers the given index (replacing any previously registered index of the same name).
+    pub fn register(mut self, index: impl SecondaryIndex + 'static) -> Self {
+        self.indices.insert(index.name(), Arc::new(index));
+        self
+    }
+
+    /// Resolves the indices of the given names.
+    pub fn resolve(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Arc<dyn SecondaryIndex>>, DatabaseConfigValidationError> {
+        names
+            .iter()
+            .map(|name| {
+                self.indices.get(name.as_str()).cloned().ok_or_else(|| {
+                    DatabaseConfigValidationError::UnknownSecondaryIndex(name.clone())
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for SecondaryIndexRegistry {
+    /// Creates a registry of all the built-in indices.
+    fn default() -> Self {
+        Self::new()
+            .register(EmittedEventTypeIndex)
+            .register(ChangedEntityIndex)
+    }
+}
+
+/// An index of transactions by the types of events they emitted.
+/// Key: `scrypto_encode(EventTypeIdentifier)`.
+pub struct EmittedEventTypeIndex;
+
+impl SecondaryIndex for EmittedEventTypeIndex {
+    fn name(&self) -> &'static str {
+        "emitted_event_type"
+    }
+
+    fn extract_keys(&self, transaction: &SecondaryIndexInput) -> IndexSet<Vec<u8>> {
+        transaction
+            .ledger_receipt
+            .application_events
+            .iter()
+            .map(|event| scrypto_encode(&event.type_id).unwrap())
+            .collect()
+    }
+}
+
+/// An index of transactions by the entities whose state they changed.
+/// Key: `NodeId.0`.
+pub struct ChangedEntityIndex;
+
+impl SecondaryIndex for ChangedEntityIndex {
+    fn name(&self) -> &'static str {
+        "changed_entity"
+    }
+
+    fn extract_keys(&self, transaction: &SecondaryIndexInput) -> IndexSet<Vec<u8>> {
+        let state_changes = &transaction.ledger_receipt.state_changes;
+        state_changes
+            .substate_level_changes
+            .iter_node_ids()
+            .chain(
+                state_changes
+                    .partition_level_changes
+                    .iter()
+                    .map(|(PartitionReference(node_id, _), _)| node_id),
+            )
+            .map(|node_id| node_id.0.to_vec())
+            .collect()
+    }
+}
+
+/// A background process catching up the enabled [`SecondaryIndex`]es which lag behind the ledger
+/// (i.e. which were enabled on an already-populated ledger).
+/// The implementation is suited for being driven by an external scheduler.
+pub struct SecondaryIndicesCatchup {
+    database: Arc<DbLock<ActualStateManagerDatabase>>,
+}
+
+impl SecondaryIndicesCatchup {
+    const INTERVAL: Duration = Duration::from_secs(10);
+    const TRANSACTION_BATCH_SIZE: usize = 1000;
+
+    /// Creates a new catch-up process.
+    pub fn new(database: Arc<DbLock<ActualStateManagerDatabase>>) -> Self {
+        Self { database }
+    }
+
+    /// An interval between [`run()`]s, to be used by this instance's scheduler.
+    pub fn interval(&self) -> Duration {
+        Self::INTERVAL
+    }
+
+    /// Performs a single catch-up run, which processes the committed transactions in batches,
+    /// until all the enabled indices reach the top of the ledger.
+    ///
+    /// *Note on concurrent database access:*
+    /// Each batch's index entries are computed from the (immutable) committed transactions on a
+    /// [`DbLock::snapshot()`], without blocking the commits. Only the write of the batch happens
+    /// under the [`DbLock::lock()`], so that it cannot interleave with a commit (which maintains
+    /// the already caught-up indices on its own).
+    pub fn run(&self) {
+        loop {
+            let Some(batch) = self
+                .database
+                .snapshot()
+                .compute_secondary_indices_catchup(Self::TRANSACTION_BATCH_SIZE)
+            else {
+                break;
+            };
+            let caught_up = self.database.lock().apply_secondary_indices_catchup(batch);
+            if caught_up {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::ledger_transactions_gc::LedgerTransactionsGc;
+    use crate::store::proofs_gc::{LedgerProofsGc, LedgerProofsGcConfig};
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager(
+        path: &std::path::Path,
+        enabled_secondary_indices: Vec<String>,
+    ) -> StateManager {
+        let mut config = StateManagerConfig::new_for_testing(path.to_str().unwrap());
+        config.database_config.enabled_secondary_indices = enabled_secondary_indices;
+        create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+    }
+
+    fn assert_changed_entity_index_complete(database: &ActualStateManagerDatabase) {
+        let consensus_manager = CONSENSUS_MANAGER.into_node_id();
+        let expected_state_versions = (1..=database.max_state_version().number())
+            .map(StateVersion::of)
+            .filter(|state_version| {
+                database
+                    .get_committed_ledger_transaction_receipt(*state_version)
+                    .unwrap()
+                    .state_changes
+                    .substate_level_changes
+                    .iter_node_ids()
+                    .any(|node_id| node_id == &consensus_manager)
+            })
+            .collect::<Vec<_>>();
+        let indexed_state_versions = database
+            .get_state_versions_for_secondary_index_key_iter(
+                ChangedEntityIndex.name(),
+                consensus_manager.0.as_slice(),
+                StateVersion::pre_genesis(),
+            )
+            .collect::<Vec<_>>();
+        assert!(!expected_state_versions.is_empty());
+        assert_eq!(indexed_state_versions, expected_state_versions);
+    }
+
+    #[test]
+    fn enabled_index_is_maintained_on_commit() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager =
+            create_state_manager(tmp.path(), vec![ChangedEntityIndex.name().to_string()]);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            Some(database.max_state_version())
+        );
+        assert_changed_entity_index_complete(database.deref());
+    }
+
+    #[test]
+    fn index_enabled_retroactively_is_caught_up_in_background() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), vec![]);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        drop(state_manager);
+
+        let state_manager =
+            create_state_manager(tmp.path(), vec![ChangedEntityIndex.name().to_string()]);
+        // The lagging index is not maintained on commit...
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+        assert_eq!(
+            state_manager
+                .database
+                .access_direct()
+                .get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            Some(StateVersion::pre_genesis())
+        );
+
+        // ... until the background process catches it up:
+        SecondaryIndicesCatchup::new(state_manager.database.clone()).run();
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(5));
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            Some(database.max_state_version())
+        );
+        assert_changed_entity_index_complete(database.deref());
+    }
+
+    #[test]
+    fn catchup_batch_computed_outside_lock_survives_concurrent_commits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), vec![]);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        drop(state_manager);
+
+        let state_manager =
+            create_state_manager(tmp.path(), vec![ChangedEntityIndex.name().to_string()]);
+        let batch = state_manager
+            .database
+            .snapshot()
+            .compute_secondary_indices_catchup(usize::MAX)
+            .unwrap();
+        let outdated_batch = state_manager
+            .database
+            .snapshot()
+            .compute_secondary_indices_catchup(usize::MAX)
+            .unwrap();
+
+        // The commits happening before the batch is written are left for the next batch:
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+        let caught_up = state_manager
+            .database
+            .lock()
+            .apply_secondary_indices_catchup(batch);
+        assert!(!caught_up);
+
+        // An outdated batch (i.e. computed from the same progress) is not written:
+        let database = state_manager.database.lock();
+        let last_processed_state_version =
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name());
+        database.apply_secondary_indices_catchup(outdated_batch);
+        assert_eq!(
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            last_processed_state_version
+        );
+        drop(database);
+
+        SecondaryIndicesCatchup::new(state_manager.database.clone()).run();
+        let database = state_manager.database.access_direct();
+        assert_eq!(
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            Some(database.max_state_version())
+        );
+        assert_changed_entity_index_complete(database.deref());
+    }
+
+    #[test]
+    fn catchup_skips_transactions_pruned_in_the_meantime() {
+        let tmp = tempfile::tempdir().unwrap();
+        let create_state_manager = |enabled_secondary_indices: Vec<String>| {
+            let mut config = StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap());
+            config.database_config.enabled_secondary_indices = enabled_secondary_indices;
+            config
+                .database_config
+                .ledger_transaction_retention_epoch_count = Some(2);
+            create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+        };
+        let state_manager = create_state_manager(vec![]);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(8));
+        drop(state_manager);
+
+        // The index gets enabled, but the old transactions are pruned before it catches up:
+        let state_manager = create_state_manager(vec![ChangedEntityIndex.name().to_string()]);
+        let raw_db_metrics = Arc::new(RawDbMetrics::new(&MetricRegistry::new()));
+        LedgerProofsGc::new(
+            state_manager.database.clone(),
+            LedgerProofsGcConfig {
+                interval_sec: 0,
+                most_recent_full_resolution_epoch_count: 0,
+            },
+            LedgerSyncLimitsConfig {
+                max_txns_for_responses_spanning_more_than_one_proof: 8,
+                max_txn_bytes_for_single_response: 1_000_000,
+            },
+            raw_db_metrics.clone(),
+        )
+        .run();
+        LedgerTransactionsGc::new(state_manager.database.clone(), 2, raw_db_metrics).run();
+        SecondaryIndicesCatchup::new(state_manager.database.clone()).run();
+
+        let database = state_manager.database.access_direct();
+        let first_available_state_version =
+            database.get_first_stored_ledger_transaction_state_version();
+        assert!(first_available_state_version > StateVersion::of(1));
+        assert_eq!(
+            database.get_secondary_index_last_processed_state_version(ChangedEntityIndex.name()),
+            Some(database.max_state_version())
+        );
+        assert_eq!(
+            database.get_secondary_index_skipped_state_version_ranges(ChangedEntityIndex.name()),
+            vec![(
+                StateVersion::of(1),
+                first_available_state_version.previous().unwrap()
+            )]
+        );
+        let consensus_manager = CONSENSUS_MANAGER.into_node_id();
+        let indexed_state_versions = database
+            .get_state_versions_for_secondary_index_key_iter(
+                ChangedEntityIndex.name(),
+                consensus_manager.0.as_slice(),
+                StateVersion::pre_genesis(),
+            )
+            .collect::<Vec<_>>();
+        assert!(!indexed_state_versions.is_empty());
+        assert!(indexed_state_versions
+            .iter()
+            .all(|state_version| *state_version >= first_available_state_version));
+    }
+
+    #[test]
+    fn index_disabled_retroactively_is_dropped() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager =
+            create_state_manager(tmp.path(), vec![EmittedEventTypeIndex.name().to_string()]);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        drop(state_manager);
+
+        let state_manager = create_state_manager(tmp.path(), vec![]);
+        assert_eq!(
+            state_manager
+                .database
+                .access_direct()
+                .get_secondary_index_last_processed_state_version(EmittedEventTypeIndex.name()),
+            None
+        );
+    }
+
+    #[test]
+    fn unknown_index_is_rejected() {
+        let result = SecondaryIndexRegistry::default().resolve(&["unknown".to_string()]);
+        assert!(matches!(
+            result,
+            Err(DatabaseConfigValidationError::UnknownSecondaryIndex(name)) if name == "unknown"
+        ));
+    }
+}
@@ -0,0 +1,1023 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::checksummed_records::*;
+use crate::store::historical_state::{
+    StateHistoryError, VersionScopedDatabase, VersionScopingSupport,
+};
+use crate::store::ledger_archive::ArchivedTransaction;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+/// A magic prefix of every state snapshot file.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"RDXSTATE";
+
+/// A default (approximate) total size of Substate values to put in a single snapshot record.
+/// The loader recomputes the state tree in the same batches.
+pub const DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE: usize = 16 * 1024 * 1024;
+
+define_single_versioned! {
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub VersionedStateSnapshotRecord(StateSnapshotRecordVersions) => StateSnapshotRecord = StateSnapshotRecordV1
+}
+
+/// A single, checksummed unit of the state snapshot.
+/// A valid snapshot consists of a [`StateSnapshotRecordV1::Header`], followed by any number of
+/// the content records (in any order), and terminated by a [`StateSnapshotRecordV1::End`].
+///
+/// Note: the state hash tree nodes are deliberately not a part of the snapshot - the loader
+/// recomputes them from the Substates (which is the actual verification of the snapshot).
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum StateSnapshotRecordV1 {
+    Header(StateSnapshotHeader),
+    EpochChange(StateSnapshotEpochChange),
+    ProtocolUpdateProofs(Vec<VersionedLedgerProof>),
+    Substates(Vec<(DbSubstateKey, DbSubstateValue)>),
+    NodeAncestryRecords(Vec<(NodeId, VersionedSubstateNodeAncestryRecord)>),
+    End(StateSnapshotEnd),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct StateSnapshotHeader {
+    /// The network of the snapshotted ledger.
+    pub network_definition: NetworkDefinition,
+    /// The epoch proof at which the snapshot was taken (i.e. the snapshot contains the state right
+    /// after committing this proof). Its state root is what the loader verifies the state against.
+    pub anchor_proof: VersionedLedgerProof,
+}
+
+/// A past epoch change: the transaction which ended an epoch, together with its epoch proof.
+/// These are needed by the Node's protocol state (and are cheap to include).
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct StateSnapshotEpochChange {
+    pub transaction: ArchivedTransaction,
+    pub epoch_proof: VersionedLedgerProof,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct StateSnapshotEnd {
+    /// The number of content records (i.e. excluding the header and this end marker).
+    pub record_count: u64,
+}
+
+#[derive(Debug)]
+pub enum StateSnapshotError {
+    Format(ChecksummedRecordError),
+    UnexpectedRecord {
+        record_index: u64,
+    },
+    RecordCountMismatch {
+        declared: u64,
+        actual: u64,
+    },
+    EpochProofNotFound {
+        epoch: Epoch,
+    },
+    StateUnavailable {
+        state_version: StateVersion,
+        error: StateHistoryError,
+    },
+    MissingTransaction {
+        state_version: StateVersion,
+    },
+    TargetDatabaseNotEmpty {
+        max_state_version: StateVersion,
+    },
+    TargetStateHistoryEnabled,
+    EpochChangeMismatch {
+        state_version: StateVersion,
+    },
+    AnchorEpochChangeMismatch,
+    /// The snapshot was taken on a different network than the one it is being loaded for.
+    NetworkMismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The snapshot does not contain the trusted epoch proof (see
+    /// [`SnapshotProofVerification::SignedChain`]).
+    TrustedEpochProofNotFound,
+    /// The proofs chain (from the trusted epoch proof up to the anchor) is invalid.
+    ProofVerificationFailed {
+        state_version: StateVersion,
+        error: LedgerProofVerificationError,
+    },
+    StateRootMismatch {
+        anchor: StateHash,
+        computed: Option<StateHash>,
+    },
+}
+
+impl From<ChecksummedRecordError> for StateSnapshotError {
+    fn from(error: ChecksummedRecordError) -> Self {
+        StateSnapshotError::Format(error)
+    }
+}
+
+/// A low-level writer of the snapshot file format (see [`ChecksummedRecordWriter`]).
+struct StateSnapshotWriter<W: Write> {
+    records: ChecksummedRecordWriter<W>,
+    record_count: u64,
+}
+
+impl<W: Write> StateSnapshotWriter<W> {
+    fn new(output: W, header: StateSnapshotHeader) -> Result<Self, StateSnapshotError> {
+        let mut records = ChecksummedRecordWriter::new(output, SNAPSHOT_MAGIC)?;
+        records.write(&VersionedStateSnapshotRecord::from(
+            StateSnapshotRecordV1::Header(header),
+        ))?;
+        Ok(Self {
+            records,
+            record_count: 0,
+        })
+    }
+
+    fn write(&mut self, record: StateSnapshotRecord) -> Result<(), StateSnapshotError> {
+        self.record_count += 1;
+        Ok(self
+            .records
+            .write(&VersionedStateSnapshotRecord::from(record))?)
+    }
+
+    fn finish(mut self) -> Result<W, StateSnapshotError> {
+        self.records.write(&VersionedStateSnapshotRecord::from(
+            StateSnapshotRecordV1::End(StateSnapshotEnd {
+                record_count: self.record_count,
+            }),
+        ))?;
+        Ok(self.records.finish()?)
+    }
+}
+
+/// A low-level reader of the snapshot file format.
+pub struct StateSnapshotReader<R: Read> {
+    records: ChecksummedRecordReader<R>,
+    header: StateSnapshotHeader,
+    finished: bool,
+}
+
+impl<R: Read> StateSnapshotReader<R> {
+    /// Reads and validates the snapshot preamble and header.
+    pub fn new(input: R) -> Result<Self, StateSnapshotError> {
+        let mut records = ChecksummedRecordReader::new(input, SNAPSHOT_MAGIC)?;
+        let StateSnapshotRecordV1::Header(header) = read_record(&mut records)? else {
+            return Err(StateSnapshotError::UnexpectedRecord { record_index: 0 });
+        };
+        Ok(Self {
+            records,
+            header,
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &StateSnapshotHeader {
+        &self.header
+    }
+
+    /// Reads the next content record, or returns `None` after reaching the (validated) end of the
+    /// snapshot. Never returns the header nor end records.
+    pub fn next_record(&mut self) -> Result<Option<StateSnapshotRecord>, StateSnapshotError> {
+        if self.finished {
+            return Ok(None);
+        }
+        let record_index = self.records.next_record_index();
+        match read_record(&mut self.records)? {
+            StateSnapshotRecordV1::Header(_) => {
+                Err(StateSnapshotError::UnexpectedRecord { record_index })
+            }
+            StateSnapshotRecordV1::End(end) => {
+                let actual = record_index - 1;
+                if end.record_count != actual {
+                    return Err(StateSnapshotError::RecordCountMismatch {
+                        declared: end.record_count,
+                        actual,
+                    });
+                }
+                self.finished = true;
+                Ok(None)
+            }
+            record => Ok(Some(record)),
+        }
+    }
+}
+
+fn read_record(
+    records: &mut ChecksummedRecordReader<impl Read>,
+) -> Result<StateSnapshotRecord, StateSnapshotError> {
+    Ok(records
+        .read::<VersionedStateSnapshotRecord>()?
+        .fully_update_and_into_latest_version())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StateSnapshotDumpSummary {
+    pub anchor_state_version: StateVersion,
+    pub epoch_change_count: u64,
+    pub substate_count: u64,
+    pub node_ancestry_record_count: u64,
+}
+
+/// Writes a snapshot of the entire state (i.e. all Substates, with their Nodes' ancestry records)
+/// as of the epoch proof which initiated the given `anchor_epoch`.
+///
+/// The state at the anchor proof must be readable: either it is the current top of the ledger, or
+/// the source database must have the state history covering it. Additionally, the database must
+/// have the local transaction execution index enabled (since past epoch change transactions are
+/// included in their entirety).
+pub fn dump_state_snapshot<R: ReadableRocks, W: Write>(
+    database: &StateManagerDatabase<R>,
+    network_definition: &NetworkDefinition,
+    anchor_epoch: Epoch,
+    output: W,
+    batch_byte_size: usize,
+) -> Result<(W, StateSnapshotDumpSummary), StateSnapshotError> {
+    let anchor_proof =
+        database
+            .get_epoch_proof(anchor_epoch)
+            .ok_or(StateSnapshotError::EpochProofNotFound {
+                epoch: anchor_epoch,
+            })?;
+    let anchor_state_version = anchor_proof.ledger_header.state_version;
+    let scoped_database = database
+        .scoped_at(Some(anchor_state_version))
+        .map_err(|error| StateSnapshotError::StateUnavailable {
+            state_version: anchor_state_version,
+            error,
+        })?;
+
+    let mut writer = StateSnapshotWriter::new(
+        output,
+        StateSnapshotHeader {
+            network_definition: network_definition.clone(),
+            anchor_proof: anchor_proof.into(),
+        },
+    )?;
+    let mut summary = StateSnapshotDumpSummary {
+        anchor_state_version,
+        ..Default::default()
+    };
+
+    for epoch_proof in database.get_next_epoch_proof_iter(Epoch::of(0)) {
+        let state_version = epoch_proof.ledger_header.state_version;
+        if state_version > anchor_state_version {
+            break;
+        }
+        let transaction = get_committed_transaction_bundle(database, state_version)
+            .ok_or(StateSnapshotError::MissingTransaction { state_version })?;
+        writer.write(StateSnapshotRecordV1::EpochChange(
+            StateSnapshotEpochChange {
+                transaction: transaction.into(),
+                epoch_proof: epoch_proof.into(),
+            },
+        ))?;
+        summary.epoch_change_count += 1;
+    }
+
+    let first_state_version = StateVersion::pre_genesis().next().unwrap();
+    let protocol_update_proofs = database
+        .get_protocol_update_init_proof_iter(first_state_version)
+        .take_while(|proof| proof.ledger_header.state_version <= anchor_state_version)
+        .chain(
+            database
+                .get_protocol_update_execution_proof_iter(first_state_version)
+                .take_while(|proof| proof.ledger_header.state_version <= anchor_state_version),
+        )
+        .map(VersionedLedgerProof::from)
+        .collect::<Vec<_>>();
+    writer.write(StateSnapshotRecordV1::ProtocolUpdateProofs(
+        protocol_update_proofs,
+    ))?;
+
+    let substates: Box<dyn Iterator<Item = (DbSubstateKey, DbSubstateValue)> + '_> =
+        match &scoped_database {
+            VersionScopedDatabase::Current(current) => Box::new(
+                current
+                    .list_partition_keys()
+                    .flat_map(move |partition_key| {
+                        current
+                            .list_raw_values_from_db_key(&partition_key, None)
+                            .map(move |(sort_key, value)| {
+                                ((partition_key.clone(), sort_key), value)
+                            })
+                    }),
+            ),
+            VersionScopedDatabase::Historical(historical) => Box::new(historical.iter_substates()),
+        };
+
+    // Both sources list the Substates grouped by their Nodes, so we can collect the Nodes' ancestry
+    // records by simply tracking the Node key changes:
+    let mut last_node_key = None;
+    let mut batch_node_ids = Vec::new();
+    let mut batch_substates = Vec::new();
+    let mut batch_substates_byte_size = 0;
+    for (substate_key, value) in substates {
+        let partition_key = &substate_key.0;
+        if last_node_key.as_ref() != Some(&partition_key.node_key) {
+            let (node_id, _) = SpreadPrefixKeyMapper::from_db_partition_key(partition_key);
+            batch_node_ids.push(node_id);
+            last_node_key = Some(partition_key.node_key.clone());
+        }
+        batch_substates_byte_size += value.len();
+        batch_substates.push((substate_key, value));
+        if batch_substates_byte_size >= batch_byte_size {
+            write_substates_batch(
+                &mut writer,
+                database,
+                std::mem::take(&mut batch_substates),
+                std::mem::take(&mut batch_node_ids),
+                &mut summary,
+            )?;
+            batch_substates_byte_size = 0;
+        }
+    }
+    if !batch_substates.is_empty() {
+        write_substates_batch(
+            &mut writer,
+            database,
+            batch_substates,
+            batch_node_ids,
+            &mut summary,
+        )?;
+    }
+
+    Ok((writer.finish()?, summary))
+}
+
+fn write_substates_batch<W: Write>(
+    writer: &mut StateSnapshotWriter<W>,
+    ancestry_store: &impl SubstateNodeAncestryStore,
+    substates: Vec<(DbSubstateKey, DbSubstateValue)>,
+    node_ids: Vec<NodeId>,
+    summary: &mut StateSnapshotDumpSummary,
+) -> Result<(), StateSnapshotError> {
+    summary.substate_count += substates.len() as u64;
+    writer.write(StateSnapshotRecordV1::Substates(substates))?;
+
+    // Root Nodes do not have ancestry records:
+    let ancestry_records = ancestry_store
+        .batch_get_ancestry(node_ids.iter())
+        .into_iter()
+        .zip(node_ids)
+        .filter_map(|(record, node_id)| record.map(|record| (node_id, record.into())))
+        .collect::<Vec<_>>();
+    if !ancestry_records.is_empty() {
+        summary.node_ancestry_record_count += ancestry_records.len() as u64;
+        writer.write(StateSnapshotRecordV1::NodeAncestryRecords(ancestry_records))?;
+    }
+    Ok(())
+}
+
+fn get_committed_transaction_bundle(
+    database: &impl QueryableTransactionStore,
+    state_version: StateVersion,
+) -> Option<CommittedTransactionBundle> {
+    Some(CommittedTransactionBundle {
+        state_version,
+        raw: database.get_committed_transaction(state_version)?,
+        receipt: database.get_committed_local_transaction_receipt(state_version)?,
+        identifiers: database.get_committed_transaction_identifiers(state_version)?,
+    })
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StateSnapshotLoadSummary {
+    pub anchor_state_version: StateVersion,
+    pub epoch_change_count: u64,
+    pub substate_count: u64,
+    pub node_ancestry_record_count: u64,
+}
+
+/// How [`load_state_snapshot()`] verifies the snapshot's proofs.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SnapshotProofVerification {
+    /// The proofs must form a quorum-signed chain from the trusted epoch proof to the anchor.
+    /// The trusted proof is identified by its [`compute_ledger_header_hash()`], which must be
+    /// obtained out-of-band (e.g. from an own, already-synced Node), since nothing within the
+    /// snapshot itself can be trusted.
+    SignedChain {
+        trusted_epoch_proof_header_hash: Hash,
+    },
+    /// The proofs are not verified (only the state root is). This is only meant for snapshots of
+    /// ledgers without validator signatures (i.e. test ledgers).
+    Skipped,
+}
+
+/// Initializes the given (empty) database from the given snapshot, which must have been taken on
+/// the given network.
+///
+/// Unless [`SnapshotProofVerification::Skipped`], the anchor proof is verified as the end of a
+/// quorum-signed proofs chain starting at the trusted epoch proof, so that each epoch's proofs are
+/// checked against the validator set announced by the previous epoch change. The proofs preceding
+/// the trusted one are not verified (i.e. in order to verify the entire chain, the genesis' epoch
+/// proof should be trusted).
+///
+/// The state hash tree is recomputed from the snapshot's Substates, and its root is verified
+/// against the anchor proof's state root. Only after a successful verification, the anchor proof
+/// is written as the top of the ledger. An interrupted or failed load leaves an incomplete
+/// database, which should be discarded.
+///
+/// The resulting ledger has no transaction history below the anchor (apart from the epoch changes
+/// needed by the protocol state), and thus cannot serve it to syncing peers. It also cannot have
+/// the historical Substate values before the anchor, which is why the database must be opened with
+/// the state history disabled (it may be enabled on a subsequent boot-up).
+pub fn load_state_snapshot<D, R>(
+    database: &D,
+    network_definition: &NetworkDefinition,
+    reader: &mut StateSnapshotReader<R>,
+    proof_verification: SnapshotProofVerification,
+) -> Result<StateSnapshotLoadSummary, StateSnapshotError>
+where
+    D: StateSnapshotLoadingStore + ReadableTreeStore + QueryableProofStore + ConfigurableDatabase,
+    R: Read,
+{
+    let max_state_version = database.max_state_version();
+    if max_state_version != StateVersion::pre_genesis() {
+        return Err(StateSnapshotError::TargetDatabaseNotEmpty { max_state_version });
+    }
+    if database.is_state_history_enabled() {
+        return Err(StateSnapshotError::TargetStateHistoryEnabled);
+    }
+    let snapshot_network = &reader.header().network_definition;
+    if snapshot_network.id != network_definition.id
+        || snapshot_network.logical_name != network_definition.logical_name
+    {
+        return Err(StateSnapshotError::NetworkMismatch {
+            expected: network_definition.logical_name.to_string(),
+            actual: snapshot_network.logical_name.to_string(),
+        });
+    }
+
+    let anchor_proof = reader
+        .header()
+        .anchor_proof
+        .clone()
+        .fully_update_and_into_latest_version();
+    let anchor_state_version = anchor_proof.ledger_header.state_version;
+    let mut summary = StateSnapshotLoadSummary {
+        anchor_state_version,
+        ..Default::default()
+    };
+
+    let tree_store = VersionPinningTreeStore::new(database, anchor_state_version.number());
+    let mut tree_version = None;
+    let mut computed_state_root = None;
+    let mut last_epoch_change = None;
+    let mut chain_proofs = BTreeMap::new();
+    while let Some(record) = reader.next_record()? {
+        match record {
+            StateSnapshotRecordV1::EpochChange(epoch_change) => {
+                let transaction = CommittedTransactionBundle::from(epoch_change.transaction);
+                let epoch_proof = epoch_change
+                    .epoch_proof
+                    .fully_update_and_into_latest_version();
+                let state_version = epoch_proof.ledger_header.state_version;
+                if transaction.state_version != state_version
+                    || transaction.identifiers.resultant_ledger_hashes
+                        != epoch_proof.ledger_header.hashes
+                {
+                    return Err(StateSnapshotError::EpochChangeMismatch { state_version });
+                }
+                last_epoch_change = Some((state_version, epoch_proof.ledger_header.hashes));
+                chain_proofs.insert(state_version, epoch_proof.clone());
+                database.put_snapshot_ledger_history(vec![(transaction, epoch_proof)], vec![]);
+                summary.epoch_change_count += 1;
+            }
+            StateSnapshotRecordV1::ProtocolUpdateProofs(proofs) => {
+                let proofs = proofs
+                    .into_iter()
+                    .map(|proof| proof.fully_update_and_into_latest_version())
+                    .collect::<Vec<_>>();
+                for proof in &proofs {
+                    chain_proofs.insert(proof.ledger_header.state_version, proof.clone());
+                }
+                database.put_snapshot_ledger_history(vec![], proofs);
+            }
+            StateSnapshotRecordV1::Substates(substates) => {
+                let mut delta_maps =
+                    index_map_new::<DbPartitionKey, IndexMap<DbSortKey, DatabaseUpdate>>();
+                for ((partition_key, sort_key), value) in &substates {
+                    delta_maps
+                        .entry(partition_key.clone())
+                        .or_default()
+                        .insert(sort_key.clone(), DatabaseUpdate::Set(value.clone()));
+                }
+                let root_hash = put_at_next_version(
+                    &tree_store,
+                    tree_version,
+                    &DatabaseUpdates::from_delta_maps(delta_maps),
+                );
+                tree_version = Some(tree_version.map_or(1, |version| version + 1));
+                computed_state_root = Some(StateHash::from(root_hash));
+                database.put_snapshot_tree_nodes(tree_store.take_new_nodes());
+                summary.substate_count += substates.len() as u64;
+                database.put_snapshot_substates(substates);
+            }
+            StateSnapshotRecordV1::NodeAncestryRecords(records) => {
+                summary.node_ancestry_record_count += records.len() as u64;
+                database.put_snapshot_node_ancestry_records(
+                    records
+                        .into_iter()
+                        .map(|(node_id, record)| {
+                            (node_id, record.fully_update_and_into_latest_version())
+                        })
+                        .collect(),
+                );
+            }
+            StateSnapshotRecordV1::Header(_) | StateSnapshotRecordV1::End(_) => {
+                unreachable!("never returned by the reader")
+            }
+        }
+    }
+
+    // The anchor proof must be the last epoch proof (and is verified against its transaction):
+    if last_epoch_change != Some((anchor_state_version, anchor_proof.ledger_header.hashes)) {
+        return Err(StateSnapshotError::AnchorEpochChangeMismatch);
+    }
+    if let SnapshotProofVerification::SignedChain {
+        trusted_epoch_proof_header_hash,
+    } = proof_verification
+    {
+        verify_proofs_chain(
+            chain_proofs.into_values(),
+            &trusted_epoch_proof_header_hash,
+            &anchor_proof,
+        )?;
+    }
+    let anchor_state_root = anchor_proof.ledger_header.hashes.state_root;
+    if computed_state_root != Some(anchor_state_root) {
+        return Err(StateSnapshotError::StateRootMismatch {
+            anchor: anchor_state_root,
+            computed: computed_state_root,
+        });
+    }
+    database.put_snapshot_anchor_proof(anchor_proof);
+    Ok(summary)
+}
+
+/// Computes the hash identifying a trusted epoch proof (see
+/// [`SnapshotProofVerification::SignedChain`]), i.e. of its entire ledger header (which includes
+/// the next epoch's validator set).
+pub fn compute_ledger_header_hash(ledger_header: &LedgerHeader) -> Hash {
+    hash(scrypto_encode(ledger_header).expect("ledger header is always encodable"))
+}
+
+/// Verifies the given proofs (in their ledger order, up to and including the given anchor) as a
+/// chain starting at the trusted epoch proof among them.
+fn verify_proofs_chain(
+    proofs: impl IntoIterator<Item = LedgerProof>,
+    trusted_epoch_proof_header_hash: &Hash,
+    anchor_proof: &LedgerProof,
+) -> Result<(), StateSnapshotError> {
+    let mut verifier: Option<LedgerProofChainVerifier> = None;
+    for proof in proofs {
+        let state_version = proof.ledger_header.state_version;
+        let result = match verifier.as_mut() {
+            Some(verifier) => verifier
+                .verify_next(&VerifiableLedgerProof::from(&proof))
+                .map(|_| ()),
+            // The proofs before the trusted one are skipped:
+            None if compute_ledger_header_hash(&proof.ledger_header)
+                != *trusted_epoch_proof_header_hash =>
+            {
+                Ok(())
+            }
+            None => LedgerProofChainVerifier::new(&VerifiableLedgerProof::from(&proof)).map(
+                |new_verifier| {
+                    verifier = Some(new_verifier);
+                },
+            ),
+        };
+        result.map_err(|error| StateSnapshotError::ProofVerificationFailed {
+            state_version,
+            error,
+        })?;
+    }
+    let Some(verifier) = verifier else {
+        return Err(StateSnapshotError::TrustedEpochProofNotFound);
+    };
+    // The anchor itself is the last epoch proof, so the chain must have reached it:
+    let anchor_state_version = anchor_proof.ledger_header.state_version;
+    if verifier.latest_state_version() < anchor_state_version.number() {
+        return Err(StateSnapshotError::AnchorEpochChangeMismatch);
+    }
+    Ok(())
+}
+
+/// A state hash tree store which stores all nodes at a single, "pinned" version.
+///
+/// This allows to compute the state hash tree of a large snapshot incrementally (i.e. by applying
+/// its Substates in multiple batches, each at a subsequent "working" version), while ending up with
+/// a tree which looks as if it was entirely created at the snapshot's state version. The working
+/// versions never reach the storage: all node keys and all version references within the nodes
+/// (i.e. of children and sub-tiers) are re-written to the pinned version. This is correct since
+/// each batch only adds leaves, and a node version is not a part of its hash.
+///
+/// Stale tree parts and leaf associations are ignored (since nothing is stale at the pinned version
+/// and the state history is not supported for a snapshot's initial state).
+struct VersionPinningTreeStore<'s, S> {
+    base_store: &'s S,
+    pinned_version: u64,
+    new_nodes: RefCell<IndexMap<StoredTreeNodeKey, TreeNode>>,
+}
+
+impl<'s, S: ReadableTreeStore> VersionPinningTreeStore<'s, S> {
+    pub fn new(base_store: &'s S, pinned_version: u64) -> Self {
+        Self {
+            base_store,
+            pinned_version,
+            new_nodes: RefCell::new(index_map_new()),
+        }
+    }
+
+    /// Returns all the nodes inserted since the previous call (or creation).
+    pub fn take_new_nodes(&self) -> Vec<(StoredTreeNodeKey, TreeNode)> {
+        self.new_nodes.take().into_iter().collect()
+    }
+
+    fn pin_key(&self, key: &StoredTreeNodeKey) -> StoredTreeNodeKey {
+        StoredTreeNodeKey::new(self.pinned_version, key.nibble_path().clone())
+    }
+
+    fn pin_node(&self, node: TreeNode) -> TreeNode {
+        match node {
+            TreeNode::Internal(internal) => TreeNode::Internal(TreeInternalNode {
+                children: internal
+                    .children
+                    .into_iter()
+                    .map(|child| TreeChildEntry {
+                        version: self.pinned_version,
+                        ..child
+                    })
+                    .collect(),
+            }),
+            TreeNode::Leaf(leaf) => TreeNode::Leaf(TreeLeafNode {
+                last_hash_change_version: self.pinned_version,
+                ..leaf
+            }),
+            TreeNode::Null => TreeNode::Null,
+        }
+    }
+}
+
+impl<S: ReadableTreeStore> ReadableTreeStore for VersionPinningTreeStore<'_, S> {
+    fn get_node(&self, key: &StoredTreeNodeKey) -> Option<TreeNode> {
+        let pinned_key = self.pin_key(key);
+        if let Some(node) = self.new_nodes.borrow().get(&pinned_key) {
+            return Some(node.clone());
+        }
+        self.base_store.get_node(&pinned_key)
+    }
+}
+
+impl<S: ReadableTreeStore> WriteableTreeStore for VersionPinningTreeStore<'_, S> {
+    fn insert_node(&self, key: StoredTreeNodeKey, node: TreeNode) {
+        let pinned_node = self.pin_node(node);
+        self.new_nodes
+            .borrow_mut()
+            .insert(self.pin_key(&key), pinned_node);
+    }
+
+    fn associate_substate(
+        &self,
+        _state_tree_leaf_key: &StoredTreeNodeKey,
+        _partition_key: &DbPartitionKey,
+        _sort_key: &DbSortKey,
+        _substate_value: AssociatedSubstateValue,
+    ) {
+    }
+
+    fn record_stale_tree_part(&self, _part: StaleTreePart) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::secondary_indices::SecondaryIndexRegistry;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager(path: &std::path::Path, enable_state_history: bool) -> StateManager {
+        let mut config = StateManagerConfig::new_for_testing(path.to_str().unwrap());
+        config.database_config.enable_historical_substate_values = enable_state_history;
+        create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+    }
+
+    #[test]
+    fn loaded_snapshot_continues_as_identical_ledger() {
+        let source_tmp = tempfile::tempdir().unwrap();
+        let source = create_state_manager(source_tmp.path(), true);
+        commit_round_updates_until_epoch(&source, Epoch::of(4));
+
+        // Dump a historical state, in small batches (to exercise the incremental tree computation):
+        let (snapshot, dump_summary) = dump_state_snapshot(
+            source.database.access_direct().deref(),
+            &source.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            1024,
+        )
+        .unwrap();
+        assert!(dump_summary.substate_count > 0);
+
+        let target_tmp = tempfile::tempdir().unwrap();
+        {
+            let target_database = ActualStateManagerDatabase::new(
+                target_tmp.path().to_path_buf(),
+                DatabaseConfig::default(),
+                &source.network_definition,
+            )
+            .unwrap();
+            let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+            let load_summary = load_state_snapshot(
+                &target_database,
+                &source.network_definition,
+                &mut reader,
+                SnapshotProofVerification::Skipped,
+            )
+            .unwrap();
+            assert_eq!(
+                load_summary.anchor_state_version,
+                dump_summary.anchor_state_version
+            );
+            assert_eq!(load_summary.substate_count, dump_summary.substate_count);
+            assert_eq!(
+                target_database.max_state_version(),
+                load_summary.anchor_state_version
+            );
+        }
+
+        // The bootstrapped Node should pick up the ledger from the snapshot's anchor:
+        let target = create_state_manager(target_tmp.path(), false);
+        commit_round_updates_until_epoch(&target, Epoch::of(4));
+        let source_top = source
+            .database
+            .access_direct()
+            .get_top_transaction_identifiers()
+            .unwrap();
+        let target_top = target
+            .database
+            .access_direct()
+            .get_top_transaction_identifiers()
+            .unwrap();
+        assert_eq!(target_top.0, source_top.0);
+        assert_eq!(
+            target_top.1.resultant_ledger_hashes,
+            source_top.1.resultant_ledger_hashes
+        );
+    }
+
+    #[test]
+    fn snapshot_is_not_loaded_into_non_empty_database() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), false);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.access_direct();
+        let (snapshot, _) = dump_state_snapshot(
+            database.deref(),
+            &state_manager.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+        )
+        .unwrap();
+        let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+        let result = load_state_snapshot(
+            database.deref(),
+            &state_manager.network_definition,
+            &mut reader,
+            SnapshotProofVerification::Skipped,
+        );
+        assert!(matches!(
+            result,
+            Err(StateSnapshotError::TargetDatabaseNotEmpty { .. })
+        ));
+    }
+
+    #[test]
+    fn snapshot_without_signed_proofs_chain_is_rejected() {
+        let source_tmp = tempfile::tempdir().unwrap();
+        let source = create_state_manager(source_tmp.path(), false);
+        commit_round_updates_until_epoch(&source, Epoch::of(3));
+
+        let source_database = source.database.access_direct();
+        let (snapshot, _) = dump_state_snapshot(
+            source_database.deref(),
+            &source.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+        )
+        .unwrap();
+        let genesis_epoch_proof = source_database
+            .get_next_epoch_proof_iter(Epoch::of(0))
+            .next()
+            .unwrap();
+
+        // The test ledger's consensus proofs carry no validator signatures:
+        let target_tmp = tempfile::tempdir().unwrap();
+        let target_database = ActualStateManagerDatabase::new(
+            target_tmp.path().to_path_buf(),
+            DatabaseConfig::default(),
+            &source.network_definition,
+        )
+        .unwrap();
+        let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+        let result = load_state_snapshot(
+            &target_database,
+            &source.network_definition,
+            &mut reader,
+            SnapshotProofVerification::SignedChain {
+                trusted_epoch_proof_header_hash: compute_ledger_header_hash(
+                    &genesis_epoch_proof.ledger_header,
+                ),
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(StateSnapshotError::ProofVerificationFailed {
+                error: LedgerProofVerificationError::InsufficientSignedStake { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn snapshot_without_trusted_epoch_proof_is_rejected() {
+        let source_tmp = tempfile::tempdir().unwrap();
+        let source = create_state_manager(source_tmp.path(), false);
+        commit_round_updates_until_epoch(&source, Epoch::of(3));
+
+        let (snapshot, _) = dump_state_snapshot(
+            source.database.access_direct().deref(),
+            &source.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+        )
+        .unwrap();
+
+        // Whatever the snapshot's own proofs are, they cannot replace the trusted one:
+        let target_database = ActualStateManagerDatabase::new_in_memory(
+            DatabaseConfig::default(),
+            &SecondaryIndexRegistry::default(),
+            &source.network_definition,
+        )
+        .unwrap();
+        let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+        let result = load_state_snapshot(
+            &target_database,
+            &source.network_definition,
+            &mut reader,
+            SnapshotProofVerification::SignedChain {
+                trusted_epoch_proof_header_hash: Hash([7; Hash::LENGTH]),
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(StateSnapshotError::TrustedEpochProofNotFound)
+        ));
+    }
+
+    #[test]
+    fn snapshot_of_different_network_is_rejected() {
+        let source_tmp = tempfile::tempdir().unwrap();
+        let source = create_state_manager(source_tmp.path(), false);
+        commit_round_updates_until_epoch(&source, Epoch::of(3));
+
+        let (snapshot, _) = dump_state_snapshot(
+            source.database.access_direct().deref(),
+            &source.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+        )
+        .unwrap();
+
+        let target_database = ActualStateManagerDatabase::new_in_memory(
+            DatabaseConfig::default(),
+            &SecondaryIndexRegistry::default(),
+            &source.network_definition,
+        )
+        .unwrap();
+        // The operator expects a mainnet snapshot (regardless of what the snapshot's header says):
+        let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+        let result = load_state_snapshot(
+            &target_database,
+            &NetworkDefinition::mainnet(),
+            &mut reader,
+            SnapshotProofVerification::Skipped,
+        );
+        assert!(matches!(
+            result,
+            Err(StateSnapshotError::NetworkMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn snapshot_loads_into_in_memory_database() {
+        let tmp = tempfile::tempdir().unwrap();
+        let source = create_state_manager(tmp.path(), false);
+        commit_round_updates_until_epoch(&source, Epoch::of(3));
+
+        let source_database = source.database.access_direct();
+        let (snapshot, dump_summary) = dump_state_snapshot(
+            source_database.deref(),
+            &source.network_definition,
+            Epoch::of(3),
+            Vec::new(),
+            DEFAULT_SNAPSHOT_BATCH_BYTE_SIZE,
+        )
+        .unwrap();
+
+        let target_database = ActualStateManagerDatabase::new_in_memory(
+            DatabaseConfig::default(),
+            &SecondaryIndexRegistry::default(),
+            &source.network_definition,
+        )
+        .unwrap();
+        let mut reader = StateSnapshotReader::new(snapshot.as_slice()).unwrap();
+        let load_summary = load_state_snapshot(
+            &target_database,
+            &source.network_definition,
+            &mut reader,
+            SnapshotProofVerification::Skipped,
+        )
+        .unwrap();
+        assert_eq!(load_summary.substate_count, dump_summary.substate_count);
+        assert_eq!(
+            target_database.max_state_version(),
+            dump_summary.anchor_state_version
+        );
+        assert_eq!(
+            target_database.get_latest_proof().unwrap().ledger_header,
+            source_database
+                .get_proof(dump_summary.anchor_state_version)
+                .unwrap()
+                .ledger_header
+        );
+    }
+}
@@ -0,0 +1,519 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+
+/// A byte separating the nibble paths of a nested tier's nodes from the full leaf key of their
+/// parent tier (an implementation detail of the tiered tree store).
+const TIER_SEPARATOR: u8 = b'_';
+
+/// A hash of an empty subtree, as used by the Jellyfish Merkle Tree.
+const SPARSE_MERKLE_PLACEHOLDER_HASH: Hash = Hash([0; Hash::LENGTH]);
+
+/// A number of children of a Jellyfish Merkle Tree's internal node (i.e. one per nibble).
+const INTERNAL_NODE_ARITY: u8 = 16;
+
+/// A Merkle proof of a single Substate's value being included in the state tree at a specific
+/// version (i.e. under its [`LedgerHashes::state_root`]).
+///
+/// The state tree consists of 3 nested tiers of Jellyfish Merkle Trees: the Entity tier (keyed by
+/// [`DbNodeKey`]s) has leaves holding root hashes of Partition tiers (keyed by a single-byte
+/// [`DbPartitionNum`]), which in turn have leaves holding root hashes of Substate tiers (keyed by
+/// [`DbSortKey`]s), whose leaves finally hold hashes of Substate values. Hence the proof consists of
+/// a separate path within each tier.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateInclusionProof {
+    pub entity_tier: StateTreeTierProof,
+    pub partition_tier: StateTreeTierProof,
+    pub substate_tier: StateTreeTierProof,
+}
+
+/// A path from a tier's root to a leaf, represented by the *other* children of each internal node
+/// on the path (ordered from the root).
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StateTreeTierProof {
+    pub levels: Vec<StateTreeProofLevel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StateTreeProofLevel {
+    pub siblings: Vec<StateTreeProofSibling>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct StateTreeProofSibling {
+    pub nibble: u8,
+    pub hash: Hash,
+    pub is_leaf: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubstateProofError {
+    /// The state tree nodes of the requested version are not available (either not committed yet,
+    /// or already garbage-collected - see [`StateTreeGcConfig::state_version_history_length`]).
+    StateVersionNotRetained,
+    /// The requested Substate does not exist at the requested version.
+    SubstateNotFound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubstateProofVerificationError {
+    /// The proof is structurally invalid (e.g. deeper than the proven key, or with siblings at
+    /// invalid or duplicate positions).
+    MalformedProof,
+    /// The root computed from the proof does not match the expected one.
+    RootMismatch { computed_state_root: StateHash },
+}
+
+/// Generates a proof of the given Substate's inclusion in the state tree at the given version.
+pub fn prove_substate_inclusion<S: ReadableTreeStore>(
+    tree_store: &S,
+    state_version: StateVersion,
+    node_id: &NodeId,
+    partition_number: PartitionNumber,
+    substate_key: &SubstateKey,
+) -> Result<SubstateInclusionProof, SubstateProofError> {
+    prove_db_substate_inclusion(
+        tree_store,
+        state_version,
+        &SpreadPrefixKeyMapper::to_db_partition_key(node_id, partition_number),
+        &SpreadPrefixKeyMapper::to_db_sort_key(substate_key),
+    )
+}
+
+/// Generates a proof of the given Substate's (addressed by its DB keys) inclusion in the state tree
+/// at the given version.
+pub fn prove_db_substate_inclusion<S: ReadableTreeStore>(
+    tree_store: &S,
+    state_version: StateVersion,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Result<SubstateInclusionProof, SubstateProofError> {
+    let entity_leaf_key = partition_key.node_key.clone();
+    let (entity_tier, entity_leaf) =
+        prove_tier_leaf(tree_store, &[], state_version.number(), &entity_leaf_key)?;
+
+    let partition_tier_prefix = nested_tier_prefix(&[], &entity_leaf_key);
+    let partition_leaf_key = vec![partition_key.partition_num];
+    let (partition_tier, partition_leaf) = prove_tier_leaf(
+        tree_store,
+        &partition_tier_prefix,
+        entity_leaf.last_hash_change_version,
+        &partition_leaf_key,
+    )?;
+
+    let substate_tier_prefix = nested_tier_prefix(&partition_tier_prefix, &partition_leaf_key);
+    let (substate_tier, _substate_leaf) = prove_tier_leaf(
+        tree_store,
+        &substate_tier_prefix,
+        partition_leaf.last_hash_change_version,
+        &sort_key.0,
+    )?;
+
+    Ok(SubstateInclusionProof {
+        entity_tier,
+        partition_tier,
+        substate_tier,
+    })
+}
+
+/// Verifies that the given proof ties the given Substate value to the expected state root.
+/// This function is independent of any database, so that it can be used by light clients.
+pub fn verify_substate_inclusion(
+    proof: &SubstateInclusionProof,
+    node_id: &NodeId,
+    partition_number: PartitionNumber,
+    substate_key: &SubstateKey,
+    substate_value: &[u8],
+    expected_state_root: &StateHash,
+) -> Result<(), SubstateProofVerificationError> {
+    verify_db_substate_inclusion(
+        proof,
+        &SpreadPrefixKeyMapper::to_db_partition_key(node_id, partition_number),
+        &SpreadPrefixKeyMapper::to_db_sort_key(substate_key),
+        substate_value,
+        expected_state_root,
+    )
+}
+
+/// Verifies that the given proof ties the given Substate value (addressed by its DB keys) to the
+/// expected state root.
+pub fn verify_db_substate_inclusion(
+    proof: &SubstateInclusionProof,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+    substate_value: &[u8],
+    expected_state_root: &StateHash,
+) -> Result<(), SubstateProofVerificationError> {
+    let substate_tier_root =
+        compute_tier_root(&proof.substate_tier, &sort_key.0, hash(substate_value))?;
+    let partition_tier_root = compute_tier_root(
+        &proof.partition_tier,
+        &[partition_key.partition_num],
+        substate_tier_root,
+    )?;
+    let state_root = StateHash::from(compute_tier_root(
+        &proof.entity_tier,
+        &partition_key.node_key,
+        partition_tier_root,
+    )?);
+    if &state_root != expected_state_root {
+        return Err(SubstateProofVerificationError::RootMismatch {
+            computed_state_root: state_root,
+        });
+    }
+    Ok(())
+}
+
+/// Walks down a single tier (whose nodes are stored under the given nibble prefix), from its root
+/// at the given version to the leaf of the given key.
+fn prove_tier_leaf<S: ReadableTreeStore>(
+    tree_store: &S,
+    tier_prefix: &[u8],
+    root_version: Version,
+    leaf_key: &[u8],
+) -> Result<(StateTreeTierProof, TreeLeafNode), SubstateProofError> {
+    let key_nibbles = to_nibbles(leaf_key);
+    let mut path = tier_prefix.to_vec();
+    let mut version = root_version;
+    let mut levels = Vec::new();
+    loop {
+        let node_key = StoredTreeNodeKey::new(version, to_nibble_path(&path));
+        let Some(node) = tree_store.get_node(&node_key) else {
+            return Err(SubstateProofError::StateVersionNotRetained);
+        };
+        match node {
+            TreeNode::Internal(internal) => {
+                let depth = path.len() - tier_prefix.len();
+                let Some(nibble) = key_nibbles.get(depth).cloned() else {
+                    return Err(SubstateProofError::SubstateNotFound);
+                };
+                let mut proven_child = None;
+                let mut siblings = Vec::new();
+                for child in internal.children {
+                    let child_nibble = u8::from(child.nibble);
+                    if child_nibble == nibble {
+                        proven_child = Some(child);
+                    } else {
+                        siblings.push(StateTreeProofSibling {
+                            nibble: child_nibble,
+                            hash: child.hash,
+                            is_leaf: child.is_leaf,
+                        });
+                    }
+                }
+                let Some(proven_child) = proven_child else {
+                    return Err(SubstateProofError::SubstateNotFound);
+                };
+                levels.push(StateTreeProofLevel { siblings });
+                path.push(nibble);
+                version = proven_child.version;
+            }
+            TreeNode::Leaf(leaf) => {
+                let mut leaf_key_nibbles = path[tier_prefix.len()..].to_vec();
+                leaf_key_nibbles.extend(leaf.key_suffix.nibbles().map(u8::from));
+                if leaf_key_nibbles != key_nibbles {
+                    return Err(SubstateProofError::SubstateNotFound);
+                }
+                return Ok((StateTreeTierProof { levels }, leaf));
+            }
+            TreeNode::Null => return Err(SubstateProofError::SubstateNotFound),
+        }
+    }
+}
+
+/// Computes the root hash of a tier from the proof of its leaf.
+fn compute_tier_root(
+    proof: &StateTreeTierProof,
+    leaf_key: &[u8],
+    value_hash: Hash,
+) -> Result<Hash, SubstateProofVerificationError> {
+    let key_nibbles = to_nibbles(leaf_key);
+    if proof.levels.len() > key_nibbles.len() {
+        return Err(SubstateProofVerificationError::MalformedProof);
+    }
+    let mut current_hash = hash([leaf_key, &value_hash.0[..]].concat());
+    let mut current_is_leaf = true;
+    for (depth, level) in proof.levels.iter().enumerate().rev() {
+        let mut children = [None; INTERNAL_NODE_ARITY as usize];
+        children[usize::from(key_nibbles[depth])] = Some((current_hash, current_is_leaf));
+        for sibling in &level.siblings {
+            let Some(slot) = children.get_mut(usize::from(sibling.nibble)) else {
+                return Err(SubstateProofVerificationError::MalformedProof);
+            };
+            if slot.is_some() {
+                return Err(SubstateProofVerificationError::MalformedProof);
+            }
+            *slot = Some((sibling.hash, sibling.is_leaf));
+        }
+        current_hash = internal_node_hash(&children, 0, INTERNAL_NODE_ARITY);
+        current_is_leaf = false;
+    }
+    Ok(current_hash)
+}
+
+/// Computes a hash of the given range of an internal node's children, the way the Jellyfish Merkle
+/// Tree does it: as a binary Merkle tree, in which empty subtrees are represented by a placeholder,
+/// and subtrees containing only a single leaf are represented by that leaf.
+fn internal_node_hash(children: &[Option<(Hash, bool)>], start: u8, width: u8) -> Hash {
+    let range = &children[usize::from(start)..usize::from(start + width)];
+    let mut present = range.iter().flatten();
+    let Some(first) = present.next() else {
+        return SPARSE_MERKLE_PLACEHOLDER_HASH;
+    };
+    let (first_hash, first_is_leaf) = *first;
+    if width == 1 || (first_is_leaf && present.next().is_none()) {
+        return first_hash;
+    }
+    let half_width = width / 2;
+    let left = internal_node_hash(children, start, half_width);
+    let right = internal_node_hash(children, start + half_width, half_width);
+    hash([left.0, right.0].concat())
+}
+
+pub(crate) fn nested_tier_prefix(parent_tier_prefix: &[u8], parent_leaf_key: &[u8]) -> Vec<u8> {
+    let mut prefix = parent_tier_prefix.to_vec();
+    prefix.extend(to_nibbles(parent_leaf_key));
+    prefix.extend(to_nibbles(&[TIER_SEPARATOR]));
+    prefix
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+pub(crate) fn to_nibble_path(nibbles: &[u8]) -> NibblePath {
+    let bytes = from_nibbles(nibbles);
+    if nibbles.len() % 2 == 0 {
+        NibblePath::new_even(bytes)
+    } else {
+        // The odd path's trailing (padding) nibble is zero, as ensured by `from_nibbles()`.
+        NibblePath::new_odd(bytes)
+    }
+}
+
+/// Packs the given nibbles into bytes (an odd trailing nibble gets padded with a zero nibble).
+pub(crate) fn from_nibbles(nibbles: &[u8]) -> Vec<u8> {
+    nibbles
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair.get(1).cloned().unwrap_or(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::historical_state::StateTreeBasedSubstateDatabase;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    #[test]
+    fn proofs_of_in_memory_tree_substates_verify_against_root() {
+        let tree_store = TypedInMemoryTreeStore::new();
+        let substates = vec![
+            (db_key("c0ffee", 7, "dec0"), vec![1]),
+            (db_key("c0ffee", 7, "beef"), vec![2]),
+            (db_key("c0ffee", 7, "bada55"), vec![3]),
+            (db_key("c0ffee", 64, "af"), vec![4]),
+            (db_key("c0fefe", 7, "dec0"), vec![5]),
+            (db_key("deadbeef", 1, "00"), vec![6]),
+        ];
+        let root = StateHash::from(put_at_next_version(
+            &tree_store,
+            None,
+            &database_updates(&substates),
+        ));
+        for ((partition_key, sort_key), value) in &substates {
+            let proof = prove_db_substate_inclusion(
+                &tree_store,
+                StateVersion::of(1),
+                partition_key,
+                sort_key,
+            )
+            .unwrap();
+            assert_eq!(
+                verify_db_substate_inclusion(&proof, partition_key, sort_key, value, &root),
+                Ok(())
+            );
+            assert!(matches!(
+                verify_db_substate_inclusion(&proof, partition_key, sort_key, &[9], &root),
+                Err(SubstateProofVerificationError::RootMismatch { .. })
+            ));
+        }
+
+        // Change a single Substate - the old version's proofs must still verify against its root:
+        let ((partition_key, sort_key), old_value) = substates[1].clone();
+        let new_root = StateHash::from(put_at_next_version(
+            &tree_store,
+            Some(1),
+            &database_updates(&[((partition_key.clone(), sort_key.clone()), vec![7])]),
+        ));
+        let old_proof = prove_db_substate_inclusion(
+            &tree_store,
+            StateVersion::of(1),
+            &partition_key,
+            &sort_key,
+        )
+        .unwrap();
+        let new_proof = prove_db_substate_inclusion(
+            &tree_store,
+            StateVersion::of(2),
+            &partition_key,
+            &sort_key,
+        )
+        .unwrap();
+        assert_eq!(
+            verify_db_substate_inclusion(&old_proof, &partition_key, &sort_key, &old_value, &root),
+            Ok(())
+        );
+        assert_eq!(
+            verify_db_substate_inclusion(&new_proof, &partition_key, &sort_key, &[7], &new_root),
+            Ok(())
+        );
+
+        let (missing_partition_key, missing_sort_key) = db_key("c0ffee", 7, "dead");
+        assert_eq!(
+            prove_db_substate_inclusion(
+                &tree_store,
+                StateVersion::of(2),
+                &missing_partition_key,
+                &missing_sort_key
+            ),
+            Err(SubstateProofError::SubstateNotFound)
+        );
+        assert_eq!(
+            prove_db_substate_inclusion(
+                &tree_store,
+                StateVersion::of(3),
+                &partition_key,
+                &sort_key
+            ),
+            Err(SubstateProofError::StateVersionNotRetained)
+        );
+    }
+
+    #[test]
+    fn proofs_of_committed_substates_verify_against_ledger_proof() {
+        let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(
+            StateManagerConfig::new_for_testing_in_memory(),
+            10,
+        );
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+
+        let database = state_manager.database.lock();
+        let ledger_header = database.get_latest_proof().unwrap().ledger_header;
+        let state_tree =
+            StateTreeBasedSubstateDatabase::new(database.deref(), ledger_header.state_version);
+        // Note: no commits happen after the latest proof, so we can read the current values.
+        for (_leaf_key, (partition_key, sort_key)) in
+            state_tree.iter_substate_leaf_keys().step_by(97)
+        {
+            let value = database
+                .get_raw_substate_by_db_key(&partition_key, &sort_key)
+                .unwrap();
+            let proof = prove_db_substate_inclusion(
+                database.deref(),
+                ledger_header.state_version,
+                &partition_key,
+                &sort_key,
+            )
+            .unwrap();
+            assert_eq!(
+                verify_db_substate_inclusion(
+                    &proof,
+                    &partition_key,
+                    &sort_key,
+                    &value,
+                    &ledger_header.hashes.state_root
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    fn db_key(node_key: &str, partition_num: u8, sort_key: &str) -> DbSubstateKey {
+        (
+            DbPartitionKey {
+                node_key: hex::decode(node_key).unwrap(),
+                partition_num,
+            },
+            DbSortKey(hex::decode(sort_key).unwrap()),
+        )
+    }
+
+    fn database_updates(substates: &[(DbSubstateKey, DbSubstateValue)]) -> DatabaseUpdates {
+        let mut delta_maps = index_map_new::<DbPartitionKey, IndexMap<DbSortKey, DatabaseUpdate>>();
+        for ((partition_key, sort_key), value) in substates {
+            delta_maps
+                .entry(partition_key.clone())
+                .or_default()
+                .insert(sort_key.clone(), DatabaseUpdate::Set(value.clone()));
+        }
+        DatabaseUpdates::from_delta_maps(delta_maps)
+    }
+}
@@ -68,20 +68,26 @@ use std::iter::Peekable;
 use crate::prelude::*;
 use crate::staging::StateTreeDiff;
 pub use commit::*;
+pub use consistency::*;
 pub use extensions::*;
 pub use gc::*;
 pub use indices::*;
 pub use measurement::*;
 pub use proofs::*;
+pub use rollback::*;
 pub use scenario::*;
+pub use snapshot::*;
 pub use substate::*;
 pub use transactions::*;
 pub use vertex::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DatabaseConfigValidationError {
     AccountChangeIndexRequiresLocalTransactionExecutionIndex,
-    LocalTransactionExecutionIndexChanged,
+    ResourceChangeIndexRequiresLocalTransactionExecutionIndex,
+    SecondaryIndicesRequireLocalTransactionExecutionIndex,
+    UnknownSecondaryIndex(String),
+    NewerSchemaVersion { persisted: u32, supported: u32 },
 }
 
 /// Database flags required for initialization built from config file and environment variables.
@@ -91,6 +97,13 @@ pub struct DatabaseConfig {
     pub enable_account_change_index: bool,
     pub enable_historical_substate_values: bool,
     pub enable_entity_listing_indices: bool,
+    pub enable_resource_change_index: bool,
+    /// Names of the [`SecondaryIndex`]es to maintain (resolved by a `SecondaryIndexRegistry`).
+    pub enabled_secondary_indices: Vec<String>,
+    /// If set, only this many most recent *completed* epochs of full transaction data (i.e. raw
+    /// payloads, ledger receipts and local executions) are kept - see `LedgerTransactionsGc`.
+    /// Leaving it unset means running an "archive" Node.
+    pub ledger_transaction_retention_epoch_count: Option<u64>,
 }
 
 impl Default for DatabaseConfig {
@@ -100,6 +113,9 @@ impl Default for DatabaseConfig {
             enable_account_change_index: true,
             enable_historical_substate_values: false,
             enable_entity_listing_indices: true,
+            enable_resource_change_index: false,
+            enabled_secondary_indices: vec![],
+            ledger_transaction_retention_epoch_count: None,
         }
     }
 }
@@ -115,21 +131,21 @@ pub struct DatabaseConfigState {
 }
 
 impl DatabaseConfig {
-    pub fn validate(
-        &self,
-        current_database_config: &DatabaseConfigState,
-    ) -> Result<(), DatabaseConfigValidationError> {
+    /// Validates the internal consistency of the configured flags.
+    ///
+    /// Note: all flags may change between the Node's restarts (e.g. the local transaction execution
+    /// index gets backfilled or pruned accordingly - see [`LocalTransactionExecutionIndexStatusV1`]).
+    pub fn validate(&self) -> Result<(), DatabaseConfigValidationError> {
         if !self.enable_local_transaction_execution_index && self.enable_account_change_index {
             return Err(DatabaseConfigValidationError::AccountChangeIndexRequiresLocalTransactionExecutionIndex);
         }
-        if let Some(local_transaction_execution_index_enabled) =
-            current_database_config.local_transaction_execution_index_enabled
+        if !self.enable_local_transaction_execution_index && self.enable_resource_change_index {
+            return Err(DatabaseConfigValidationError::ResourceChangeIndexRequiresLocalTransactionExecutionIndex);
+        }
+        if !self.enable_local_transaction_execution_index
+            && !self.enabled_secondary_indices.is_empty()
         {
-            if self.enable_local_transaction_execution_index
-                != local_transaction_execution_index_enabled
-            {
-                return Err(DatabaseConfigValidationError::LocalTransactionExecutionIndexChanged);
-            }
+            return Err(DatabaseConfigValidationError::SecondaryIndicesRequireLocalTransactionExecutionIndex);
         }
         Ok(())
     }
@@ -138,6 +154,8 @@ impl DatabaseConfig {
 pub trait ConfigurableDatabase {
     fn is_account_change_index_enabled(&self) -> bool;
 
+    fn is_resource_change_index_enabled(&self) -> bool;
+
     fn is_local_transaction_execution_index_enabled(&self) -> bool;
 
     fn are_entity_listing_indices_enabled(&self) -> bool;
@@ -155,6 +173,15 @@ pub trait ConfigurableDatabase {
     /// This method assumes that [`Self::is_state_history_enabled()`] returned [`true`] and *panics*
     /// otherwise.
     fn get_first_stored_historical_state_version(&self) -> StateVersion;
+
+    /// Returns the first [`StateVersion`] for which the local transaction execution is currently
+    /// available in the database.
+    ///
+    /// This is the genesis, unless the index was enabled on an already-populated database (in
+    /// which case the older executions may only be partially backfilled).
+    /// This method assumes that [`Self::is_local_transaction_execution_index_enabled()`] returned
+    /// [`true`] and *panics* otherwise.
+    fn get_first_stored_local_transaction_execution_state_version(&self) -> StateVersion;
 }
 
 #[derive(Debug, Clone)]
@@ -276,13 +303,30 @@ pub mod transactions {
     use super::*;
 
     pub trait IterableTransactionStore {
+        /// Returns an iterator of full committed transaction data, starting at the given state
+        /// version - or an error if this data was already pruned at that state version.
         fn get_committed_transaction_bundle_iter(
             &self,
             from_state_version: StateVersion,
-        ) -> Box<dyn Iterator<Item = CommittedTransactionBundle> + '_>;
+        ) -> Result<
+            Box<dyn Iterator<Item = CommittedTransactionBundle> + '_>,
+            TransactionsPrunedError,
+        >;
+    }
+
+    /// An error returned when the full data of the requested transactions was already pruned by
+    /// the `LedgerTransactionsGc` (i.e. only their identifiers and hashes remain).
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct TransactionsPrunedError {
+        pub first_available_state_version: StateVersion,
     }
 
     pub trait QueryableTransactionStore {
+        /// Returns the first state version from which the full transaction data is stored.
+        /// Below it, only the epoch change transactions are kept (together with all transactions'
+        /// identifiers and accumulator tree slices).
+        fn get_first_stored_ledger_transaction_state_version(&self) -> StateVersion;
+
         fn get_committed_transaction(
             &self,
             state_version: StateVersion,
@@ -648,6 +692,85 @@ pub mod extensions {
         fn catchup_account_change_index(&self);
     }
 
+    pub trait ResourceChangeIndexExtension {
+        fn resource_change_index_last_processed_state_version(&self) -> StateVersion;
+
+        /// Indexes the next batch of (at most the given number of) transactions, if the enabled
+        /// index lags behind the ledger (e.g. after being enabled on an already-populated ledger).
+        /// Returns `true` if the index has caught up with the ledger.
+        fn catchup_resource_change_index(&self, max_transaction_count: usize) -> bool;
+    }
+
+    pub trait LocalTransactionExecutionBackfillStore {
+        /// Writes the given re-computed local executions, which must directly precede (in their
+        /// descending order) the [`ConfigurableDatabase::get_first_stored_local_transaction_execution_state_version()`].
+        /// Moves that first available state version accordingly (and updates the dependent account
+        /// and resource change indices, if enabled).
+        fn put_backfilled_local_transaction_executions(
+            &self,
+            executions: Vec<(StateVersion, LocalTransactionExecution)>,
+        );
+    }
+
+    /// A user-defined index over committed transactions.
+    ///
+    /// The index maps its arbitrary keys to the [`StateVersion`]s of all committed transactions
+    /// which "touched" them (in an index-specific sense). It is maintained by the database in a
+    /// dedicated [`SecondaryIndexCf`] - both on commit and by a background catch-up (i.e. after
+    /// the index gets enabled on an already-populated ledger).
+    pub trait SecondaryIndex: Send + Sync {
+        /// The unique name of this index.
+        ///
+        /// IMPORTANT NOTE: this is a database identifier (used to derive the index's column family
+        /// name and its progress marker). Any change would effectively wipe and rebuild the index.
+        fn name(&self) -> &'static str;
+
+        /// Returns the keys (in an index-specific encoding) under which the given committed
+        /// transaction should be findable.
+        fn extract_keys(&self, transaction: &SecondaryIndexInput) -> IndexSet<Vec<u8>>;
+    }
+
+    /// All the data of a committed transaction, available to [`SecondaryIndex::extract_keys()`].
+    pub struct SecondaryIndexInput<'a> {
+        pub state_version: StateVersion,
+        pub raw: &'a RawLedgerTransaction,
+        pub ledger_receipt: &'a LedgerTransactionReceipt,
+        pub local_execution: &'a LocalTransactionExecution,
+    }
+
+    pub trait SecondaryIndexExtension {
+        /// Computes the processing of at most the given number of committed transactions
+        /// (following the last processed one) for each lagging enabled index, without writing
+        /// anything (i.e. it can be computed on a database snapshot).
+        /// The transactions which are no longer available are skipped (see
+        /// [`IterableSecondaryIndex::get_secondary_index_skipped_state_version_ranges()`]).
+        /// Returns [`None`] if all the enabled indices are caught up with the ledger.
+        fn compute_secondary_indices_catchup(
+            &self,
+            max_transaction_count: usize,
+        ) -> Option<SecondaryIndicesCatchupBatch>;
+    }
+
+    pub trait SecondaryIndicesCatchupStore {
+        /// Writes the given batch computed by
+        /// [`SecondaryIndexExtension::compute_secondary_indices_catchup()`], unless the lagging
+        /// indices' progress has changed since then (in which case nothing is written).
+        /// Returns [`true`] if all the enabled indices are caught up with the ledger (or if the
+        /// batch was outdated).
+        fn apply_secondary_indices_catchup(&self, batch: SecondaryIndicesCatchupBatch) -> bool;
+    }
+
+    /// A computed (but not yet written) progress of the lagging [`SecondaryIndex`]es.
+    pub struct SecondaryIndicesCatchupBatch {
+        /// The last processed state versions of the lagging indices, from which the batch was
+        /// computed.
+        pub base_last_processed_state_versions: IndexMap<String, StateVersion>,
+        /// The indices' progress after this batch.
+        pub progress: SecondaryIndicesProgress,
+        /// The index entries to write, as `(index name, key, state version)`.
+        pub entries: Vec<(String, Vec<u8>, StateVersion)>,
+    }
+
     pub trait RestoreDecember2023LostSubstates {
         fn restore_december_2023_lost_substates(&self, network: &NetworkDefinition);
     }
@@ -660,6 +783,44 @@ pub mod extensions {
         ) -> Box<dyn Iterator<Item = StateVersion> + '_>;
     }
 
+    pub trait IterableResourceChangeIndex {
+        /// Returns the last state version processed by the index, or [`None`] if the index is not
+        /// enabled.
+        fn get_resource_change_index_last_processed_state_version(&self) -> Option<StateVersion>;
+
+        fn get_state_versions_for_resource_iter(
+            &self,
+            resource: ResourceAddress,
+            from_state_version: StateVersion,
+        ) -> Box<dyn Iterator<Item = StateVersion> + '_>;
+    }
+
+    pub trait IterableSecondaryIndex {
+        /// Returns the last state version processed by the given enabled index, or [`None`] if the
+        /// index is not enabled.
+        fn get_secondary_index_last_processed_state_version(
+            &self,
+            index_name: &str,
+        ) -> Option<StateVersion>;
+
+        /// Returns the (inclusive) state version ranges which the given index had to skip during
+        /// its catch-up (i.e. which it does not cover).
+        fn get_secondary_index_skipped_state_version_ranges(
+            &self,
+            index_name: &str,
+        ) -> Vec<(StateVersion, StateVersion)>;
+
+        /// Returns the state versions of transactions indexed under the given key by the given
+        /// enabled index (starting from the given state version).
+        /// Note: the result is complete only up to the index's last processed state version.
+        fn get_state_versions_for_secondary_index_key_iter(
+            &self,
+            index_name: &str,
+            key: &[u8],
+            from_state_version: StateVersion,
+        ) -> Box<dyn Iterator<Item = StateVersion> + '_>;
+    }
+
     define_single_versioned! {
         #[derive(Debug, Clone, Sbor)]
         pub VersionedStateTreeAssociatedValuesStatus(StateTreeAssociatedValuesStatusVersions) => StateTreeAssociatedValuesStatus = StateTreeAssociatedValuesStatusV1
@@ -675,6 +836,97 @@ pub mod extensions {
         /// forward appropriately.
         pub historical_substate_values_available_from: StateVersion,
     }
+
+    define_single_versioned! {
+        #[derive(Debug, Clone, Sbor)]
+        pub VersionedLocalTransactionExecutionIndexStatus(LocalTransactionExecutionIndexStatusVersions) => LocalTransactionExecutionIndexStatus = LocalTransactionExecutionIndexStatusV1
+    }
+
+    #[derive(Debug, Clone, Sbor)]
+    pub struct LocalTransactionExecutionIndexStatusV1 {
+        /// The (inclusive) [`StateVersion`] from which the local transaction executions are
+        /// currently present in the database.
+        ///
+        /// This value is only recorded when the index gets enabled on an already-populated database
+        /// (otherwise, the executions are available since genesis). Then, every subsequent backfill
+        /// batch progresses it backwards appropriately.
+        pub local_transaction_executions_available_from: StateVersion,
+    }
+
+    define_single_versioned! {
+        #[derive(Debug, Clone, Sbor)]
+        pub VersionedLedgerTransactionsStatus(LedgerTransactionsStatusVersions) => LedgerTransactionsStatus = LedgerTransactionsStatusV1
+    }
+
+    #[derive(Debug, Clone, Sbor)]
+    pub struct LedgerTransactionsStatusV1 {
+        /// The (inclusive) [`StateVersion`] from which the full transaction data is currently
+        /// present in the database.
+        ///
+        /// This value is only recorded after the first `LedgerTransactionsGc` run (otherwise, the
+        /// transactions are available since genesis). Then, every subsequent run progresses it
+        /// forward appropriately.
+        pub ledger_transactions_available_from: StateVersion,
+    }
+
+    define_single_versioned! {
+        #[derive(Debug, Clone, Sbor)]
+        pub VersionedSecondaryIndicesProgress(SecondaryIndicesProgressVersions) => SecondaryIndicesProgress = SecondaryIndicesProgressV1
+    }
+
+    #[derive(Debug, Clone, Default, Sbor)]
+    pub struct SecondaryIndicesProgressV1 {
+        /// The last [`StateVersion`] processed by each enabled [`SecondaryIndex`] (by its name).
+        ///
+        /// An index gets its entry when it is enabled (starting right before the first available
+        /// local transaction execution), and loses it when it is disabled.
+        pub last_processed_state_versions: IndexMap<String, StateVersion>,
+        /// The (inclusive) [`StateVersion`] ranges which each index's catch-up had to skip, since
+        /// their transactions were no longer available (e.g. pruned by the ledger transactions
+        /// GC). The index has no entries for these transactions.
+        pub skipped_state_version_ranges: IndexMap<String, Vec<(StateVersion, StateVersion)>>,
+    }
+
+    impl SecondaryIndicesProgressV1 {
+        /// Marks the given (inclusive) range as processed by the given index, without indexing
+        /// it. The range must directly follow the index's last processed state version.
+        pub fn skip(&mut self, index_name: &str, from: StateVersion, to: StateVersion) {
+            self.last_processed_state_versions
+                .insert(index_name.to_string(), to);
+            let ranges = self
+                .skipped_state_version_ranges
+                .entry(index_name.to_string())
+                .or_default();
+            match ranges.last_mut() {
+                Some((_, last_to)) if last_to.next().unwrap() == from => *last_to = to,
+                _ => ranges.push((from, to)),
+            }
+        }
+    }
+
+    define_single_versioned! {
+        #[derive(Debug, Clone, Sbor)]
+        pub VersionedDatabaseSchemaStatus(DatabaseSchemaStatusVersions) => DatabaseSchemaStatus = DatabaseSchemaStatusV1
+    }
+
+    #[derive(Debug, Clone, Default, Sbor)]
+    pub struct DatabaseSchemaStatusV1 {
+        /// The names of the applied `DatabaseMigration`s, in their application order.
+        ///
+        /// The database's schema version is thus `BASELINE_DATABASE_SCHEMA_VERSION` plus the
+        /// number of these migrations.
+        pub applied_migrations: Vec<String>,
+        /// The migration interrupted in the middle (if any), which must be resumed first.
+        pub in_progress_migration: Option<InProgressDatabaseMigration>,
+    }
+
+    #[derive(Debug, Clone, Sbor)]
+    pub struct InProgressDatabaseMigration {
+        /// The migration's name.
+        pub name: String,
+        /// The migration-specific cursor to resume from.
+        pub cursor: Vec<u8>,
+    }
 }
 
 pub mod indices {
@@ -861,6 +1113,15 @@ pub mod measurement {
             self.max_level = max(self.max_level, level);
         }
     }
+
+    /// An approximate number of bytes reclaimed (e.g. by a GC's targeted compaction) within a given
+    /// category of persisted items.
+    pub struct CategoryReclaimedBytes {
+        /// Name of the table/map/cf.
+        pub category_name: String,
+        /// A decrease of the persisted size of the affected part of this category, in bytes.
+        pub reclaimed_bytes: usize,
+    }
 }
 
 pub mod gc {
@@ -879,10 +1140,65 @@ pub mod gc {
         fn progress_historical_substate_values_availability(&self, available_from: StateVersion);
 
         /// Deletes a batch of state hash tree nodes.
+        /// The runs of nodes adjacent in the database are deleted using range deletes.
         fn batch_delete_node<'a>(&self, keys: impl IntoIterator<Item = &'a StoredTreeNodeKey>);
 
         /// Deletes all stale hash tree parts' records up to the given state version, *exclusive*.
         fn delete_stale_tree_parts_up_to_version(&self, state_version: StateVersion);
+
+        /// Compacts the state hash tree nodes (and their associated values) created within the given
+        /// range of state versions (from inclusive, to exclusive), and the stale hash tree parts'
+        /// records within the same range - i.e. the bulk of the GC run's deletes.
+        fn compact_state_tree_range(
+            &self,
+            from: StateVersion,
+            to: StateVersion,
+        ) -> Vec<CategoryReclaimedBytes>;
+
+        /// Records the given state version as a sparse historical state checkpoint.
+        fn record_state_history_checkpoint(&self, state_version: StateVersion);
+
+        /// Stores a batch of stale hash tree parts which must not be deleted (since they are still
+        /// reachable from some historical state checkpoint), by the state version at which they
+        /// became stale.
+        fn batch_retain_stale_tree_parts(
+            &self,
+            retained_parts: impl IntoIterator<Item = (StateVersion, StaleTreeParts)>,
+        );
+    }
+
+    /// A read API of the sparse historical state checkpoints maintained by the [`StateTreeGc`].
+    pub trait StateHistoryCheckpointStore {
+        /// Returns an iterator of the checkpoints' state versions, starting from the given one
+        /// (inclusive), ascending.
+        fn get_state_history_checkpoint_iter(
+            &self,
+            from_state_version: StateVersion,
+        ) -> Box<dyn Iterator<Item = StateVersion> + '_>;
+
+        /// Returns true if the historical state is available at the given checkpoint state version
+        /// (even if it is beyond the full-resolution state history).
+        fn is_state_history_checkpoint(&self, state_version: StateVersion) -> bool {
+            self.get_state_history_checkpoint_iter(state_version).next() == Some(state_version)
+        }
+    }
+
+    /// A storage API tailored for the [`LedgerTransactionsGc`].
+    pub trait LedgerTransactionsGcStore {
+        /// Updates the metadata of the full transaction data availability - but only if the given
+        /// `available_from` version is actually greater than the currently stored one.
+        fn progress_ledger_transactions_availability(&self, available_from: StateVersion);
+
+        /// Deletes the full data (i.e. raw payloads, ledger receipts and local executions) of the
+        /// given range (from inclusive, to exclusive) of transactions.
+        fn delete_ledger_transactions_range(&self, from: StateVersion, to: StateVersion);
+
+        /// Compacts the full data of the given range (from inclusive, to exclusive) of transactions.
+        fn compact_ledger_transactions_range(
+            &self,
+            from: StateVersion,
+            to: StateVersion,
+        ) -> Vec<CategoryReclaimedBytes>;
     }
 
     /// A storage API tailored for the [`LedgerProofsGc`].
@@ -893,8 +1209,20 @@ pub mod gc {
         /// Updates the progress.
         fn set_progress(&self, progress: LedgerProofsGcProgress);
 
-        /// Deletes the given range (from inclusive, to exclusive) of ledger proofs.
-        fn delete_ledger_proofs_range(&self, from: StateVersion, to: StateVersion);
+        /// Deletes the given ranges (each one from inclusive, to exclusive) of ledger proofs.
+        /// The ranges must be sorted and disjoint.
+        ///
+        /// The implementation uses a single range delete spanning all the given ranges (and then
+        /// re-writes the few proofs retained in between them), to avoid fragmenting the range
+        /// tombstones.
+        fn delete_ledger_proofs_ranges(&self, ranges: &[(StateVersion, StateVersion)]);
+
+        /// Compacts the given range (from inclusive, to exclusive) of ledger proofs.
+        fn compact_ledger_proofs_range(
+            &self,
+            from: StateVersion,
+            to: StateVersion,
+        ) -> Vec<CategoryReclaimedBytes>;
     }
 
     define_single_versioned! {
@@ -932,6 +1260,98 @@ pub mod gc {
     }
 }
 
+pub mod consistency {
+    use super::*;
+
+    /// A storage API tailored for the [`check_database_consistency()`], i.e. exposing the raw
+    /// contents of certain indices (which are otherwise only used for point lookups).
+    ///
+    /// [`check_database_consistency()`]: crate::store::consistency_check::check_database_consistency
+    pub trait ConsistencyCheckStore {
+        fn get_intent_hash_index_iter(
+            &self,
+        ) -> Box<dyn Iterator<Item = (TransactionIntentHash, StateVersion)> + '_>;
+
+        fn get_notarized_transaction_hash_index_iter(
+            &self,
+        ) -> Box<dyn Iterator<Item = (NotarizedTransactionHash, StateVersion)> + '_>;
+
+        fn get_ledger_transaction_hash_index_iter(
+            &self,
+        ) -> Box<dyn Iterator<Item = (LedgerTransactionHash, StateVersion)> + '_>;
+
+        fn get_substate_node_ancestry_record_iter(
+            &self,
+        ) -> Box<dyn Iterator<Item = (NodeId, SubstateNodeAncestryRecord)> + '_>;
+    }
+}
+
+pub mod snapshot {
+    use super::*;
+
+    /// A storage API tailored for bootstrapping an empty database from a state snapshot (see
+    /// [`crate::store::state_snapshot`]).
+    ///
+    /// These writes bypass the regular [`CommitStore::commit()`] flow (and its indices), so they
+    /// are only meaningful for a database which has never committed anything.
+    pub trait StateSnapshotLoadingStore {
+        /// Writes a batch of Substate values.
+        fn put_snapshot_substates(&self, substates: Vec<(DbSubstateKey, DbSubstateValue)>);
+
+        /// Writes a batch of ancestry records of the Nodes.
+        fn put_snapshot_node_ancestry_records(
+            &self,
+            records: Vec<(NodeId, SubstateNodeAncestryRecord)>,
+        );
+
+        /// Writes a batch of state hash tree nodes.
+        fn put_snapshot_tree_nodes(&self, nodes: Vec<(StoredTreeNodeKey, TreeNode)>);
+
+        /// Writes the past epoch change transactions (together with their epoch proofs) and
+        /// protocol update proofs - i.e. the minimal history required to initialize the Node's
+        /// protocol state.
+        fn put_snapshot_ledger_history(
+            &self,
+            epoch_changes: Vec<(CommittedTransactionBundle, LedgerProof)>,
+            protocol_update_proofs: Vec<LedgerProof>,
+        );
+
+        /// Writes the given proof as the top of the ledger, marks all the enabled incrementally
+        /// maintained indices (and the ledger proofs' GC) as already caught up to it, and marks
+        /// the database schema as current.
+        fn put_snapshot_anchor_proof(&self, proof: LedgerProof);
+    }
+}
+
+pub mod rollback {
+    use super::*;
+
+    /// A storage API for truncating the ledger back to a past epoch change (see
+    /// [`crate::store::ledger_rollback`]).
+    ///
+    /// These writes bypass all the regular flows, so they must only be performed on a database
+    /// which is not used by a running Node.
+    pub trait LedgerRollbackStore {
+        /// Writes a batch of reverted Substates: first replaces the entire contents of the given
+        /// Partitions, and then sets (or deletes, if `None`) the given individual Substates.
+        fn put_reverted_substates(
+            &self,
+            partitions: Vec<(DbPartitionKey, Vec<(DbSortKey, DbSubstateValue)>)>,
+            substates: Vec<(DbSubstateKey, Option<DbSubstateValue>)>,
+        );
+
+        /// Deletes the index entries (i.e. hash lookups, change indices, entity listings and
+        /// secondary indices) of all the committed transactions above the given state version.
+        fn delete_index_entries_after(&self, state_version: StateVersion);
+
+        /// Atomically deletes all the ledger data above the given epoch change proof (i.e.
+        /// transactions, proofs, state hash tree nodes and accumulator tree slices), clamps the
+        /// progress markers of the incrementally maintained indices and GCs, and resets the vertex
+        /// store.
+        fn truncate_ledger_after(&self, epoch_proof: &LedgerProof);
+    }
+}
+
 pub struct TransactionAndProofIterator<'a> {
     committed_transaction_bundle:
         Peekable<Box<dyn Iterator<Item = CommittedTransactionBundle> + 'a>>,
@@ -0,0 +1,311 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+use crate::prelude::*;
+use crate::store::column_families::*;
+use rocksdb::{BlockBasedOptions, Cache, DBCompressionType, Options, SliceTransform};
+
+/// A named set of per-column-family RocksDB options, matching a specific kind of Node.
+///
+/// The column families are grouped into a few access classes, and each profile
+/// decides the options (block cache share, Bloom filters, compression, prefix extraction) of every
+/// class, depending on the expected access pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sbor)]
+pub enum DatabaseTuningProfile {
+    /// A validator: mostly executes and commits transactions (i.e. reads the current state and
+    /// checks the intent hashes), and serves ledger sync. Keeps the memory footprint small.
+    Validator,
+    /// An archive Node: retains the entire ledger history, which is mostly written once and rarely
+    /// read. Trades some CPU for a stronger compression of the large transaction data.
+    Archive,
+    /// A Node serving a heavy Core API traffic: reads transactions, receipts and the address-keyed
+    /// change indices a lot. Uses a large block cache.
+    ApiHeavy,
+}
+
+/// A group of column families sharing the same access pattern (and thus tuning).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ColumnFamilyAccessClass {
+    /// Keyed by hashes, read by point lookups (often of absent keys, e.g. intent hash checks).
+    HashLookup,
+    /// The current state (and its hash tree), read constantly by the transaction execution.
+    HotState,
+    /// Large transaction data (payloads, receipts, executions), keyed by state version.
+    TransactionData,
+    /// Indices keyed by an entity address prefix, read by per-entity scans.
+    AddressPrefixed,
+    /// All the remaining (small, mostly sequentially accessed) column families.
+    Other,
+}
+
+impl ColumnFamilyAccessClass {
+    const ALL: [Self; 5] = [
+        Self::HashLookup,
+        Self::HotState,
+        Self::TransactionData,
+        Self::AddressPrefixed,
+        Self::Other,
+    ];
+
+    /// Resolves the class of the column family of the given name.
+    fn of(cf_name: &str) -> Self {
+        if [
+            IntentHashesCf::NAME,
+            NotarizedTransactionHashesCf::NAME,
+            LedgerTransactionHashesCf::NAME,
+            FinalizedSubintentHashesCf::NAME,
+        ]
+        .contains(&cf_name)
+        {
+            Self::HashLookup
+        } else if [
+            SubstatesCf::NAME,
+            SubstateNodeAncestryRecordsCf::NAME,
+            StateTreeNodesCf::NAME,
+        ]
+        .contains(&cf_name)
+        {
+            Self::HotState
+        } else if [
+            RawLedgerTransactionsCf::NAME,
+            TransactionReceiptsCf::NAME,
+            LocalTransactionExecutionsCf::NAME,
+        ]
+        .contains(&cf_name)
+        {
+            Self::TransactionData
+        } else if [
+            AccountChangeStateVersionsCf::NAME,
+            ResourceChangeStateVersionsCf::NAME,
+        ]
+        .contains(&cf_name)
+        {
+            Self::AddressPrefixed
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// The options of a single [`ColumnFamilyAccessClass`] within a [`DatabaseTuningProfile`].
+struct AccessClassTuning {
+    /// A share of the profile's total block cache, in percent.
+    block_cache_percent: usize,
+    /// Bits per key of a Bloom filter (if any).
+    bloom_filter_bits_per_key: Option<f64>,
+    /// Compression of all levels.
+    compression: DBCompressionType,
+    /// Whether the keys' entity address prefix should be extracted (for prefix Bloom filters).
+    extract_address_prefix: bool,
+}
+
+const MIB: usize = 1024 * 1024;
+
+/// A Bloom filter setting giving ~1% false positive rate.
+const BLOOM_FILTER_BITS_PER_KEY: f64 = 10.0;
+
+/// A fraction of the memtable used for the prefix Bloom filter (when a prefix is extracted).
+const MEMTABLE_PREFIX_BLOOM_RATIO: f64 = 0.1;
+
+impl DatabaseTuningProfile {
+    /// A total size of the block caches of all column families.
+    fn total_block_cache_size(&self) -> usize {
+        match self {
+            Self::Validator => 256 * MIB,
+            Self::Archive => 512 * MIB,
+            Self::ApiHeavy => 2048 * MIB,
+        }
+    }
+
+    fn access_class_tuning(&self, class: ColumnFamilyAccessClass) -> AccessClassTuning {
+        use ColumnFamilyAccessClass::*;
+        let (block_cache_percent, bloom_filter, compression, extract_address_prefix) =
+            match (self, class) {
+                (Self::Validator, HashLookup) => (25, true, DBCompressionType::Lz4, false),
+                (Self::Validator, HotState) => (60, true, DBCompressionType::Lz4, false),
+                (Self::Validator, TransactionData) => (5, false, DBCompressionType::Lz4, false),
+                (Self::Validator, AddressPrefixed) => (5, false, DBCompressionType::Lz4, false),
+                (Self::Validator, Other) => (5, false, DBCompressionType::Lz4, false),
+                (Self::Archive, HashLookup) => (20, true, DBCompressionType::Lz4, false),
+                (Self::Archive, HotState) => (40, true, DBCompressionType::Lz4, false),
+                (Self::Archive, TransactionData) => (10, false, DBCompressionType::Zstd, false),
+                (Self::Archive, AddressPrefixed) => (15, true, DBCompressionType::Lz4, true),
+                (Self::Archive, Other) => (15, false, DBCompressionType::Lz4, false),
+                (Self::ApiHeavy, HashLookup) => (15, true, DBCompressionType::Lz4, false),
+                (Self::ApiHeavy, HotState) => (35, true, DBCompressionType::Lz4, false),
+                (Self::ApiHeavy, TransactionData) => (25, false, DBCompressionType::Lz4, false),
+                (Self::ApiHeavy, AddressPrefixed) => (20, true, DBCompressionType::Lz4, true),
+                (Self::ApiHeavy, Other) => (5, false, DBCompressionType::Lz4, false),
+            };
+        AccessClassTuning {
+            block_cache_percent,
+            bloom_filter_bits_per_key: bloom_filter.then_some(BLOOM_FILTER_BITS_PER_KEY),
+            compression,
+            extract_address_prefix,
+        }
+    }
+}
+
+/// A source of per-column-family [`Options`] of a given [`DatabaseTuningProfile`] (or of the
+/// RocksDB's defaults, if no profile is configured).
+pub struct ColumnFamilyOptionsFactory {
+    profile: Option<DatabaseTuningProfile>,
+    /// Block caches shared by the column families of the same class.
+    block_caches: IndexMap<ColumnFamilyAccessClass, Cache>,
+}
+
+impl ColumnFamilyOptionsFactory {
+    /// Creates a factory (along with the block caches) of the given profile.
+    pub fn new(profile: Option<DatabaseTuningProfile>) -> Self {
+        let block_caches = profile
+            .map(|profile| {
+                ColumnFamilyAccessClass::ALL
+                    .into_iter()
+                    .map(|class| {
+                        let share = profile.access_class_tuning(class).block_cache_percent;
+                        let size = profile.total_block_cache_size() / 100 * share;
+                        (class, Cache::new_lru_cache(size))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            profile,
+            block_caches,
+        }
+    }
+
+    /// Creates the options of the column family of the given name.
+    pub fn create(&self, cf_name: &str) -> Options {
+        let mut options = Options::default();
+        let Some(profile) = self.profile else {
+            return options;
+        };
+        let class = ColumnFamilyAccessClass::of(cf_name);
+        let tuning = profile.access_class_tuning(class);
+
+        let mut table_options = BlockBasedOptions::default();
+        table_options.set_block_cache(
+            self.block_caches
+                .get(&class)
+                .expect("caches are created for all classes"),
+        );
+        // Keep the (potentially large) index and filter blocks within the cache's budget:
+        table_options.set_cache_index_and_filter_blocks(true);
+        table_options.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        if let Some(bits_per_key) = tuning.bloom_filter_bits_per_key {
+            table_options.set_bloom_filter(bits_per_key, false);
+        }
+        options.set_block_based_table_factory(&table_options);
+
+        options.set_compression_type(tuning.compression);
+        options.set_bottommost_compression_type(tuning.compression);
+
+        if tuning.extract_address_prefix {
+            // Note: the Bloom filter above then applies to the prefixes (see
+            // `ReadableRocks::prefix_iterator_cf()` for the reads which can benefit from it).
+            options.set_prefix_extractor(SliceTransform::create_fixed_prefix(NodeId::LENGTH));
+            options.set_memtable_prefix_bloom_ratio(MEMTABLE_PREFIX_BLOOM_RATIO);
+        }
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_profile_distributes_its_entire_block_cache() {
+        for profile in [
+            DatabaseTuningProfile::Validator,
+            DatabaseTuningProfile::Archive,
+            DatabaseTuningProfile::ApiHeavy,
+        ] {
+            let total_percent = ColumnFamilyAccessClass::ALL
+                .into_iter()
+                .map(|class| profile.access_class_tuning(class).block_cache_percent)
+                .sum::<usize>();
+            assert_eq!(total_percent, 100, "{:?}", profile);
+        }
+    }
+
+    #[test]
+    fn hash_keyed_column_families_use_bloom_filters() {
+        for cf_name in [IntentHashesCf::NAME, FinalizedSubintentHashesCf::NAME] {
+            assert_eq!(
+                ColumnFamilyAccessClass::of(cf_name),
+                ColumnFamilyAccessClass::HashLookup
+            );
+        }
+        assert_eq!(
+            ColumnFamilyAccessClass::of("some_secondary_index"),
+            ColumnFamilyAccessClass::Other
+        );
+        assert!(DatabaseTuningProfile::Validator
+            .access_class_tuning(ColumnFamilyAccessClass::HashLookup)
+            .bloom_filter_bits_per_key
+            .is_some());
+    }
+}
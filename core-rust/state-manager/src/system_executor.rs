@@ -362,7 +362,6 @@ mod tests {
     use super::*;
 
     use crate::test::*;
-    use tempfile::TempDir;
 
     // TODO: maybe move/refactor testing infra as we add more Rust tests
     fn build_unit_test_round_history(proof: &LedgerProof) -> RoundHistory {
@@ -451,12 +450,11 @@ mod tests {
     }
 
     fn setup_state_manager(
-        tmp: &TempDir,
         vertex_limits_config: VertexLimitsConfig,
     ) -> (LedgerProof, StateManager) {
         let config = StateManagerConfig {
             vertex_limits_config: Some(vertex_limits_config),
-            ..StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap())
+            ..StateManagerConfig::new_for_testing_in_memory()
         };
         let state_manager = create_bootstrapped_state_manager_with_rounds_per_epoch(config, 3);
         let epoch_proof = state_manager
@@ -468,11 +466,10 @@ mod tests {
     }
 
     fn prepare_with_vertex_limits(
-        tmp: &TempDir,
         vertex_limits_config: VertexLimitsConfig,
         proposed_transactions: Vec<RawNotarizedTransaction>,
     ) -> PrepareResult {
-        let (epoch_proof, state_manager) = setup_state_manager(tmp, vertex_limits_config);
+        let (epoch_proof, state_manager) = setup_state_manager(vertex_limits_config);
         state_manager
             .preparator
             .prepare(build_unit_test_prepare_request(
@@ -547,8 +544,7 @@ mod tests {
 
     #[test]
     fn test_prepare_vertex_limits() {
-        let tmp = tempfile::tempdir().unwrap();
-        let (proof, state_manager) = setup_state_manager(&tmp, VertexLimitsConfig::max());
+        let (proof, state_manager) = setup_state_manager(VertexLimitsConfig::max());
 
         let mut proposed_transactions = Vec::new();
         let epoch = proof.ledger_header.epoch;
@@ -577,9 +573,7 @@ mod tests {
         assert_eq!(prepare_result.committed.len(), 10); // 9 committable transactions + 1 round update transaction
         assert_eq!(prepare_result.rejected.len(), 5); // 5 rejected transactions
 
-        let tmp = tempfile::tempdir().unwrap();
         let prepare_result = prepare_with_vertex_limits(
-            &tmp,
             VertexLimitsConfig {
                 max_transaction_count: 6,
                 ..VertexLimitsConfig::max()
@@ -594,9 +588,7 @@ mod tests {
         let limited_proposal_ledger_hashes = prepare_result.ledger_hashes;
 
         // We now compute PrepareResult only for the first 7 transactions in order to test that indeed resultant states are the same.
-        let tmp = tempfile::tempdir().unwrap();
         let prepare_result = prepare_with_vertex_limits(
-            &tmp,
             VertexLimitsConfig::max(),
             proposed_transactions.clone()[0..7].to_vec(),
         );
@@ -605,14 +597,11 @@ mod tests {
         assert_eq!(prepare_result.ledger_hashes, limited_proposal_ledger_hashes);
 
         // Transaction size/count only tests `check_pre_execution`. We also need to test `try_next_transaction`.
-        let tmp = tempfile::tempdir().unwrap();
         let cost_for_first_9_user_transactions = compute_consumed_execution_units(
-            &setup_state_manager(&tmp, VertexLimitsConfig::max()).1,
+            &setup_state_manager(VertexLimitsConfig::max()).1,
             build_unit_test_prepare_request(&proof, proposed_transactions.clone()[0..9].to_vec()),
         );
-        let tmp = tempfile::tempdir().unwrap();
         let prepare_result = prepare_with_vertex_limits(
-            &tmp,
             VertexLimitsConfig {
                 // We add an extra cost unit in order to not trigger the LimitExceeded right at 9th transaction.
                 max_total_execution_cost_units_consumed: cost_for_first_9_user_transactions + 1,
@@ -624,9 +613,7 @@ mod tests {
         assert_eq!(prepare_result.rejected.len(), 4); // 3 rejected transactions + last one that is committable but gets discarded due to limits
 
         let limited_proposal_ledger_hashes = prepare_result.ledger_hashes;
-        let tmp = tempfile::tempdir().unwrap();
         let prepare_result = prepare_with_vertex_limits(
-            &tmp,
             VertexLimitsConfig::max(),
             proposed_transactions.clone()[0..9].to_vec(),
         );
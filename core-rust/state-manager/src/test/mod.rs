@@ -50,6 +50,15 @@ pub fn commit_round_updates_until_epoch(state_manager: &StateManager, epoch: Epo
 
 pub fn prepare_and_commit_round_update(
     state_manager: &StateManager,
+) -> (PrepareResult, CommitSummary) {
+    prepare_and_commit_round_with_transactions(state_manager, vec![])
+}
+
+/// Prepares and commits the next round, including the given user transactions (as far as they
+/// are committable).
+pub fn prepare_and_commit_round_with_transactions(
+    state_manager: &StateManager,
+    proposed_transactions: Vec<RawNotarizedTransaction>,
 ) -> (PrepareResult, CommitSummary) {
     let database = state_manager.database.access_direct();
     let latest_proof: LedgerProof = database.get_latest_proof().unwrap();
@@ -93,7 +102,7 @@ pub fn prepare_and_commit_round_update(
         committed_ledger_hashes: top_identifiers.resultant_ledger_hashes,
         ancestor_transactions: vec![],
         ancestor_ledger_hashes: top_identifiers.resultant_ledger_hashes,
-        proposed_transactions: vec![],
+        proposed_transactions,
         round_history: RoundHistory {
             is_fallback: false,
             epoch,
@@ -139,3 +148,42 @@ pub fn prepare_and_commit_round_update(
 
     (prepare_result, commit_result)
 }
+
+/// Builds a committable user transaction (valid from the given epoch), which deposits some free
+/// XRD from the faucet to the given account.
+pub fn build_faucet_transaction(
+    epoch: Epoch,
+    account: ComponentAddress,
+) -> RawNotarizedTransaction {
+    build_faucet_transaction_with_tip(epoch, account, 0)
+}
+
+/// Like [`build_faucet_transaction()`], but specifying the given tip.
+pub fn build_faucet_transaction_with_tip(
+    epoch: Epoch,
+    account: ComponentAddress,
+    tip_percentage: u16,
+) -> RawNotarizedTransaction {
+    let notary_private_key = Secp256k1PrivateKey::from_u64(2).unwrap();
+    TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: epoch,
+            end_epoch_exclusive: epoch.after(100).unwrap(),
+            nonce: 0,
+            notary_public_key: notary_private_key.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+        )
+        .notarize(&notary_private_key)
+        .build()
+        .to_raw()
+        .unwrap()
+}
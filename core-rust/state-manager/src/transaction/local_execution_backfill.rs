@@ -0,0 +1,386 @@
+use crate::prelude::*;
+
+use historical_state::{VersionScopedDatabase, VersionScopingSupport};
+use std::time::Duration;
+
+/// A background re-executor of already-committed transactions, capable of populating the local
+/// transaction execution index retroactively (i.e. when the index was enabled on an
+/// already-populated ledger).
+/// The implementation is suited for being driven by an external scheduler.
+pub struct LocalTransactionExecutionBackfiller {
+    database: Arc<DbLock<ActualStateManagerDatabase>>,
+    execution_configurator: Arc<ExecutionConfigurator>,
+    network_definition: NetworkDefinition,
+    failure: Mutex<Option<LocalExecutionBackfillFailure>>,
+}
+
+/// A reason for which a committed transaction could not be re-executed.
+#[derive(Debug, Clone)]
+pub enum LocalExecutionBackfillError {
+    MissingTransaction,
+    MissingLedgerReceipt,
+    ValidationFailed(String),
+    NoLongerCommittable(String),
+    ReceiptHashMismatch {
+        re_executed: LedgerReceiptHash,
+        committed: LedgerReceiptHash,
+    },
+}
+
+/// A batch of re-executed transactions, computed outside of the [`DbLock::lock()`].
+struct ReExecutedBatch {
+    /// The first state version with the local execution available at the time of re-execution.
+    preceded_state_version: StateVersion,
+    /// The local executions, going backwards from the one directly preceding the above.
+    executions: Vec<(StateVersion, LocalTransactionExecution)>,
+    /// Whether nothing more can be backfilled after this batch.
+    is_last: bool,
+}
+
+/// A recorded failure of the backfill, which halts it (until the Node's restart).
+#[derive(Debug, Clone)]
+pub struct LocalExecutionBackfillFailure {
+    pub state_version: StateVersion,
+    pub error: LocalExecutionBackfillError,
+}
+
+impl LocalTransactionExecutionBackfiller {
+    const INTERVAL: Duration = Duration::from_secs(10);
+    const TRANSACTION_BATCH_SIZE: usize = 1000;
+
+    pub fn new(
+        database: Arc<DbLock<ActualStateManagerDatabase>>,
+        execution_configurator: Arc<ExecutionConfigurator>,
+        network_definition: NetworkDefinition,
+        lock_factory: &LockFactory,
+    ) -> Self {
+        Self {
+            database,
+            execution_configurator,
+            network_definition,
+            failure: lock_factory.named("failure").new_mutex(None),
+        }
+    }
+
+    /// An interval between [`run()`]s, to be used by this instance's scheduler.
+    pub fn interval(&self) -> Duration {
+        Self::INTERVAL
+    }
+
+    /// Returns the failure which halted the backfill (if any).
+    pub fn failure(&self) -> Option<LocalExecutionBackfillFailure> {
+        self.failure.lock().clone()
+    }
+
+    /// Performs a single backfill run, which re-executes past transactions in batches, going
+    /// backwards from the oldest one currently available, for as long as the historical state
+    /// allows to re-execute them.
+    ///
+    /// Each re-executed transaction is verified against its committed ledger receipt. The progress
+    /// is persisted after each batch, so that an interrupted backfill simply continues on the next
+    /// run (or after a restart). A transaction which cannot be re-executed is recorded as the
+    /// [`Self::failure()`] (and logged), which stops any further backfilling.
+    ///
+    /// *Note on concurrent database access:*
+    /// The re-execution only reads immutable data (i.e. the committed transactions and their
+    /// historical state), so each batch is computed on a [`DbLock::snapshot()`] (which also keeps
+    /// the data available in case of a concurrent GC), without blocking the commits. Only the
+    /// write of the batch's results happens under the [`DbLock::lock()`], after re-checking that
+    /// the backfill's progress and the ledger transactions' availability still allow it.
+    pub fn run(&self) {
+        if self.failure.lock().is_some() {
+            return;
+        }
+        loop {
+            let finished = self.run_batch();
+            if finished {
+                break;
+            }
+        }
+    }
+
+    /// Backfills a single batch; returns `true` if there is nothing more to do.
+    fn run_batch(&self) -> bool {
+        let Some(ReExecutedBatch {
+            preceded_state_version,
+            mut executions,
+            is_last,
+        }) = self.re_execute_batch()
+        else {
+            return true;
+        };
+
+        let database = self.database.lock();
+        if !database.is_local_transaction_execution_index_enabled()
+            || database.get_first_stored_local_transaction_execution_state_version()
+                != preceded_state_version
+        {
+            // Someone else progressed the index in the meantime (should not happen in practice):
+            return true;
+        }
+        // The batch goes backwards, so only its tail may have been pruned in the meantime:
+        let first_state_version = database.get_first_stored_ledger_transaction_state_version();
+        executions.retain(|(state_version, _)| *state_version >= first_state_version);
+        let Some((available_from, _)) = executions.last() else {
+            return true;
+        };
+        let available_from = *available_from;
+        database.put_backfilled_local_transaction_executions(executions);
+        if is_last {
+            info!(
+                "Local transaction executions are available from {}",
+                available_from
+            );
+        } else {
+            info!(
+                "Backfilled local transaction executions down to {}",
+                available_from
+            );
+        }
+        is_last
+    }
+
+    /// Re-executes (on a database snapshot) a batch of transactions directly preceding the ones
+    /// currently having their local executions available, or returns `None` if there is nothing to
+    /// re-execute.
+    fn re_execute_batch(&self) -> Option<ReExecutedBatch> {
+        let database = self.database.snapshot();
+        if !database.is_local_transaction_execution_index_enabled() {
+            return None;
+        }
+
+        // Transactions pruned from the ledger cannot be re-executed, even if their state is known:
+        let first_state_version = database.get_first_stored_ledger_transaction_state_version();
+        let preceded_state_version =
+            database.get_first_stored_local_transaction_execution_state_version();
+        let mut available_from = preceded_state_version;
+        let mut executions = Vec::new();
+        let mut is_last = true;
+        while available_from > first_state_version {
+            if executions.len() >= Self::TRANSACTION_BATCH_SIZE {
+                is_last = false;
+                break;
+            }
+            let state_version = available_from.previous().unwrap();
+            let parent_database = match database
+                .deref()
+                .scoped_at(Some(state_version.previous().unwrap()))
+            {
+                Ok(parent_database) => parent_database,
+                Err(error) => {
+                    // This is the expected end of the backfill (i.e. the history's horizon):
+                    info!(
+                        "Cannot backfill local transaction execution at {}: {:?}",
+                        state_version, error
+                    );
+                    break;
+                }
+            };
+            match self.re_execute(database.deref(), &parent_database, state_version) {
+                Ok(execution) => executions.push((state_version, execution)),
+                Err(error) => {
+                    warn!(
+                        "Local transaction execution backfill halted at {}: {:?}",
+                        state_version, error
+                    );
+                    *self.failure.lock() = Some(LocalExecutionBackfillFailure {
+                        state_version,
+                        error,
+                    });
+                    break;
+                }
+            }
+            available_from = state_version;
+        }
+        if executions.is_empty() {
+            return None;
+        }
+        Some(ReExecutedBatch {
+            preceded_state_version,
+            executions,
+            is_last,
+        })
+    }
+
+    /// Re-executes the committed transaction at the given state version, on top of its historical
+    /// parent state.
+    ///
+    /// The transaction is validated and executed according to the protocol version in force at
+    /// that historical state: the validator's settings are read from the parent state, and the
+    /// engine resolves its versioned behaviours (i.e. the system boot settings) from that state
+    /// as well.
+    fn re_execute<R: ReadableRocks>(
+        &self,
+        database: &StateManagerDatabase<R>,
+        parent_database: &VersionScopedDatabase<&StateManagerDatabase<R>>,
+        state_version: StateVersion,
+    ) -> Result<LocalTransactionExecution, LocalExecutionBackfillError> {
+        let raw = database
+            .get_committed_transaction(state_version)
+            .ok_or(LocalExecutionBackfillError::MissingTransaction)?;
+        let committed_receipt = database
+            .get_committed_ledger_transaction_receipt(state_version)
+            .ok_or(LocalExecutionBackfillError::MissingLedgerReceipt)?;
+        let historical_validator =
+            TransactionValidator::new(parent_database, &self.network_definition);
+        let IdentifiedLedgerExecutable { executable, hashes } = raw
+            .create_identifiable_ledger_executable(
+                &historical_validator,
+                AcceptedLedgerTransactionKind::Any,
+            )
+            .map_err(|error| LocalExecutionBackfillError::ValidationFailed(format!("{error:?}")))?;
+
+        let receipt = self
+            .execution_configurator
+            .wrap_ledger_transaction(&hashes, &executable, "backfilled")
+            .execute_on(parent_database);
+        let commit = match receipt.result {
+            TransactionResult::Commit(commit) => commit,
+            other => {
+                return Err(LocalExecutionBackfillError::NoLongerCommittable(format!(
+                    "{other:?}"
+                )))
+            }
+        };
+        let state_changes = ProcessedCommitResult::compute_ledger_state_changes(
+            parent_database,
+            &commit.state_updates,
+        );
+        let global_balance_update = ProcessedCommitResult::compute_global_balance_update(
+            parent_database,
+            &state_changes,
+            &commit.state_update_summary.vault_balance_changes,
+        );
+        let local_receipt = LocalTransactionReceipt::new(
+            commit,
+            state_changes,
+            global_balance_update.global_balance_summary,
+            ExecutionFeeData {
+                fee_summary: receipt.fee_summary,
+                engine_costing_parameters: receipt.costing_parameters,
+                transaction_costing_parameters: receipt.transaction_costing_parameters,
+            },
+        );
+
+        let re_executed = local_receipt.on_ledger.get_consensus_receipt().get_hash();
+        let committed = committed_receipt.get_consensus_receipt().get_hash();
+        if re_executed != committed {
+            return Err(LocalExecutionBackfillError::ReceiptHashMismatch {
+                re_executed,
+                committed,
+            });
+        }
+        Ok(local_receipt.local_execution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{
+        commit_round_updates_until_epoch, create_bootstrapped_state_manager_with_rounds_per_epoch,
+    };
+
+    fn create_state_manager(
+        path: &std::path::Path,
+        enable_local_transaction_execution_index: bool,
+    ) -> StateManager {
+        let mut config = StateManagerConfig::new_for_testing(path.to_str().unwrap());
+        config.database_config = DatabaseConfig {
+            enable_local_transaction_execution_index,
+            enable_account_change_index: enable_local_transaction_execution_index,
+            enable_historical_substate_values: true,
+            enable_entity_listing_indices: true,
+            enable_resource_change_index: enable_local_transaction_execution_index,
+            enabled_secondary_indices: vec![],
+            ledger_transaction_retention_epoch_count: None,
+        };
+        create_bootstrapped_state_manager_with_rounds_per_epoch(config, 10)
+    }
+
+    fn create_backfiller(state_manager: &StateManager) -> LocalTransactionExecutionBackfiller {
+        LocalTransactionExecutionBackfiller::new(
+            state_manager.database.clone(),
+            Arc::new(ExecutionConfigurator::new(
+                &state_manager.network_definition,
+                false,
+                false,
+            )),
+            state_manager.network_definition.clone(),
+            &LockFactory::new("testing"),
+        )
+    }
+
+    #[test]
+    fn index_enabled_retroactively_is_backfilled_from_state_history() {
+        let reference_tmp = tempfile::tempdir().unwrap();
+        let reference = create_state_manager(reference_tmp.path(), true);
+        commit_round_updates_until_epoch(&reference, Epoch::of(3));
+
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), false);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        let enabled_at_state_version = state_manager.database.access_direct().max_state_version();
+        drop(state_manager);
+
+        // Restarting with the index enabled does not re-execute anything on boot-up...
+        let state_manager = create_state_manager(tmp.path(), true);
+        assert_eq!(
+            state_manager
+                .database
+                .access_direct()
+                .get_first_stored_local_transaction_execution_state_version(),
+            enabled_at_state_version.next().unwrap()
+        );
+
+        // ... but the background process backfills the past executions:
+        let backfiller = create_backfiller(&state_manager);
+        backfiller.run();
+        assert!(backfiller.failure().is_none());
+        let database = state_manager.database.access_direct();
+        let first_stored_historical_state_version =
+            database.get_first_stored_historical_state_version();
+        assert_eq!(
+            database.get_first_stored_local_transaction_execution_state_version(),
+            first_stored_historical_state_version.next().unwrap()
+        );
+        let reference_database = reference.database.access_direct();
+        let mut state_version = first_stored_historical_state_version.next().unwrap();
+        while state_version <= enabled_at_state_version {
+            let execution = database
+                .get_committed_local_transaction_execution(state_version)
+                .unwrap();
+            let reference_execution = reference_database
+                .get_committed_local_transaction_execution(state_version)
+                .unwrap();
+            assert_eq!(
+                scrypto_encode(&execution).unwrap(),
+                scrypto_encode(&reference_execution).unwrap()
+            );
+            state_version = state_version.next().unwrap();
+        }
+        drop(database);
+
+        // ... and the new transactions are indexed normally:
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(4));
+        let database = state_manager.database.access_direct();
+        assert!(database
+            .get_committed_local_transaction_execution(database.max_state_version())
+            .is_some());
+    }
+
+    #[test]
+    fn index_disabled_retroactively_is_pruned() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state_manager = create_state_manager(tmp.path(), true);
+        commit_round_updates_until_epoch(&state_manager, Epoch::of(3));
+        let state_version = state_manager.database.access_direct().max_state_version();
+        drop(state_manager);
+
+        let state_manager = create_state_manager(tmp.path(), false);
+        let database = state_manager.database.access_direct();
+        assert!(database
+            .get_committed_local_transaction_execution(state_version)
+            .is_none());
+    }
+}
@@ -63,6 +63,7 @@
  */
 
 mod executable_logic;
+mod local_execution_backfill;
 mod preparation;
 mod preview;
 mod round_updates;
@@ -70,6 +71,7 @@ mod series_execution;
 mod validation;
 
 pub use executable_logic::*;
+pub use local_execution_backfill::*;
 pub use preparation::*;
 pub use preview::*;
 pub use round_updates::*;
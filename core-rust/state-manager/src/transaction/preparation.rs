@@ -391,6 +391,7 @@ impl Preparator {
         let mut rejected_transactions = Vec::new();
         let pending_transaction_timestamp = SystemTime::now();
         let mut pending_transaction_results = Vec::new();
+        let mut execution_cost_units_observations = Vec::new();
         let total_proposal_size: usize = prepare_request
             .proposed_transactions
             .iter()
@@ -477,6 +478,15 @@ impl Preparator {
             );
             match execute_result {
                 Ok(processed_commit_result) => {
+                    // Regardless of whether it fits the vertex, the transaction's cost is now known:
+                    execution_cost_units_observations.push((
+                        user_hashes.notarized_transaction_hash,
+                        processed_commit_result
+                            .local_receipt
+                            .local_execution
+                            .fee_summary
+                            .total_execution_cost_units_consumed,
+                    ));
                     match vertex_limits_tracker.try_next_transaction(
                         transaction_size,
                         &processed_commit_result
@@ -578,6 +588,8 @@ impl Preparator {
                     attempt,
                 );
         }
+        self.mempool_manager
+            .observe_execution_cost_units(execution_cost_units_observations);
 
         self.vertex_prepare_metrics.update(
             total_proposal_size,
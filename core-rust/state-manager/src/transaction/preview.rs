@@ -200,9 +200,8 @@ mod tests {
 
     #[test]
     fn test_preview_processed_substate_changes() {
-        let tmp = tempfile::tempdir().unwrap();
         let state_manager = create_bootstrapped_state_manager(
-            StateManagerConfig::new_for_testing(tmp.path().to_str().unwrap()),
+            StateManagerConfig::new_for_testing_in_memory(),
             BabylonSettings::test_default(),
         );
 
@@ -68,7 +68,7 @@ impl CommittabilityValidator {
         executable: &ExecutableTransaction,
         user_hashes: &UserTransactionHashes,
         timestamp: SystemTime,
-    ) -> TransactionAttempt {
+    ) -> CommittabilityCheck {
         let database = self.database.snapshot();
         let executed_at_state_version = database.max_state_version();
 
@@ -76,25 +76,28 @@ impl CommittabilityValidator {
             database.get_txn_state_version_by_identifier(&user_hashes.transaction_intent_hash);
 
         if let Some(state_version) = existing {
-            let committed_transaction_identifiers = database
+            let committed_notarized_transaction_hash = database
                 .get_committed_transaction_identifiers(state_version)
-                .expect("transaction of a state version obtained from an index");
-
-            return TransactionAttempt {
-                rejection: Some(MempoolRejectionReason::TransactionIntentAlreadyCommitted(
-                    AlreadyCommittedError {
-                        committed_state_version: state_version,
-                        committed_notarized_transaction_hash: committed_transaction_identifiers
-                            .transaction_hashes
-                            .as_user()
-                            .expect("non-user transaction located by intent hash")
-                            .notarized_transaction_hash,
-                    },
-                )),
-                against_state: AtState::Specific(AtSpecificState::Committed {
-                    state_version: executed_at_state_version,
-                }),
-                timestamp,
+                .expect("transaction of a state version obtained from an index")
+                .transaction_hashes
+                .as_user()
+                .expect("non-user transaction located by intent hash")
+                .notarized_transaction_hash;
+
+            return CommittabilityCheck {
+                attempt: TransactionAttempt {
+                    rejection: Some(MempoolRejectionReason::TransactionIntentAlreadyCommitted(
+                        AlreadyCommittedError {
+                            committed_state_version: state_version,
+                            committed_notarized_transaction_hash,
+                        },
+                    )),
+                    against_state: AtState::Specific(AtSpecificState::Committed {
+                        state_version: executed_at_state_version,
+                    }),
+                    timestamp,
+                },
+                fee_payer: None,
             };
         }
 
@@ -110,6 +113,12 @@ impl CommittabilityValidator {
             .wrap_pending_transaction(executable, user_hashes)
             .execute_on(database.deref());
 
+        let fee_payer = match &receipt.result {
+            TransactionResult::Commit(commit_result) => {
+                Self::resolve_fee_payer(database.deref(), &commit_result.fee_source)
+            }
+            TransactionResult::Reject(_) | TransactionResult::Abort(_) => None,
+        };
         let result = match receipt.result {
             TransactionResult::Reject(RejectResult { reason }) => {
                 if matches!(
@@ -135,12 +144,35 @@ impl CommittabilityValidator {
             }
         };
 
-        TransactionAttempt {
-            rejection: result.err(),
-            against_state: AtState::Specific(AtSpecificState::Committed {
-                state_version: executed_at_state_version,
-            }),
-            timestamp,
+        CommittabilityCheck {
+            attempt: TransactionAttempt {
+                rejection: result.err(),
+                against_state: AtState::Specific(AtSpecificState::Committed {
+                    state_version: executed_at_state_version,
+                }),
+                timestamp,
+            },
+            fee_payer,
         }
     }
+
+    /// Resolves the global ancestor of the vault which was the first to pay the fee (i.e. the
+    /// first one locked), if known.
+    fn resolve_fee_payer(
+        database: &impl SubstateNodeAncestryStore,
+        fee_source: &FeeSource,
+    ) -> Option<GlobalAddress> {
+        let first_paying_vault_id = fee_source.paying_vaults.keys().next()?;
+        let ancestry = database.get_ancestry(first_paying_vault_id)?;
+        Some(GlobalAddress::new_or_panic(ancestry.root.0.into()))
+    }
+}
+
+/// The outcome of [`CommittabilityValidator::check_for_rejection()`].
+pub struct CommittabilityCheck {
+    pub attempt: TransactionAttempt,
+    /// The global entity which paid the fee during the check's execution (see
+    /// [`MempoolTransactionParties::fee_payer`]).
+    /// Absent if the transaction was not committed, or if the paying vault has no known ancestry.
+    pub fee_payer: Option<GlobalAddress>,
 }
@@ -636,6 +636,66 @@ impl From<LedgerHeaderV1> for LedgerHeader {
     }
 }
 
+impl From<&LedgerProof> for VerifiableLedgerProof {
+    fn from(proof: &LedgerProof) -> Self {
+        let header = &proof.ledger_header;
+        Self {
+            ledger_header: VerifiableLedgerHeader {
+                epoch: header.epoch,
+                round: header.round,
+                state_version: header.state_version.number(),
+                state_root: header.hashes.state_root.into_hash(),
+                transaction_root: header.hashes.transaction_root.into_hash(),
+                receipt_root: header.hashes.receipt_root.into_hash(),
+                consensus_parent_round_timestamp_ms: header.consensus_parent_round_timestamp_ms,
+                proposer_timestamp_ms: header.proposer_timestamp_ms,
+                next_epoch: header
+                    .next_epoch
+                    .as_ref()
+                    .map(|next_epoch| VerifiableNextEpoch {
+                        epoch: next_epoch.epoch,
+                        validator_set: next_epoch
+                            .validator_set
+                            .iter()
+                            .map(|validator| VerifiableValidator {
+                                address: validator.address,
+                                key: validator.key,
+                                stake: validator.stake,
+                            })
+                            .collect(),
+                    }),
+                next_protocol_version: header
+                    .next_protocol_version
+                    .as_ref()
+                    .map(|name| name.as_str().to_string()),
+            },
+            origin: match &proof.origin {
+                LedgerProofOrigin::Consensus {
+                    opaque,
+                    timestamped_signatures,
+                } => VerifiableLedgerProofOrigin::Consensus {
+                    opaque: *opaque,
+                    timestamped_signatures: timestamped_signatures
+                        .iter()
+                        .map(|signature| VerifiableValidatorSignature {
+                            key: signature.key,
+                            validator_address: signature.validator_address,
+                            timestamp_ms: signature.timestamp_ms,
+                            signature: signature.signature,
+                        })
+                        .collect(),
+                },
+                LedgerProofOrigin::ProtocolUpdate {
+                    protocol_version_name,
+                    ..
+                } => VerifiableLedgerProofOrigin::ProtocolUpdate {
+                    protocol_version_name: protocol_version_name.as_str().to_string(),
+                },
+            },
+        }
+    }
+}
+
 pub struct LedgerStateSummary {
     pub epoch: Epoch,
     pub round: Round,
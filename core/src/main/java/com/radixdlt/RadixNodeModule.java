@@ -65,6 +65,7 @@
 package com.radixdlt;
 
 import com.google.common.base.Preconditions;
+import com.google.common.collect.ImmutableList;
 import com.google.inject.AbstractModule;
 import com.radixdlt.addressing.Addressing;
 import com.radixdlt.api.CoreApiServerModule;
@@ -108,6 +109,7 @@ import com.radixdlt.utils.UInt32;
 import com.radixdlt.utils.UInt64;
 import com.radixdlt.utils.properties.RuntimeProperties;
 import java.time.Duration;
+import java.util.Arrays;
 import java.util.Optional;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
@@ -300,8 +302,29 @@ public final class RadixNodeModule extends AbstractModule {
         "Invalid configuration: Computed mempool total transactions size is lower than the maximum"
             + " transaction size (%s). Please increase mempool.max_memory.",
         NodeConstants.DEFAULT_MAX_TRANSACTION_SIZE);
+    // An optional replace-by-tip policy for transactions conflicting on an intent or a subintent
+    // (by default, the conflicting transactions co-exist in the mempool):
+    var mempoolReplacementMinTipBumpBasisPoints =
+        properties.get("mempool.replacement.min_tip_bump_basis_points", Integer::parseInt);
+    // Optional per-party quotas, preventing a single fee payer (or notary) from flooding the mempool
+    // (by default, only the total count/size limits apply):
+    var mempoolMaxTransactionsPerFeePayer =
+        properties.get("mempool.quota.max_transactions_per_fee_payer", Integer::parseInt);
+    var mempoolMaxTransactionsPerNotary =
+        properties.get("mempool.quota.max_transactions_per_notary", Integer::parseInt);
+    Preconditions.checkArgument(
+        mempoolMaxTransactionsPerFeePayer.map(count -> count > 0).orElse(true)
+            && mempoolMaxTransactionsPerNotary.map(count -> count > 0).orElse(true),
+        "Invalid configuration: mempool.quota.* must be non zero positive numbers.");
     var mempoolConfig =
-        new RustMempoolConfig(mempoolMaxTotalTransactionsSize, mempoolMaxTransactionCount);
+        new RustMempoolConfig(
+            mempoolMaxTotalTransactionsSize,
+            mempoolMaxTransactionCount,
+            parseMempoolPersistenceConfig(properties),
+            Option.from(
+                mempoolReplacementMinTipBumpBasisPoints.map(UInt32::fromNonNegativeInt)),
+            Option.from(mempoolMaxTransactionsPerFeePayer.map(UInt32::fromNonNegativeInt)),
+            Option.from(mempoolMaxTransactionsPerNotary.map(UInt32::fromNonNegativeInt)));
 
     var enableLocalTransactionExecutionIndex =
         properties.get("db.local_transaction_execution_index.enable", true);
@@ -309,12 +332,25 @@ public final class RadixNodeModule extends AbstractModule {
     var enableHistoricalSubstateValues =
         properties.get("db.historical_substate_values.enable", false);
     var enableEntityListingIndices = properties.get("db.entity_listing_indices.enable", false);
+    var enableResourceChangeIndex = properties.get("db.resource_change_index.enable", false);
+    var enabledSecondaryIndices =
+        Arrays.stream(properties.get("db.secondary_indices.enable", "").split(","))
+            .map(String::trim)
+            .filter(name -> !name.isEmpty())
+            .collect(ImmutableList.toImmutableList());
+    // An optional retention of the full ledger transactions (by default, the node keeps the entire
+    // ledger history, i.e. acts as an archive node):
+    var ledgerTransactionRetentionEpochCount =
+        properties.get("db.ledger_transactions.retention_epoch_count", Long::parseLong);
     var databaseConfig =
         new DatabaseConfig(
             enableLocalTransactionExecutionIndex,
             enableAccountChangeIndex,
             enableHistoricalSubstateValues,
-            enableEntityListingIndices);
+            enableEntityListingIndices,
+            enableResourceChangeIndex,
+            enabledSecondaryIndices,
+            Option.from(ledgerTransactionRetentionEpochCount.map(UInt64::fromNonNegativeLong)));
 
     var vertexMaxTransactionCount =
         properties.get(
@@ -384,6 +420,8 @@ public final class RadixNodeModule extends AbstractModule {
 
     var stateTreeGcConfig = parseStateTreeGcConfig(properties);
     var ledgerProofsGcConfig = parseLedgerProofsGcConfig(properties);
+    var dbCheckpointsConfigOpt = parseDbCheckpointsConfig(properties);
+    var databaseTuningProfileOpt = parseDatabaseTuningProfile(properties);
 
     // this is tied to the number of actually-persisted proofs, and should not be configureable:
     var ledgerSyncLimitsConfig = LedgerSyncLimitsConfig.defaults();
@@ -410,6 +448,8 @@ public final class RadixNodeModule extends AbstractModule {
             Option.some(mempoolConfig),
             stateTreeGcConfig,
             ledgerProofsGcConfig,
+            dbCheckpointsConfigOpt,
+            databaseTuningProfileOpt,
             ledgerSyncLimitsConfig,
             protocolConfig,
             ScenariosExecutionConfig.ALL_FOR_NETWORK));
@@ -512,9 +552,27 @@ public final class RadixNodeModule extends AbstractModule {
         "state version history length must not be negative: %s",
         stateVersionHistoryLength);
 
+    // An optional retention of the historical Substate values at full resolution (which extends
+    // the history length above), expressed either in epochs or in seconds of ledger time:
+    var historicalStateFullResolutionEpochCount =
+        properties.get("db.historical_substate_values.full_resolution_epoch_count", Long::parseLong);
+    var historicalStateFullResolutionDurationSec =
+        properties.get("db.historical_substate_values.full_resolution_duration_sec", Long::parseLong);
+    // An optional interval of epochs at which sparse historical state checkpoints are retained
+    // (beyond the full-resolution window):
+    var historicalStateCheckpointEpochInterval =
+        properties.get("db.historical_substate_values.checkpoint_epoch_interval", Long::parseLong);
+    historicalStateCheckpointEpochInterval.ifPresent(
+        interval ->
+            Preconditions.checkArgument(
+                interval > 0, "checkpoint epoch interval must be positive: %s", interval));
+
     return new StateTreeGcConfig(
         UInt32.fromNonNegativeInt(intervalSec),
-        UInt64.fromNonNegativeLong(stateVersionHistoryLength));
+        UInt64.fromNonNegativeLong(stateVersionHistoryLength),
+        Option.from(historicalStateFullResolutionEpochCount.map(UInt64::fromNonNegativeLong)),
+        Option.from(historicalStateFullResolutionDurationSec.map(UInt64::fromNonNegativeLong)),
+        Option.from(historicalStateCheckpointEpochInterval.map(UInt64::fromNonNegativeLong)));
   }
 
   /**
@@ -547,6 +605,71 @@ public final class RadixNodeModule extends AbstractModule {
         UInt64.fromNonNegativeLong(mostRecentFullResolutionEpochCount));
   }
 
+  /**
+   * Parses the part of the configuration related to the scheduled database checkpoints. When
+   * enabled, right after committing an epoch change we check whether at least {@link
+   * DbCheckpointsConfig#epochInterval()} epochs have started since the most recent checkpoint and,
+   * if so, create (and verify) a new one, retaining only the most recent {@link
+   * DbCheckpointsConfig#retainedCheckpointCount()} checkpoints. The same check is also performed
+   * each {@link DbCheckpointsConfig#intervalSec()} seconds, as a fallback (e.g. for an epoch change
+   * committed during a GC).
+   */
+  private Option<DbCheckpointsConfig> parseDbCheckpointsConfig(RuntimeProperties properties) {
+    // The scheduled checkpoints are disabled unless their (dedicated) directory is configured.
+    var checkpointsPath = properties.get("db.scheduled_checkpoints.path", "");
+    if (checkpointsPath.isEmpty()) {
+      return Option.none();
+    }
+
+    var intervalSec = properties.get("db.scheduled_checkpoints.interval_sec", 60);
+    Preconditions.checkArgument(
+        intervalSec > 0, "scheduled DB checkpoints interval must be positive: %s sec", intervalSec);
+
+    var epochInterval = properties.get("db.scheduled_checkpoints.epoch_interval", 1);
+    Preconditions.checkArgument(
+        epochInterval > 0,
+        "scheduled DB checkpoints epoch interval must be positive: %s",
+        epochInterval);
+
+    var retainedCheckpointCount = properties.get("db.scheduled_checkpoints.retained_count", 3);
+    Preconditions.checkArgument(
+        retainedCheckpointCount > 0,
+        "retained scheduled DB checkpoints count must be positive: %s",
+        retainedCheckpointCount);
+
+    return Option.some(
+        new DbCheckpointsConfig(
+            checkpointsPath,
+            UInt32.fromNonNegativeInt(intervalSec),
+            UInt64.fromNonNegativeLong(epochInterval),
+            UInt32.fromNonNegativeInt(retainedCheckpointCount)));
+  }
+
+  private Option<DatabaseTuningProfile> parseDatabaseTuningProfile(RuntimeProperties properties) {
+    // The RocksDB's default options are used for all column families unless a profile is chosen.
+    var profileName = properties.get("db.tuning_profile", "");
+    if (profileName.isEmpty()) {
+      return Option.none();
+    }
+    return Option.some(DatabaseTuningProfile.fromName(profileName));
+  }
+
+  private Option<MempoolPersistenceConfig> parseMempoolPersistenceConfig(
+      RuntimeProperties properties) {
+    // The mempool starts empty after each restart unless its persistence file is configured.
+    var filePath = properties.get("mempool.persistence.file_path", "");
+    if (filePath.isEmpty()) {
+      return Option.none();
+    }
+
+    var intervalSec = properties.get("mempool.persistence.interval_sec", 60);
+    Preconditions.checkArgument(
+        intervalSec > 0, "mempool persistence interval must be positive: %s sec", intervalSec);
+
+    return Option.some(
+        new MempoolPersistenceConfig(filePath, UInt32.fromNonNegativeInt(intervalSec)));
+  }
+
   private void warnProtocolPropertySet(String prop) {
     log.warn(
         "WARNING: A {} property was set. It is highly NOT recommended to modify any protocol.**"
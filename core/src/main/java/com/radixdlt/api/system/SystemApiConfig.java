@@ -64,4 +64,17 @@
 
 package com.radixdlt.api.system;
 
-public record SystemApiConfig(boolean dbCheckpointEnabled, String dbCheckpointsPath) {}
+import java.util.Optional;
+
+/**
+ * System API configuration options
+ *
+ * @param adminToken if present, enables the admin endpoints (e.g. the GC control), which then
+ *     require this token in an {@code Authorization: Bearer <token>} header
+ */
+public record SystemApiConfig(
+    boolean dbCheckpointEnabled, String dbCheckpointsPath, Optional<String> adminToken) {
+  public SystemApiConfig(boolean dbCheckpointEnabled, String dbCheckpointsPath) {
+    this(dbCheckpointEnabled, dbCheckpointsPath, Optional.empty());
+  }
+}
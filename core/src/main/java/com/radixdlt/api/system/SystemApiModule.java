@@ -75,6 +75,7 @@ import com.radixdlt.utils.properties.RuntimeProperties;
 import io.undertow.server.HttpHandler;
 import java.nio.file.Path;
 import java.util.Map;
+import java.util.Optional;
 
 public class SystemApiModule extends AbstractModule {
   private static final int MAXIMUM_CONCURRENT_REQUESTS =
@@ -105,6 +106,8 @@ public class SystemApiModule extends AbstractModule {
         .addBinding(HandlerRoute.get("/system/network-sync-status"))
         .to(NetworkSyncStatusHandler.class);
     binder.addBinding(HandlerRoute.post("/system/db-checkpoint")).to(DbCheckpointHandler.class);
+    binder.addBinding(HandlerRoute.get("/system/gc")).to(GcStatusHandler.class);
+    binder.addBinding(HandlerRoute.post("/system/gc")).to(GcUpdateHandler.class);
   }
 
   @Provides
@@ -122,6 +125,9 @@ public class SystemApiModule extends AbstractModule {
         runtimeProperties.get(
             "db.checkpoints_path",
             Path.of(nodeStorageLocation, "state_manager_checkpoints").toString());
-    return new SystemApiConfig(dbCheckpointEnabled, dbCheckpointsPath);
+    final var adminToken =
+        Optional.ofNullable(runtimeProperties.get("api.system.admin_token"))
+            .filter(token -> !token.isBlank());
+    return new SystemApiConfig(dbCheckpointEnabled, dbCheckpointsPath, adminToken);
   }
 }
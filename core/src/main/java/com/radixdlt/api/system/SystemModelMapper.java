@@ -68,6 +68,8 @@ import com.google.inject.Inject;
 import com.radixdlt.addressing.Addressing;
 import com.radixdlt.api.system.generated.models.*;
 import com.radixdlt.crypto.ECDSASecp256k1PublicKey;
+import com.radixdlt.db.gc.GcControlsSummary;
+import com.radixdlt.db.gc.GcRunStatus;
 import com.radixdlt.p2p.P2PConfig;
 import com.radixdlt.p2p.PeersView;
 import com.radixdlt.p2p.RadixNodeUri;
@@ -78,6 +80,7 @@ import com.radixdlt.p2p.capability.RemotePeerCapability;
 import com.radixdlt.protocol.ProtocolConfig;
 import com.radixdlt.protocol.RustProtocolUpdate;
 import com.radixdlt.sync.SyncRelayConfig;
+import com.radixdlt.utils.UInt64;
 import java.math.BigDecimal;
 import java.time.Instant;
 import java.util.Map;
@@ -188,6 +191,31 @@ public final class SystemModelMapper {
                 .toList());
   }
 
+  public GcStatusResponse gcStatusResponse(GcControlsSummary summary) {
+    return new GcStatusResponse()
+        .stateTreeGc(
+            gcStatus(
+                summary.stateTreeGcStatus(),
+                Integer.toUnsignedLong(summary.stateTreeGcConfig().intervalSec().toInt()),
+                summary.stateTreeGcConfig().stateVersionHistoryLength().toLong()))
+        .ledgerProofsGc(
+            gcStatus(
+                summary.ledgerProofsGcStatus(),
+                Integer.toUnsignedLong(summary.ledgerProofsGcConfig().intervalSec().toInt()),
+                summary.ledgerProofsGcConfig().mostRecentFullResolutionEpochCount().toLong()));
+  }
+
+  private GcStatus gcStatus(GcRunStatus status, long intervalSec, long historyLength) {
+    return new GcStatus()
+        .isPaused(status.isPaused())
+        .isRunRequested(status.isRunRequested())
+        .isRunning(status.isRunning())
+        .completedRuns(status.completedRuns().toLong())
+        .progress(status.progress().map(UInt64::toLong).toNullable())
+        .intervalSec(intervalSec)
+        .historyLength(historyLength);
+  }
+
   private ProtocolUpdateTrigger protocolUpdateTrigger(
       com.radixdlt.protocol.ProtocolUpdateTrigger protocolUpdateTrigger) {
     var enactmentCondition =
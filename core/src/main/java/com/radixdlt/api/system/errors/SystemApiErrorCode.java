@@ -66,6 +66,8 @@ package com.radixdlt.api.system.errors;
 
 public enum SystemApiErrorCode {
   BAD_REQUEST(400, "Bad request"),
+  UNAUTHORIZED(401, "Unauthorized"),
+  FORBIDDEN(403, "Forbidden"),
   NOT_FOUND(404, "Not found"),
   CONFLICT(409, "State Conflict"),
   INTERNAL_SERVER_ERROR(500, "Internal Server Error"),
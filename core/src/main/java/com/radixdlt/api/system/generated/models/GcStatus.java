@@ -0,0 +1,300 @@
+/*
+ * Radix System API
+ * This API is exposed by the Babylon Radix node to give clients access to information about the node itself, its configuration, status and subsystems.  It is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Heavy load may impact the node's function.  If you require queries against ledger state, you may also wish to consider using the [Core API or Gateway API instead](https://docs-babylon.radixdlt.com/main/apis/api-specification.html). 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.system.generated.models;
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * GcStatus
+ */
+@JsonPropertyOrder({
+  GcStatus.JSON_PROPERTY_IS_PAUSED,
+  GcStatus.JSON_PROPERTY_IS_RUN_REQUESTED,
+  GcStatus.JSON_PROPERTY_IS_RUNNING,
+  GcStatus.JSON_PROPERTY_COMPLETED_RUNS,
+  GcStatus.JSON_PROPERTY_PROGRESS,
+  GcStatus.JSON_PROPERTY_INTERVAL_SEC,
+  GcStatus.JSON_PROPERTY_HISTORY_LENGTH
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class GcStatus {
+  public static final String JSON_PROPERTY_IS_PAUSED = "is_paused";
+  private Boolean isPaused;
+
+  public static final String JSON_PROPERTY_IS_RUN_REQUESTED = "is_run_requested";
+  private Boolean isRunRequested;
+
+  public static final String JSON_PROPERTY_IS_RUNNING = "is_running";
+  private Boolean isRunning;
+
+  public static final String JSON_PROPERTY_COMPLETED_RUNS = "completed_runs";
+  private Long completedRuns;
+
+  public static final String JSON_PROPERTY_PROGRESS = "progress";
+  private Long progress;
+
+  public static final String JSON_PROPERTY_INTERVAL_SEC = "interval_sec";
+  private Long intervalSec;
+
+  public static final String JSON_PROPERTY_HISTORY_LENGTH = "history_length";
+  private Long historyLength;
+
+
+  public GcStatus isPaused(Boolean isPaused) {
+    this.isPaused = isPaused;
+    return this;
+  }
+
+   /**
+   * Get isPaused
+   * @return isPaused
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_IS_PAUSED)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Boolean getIsPaused() {
+    return isPaused;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_IS_PAUSED)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setIsPaused(Boolean isPaused) {
+    this.isPaused = isPaused;
+  }
+
+
+  public GcStatus isRunRequested(Boolean isRunRequested) {
+    this.isRunRequested = isRunRequested;
+    return this;
+  }
+
+   /**
+   * Get isRunRequested
+   * @return isRunRequested
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_IS_RUN_REQUESTED)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Boolean getIsRunRequested() {
+    return isRunRequested;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_IS_RUN_REQUESTED)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setIsRunRequested(Boolean isRunRequested) {
+    this.isRunRequested = isRunRequested;
+  }
+
+
+  public GcStatus isRunning(Boolean isRunning) {
+    this.isRunning = isRunning;
+    return this;
+  }
+
+   /**
+   * Get isRunning
+   * @return isRunning
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_IS_RUNNING)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Boolean getIsRunning() {
+    return isRunning;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_IS_RUNNING)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setIsRunning(Boolean isRunning) {
+    this.isRunning = isRunning;
+  }
+
+
+  public GcStatus completedRuns(Long completedRuns) {
+    this.completedRuns = completedRuns;
+    return this;
+  }
+
+   /**
+   * A number of runs completed since the node started.
+   * @return completedRuns
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "A number of runs completed since the node started.")
+  @JsonProperty(JSON_PROPERTY_COMPLETED_RUNS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getCompletedRuns() {
+    return completedRuns;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_COMPLETED_RUNS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setCompletedRuns(Long completedRuns) {
+    this.completedRuns = completedRuns;
+  }
+
+
+  public GcStatus progress(Long progress) {
+    this.progress = progress;
+    return this;
+  }
+
+   /**
+   * A position reached by the latest completed run: a state version (up to which the state hash tree is pruned) for the `StateTree` GC, or an epoch (the last one of pruned ledger proofs) for the `LedgerProofs` GC. Absent if not known yet.
+   * @return progress
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "A position reached by the latest completed run: a state version (up to which the state hash tree is pruned) for the `StateTree` GC, or an epoch (the last one of pruned ledger proofs) for the `LedgerProofs` GC. Absent if not known yet.")
+  @JsonProperty(JSON_PROPERTY_PROGRESS)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Long getProgress() {
+    return progress;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_PROGRESS)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setProgress(Long progress) {
+    this.progress = progress;
+  }
+
+
+  public GcStatus intervalSec(Long intervalSec) {
+    this.intervalSec = intervalSec;
+    return this;
+  }
+
+   /**
+   * An interval between periodic runs (0 means that the GC only runs on demand).
+   * @return intervalSec
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "An interval between periodic runs (0 means that the GC only runs on demand).")
+  @JsonProperty(JSON_PROPERTY_INTERVAL_SEC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getIntervalSec() {
+    return intervalSec;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_INTERVAL_SEC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setIntervalSec(Long intervalSec) {
+    this.intervalSec = intervalSec;
+  }
+
+
+  public GcStatus historyLength(Long historyLength) {
+    this.historyLength = historyLength;
+    return this;
+  }
+
+   /**
+   * A number of most recent state versions (for the `StateTree` GC) or completed epochs (for the `LedgerProofs` GC) which are not pruned.
+   * @return historyLength
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "A number of most recent state versions (for the `StateTree` GC) or completed epochs (for the `LedgerProofs` GC) which are not pruned.")
+  @JsonProperty(JSON_PROPERTY_HISTORY_LENGTH)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getHistoryLength() {
+    return historyLength;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_HISTORY_LENGTH)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setHistoryLength(Long historyLength) {
+    this.historyLength = historyLength;
+  }
+
+
+  /**
+   * Return true if this GcStatus object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    GcStatus gcStatus = (GcStatus) o;
+    return Objects.equals(this.isPaused, gcStatus.isPaused) &&
+        Objects.equals(this.isRunRequested, gcStatus.isRunRequested) &&
+        Objects.equals(this.isRunning, gcStatus.isRunning) &&
+        Objects.equals(this.completedRuns, gcStatus.completedRuns) &&
+        Objects.equals(this.progress, gcStatus.progress) &&
+        Objects.equals(this.intervalSec, gcStatus.intervalSec) &&
+        Objects.equals(this.historyLength, gcStatus.historyLength);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(isPaused, isRunRequested, isRunning, completedRuns, progress, intervalSec, historyLength);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class GcStatus {\n");
+    sb.append("    isPaused: ").append(toIndentedString(isPaused)).append("\n");
+    sb.append("    isRunRequested: ").append(toIndentedString(isRunRequested)).append("\n");
+    sb.append("    isRunning: ").append(toIndentedString(isRunning)).append("\n");
+    sb.append("    completedRuns: ").append(toIndentedString(completedRuns)).append("\n");
+    sb.append("    progress: ").append(toIndentedString(progress)).append("\n");
+    sb.append("    intervalSec: ").append(toIndentedString(intervalSec)).append("\n");
+    sb.append("    historyLength: ").append(toIndentedString(historyLength)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
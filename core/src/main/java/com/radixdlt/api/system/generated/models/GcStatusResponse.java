@@ -0,0 +1,141 @@
+/*
+ * Radix System API
+ * This API is exposed by the Babylon Radix node to give clients access to information about the node itself, its configuration, status and subsystems.  It is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Heavy load may impact the node's function.  If you require queries against ledger state, you may also wish to consider using the [Core API or Gateway API instead](https://docs-babylon.radixdlt.com/main/apis/api-specification.html). 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.system.generated.models;
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.system.generated.models.GcStatus;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * GcStatusResponse
+ */
+@JsonPropertyOrder({
+  GcStatusResponse.JSON_PROPERTY_STATE_TREE_GC,
+  GcStatusResponse.JSON_PROPERTY_LEDGER_PROOFS_GC
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class GcStatusResponse {
+  public static final String JSON_PROPERTY_STATE_TREE_GC = "state_tree_gc";
+  private GcStatus stateTreeGc;
+
+  public static final String JSON_PROPERTY_LEDGER_PROOFS_GC = "ledger_proofs_gc";
+  private GcStatus ledgerProofsGc;
+
+
+  public GcStatusResponse stateTreeGc(GcStatus stateTreeGc) {
+    this.stateTreeGc = stateTreeGc;
+    return this;
+  }
+
+   /**
+   * Get stateTreeGc
+   * @return stateTreeGc
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_STATE_TREE_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public GcStatus getStateTreeGc() {
+    return stateTreeGc;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_STATE_TREE_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setStateTreeGc(GcStatus stateTreeGc) {
+    this.stateTreeGc = stateTreeGc;
+  }
+
+
+  public GcStatusResponse ledgerProofsGc(GcStatus ledgerProofsGc) {
+    this.ledgerProofsGc = ledgerProofsGc;
+    return this;
+  }
+
+   /**
+   * Get ledgerProofsGc
+   * @return ledgerProofsGc
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOFS_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public GcStatus getLedgerProofsGc() {
+    return ledgerProofsGc;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOFS_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLedgerProofsGc(GcStatus ledgerProofsGc) {
+    this.ledgerProofsGc = ledgerProofsGc;
+  }
+
+
+  /**
+   * Return true if this GcStatusResponse object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    GcStatusResponse gcStatusResponse = (GcStatusResponse) o;
+    return Objects.equals(this.stateTreeGc, gcStatusResponse.stateTreeGc) &&
+        Objects.equals(this.ledgerProofsGc, gcStatusResponse.ledgerProofsGc);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(stateTreeGc, ledgerProofsGc);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class GcStatusResponse {\n");
+    sb.append("    stateTreeGc: ").append(toIndentedString(stateTreeGc)).append("\n");
+    sb.append("    ledgerProofsGc: ").append(toIndentedString(ledgerProofsGc)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,237 @@
+/*
+ * Radix System API
+ * This API is exposed by the Babylon Radix node to give clients access to information about the node itself, its configuration, status and subsystems.  It is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Heavy load may impact the node's function.  If you require queries against ledger state, you may also wish to consider using the [Core API or Gateway API instead](https://docs-babylon.radixdlt.com/main/apis/api-specification.html). 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.system.generated.models;
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.system.generated.models.GcType;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * GcUpdateRequest
+ */
+@JsonPropertyOrder({
+  GcUpdateRequest.JSON_PROPERTY_GC,
+  GcUpdateRequest.JSON_PROPERTY_INTERVAL_SEC,
+  GcUpdateRequest.JSON_PROPERTY_HISTORY_LENGTH,
+  GcUpdateRequest.JSON_PROPERTY_IS_PAUSED,
+  GcUpdateRequest.JSON_PROPERTY_RUN_NOW
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class GcUpdateRequest {
+  public static final String JSON_PROPERTY_GC = "gc";
+  private GcType gc;
+
+  public static final String JSON_PROPERTY_INTERVAL_SEC = "interval_sec";
+  private Long intervalSec;
+
+  public static final String JSON_PROPERTY_HISTORY_LENGTH = "history_length";
+  private Long historyLength;
+
+  public static final String JSON_PROPERTY_IS_PAUSED = "is_paused";
+  private Boolean isPaused;
+
+  public static final String JSON_PROPERTY_RUN_NOW = "run_now";
+  private Boolean runNow;
+
+
+  public GcUpdateRequest gc(GcType gc) {
+    this.gc = gc;
+    return this;
+  }
+
+   /**
+   * Get gc
+   * @return gc
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public GcType getGc() {
+    return gc;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_GC)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setGc(GcType gc) {
+    this.gc = gc;
+  }
+
+
+  public GcUpdateRequest intervalSec(Long intervalSec) {
+    this.intervalSec = intervalSec;
+    return this;
+  }
+
+   /**
+   * If present, changes the interval between periodic runs (0 means that the GC only runs on demand).
+   * @return intervalSec
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "If present, changes the interval between periodic runs (0 means that the GC only runs on demand).")
+  @JsonProperty(JSON_PROPERTY_INTERVAL_SEC)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Long getIntervalSec() {
+    return intervalSec;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_INTERVAL_SEC)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setIntervalSec(Long intervalSec) {
+    this.intervalSec = intervalSec;
+  }
+
+
+  public GcUpdateRequest historyLength(Long historyLength) {
+    this.historyLength = historyLength;
+    return this;
+  }
+
+   /**
+   * If present, changes the number of most recent state versions (for the `StateTree` GC) or completed epochs (for the `LedgerProofs` GC) which are not pruned.
+   * @return historyLength
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "If present, changes the number of most recent state versions (for the `StateTree` GC) or completed epochs (for the `LedgerProofs` GC) which are not pruned.")
+  @JsonProperty(JSON_PROPERTY_HISTORY_LENGTH)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Long getHistoryLength() {
+    return historyLength;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_HISTORY_LENGTH)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setHistoryLength(Long historyLength) {
+    this.historyLength = historyLength;
+  }
+
+
+  public GcUpdateRequest isPaused(Boolean isPaused) {
+    this.isPaused = isPaused;
+    return this;
+  }
+
+   /**
+   * If present, pauses or resumes the GC. Pausing waits for the in-progress run (if any) to finish.
+   * @return isPaused
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "If present, pauses or resumes the GC. Pausing waits for the in-progress run (if any) to finish.")
+  @JsonProperty(JSON_PROPERTY_IS_PAUSED)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Boolean getIsPaused() {
+    return isPaused;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_IS_PAUSED)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setIsPaused(Boolean isPaused) {
+    this.isPaused = isPaused;
+  }
+
+
+  public GcUpdateRequest runNow(Boolean runNow) {
+    this.runNow = runNow;
+    return this;
+  }
+
+   /**
+   * If true, requests an immediate run of the GC (which only happens when the GC is not paused).
+   * @return runNow
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "If true, requests an immediate run of the GC (which only happens when the GC is not paused).")
+  @JsonProperty(JSON_PROPERTY_RUN_NOW)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Boolean getRunNow() {
+    return runNow;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_RUN_NOW)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setRunNow(Boolean runNow) {
+    this.runNow = runNow;
+  }
+
+
+  /**
+   * Return true if this GcUpdateRequest object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    GcUpdateRequest gcUpdateRequest = (GcUpdateRequest) o;
+    return Objects.equals(this.gc, gcUpdateRequest.gc) &&
+        Objects.equals(this.intervalSec, gcUpdateRequest.intervalSec) &&
+        Objects.equals(this.historyLength, gcUpdateRequest.historyLength) &&
+        Objects.equals(this.isPaused, gcUpdateRequest.isPaused) &&
+        Objects.equals(this.runNow, gcUpdateRequest.runNow);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(gc, intervalSec, historyLength, isPaused, runNow);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class GcUpdateRequest {\n");
+    sb.append("    gc: ").append(toIndentedString(gc)).append("\n");
+    sb.append("    intervalSec: ").append(toIndentedString(intervalSec)).append("\n");
+    sb.append("    historyLength: ").append(toIndentedString(historyLength)).append("\n");
+    sb.append("    isPaused: ").append(toIndentedString(isPaused)).append("\n");
+    sb.append("    runNow: ").append(toIndentedString(runNow)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
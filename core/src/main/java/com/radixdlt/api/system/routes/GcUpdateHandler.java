@@ -0,0 +1,185 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+package com.radixdlt.api.system.routes;
+
+import com.google.inject.Inject;
+import com.radixdlt.api.common.JSON;
+import com.radixdlt.api.system.SystemAdminJsonHandler;
+import com.radixdlt.api.system.SystemApiConfig;
+import com.radixdlt.api.system.SystemModelMapper;
+import com.radixdlt.api.system.generated.models.GcStatusResponse;
+import com.radixdlt.api.system.generated.models.GcUpdateRequest;
+import com.radixdlt.db.gc.GcKind;
+import com.radixdlt.db.gc.RustGcControl;
+import com.radixdlt.environment.LedgerProofsGcConfig;
+import com.radixdlt.environment.StateTreeGcConfig;
+import com.radixdlt.utils.UInt32;
+import com.radixdlt.utils.UInt64;
+
+public final class GcUpdateHandler extends SystemAdminJsonHandler<GcStatusResponse> {
+
+  private final RustGcControl rustGcControl;
+  private final SystemModelMapper systemModelMapper;
+
+  @Inject
+  GcUpdateHandler(
+      RustGcControl rustGcControl, SystemModelMapper systemModelMapper, SystemApiConfig config) {
+    super(config);
+    this.rustGcControl = rustGcControl;
+    this.systemModelMapper = systemModelMapper;
+  }
+
+  @Override
+  protected GcStatusResponse handleAuthorizedRequest(byte[] requestBody) throws Exception {
+    final var request = JSON.getDefault().getMapper().readValue(requestBody, GcUpdateRequest.class);
+    if (request.getGc() == null) {
+      throw new IllegalArgumentException("The `gc` to update must be specified");
+    }
+    final var intervalSec =
+        request.getIntervalSec() == null ? null : toUInt32("interval_sec", request.getIntervalSec());
+    final var historyLength =
+        request.getHistoryLength() == null
+            ? null
+            : toUInt64("history_length", request.getHistoryLength());
+
+    final GcKind kind;
+    switch (request.getGc()) {
+      case STATETREE -> {
+        kind = new GcKind.StateTree();
+        if (intervalSec != null || historyLength != null) {
+          final var config = rustGcControl.getSummary().stateTreeGcConfig();
+          final var newConfig =
+              new StateTreeGcConfig(
+                  intervalSec != null ? intervalSec : config.intervalSec(),
+                  historyLength != null ? historyLength : config.stateVersionHistoryLength(),
+                  config.historicalStateFullResolutionEpochCount(),
+                  config.historicalStateFullResolutionDurationSec(),
+                  config.historicalStateCheckpointEpochInterval());
+          validate(newConfig);
+          rustGcControl.reconfigureStateTreeGc(newConfig);
+        }
+      }
+      case LEDGERPROOFS -> {
+        kind = new GcKind.LedgerProofs();
+        if (intervalSec != null || historyLength != null) {
+          final var config = rustGcControl.getSummary().ledgerProofsGcConfig();
+          rustGcControl.reconfigureLedgerProofsGc(
+              new LedgerProofsGcConfig(
+                  intervalSec != null ? intervalSec : config.intervalSec(),
+                  historyLength != null
+                      ? historyLength
+                      : config.mostRecentFullResolutionEpochCount()));
+        }
+      }
+      default -> throw new IllegalArgumentException("Unknown GC: " + request.getGc());
+    }
+
+    if (Boolean.TRUE.equals(request.getIsPaused())) {
+      rustGcControl.pause(kind);
+    } else if (Boolean.FALSE.equals(request.getIsPaused())) {
+      rustGcControl.resume(kind);
+    }
+    if (Boolean.TRUE.equals(request.getRunNow())) {
+      rustGcControl.requestRun(kind);
+    }
+
+    return systemModelMapper.gcStatusResponse(rustGcControl.getSummary());
+  }
+
+  /**
+   * Rejects a configuration which the Rust side could not represent in its internal units (i.e. a
+   * state version delta and a duration in milliseconds, both signed 64-bit), before it reaches the
+   * GC.
+   */
+  private static void validate(StateTreeGcConfig config) {
+    if (config.stateVersionHistoryLength().toLong() < 0) {
+      throw new IllegalArgumentException(
+          "The `history_length` is out of range: " + config.stateVersionHistoryLength());
+    }
+    config
+        .historicalStateFullResolutionDurationSec()
+        .ifPresent(
+            durationSec -> {
+              if (durationSec.toLong() < 0 || durationSec.toLong() > Long.MAX_VALUE / 1000) {
+                throw new IllegalArgumentException(
+                    "The configured full resolution duration is out of range: " + durationSec);
+              }
+            });
+  }
+
+  private static UInt32 toUInt32(String field, long value) {
+    if (value < 0 || value > Integer.MAX_VALUE) {
+      throw new IllegalArgumentException("The `" + field + "` is out of range: " + value);
+    }
+    return UInt32.fromNonNegativeInt((int) value);
+  }
+
+  private static UInt64 toUInt64(String field, long value) {
+    if (value < 0) {
+      throw new IllegalArgumentException("The `" + field + "` is out of range: " + value);
+    }
+    return UInt64.fromNonNegativeLong(value);
+  }
+}
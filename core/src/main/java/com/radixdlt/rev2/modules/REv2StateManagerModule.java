@@ -72,6 +72,7 @@ import com.radixdlt.consensus.bft.*;
 import com.radixdlt.consensus.vertexstore.PersistentVertexStore;
 import com.radixdlt.crypto.Hasher;
 import com.radixdlt.db.checkpoint.RustDbCheckpoints;
+import com.radixdlt.db.gc.RustGcControl;
 import com.radixdlt.environment.*;
 import com.radixdlt.environment.EventDispatcher;
 import com.radixdlt.environment.EventProcessor;
@@ -111,6 +112,8 @@ public final class REv2StateManagerModule extends AbstractModule {
   private final boolean debugLogging;
   private final StateTreeGcConfig stateTreeGcConfig;
   private final LedgerProofsGcConfig ledgerProofsGcConfig;
+  private final Option<DbCheckpointsConfig> dbCheckpointsConfigOpt;
+  private final Option<DatabaseTuningProfile> databaseTuningProfileOpt;
   private final LedgerSyncLimitsConfig ledgerSyncLimitsConfig;
   private final ProtocolConfig protocolConfig;
   private final boolean noFees;
@@ -125,6 +128,8 @@ public final class REv2StateManagerModule extends AbstractModule {
       boolean debugLogging,
       StateTreeGcConfig stateTreeGcConfig,
       LedgerProofsGcConfig ledgerProofsGcConfig,
+      Option<DbCheckpointsConfig> dbCheckpointsConfigOpt,
+      Option<DatabaseTuningProfile> databaseTuningProfileOpt,
       LedgerSyncLimitsConfig ledgerSyncLimitsConfig,
       ProtocolConfig protocolConfig,
       boolean noFees,
@@ -137,6 +142,8 @@ public final class REv2StateManagerModule extends AbstractModule {
     this.debugLogging = debugLogging;
     this.stateTreeGcConfig = stateTreeGcConfig;
     this.ledgerProofsGcConfig = ledgerProofsGcConfig;
+    this.dbCheckpointsConfigOpt = dbCheckpointsConfigOpt;
+    this.databaseTuningProfileOpt = databaseTuningProfileOpt;
     this.ledgerSyncLimitsConfig = ledgerSyncLimitsConfig;
     this.protocolConfig = protocolConfig;
     this.noFees = noFees;
@@ -151,6 +158,8 @@ public final class REv2StateManagerModule extends AbstractModule {
       Option<RustMempoolConfig> mempoolConfig,
       StateTreeGcConfig stateTreeGcConfig,
       LedgerProofsGcConfig ledgerProofsGcConfig,
+      Option<DbCheckpointsConfig> dbCheckpointsConfigOpt,
+      Option<DatabaseTuningProfile> databaseTuningProfileOpt,
       LedgerSyncLimitsConfig ledgerSyncLimitsConfig,
       ProtocolConfig protocolConfig,
       ScenariosExecutionConfig scenariosExecutionConfig) {
@@ -163,6 +172,8 @@ public final class REv2StateManagerModule extends AbstractModule {
         false,
         stateTreeGcConfig,
         ledgerProofsGcConfig,
+        dbCheckpointsConfigOpt,
+        databaseTuningProfileOpt,
         ledgerSyncLimitsConfig,
         protocolConfig,
         false,
@@ -190,6 +201,8 @@ public final class REv2StateManagerModule extends AbstractModule {
         debugLogging,
         stateTreeGcConfig,
         ledgerProofsGcConfig,
+        Option.none(),
+        Option.none(),
         ledgerSyncLimitsConfig,
         protocolConfig,
         noFees,
@@ -213,7 +226,7 @@ public final class REv2StateManagerModule extends AbstractModule {
           @NodeStorageLocation
           DatabaseBackendConfig stateManagerDatabaseBackendConfig(
               @NodeStorageLocation String nodeStorageLocation) {
-            return new DatabaseBackendConfig(nodeStorageLocation);
+            return new DatabaseBackendConfig(nodeStorageLocation, databaseTuningProfileOpt);
           }
 
           @Provides
@@ -237,6 +250,7 @@ public final class REv2StateManagerModule extends AbstractModule {
                     getLoggingConfig(),
                     stateTreeGcConfig,
                     ledgerProofsGcConfig,
+                    dbCheckpointsConfigOpt,
                     ledgerSyncLimitsConfig,
                     protocolConfig,
                     noFees,
@@ -403,4 +417,10 @@ public final class REv2StateManagerModule extends AbstractModule {
       Metrics metrics, NodeRustEnvironment nodeRustEnvironment) {
     return new RustDbCheckpoints(metrics, nodeRustEnvironment);
   }
+
+  @Provides
+  @Singleton
+  private RustGcControl rustGcControl(Metrics metrics, NodeRustEnvironment nodeRustEnvironment) {
+    return new RustGcControl(metrics, nodeRustEnvironment);
+  }
 }
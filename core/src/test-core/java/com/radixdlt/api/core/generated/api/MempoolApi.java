@@ -18,6 +18,8 @@ import com.radixdlt.api.core.generated.client.ApiResponse;
 import com.radixdlt.api.core.generated.client.Pair;
 
 import com.radixdlt.api.core.generated.models.BasicErrorResponse;
+import com.radixdlt.api.core.generated.models.MempoolFeeEstimateRequest;
+import com.radixdlt.api.core.generated.models.MempoolFeeEstimateResponse;
 import com.radixdlt.api.core.generated.models.MempoolListRequest;
 import com.radixdlt.api.core.generated.models.MempoolListResponse;
 import com.radixdlt.api.core.generated.models.MempoolTransactionRequest;
@@ -78,6 +80,84 @@ public class MempoolApi {
     return operationId + " call failed with: " + statusCode + " - " + body;
   }
 
+  /**
+   * Get Mempool Fee Estimate
+   * Returns the tips suggested for getting a transaction proposed within the given numbers of proposals, based on the current contents of the mempool, together with statistics of the tips of recently committed user transactions. 
+   * @param mempoolFeeEstimateRequest  (required)
+   * @return MempoolFeeEstimateResponse
+   * @throws ApiException if fails to make API call
+   */
+  public MempoolFeeEstimateResponse mempoolFeeEstimatePost(MempoolFeeEstimateRequest mempoolFeeEstimateRequest) throws ApiException {
+    ApiResponse<MempoolFeeEstimateResponse> localVarResponse = mempoolFeeEstimatePostWithHttpInfo(mempoolFeeEstimateRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Mempool Fee Estimate
+   * Returns the tips suggested for getting a transaction proposed within the given numbers of proposals, based on the current contents of the mempool, together with statistics of the tips of recently committed user transactions. 
+   * @param mempoolFeeEstimateRequest  (required)
+   * @return ApiResponse&lt;MempoolFeeEstimateResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<MempoolFeeEstimateResponse> mempoolFeeEstimatePostWithHttpInfo(MempoolFeeEstimateRequest mempoolFeeEstimateRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = mempoolFeeEstimatePostRequestBuilder(mempoolFeeEstimateRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("mempoolFeeEstimatePost", localVarResponse);
+        }
+        return new ApiResponse<MempoolFeeEstimateResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<MempoolFeeEstimateResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder mempoolFeeEstimatePostRequestBuilder(MempoolFeeEstimateRequest mempoolFeeEstimateRequest) throws ApiException {
+    // verify the required parameter 'mempoolFeeEstimateRequest' is set
+    if (mempoolFeeEstimateRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'mempoolFeeEstimateRequest' when calling mempoolFeeEstimatePost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/mempool/fee-estimate";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(mempoolFeeEstimateRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
   /**
    * Get Mempool List
    * Returns the hashes of all the transactions currently in the mempool
@@ -32,6 +32,8 @@ import com.radixdlt.api.core.generated.models.StatePackageRequest;
 import com.radixdlt.api.core.generated.models.StatePackageResponse;
 import com.radixdlt.api.core.generated.models.StateResourceRequest;
 import com.radixdlt.api.core.generated.models.StateResourceResponse;
+import com.radixdlt.api.core.generated.models.StateSubstateProofRequest;
+import com.radixdlt.api.core.generated.models.StateSubstateProofResponse;
 import com.radixdlt.api.core.generated.models.StateValidatorRequest;
 import com.radixdlt.api.core.generated.models.StateValidatorResponse;
 
@@ -636,6 +638,84 @@ public class StateApi {
     }
     return localVarRequestBuilder;
   }
+  /**
+   * Get Substate Inclusion Proof
+   * Returns a Merkle proof of the given Substate&#39;s value being included in the state tree, under the `state_root` of a validator-signed ledger proof. The proof can only be generated at state versions still retained in the node&#39;s state tree history (see `state_hash_tree.state_version_history_length`). A proof at a past state version additionally requires the state history feature (see `db.historical_substate_values.enable`). 
+   * @param stateSubstateProofRequest  (required)
+   * @return StateSubstateProofResponse
+   * @throws ApiException if fails to make API call
+   */
+  public StateSubstateProofResponse stateSubstateProofPost(StateSubstateProofRequest stateSubstateProofRequest) throws ApiException {
+    ApiResponse<StateSubstateProofResponse> localVarResponse = stateSubstateProofPostWithHttpInfo(stateSubstateProofRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Substate Inclusion Proof
+   * Returns a Merkle proof of the given Substate&#39;s value being included in the state tree, under the `state_root` of a validator-signed ledger proof. The proof can only be generated at state versions still retained in the node&#39;s state tree history (see `state_hash_tree.state_version_history_length`). A proof at a past state version additionally requires the state history feature (see `db.historical_substate_values.enable`). 
+   * @param stateSubstateProofRequest  (required)
+   * @return ApiResponse&lt;StateSubstateProofResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<StateSubstateProofResponse> stateSubstateProofPostWithHttpInfo(StateSubstateProofRequest stateSubstateProofRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = stateSubstateProofPostRequestBuilder(stateSubstateProofRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("stateSubstateProofPost", localVarResponse);
+        }
+        return new ApiResponse<StateSubstateProofResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<StateSubstateProofResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder stateSubstateProofPostRequestBuilder(StateSubstateProofRequest stateSubstateProofRequest) throws ApiException {
+    // verify the required parameter 'stateSubstateProofRequest' is set
+    if (stateSubstateProofRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'stateSubstateProofRequest' when calling stateSubstateProofPost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/state/substate-proof";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(stateSubstateProofRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
   /**
    * Get Validator Details
    * Reads the validator&#39;s substate/s from the top of the current ledger. 
@@ -17,9 +17,14 @@ import com.radixdlt.api.core.generated.client.ApiException;
 import com.radixdlt.api.core.generated.client.ApiResponse;
 import com.radixdlt.api.core.generated.client.Pair;
 
+import com.radixdlt.api.core.generated.models.BasicErrorResponse;
 import com.radixdlt.api.core.generated.models.StreamProofsErrorResponse;
 import com.radixdlt.api.core.generated.models.StreamProofsRequest;
 import com.radixdlt.api.core.generated.models.StreamProofsResponse;
+import com.radixdlt.api.core.generated.models.StreamResourceTransactionOutcomesRequest;
+import com.radixdlt.api.core.generated.models.StreamResourceTransactionOutcomesResponse;
+import com.radixdlt.api.core.generated.models.StreamSecondaryIndexTransactionOutcomesRequest;
+import com.radixdlt.api.core.generated.models.StreamSecondaryIndexTransactionOutcomesResponse;
 import com.radixdlt.api.core.generated.models.StreamTransactionsErrorResponse;
 import com.radixdlt.api.core.generated.models.StreamTransactionsRequest;
 import com.radixdlt.api.core.generated.models.StreamTransactionsResponse;
@@ -157,6 +162,162 @@ public class StreamApi {
     }
     return localVarRequestBuilder;
   }
+  /**
+   * Get Resource Transaction Outcomes
+   * Returns a list of committed transaction outcomes (containing balance changes) from a given state version, filtered to only transactions which changed any global entity&#39;s balance of the given resource. Requires the resource change index to be enabled on the node. 
+   * @param streamResourceTransactionOutcomesRequest  (required)
+   * @return StreamResourceTransactionOutcomesResponse
+   * @throws ApiException if fails to make API call
+   */
+  public StreamResourceTransactionOutcomesResponse streamResourceTransactionOutcomesPost(StreamResourceTransactionOutcomesRequest streamResourceTransactionOutcomesRequest) throws ApiException {
+    ApiResponse<StreamResourceTransactionOutcomesResponse> localVarResponse = streamResourceTransactionOutcomesPostWithHttpInfo(streamResourceTransactionOutcomesRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Resource Transaction Outcomes
+   * Returns a list of committed transaction outcomes (containing balance changes) from a given state version, filtered to only transactions which changed any global entity&#39;s balance of the given resource. Requires the resource change index to be enabled on the node. 
+   * @param streamResourceTransactionOutcomesRequest  (required)
+   * @return ApiResponse&lt;StreamResourceTransactionOutcomesResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<StreamResourceTransactionOutcomesResponse> streamResourceTransactionOutcomesPostWithHttpInfo(StreamResourceTransactionOutcomesRequest streamResourceTransactionOutcomesRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = streamResourceTransactionOutcomesPostRequestBuilder(streamResourceTransactionOutcomesRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("streamResourceTransactionOutcomesPost", localVarResponse);
+        }
+        return new ApiResponse<StreamResourceTransactionOutcomesResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<StreamResourceTransactionOutcomesResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder streamResourceTransactionOutcomesPostRequestBuilder(StreamResourceTransactionOutcomesRequest streamResourceTransactionOutcomesRequest) throws ApiException {
+    // verify the required parameter 'streamResourceTransactionOutcomesRequest' is set
+    if (streamResourceTransactionOutcomesRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'streamResourceTransactionOutcomesRequest' when calling streamResourceTransactionOutcomesPost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/stream/resource-transaction-outcomes";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(streamResourceTransactionOutcomesRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
+  /**
+   * Get Secondary Index Transaction Outcomes
+   * Returns a list of committed transaction outcomes (containing balance changes) from a given state version, filtered to only transactions indexed under the given key by the given secondary index. Requires the secondary index to be enabled on the node. 
+   * @param streamSecondaryIndexTransactionOutcomesRequest  (required)
+   * @return StreamSecondaryIndexTransactionOutcomesResponse
+   * @throws ApiException if fails to make API call
+   */
+  public StreamSecondaryIndexTransactionOutcomesResponse streamSecondaryIndexTransactionOutcomesPost(StreamSecondaryIndexTransactionOutcomesRequest streamSecondaryIndexTransactionOutcomesRequest) throws ApiException {
+    ApiResponse<StreamSecondaryIndexTransactionOutcomesResponse> localVarResponse = streamSecondaryIndexTransactionOutcomesPostWithHttpInfo(streamSecondaryIndexTransactionOutcomesRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Secondary Index Transaction Outcomes
+   * Returns a list of committed transaction outcomes (containing balance changes) from a given state version, filtered to only transactions indexed under the given key by the given secondary index. Requires the secondary index to be enabled on the node. 
+   * @param streamSecondaryIndexTransactionOutcomesRequest  (required)
+   * @return ApiResponse&lt;StreamSecondaryIndexTransactionOutcomesResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<StreamSecondaryIndexTransactionOutcomesResponse> streamSecondaryIndexTransactionOutcomesPostWithHttpInfo(StreamSecondaryIndexTransactionOutcomesRequest streamSecondaryIndexTransactionOutcomesRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = streamSecondaryIndexTransactionOutcomesPostRequestBuilder(streamSecondaryIndexTransactionOutcomesRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("streamSecondaryIndexTransactionOutcomesPost", localVarResponse);
+        }
+        return new ApiResponse<StreamSecondaryIndexTransactionOutcomesResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<StreamSecondaryIndexTransactionOutcomesResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder streamSecondaryIndexTransactionOutcomesPostRequestBuilder(StreamSecondaryIndexTransactionOutcomesRequest streamSecondaryIndexTransactionOutcomesRequest) throws ApiException {
+    // verify the required parameter 'streamSecondaryIndexTransactionOutcomesRequest' is set
+    if (streamSecondaryIndexTransactionOutcomesRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'streamSecondaryIndexTransactionOutcomesRequest' when calling streamSecondaryIndexTransactionOutcomesPost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/stream/secondary-index-transaction-outcomes";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(streamSecondaryIndexTransactionOutcomesRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
   /**
    * Get Committed Transactions
    * Returns the list of committed transactions. 
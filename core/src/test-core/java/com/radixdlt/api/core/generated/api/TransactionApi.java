@@ -20,6 +20,10 @@ import com.radixdlt.api.core.generated.client.Pair;
 import com.radixdlt.api.core.generated.models.BasicErrorResponse;
 import com.radixdlt.api.core.generated.models.TransactionCallPreviewRequest;
 import com.radixdlt.api.core.generated.models.TransactionCallPreviewResponse;
+import com.radixdlt.api.core.generated.models.TransactionConsistencyProofRequest;
+import com.radixdlt.api.core.generated.models.TransactionConsistencyProofResponse;
+import com.radixdlt.api.core.generated.models.TransactionInclusionProofRequest;
+import com.radixdlt.api.core.generated.models.TransactionInclusionProofResponse;
 import com.radixdlt.api.core.generated.models.TransactionParseRequest;
 import com.radixdlt.api.core.generated.models.TransactionParseResponse;
 import com.radixdlt.api.core.generated.models.TransactionPreviewRequest;
@@ -168,6 +172,162 @@ public class TransactionApi {
     }
     return localVarRequestBuilder;
   }
+  /**
+   * Get Transaction Accumulators Consistency Proof
+   * Returns Merkle proofs of the transaction and receipt accumulator trees at an older ledger proof being prefixes of the respective trees at a newer ledger proof (i.e. of the newer trees only appending to the older ones). Both ledger proofs must come from the same epoch. 
+   * @param transactionConsistencyProofRequest  (required)
+   * @return TransactionConsistencyProofResponse
+   * @throws ApiException if fails to make API call
+   */
+  public TransactionConsistencyProofResponse transactionConsistencyProofPost(TransactionConsistencyProofRequest transactionConsistencyProofRequest) throws ApiException {
+    ApiResponse<TransactionConsistencyProofResponse> localVarResponse = transactionConsistencyProofPostWithHttpInfo(transactionConsistencyProofRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Transaction Accumulators Consistency Proof
+   * Returns Merkle proofs of the transaction and receipt accumulator trees at an older ledger proof being prefixes of the respective trees at a newer ledger proof (i.e. of the newer trees only appending to the older ones). Both ledger proofs must come from the same epoch. 
+   * @param transactionConsistencyProofRequest  (required)
+   * @return ApiResponse&lt;TransactionConsistencyProofResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<TransactionConsistencyProofResponse> transactionConsistencyProofPostWithHttpInfo(TransactionConsistencyProofRequest transactionConsistencyProofRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = transactionConsistencyProofPostRequestBuilder(transactionConsistencyProofRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("transactionConsistencyProofPost", localVarResponse);
+        }
+        return new ApiResponse<TransactionConsistencyProofResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<TransactionConsistencyProofResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder transactionConsistencyProofPostRequestBuilder(TransactionConsistencyProofRequest transactionConsistencyProofRequest) throws ApiException {
+    // verify the required parameter 'transactionConsistencyProofRequest' is set
+    if (transactionConsistencyProofRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'transactionConsistencyProofRequest' when calling transactionConsistencyProofPost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/transaction/consistency-proof";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(transactionConsistencyProofRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
+  /**
+   * Get Transaction Inclusion Proof
+   * Returns Merkle proofs of a committed transaction being included in the transaction and receipt accumulator trees, under the `transaction_root` and `receipt_root` of a validator-signed ledger proof. Each epoch has its own pair of accumulator trees, so the ledger proof must come from the transaction&#39;s epoch. 
+   * @param transactionInclusionProofRequest  (required)
+   * @return TransactionInclusionProofResponse
+   * @throws ApiException if fails to make API call
+   */
+  public TransactionInclusionProofResponse transactionInclusionProofPost(TransactionInclusionProofRequest transactionInclusionProofRequest) throws ApiException {
+    ApiResponse<TransactionInclusionProofResponse> localVarResponse = transactionInclusionProofPostWithHttpInfo(transactionInclusionProofRequest);
+    return localVarResponse.getData();
+  }
+
+  /**
+   * Get Transaction Inclusion Proof
+   * Returns Merkle proofs of a committed transaction being included in the transaction and receipt accumulator trees, under the `transaction_root` and `receipt_root` of a validator-signed ledger proof. Each epoch has its own pair of accumulator trees, so the ledger proof must come from the transaction&#39;s epoch. 
+   * @param transactionInclusionProofRequest  (required)
+   * @return ApiResponse&lt;TransactionInclusionProofResponse&gt;
+   * @throws ApiException if fails to make API call
+   */
+  public ApiResponse<TransactionInclusionProofResponse> transactionInclusionProofPostWithHttpInfo(TransactionInclusionProofRequest transactionInclusionProofRequest) throws ApiException {
+    HttpRequest.Builder localVarRequestBuilder = transactionInclusionProofPostRequestBuilder(transactionInclusionProofRequest);
+    try {
+      HttpResponse<InputStream> localVarResponse = memberVarHttpClient.send(
+          localVarRequestBuilder.build(),
+          HttpResponse.BodyHandlers.ofInputStream());
+      if (memberVarResponseInterceptor != null) {
+        memberVarResponseInterceptor.accept(localVarResponse);
+      }
+      try {
+        if (localVarResponse.statusCode()/ 100 != 2) {
+          throw getApiException("transactionInclusionProofPost", localVarResponse);
+        }
+        return new ApiResponse<TransactionInclusionProofResponse>(
+          localVarResponse.statusCode(),
+          localVarResponse.headers().map(),
+          memberVarObjectMapper.readValue(localVarResponse.body(), new TypeReference<TransactionInclusionProofResponse>() {}) // closes the InputStream
+          
+        );
+      } finally {
+      }
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    catch (InterruptedException e) {
+      Thread.currentThread().interrupt();
+      throw new ApiException(e);
+    }
+  }
+
+  private HttpRequest.Builder transactionInclusionProofPostRequestBuilder(TransactionInclusionProofRequest transactionInclusionProofRequest) throws ApiException {
+    // verify the required parameter 'transactionInclusionProofRequest' is set
+    if (transactionInclusionProofRequest == null) {
+      throw new ApiException(400, "Missing the required parameter 'transactionInclusionProofRequest' when calling transactionInclusionProofPost");
+    }
+
+    HttpRequest.Builder localVarRequestBuilder = HttpRequest.newBuilder();
+
+    String localVarPath = "/transaction/inclusion-proof";
+
+    localVarRequestBuilder.uri(URI.create(memberVarBaseUri + localVarPath));
+
+    localVarRequestBuilder.header("Content-Type", "application/json");
+    localVarRequestBuilder.header("Accept", "application/json");
+
+    try {
+      byte[] localVarPostBody = memberVarObjectMapper.writeValueAsBytes(transactionInclusionProofRequest);
+      localVarRequestBuilder.method("POST", HttpRequest.BodyPublishers.ofByteArray(localVarPostBody));
+    } catch (IOException e) {
+      throw new ApiException(e);
+    }
+    if (memberVarReadTimeout != null) {
+      localVarRequestBuilder.timeout(memberVarReadTimeout);
+    }
+    if (memberVarInterceptor != null) {
+      memberVarInterceptor.accept(localVarRequestBuilder);
+    }
+    return localVarRequestBuilder;
+  }
   /**
    * Parse Transaction Payload
    * Extracts the contents and hashes of various types of transaction payloads, or sub-payloads.
@@ -0,0 +1,146 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.AccumulatorTreeInclusionProof;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * A Merkle proof of an older accumulator tree being a prefix of a newer one. It consists of the older tree&#39;s last leaf, together with its inclusion proof in the newer tree. The newer root is verified as usual. The older root is computed from the same path, using only as many siblings as the older tree&#39;s height, and replacing each right sibling (i.e. a sibling of a node at an even index) with 32 zero bytes. 
+ */
+@ApiModel(description = "A Merkle proof of an older accumulator tree being a prefix of a newer one. It consists of the older tree's last leaf, together with its inclusion proof in the newer tree. The newer root is verified as usual. The older root is computed from the same path, using only as many siblings as the older tree's height, and replacing each right sibling (i.e. a sibling of a node at an even index) with 32 zero bytes. ")
+@JsonPropertyOrder({
+  AccumulatorTreeConsistencyProof.JSON_PROPERTY_OLD_TREE_SIZE,
+  AccumulatorTreeConsistencyProof.JSON_PROPERTY_OLD_LAST_LEAF_PROOF
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class AccumulatorTreeConsistencyProof {
+  public static final String JSON_PROPERTY_OLD_TREE_SIZE = "old_tree_size";
+  private Long oldTreeSize;
+
+  public static final String JSON_PROPERTY_OLD_LAST_LEAF_PROOF = "old_last_leaf_proof";
+  private AccumulatorTreeInclusionProof oldLastLeafProof;
+
+  public AccumulatorTreeConsistencyProof() { 
+  }
+
+  public AccumulatorTreeConsistencyProof oldTreeSize(Long oldTreeSize) {
+    this.oldTreeSize = oldTreeSize;
+    return this;
+  }
+
+   /**
+   * The number of leaves in the older tree.
+   * minimum: 1
+   * @return oldTreeSize
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The number of leaves in the older tree.")
+  @JsonProperty(JSON_PROPERTY_OLD_TREE_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getOldTreeSize() {
+    return oldTreeSize;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_OLD_TREE_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setOldTreeSize(Long oldTreeSize) {
+    this.oldTreeSize = oldTreeSize;
+  }
+
+
+  public AccumulatorTreeConsistencyProof oldLastLeafProof(AccumulatorTreeInclusionProof oldLastLeafProof) {
+    this.oldLastLeafProof = oldLastLeafProof;
+    return this;
+  }
+
+   /**
+   * Get oldLastLeafProof
+   * @return oldLastLeafProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_OLD_LAST_LEAF_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public AccumulatorTreeInclusionProof getOldLastLeafProof() {
+    return oldLastLeafProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_OLD_LAST_LEAF_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setOldLastLeafProof(AccumulatorTreeInclusionProof oldLastLeafProof) {
+    this.oldLastLeafProof = oldLastLeafProof;
+  }
+
+
+  /**
+   * Return true if this AccumulatorTreeConsistencyProof object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    AccumulatorTreeConsistencyProof accumulatorTreeConsistencyProof = (AccumulatorTreeConsistencyProof) o;
+    return Objects.equals(this.oldTreeSize, accumulatorTreeConsistencyProof.oldTreeSize) &&
+        Objects.equals(this.oldLastLeafProof, accumulatorTreeConsistencyProof.oldLastLeafProof);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(oldTreeSize, oldLastLeafProof);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class AccumulatorTreeConsistencyProof {\n");
+    sb.append("    oldTreeSize: ").append(toIndentedString(oldTreeSize)).append("\n");
+    sb.append("    oldLastLeafProof: ").append(toIndentedString(oldLastLeafProof)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
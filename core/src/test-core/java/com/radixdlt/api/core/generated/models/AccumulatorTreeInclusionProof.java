@@ -0,0 +1,217 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import java.util.ArrayList;
+import java.util.List;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * A Merkle proof of a leaf&#39;s inclusion in an epoch&#39;s accumulator tree. The leaf at index 0 holds the previous epoch&#39;s root, and the leaf at index &#x60;N&#x60; belongs to the &#x60;N&#x60;-th transaction of the epoch. The proof is verified by hashing the leaf with each sibling, from the leaves up: a node at an even index is the left operand of &#x60;blake2b_256(left ++ right)&#x60;, and a node at an odd index is the right operand. A sibling which does not exist in the tree is represented by 32 zero bytes. 
+ */
+@ApiModel(description = "A Merkle proof of a leaf's inclusion in an epoch's accumulator tree. The leaf at index 0 holds the previous epoch's root, and the leaf at index `N` belongs to the `N`-th transaction of the epoch. The proof is verified by hashing the leaf with each sibling, from the leaves up: a node at an even index is the left operand of `blake2b_256(left ++ right)`, and a node at an odd index is the right operand. A sibling which does not exist in the tree is represented by 32 zero bytes. ")
+@JsonPropertyOrder({
+  AccumulatorTreeInclusionProof.JSON_PROPERTY_LEAF_INDEX,
+  AccumulatorTreeInclusionProof.JSON_PROPERTY_TREE_SIZE,
+  AccumulatorTreeInclusionProof.JSON_PROPERTY_LEAF_HASH,
+  AccumulatorTreeInclusionProof.JSON_PROPERTY_SIBLING_HASHES
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class AccumulatorTreeInclusionProof {
+  public static final String JSON_PROPERTY_LEAF_INDEX = "leaf_index";
+  private Long leafIndex;
+
+  public static final String JSON_PROPERTY_TREE_SIZE = "tree_size";
+  private Long treeSize;
+
+  public static final String JSON_PROPERTY_LEAF_HASH = "leaf_hash";
+  private String leafHash;
+
+  public static final String JSON_PROPERTY_SIBLING_HASHES = "sibling_hashes";
+  private List<String> siblingHashes = new ArrayList<>();
+
+  public AccumulatorTreeInclusionProof() { 
+  }
+
+  public AccumulatorTreeInclusionProof leafIndex(Long leafIndex) {
+    this.leafIndex = leafIndex;
+    return this;
+  }
+
+   /**
+   * Get leafIndex
+   * minimum: 0
+   * @return leafIndex
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_LEAF_INDEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getLeafIndex() {
+    return leafIndex;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEAF_INDEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLeafIndex(Long leafIndex) {
+    this.leafIndex = leafIndex;
+  }
+
+
+  public AccumulatorTreeInclusionProof treeSize(Long treeSize) {
+    this.treeSize = treeSize;
+    return this;
+  }
+
+   /**
+   * The number of leaves in the tree under the proven root.
+   * minimum: 1
+   * @return treeSize
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The number of leaves in the tree under the proven root.")
+  @JsonProperty(JSON_PROPERTY_TREE_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getTreeSize() {
+    return treeSize;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TREE_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTreeSize(Long treeSize) {
+    this.treeSize = treeSize;
+  }
+
+
+  public AccumulatorTreeInclusionProof leafHash(String leafHash) {
+    this.leafHash = leafHash;
+    return this;
+  }
+
+   /**
+   * The hex-encoded proven leaf hash.
+   * @return leafHash
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded proven leaf hash.")
+  @JsonProperty(JSON_PROPERTY_LEAF_HASH)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getLeafHash() {
+    return leafHash;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEAF_HASH)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLeafHash(String leafHash) {
+    this.leafHash = leafHash;
+  }
+
+
+  public AccumulatorTreeInclusionProof siblingHashes(List<String> siblingHashes) {
+    this.siblingHashes = siblingHashes;
+    return this;
+  }
+
+  public AccumulatorTreeInclusionProof addSiblingHashesItem(String siblingHashesItem) {
+    this.siblingHashes.add(siblingHashesItem);
+    return this;
+  }
+
+   /**
+   * The hex-encoded hashes of the siblings on the path from the leaf to the root, from the leaves up.
+   * @return siblingHashes
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded hashes of the siblings on the path from the leaf to the root, from the leaves up.")
+  @JsonProperty(JSON_PROPERTY_SIBLING_HASHES)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public List<String> getSiblingHashes() {
+    return siblingHashes;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_SIBLING_HASHES)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setSiblingHashes(List<String> siblingHashes) {
+    this.siblingHashes = siblingHashes;
+  }
+
+
+  /**
+   * Return true if this AccumulatorTreeInclusionProof object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    AccumulatorTreeInclusionProof accumulatorTreeInclusionProof = (AccumulatorTreeInclusionProof) o;
+    return Objects.equals(this.leafIndex, accumulatorTreeInclusionProof.leafIndex) &&
+        Objects.equals(this.treeSize, accumulatorTreeInclusionProof.treeSize) &&
+        Objects.equals(this.leafHash, accumulatorTreeInclusionProof.leafHash) &&
+        Objects.equals(this.siblingHashes, accumulatorTreeInclusionProof.siblingHashes);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(leafIndex, treeSize, leafHash, siblingHashes);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class AccumulatorTreeInclusionProof {\n");
+    sb.append("    leafIndex: ").append(toIndentedString(leafIndex)).append("\n");
+    sb.append("    treeSize: ").append(toIndentedString(treeSize)).append("\n");
+    sb.append("    leafHash: ").append(toIndentedString(leafHash)).append("\n");
+    sb.append("    siblingHashes: ").append(toIndentedString(siblingHashes)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
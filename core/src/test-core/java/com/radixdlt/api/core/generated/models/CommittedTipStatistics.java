@@ -0,0 +1,208 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * Statistics of the tips of recently committed user transactions. Absent if none were committed recently.
+ */
+@ApiModel(description = "Statistics of the tips of recently committed user transactions. Absent if none were committed recently.")
+@JsonPropertyOrder({
+  CommittedTipStatistics.JSON_PROPERTY_USER_TRANSACTION_COUNT,
+  CommittedTipStatistics.JSON_PROPERTY_MEDIAN_TIP_PROPORTION,
+  CommittedTipStatistics.JSON_PROPERTY_P90_TIP_PROPORTION,
+  CommittedTipStatistics.JSON_PROPERTY_MAX_TIP_PROPORTION
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class CommittedTipStatistics {
+  public static final String JSON_PROPERTY_USER_TRANSACTION_COUNT = "user_transaction_count";
+  private Long userTransactionCount;
+
+  public static final String JSON_PROPERTY_MEDIAN_TIP_PROPORTION = "median_tip_proportion";
+  private String medianTipProportion;
+
+  public static final String JSON_PROPERTY_P90_TIP_PROPORTION = "p90_tip_proportion";
+  private String p90TipProportion;
+
+  public static final String JSON_PROPERTY_MAX_TIP_PROPORTION = "max_tip_proportion";
+  private String maxTipProportion;
+
+  public CommittedTipStatistics() { 
+  }
+
+  public CommittedTipStatistics userTransactionCount(Long userTransactionCount) {
+    this.userTransactionCount = userTransactionCount;
+    return this;
+  }
+
+   /**
+   * The number of the recently committed user transactions which the statistics are based on.
+   * @return userTransactionCount
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The number of the recently committed user transactions which the statistics are based on.")
+  @JsonProperty(JSON_PROPERTY_USER_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getUserTransactionCount() {
+    return userTransactionCount;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_USER_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setUserTransactionCount(Long userTransactionCount) {
+    this.userTransactionCount = userTransactionCount;
+  }
+
+
+  public CommittedTipStatistics medianTipProportion(String medianTipProportion) {
+    this.medianTipProportion = medianTipProportion;
+    return this;
+  }
+
+   /**
+   * The string-encoded decimal median tip proportion.
+   * @return medianTipProportion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The string-encoded decimal median tip proportion.")
+  @JsonProperty(JSON_PROPERTY_MEDIAN_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getMedianTipProportion() {
+    return medianTipProportion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MEDIAN_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMedianTipProportion(String medianTipProportion) {
+    this.medianTipProportion = medianTipProportion;
+  }
+
+
+  public CommittedTipStatistics p90TipProportion(String p90TipProportion) {
+    this.p90TipProportion = p90TipProportion;
+    return this;
+  }
+
+   /**
+   * The string-encoded decimal 90th percentile of the tip proportions.
+   * @return p90TipProportion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The string-encoded decimal 90th percentile of the tip proportions.")
+  @JsonProperty(JSON_PROPERTY_P90_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getP90TipProportion() {
+    return p90TipProportion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_P90_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setP90TipProportion(String p90TipProportion) {
+    this.p90TipProportion = p90TipProportion;
+  }
+
+
+  public CommittedTipStatistics maxTipProportion(String maxTipProportion) {
+    this.maxTipProportion = maxTipProportion;
+    return this;
+  }
+
+   /**
+   * The string-encoded decimal maximum tip proportion.
+   * @return maxTipProportion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The string-encoded decimal maximum tip proportion.")
+  @JsonProperty(JSON_PROPERTY_MAX_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getMaxTipProportion() {
+    return maxTipProportion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MAX_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMaxTipProportion(String maxTipProportion) {
+    this.maxTipProportion = maxTipProportion;
+  }
+
+
+  /**
+   * Return true if this CommittedTipStatistics object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    CommittedTipStatistics committedTipStatistics = (CommittedTipStatistics) o;
+    return Objects.equals(this.userTransactionCount, committedTipStatistics.userTransactionCount) &&
+        Objects.equals(this.medianTipProportion, committedTipStatistics.medianTipProportion) &&
+        Objects.equals(this.p90TipProportion, committedTipStatistics.p90TipProportion) &&
+        Objects.equals(this.maxTipProportion, committedTipStatistics.maxTipProportion);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(userTransactionCount, medianTipProportion, p90TipProportion, maxTipProportion);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class CommittedTipStatistics {\n");
+    sb.append("    userTransactionCount: ").append(toIndentedString(userTransactionCount)).append("\n");
+    sb.append("    medianTipProportion: ").append(toIndentedString(medianTipProportion)).append("\n");
+    sb.append("    p90TipProportion: ").append(toIndentedString(p90TipProportion)).append("\n");
+    sb.append("    maxTipProportion: ").append(toIndentedString(maxTipProportion)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -26,9 +26,12 @@ import com.fasterxml.jackson.annotation.JsonTypeInfo;
 import com.fasterxml.jackson.annotation.JsonTypeName;
 import com.fasterxml.jackson.annotation.JsonValue;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitErrorDetailsType;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitFeePayerQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitIntentAlreadyCommitted;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitNotaryQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitPriorityThresholdNotMetErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitRejectedErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitReplacementTipTooLowErrorDetails;
 import io.swagger.annotations.ApiModel;
 import io.swagger.annotations.ApiModelProperty;
 import com.fasterxml.jackson.annotation.JsonPropertyOrder;
@@ -48,12 +51,18 @@ import com.radixdlt.api.core.generated.client.JSON;
 )
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type", visible = true)
 @JsonSubTypes({
+  @JsonSubTypes.Type(value = LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "FeePayerQuotaExceeded"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitIntentAlreadyCommitted.class, name = "IntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "NotaryQuotaExceeded"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitReplacementTipTooLowErrorDetails.class, name = "ReplacementTipTooLow"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "LtsTransactionSubmitFeePayerQuotaExceededErrorDetails"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitIntentAlreadyCommitted.class, name = "LtsTransactionSubmitIntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "LtsTransactionSubmitNotaryQuotaExceededErrorDetails"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "LtsTransactionSubmitPriorityThresholdNotMetErrorDetails"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitRejectedErrorDetails.class, name = "LtsTransactionSubmitRejectedErrorDetails"),
-  @JsonSubTypes.Type(value = LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
-  @JsonSubTypes.Type(value = LtsTransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitReplacementTipTooLowErrorDetails.class, name = "LtsTransactionSubmitReplacementTipTooLowErrorDetails"),
 })
 
 public class LtsTransactionSubmitErrorDetails {
@@ -132,12 +141,18 @@ public class LtsTransactionSubmitErrorDetails {
 static {
   // Initialize and register the discriminator mappings.
   Map<String, Class<?>> mappings = new HashMap<String, Class<?>>();
+  mappings.put("FeePayerQuotaExceeded", LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("IntentAlreadyCommitted", LtsTransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("NotaryQuotaExceeded", LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class);
+  mappings.put("PriorityThresholdNotMet", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
+  mappings.put("Rejected", LtsTransactionSubmitRejectedErrorDetails.class);
+  mappings.put("ReplacementTipTooLow", LtsTransactionSubmitReplacementTipTooLowErrorDetails.class);
+  mappings.put("LtsTransactionSubmitFeePayerQuotaExceededErrorDetails", LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("LtsTransactionSubmitIntentAlreadyCommitted", LtsTransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("LtsTransactionSubmitNotaryQuotaExceededErrorDetails", LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class);
   mappings.put("LtsTransactionSubmitPriorityThresholdNotMetErrorDetails", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   mappings.put("LtsTransactionSubmitRejectedErrorDetails", LtsTransactionSubmitRejectedErrorDetails.class);
-  mappings.put("PriorityThresholdNotMet", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
-  mappings.put("Rejected", LtsTransactionSubmitRejectedErrorDetails.class);
+  mappings.put("LtsTransactionSubmitReplacementTipTooLowErrorDetails", LtsTransactionSubmitReplacementTipTooLowErrorDetails.class);
   mappings.put("LtsTransactionSubmitErrorDetails", LtsTransactionSubmitErrorDetails.class);
   JSON.registerDiscriminator(LtsTransactionSubmitErrorDetails.class, "type", mappings);
 }
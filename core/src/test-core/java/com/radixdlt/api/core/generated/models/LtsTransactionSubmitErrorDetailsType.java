@@ -32,7 +32,13 @@ public enum LtsTransactionSubmitErrorDetailsType {
   
   INTENTALREADYCOMMITTED("IntentAlreadyCommitted"),
   
-  REJECTED("Rejected");
+  REJECTED("Rejected"),
+  
+  REPLACEMENTTIPTOOLOW("ReplacementTipTooLow"),
+  
+  FEEPAYERQUOTAEXCEEDED("FeePayerQuotaExceeded"),
+  
+  NOTARYQUOTAEXCEEDED("NotaryQuotaExceeded");
 
   private String value;
 
@@ -0,0 +1,216 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitErrorDetailsType;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitFeePayerQuotaExceededErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitIntentAlreadyCommitted;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitNotaryQuotaExceededErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitNotaryQuotaExceededErrorDetailsAllOf;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitPriorityThresholdNotMetErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitRejectedErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitReplacementTipTooLowErrorDetails;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+import com.radixdlt.api.core.generated.client.JSON;
+/**
+ * LtsTransactionSubmitNotaryQuotaExceededErrorDetails
+ */
+@JsonPropertyOrder({
+  LtsTransactionSubmitNotaryQuotaExceededErrorDetails.JSON_PROPERTY_MAX_TRANSACTION_COUNT,
+  LtsTransactionSubmitNotaryQuotaExceededErrorDetails.JSON_PROPERTY_TIP_PROPORTION,
+  LtsTransactionSubmitNotaryQuotaExceededErrorDetails.JSON_PROPERTY_MIN_TIP_PROPORTION_REQUIRED
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+@JsonIgnoreProperties(
+  value = "type", // ignore manually set type, it will be automatically generated by Jackson during serialization
+  allowSetters = true // allows the type to be set during deserialization
+)
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type", visible = true)
+@JsonSubTypes({
+  @JsonSubTypes.Type(value = LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "FeePayerQuotaExceeded"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitIntentAlreadyCommitted.class, name = "IntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "NotaryQuotaExceeded"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitReplacementTipTooLowErrorDetails.class, name = "ReplacementTipTooLow"),
+})
+
+public class LtsTransactionSubmitNotaryQuotaExceededErrorDetails extends LtsTransactionSubmitErrorDetails {
+  public static final String JSON_PROPERTY_MAX_TRANSACTION_COUNT = "max_transaction_count";
+  private Long maxTransactionCount;
+
+  public static final String JSON_PROPERTY_TIP_PROPORTION = "tip_proportion";
+  private String tipProportion;
+
+  public static final String JSON_PROPERTY_MIN_TIP_PROPORTION_REQUIRED = "min_tip_proportion_required";
+  private String minTipProportionRequired;
+
+  public LtsTransactionSubmitNotaryQuotaExceededErrorDetails() { 
+  }
+
+  public LtsTransactionSubmitNotaryQuotaExceededErrorDetails maxTransactionCount(Long maxTransactionCount) {
+    this.maxTransactionCount = maxTransactionCount;
+    return this;
+  }
+
+   /**
+   * The configured maximum number of transactions of a single notary in the mempool.
+   * @return maxTransactionCount
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The configured maximum number of transactions of a single notary in the mempool.")
+  @JsonProperty(JSON_PROPERTY_MAX_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getMaxTransactionCount() {
+    return maxTransactionCount;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MAX_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMaxTransactionCount(Long maxTransactionCount) {
+    this.maxTransactionCount = maxTransactionCount;
+  }
+
+
+  public LtsTransactionSubmitNotaryQuotaExceededErrorDetails tipProportion(String tipProportion) {
+    this.tipProportion = tipProportion;
+    return this;
+  }
+
+   /**
+   * The string-encoded decimal tip proportion of the submitted (and rejected) transaction.
+   * @return tipProportion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The string-encoded decimal tip proportion of the submitted (and rejected) transaction.")
+  @JsonProperty(JSON_PROPERTY_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getTipProportion() {
+    return tipProportion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTipProportion(String tipProportion) {
+    this.tipProportion = tipProportion;
+  }
+
+
+  public LtsTransactionSubmitNotaryQuotaExceededErrorDetails minTipProportionRequired(String minTipProportionRequired) {
+    this.minTipProportionRequired = minTipProportionRequired;
+    return this;
+  }
+
+   /**
+   * A lower bound for tip proportion required to replace the notary&#39;s lowest-priority transaction in the mempool. A missing value means there is no tip that can guarantee submission. 
+   * @return minTipProportionRequired
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "A lower bound for tip proportion required to replace the notary's lowest-priority transaction in the mempool. A missing value means there is no tip that can guarantee submission. ")
+  @JsonProperty(JSON_PROPERTY_MIN_TIP_PROPORTION_REQUIRED)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public String getMinTipProportionRequired() {
+    return minTipProportionRequired;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MIN_TIP_PROPORTION_REQUIRED)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setMinTipProportionRequired(String minTipProportionRequired) {
+    this.minTipProportionRequired = minTipProportionRequired;
+  }
+
+
+  /**
+   * Return true if this LtsTransactionSubmitNotaryQuotaExceededErrorDetails object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    LtsTransactionSubmitNotaryQuotaExceededErrorDetails ltsTransactionSubmitNotaryQuotaExceededErrorDetails = (LtsTransactionSubmitNotaryQuotaExceededErrorDetails) o;
+    return Objects.equals(this.maxTransactionCount, ltsTransactionSubmitNotaryQuotaExceededErrorDetails.maxTransactionCount) &&
+        Objects.equals(this.tipProportion, ltsTransactionSubmitNotaryQuotaExceededErrorDetails.tipProportion) &&
+        Objects.equals(this.minTipProportionRequired, ltsTransactionSubmitNotaryQuotaExceededErrorDetails.minTipProportionRequired) &&
+        super.equals(o);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(maxTransactionCount, tipProportion, minTipProportionRequired, super.hashCode());
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class LtsTransactionSubmitNotaryQuotaExceededErrorDetails {\n");
+    sb.append("    ").append(toIndentedString(super.toString())).append("\n");
+    sb.append("    maxTransactionCount: ").append(toIndentedString(maxTransactionCount)).append("\n");
+    sb.append("    tipProportion: ").append(toIndentedString(tipProportion)).append("\n");
+    sb.append("    minTipProportionRequired: ").append(toIndentedString(minTipProportionRequired)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+static {
+  // Initialize and register the discriminator mappings.
+  Map<String, Class<?>> mappings = new HashMap<String, Class<?>>();
+  mappings.put("FeePayerQuotaExceeded", LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class);
+  mappings.put("IntentAlreadyCommitted", LtsTransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("NotaryQuotaExceeded", LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class);
+  mappings.put("PriorityThresholdNotMet", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
+  mappings.put("Rejected", LtsTransactionSubmitRejectedErrorDetails.class);
+  mappings.put("ReplacementTipTooLow", LtsTransactionSubmitReplacementTipTooLowErrorDetails.class);
+  mappings.put("LtsTransactionSubmitNotaryQuotaExceededErrorDetails", LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class);
+  JSON.registerDiscriminator(LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class, "type", mappings);
+}
+}
+
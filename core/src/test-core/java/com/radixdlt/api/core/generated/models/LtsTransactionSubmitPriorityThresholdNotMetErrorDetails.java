@@ -27,10 +27,13 @@ import com.fasterxml.jackson.annotation.JsonTypeName;
 import com.fasterxml.jackson.annotation.JsonValue;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitErrorDetailsType;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitFeePayerQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitIntentAlreadyCommitted;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitNotaryQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitPriorityThresholdNotMetErrorDetails;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitPriorityThresholdNotMetErrorDetailsAllOf;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitRejectedErrorDetails;
+import com.radixdlt.api.core.generated.models.LtsTransactionSubmitReplacementTipTooLowErrorDetails;
 import io.swagger.annotations.ApiModel;
 import io.swagger.annotations.ApiModelProperty;
 import com.fasterxml.jackson.annotation.JsonPropertyOrder;
@@ -53,9 +56,12 @@ import com.radixdlt.api.core.generated.client.JSON;
 )
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type", visible = true)
 @JsonSubTypes({
+  @JsonSubTypes.Type(value = LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "FeePayerQuotaExceeded"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitIntentAlreadyCommitted.class, name = "IntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "NotaryQuotaExceeded"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
   @JsonSubTypes.Type(value = LtsTransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = LtsTransactionSubmitReplacementTipTooLowErrorDetails.class, name = "ReplacementTipTooLow"),
 })
 
 public class LtsTransactionSubmitPriorityThresholdNotMetErrorDetails extends LtsTransactionSubmitErrorDetails {
@@ -237,9 +243,12 @@ public class LtsTransactionSubmitPriorityThresholdNotMetErrorDetails extends Lts
 static {
   // Initialize and register the discriminator mappings.
   Map<String, Class<?>> mappings = new HashMap<String, Class<?>>();
+  mappings.put("FeePayerQuotaExceeded", LtsTransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("IntentAlreadyCommitted", LtsTransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("NotaryQuotaExceeded", LtsTransactionSubmitNotaryQuotaExceededErrorDetails.class);
   mappings.put("PriorityThresholdNotMet", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   mappings.put("Rejected", LtsTransactionSubmitRejectedErrorDetails.class);
+  mappings.put("ReplacementTipTooLow", LtsTransactionSubmitReplacementTipTooLowErrorDetails.class);
   mappings.put("LtsTransactionSubmitPriorityThresholdNotMetErrorDetails", LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   JSON.registerDiscriminator(LtsTransactionSubmitPriorityThresholdNotMetErrorDetails.class, "type", mappings);
 }
@@ -0,0 +1,312 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.CommittedTipStatistics;
+import com.radixdlt.api.core.generated.models.SuggestedTip;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import java.util.ArrayList;
+import java.util.List;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * MempoolFeeEstimateResponse
+ */
+@JsonPropertyOrder({
+  MempoolFeeEstimateResponse.JSON_PROPERTY_TRANSACTION_COUNT,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_MAX_TRANSACTION_COUNT,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_TOTAL_TRANSACTIONS_SIZE,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_MAX_TOTAL_TRANSACTIONS_SIZE,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_MIN_ADMISSION_TIP_PROPORTION,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_RECENT_COMMITTED_TIPS,
+  MempoolFeeEstimateResponse.JSON_PROPERTY_SUGGESTED_TIPS
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class MempoolFeeEstimateResponse {
+  public static final String JSON_PROPERTY_TRANSACTION_COUNT = "transaction_count";
+  private Long transactionCount;
+
+  public static final String JSON_PROPERTY_MAX_TRANSACTION_COUNT = "max_transaction_count";
+  private Long maxTransactionCount;
+
+  public static final String JSON_PROPERTY_TOTAL_TRANSACTIONS_SIZE = "total_transactions_size";
+  private Long totalTransactionsSize;
+
+  public static final String JSON_PROPERTY_MAX_TOTAL_TRANSACTIONS_SIZE = "max_total_transactions_size";
+  private Long maxTotalTransactionsSize;
+
+  public static final String JSON_PROPERTY_MIN_ADMISSION_TIP_PROPORTION = "min_admission_tip_proportion";
+  private String minAdmissionTipProportion;
+
+  public static final String JSON_PROPERTY_RECENT_COMMITTED_TIPS = "recent_committed_tips";
+  private CommittedTipStatistics recentCommittedTips;
+
+  public static final String JSON_PROPERTY_SUGGESTED_TIPS = "suggested_tips";
+  private List<SuggestedTip> suggestedTips = new ArrayList<>();
+
+  public MempoolFeeEstimateResponse() { 
+  }
+
+  public MempoolFeeEstimateResponse transactionCount(Long transactionCount) {
+    this.transactionCount = transactionCount;
+    return this;
+  }
+
+   /**
+   * The number of transactions currently in the mempool.
+   * @return transactionCount
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The number of transactions currently in the mempool.")
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getTransactionCount() {
+    return transactionCount;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTransactionCount(Long transactionCount) {
+    this.transactionCount = transactionCount;
+  }
+
+
+  public MempoolFeeEstimateResponse maxTransactionCount(Long maxTransactionCount) {
+    this.maxTransactionCount = maxTransactionCount;
+    return this;
+  }
+
+   /**
+   * The configured maximum number of transactions in the mempool.
+   * @return maxTransactionCount
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The configured maximum number of transactions in the mempool.")
+  @JsonProperty(JSON_PROPERTY_MAX_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getMaxTransactionCount() {
+    return maxTransactionCount;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MAX_TRANSACTION_COUNT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMaxTransactionCount(Long maxTransactionCount) {
+    this.maxTransactionCount = maxTransactionCount;
+  }
+
+
+  public MempoolFeeEstimateResponse totalTransactionsSize(Long totalTransactionsSize) {
+    this.totalTransactionsSize = totalTransactionsSize;
+    return this;
+  }
+
+   /**
+   * The total size (in bytes) of the transactions currently in the mempool.
+   * @return totalTransactionsSize
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The total size (in bytes) of the transactions currently in the mempool.")
+  @JsonProperty(JSON_PROPERTY_TOTAL_TRANSACTIONS_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getTotalTransactionsSize() {
+    return totalTransactionsSize;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TOTAL_TRANSACTIONS_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTotalTransactionsSize(Long totalTransactionsSize) {
+    this.totalTransactionsSize = totalTransactionsSize;
+  }
+
+
+  public MempoolFeeEstimateResponse maxTotalTransactionsSize(Long maxTotalTransactionsSize) {
+    this.maxTotalTransactionsSize = maxTotalTransactionsSize;
+    return this;
+  }
+
+   /**
+   * The configured maximum total size (in bytes) of the transactions in the mempool.
+   * @return maxTotalTransactionsSize
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The configured maximum total size (in bytes) of the transactions in the mempool.")
+  @JsonProperty(JSON_PROPERTY_MAX_TOTAL_TRANSACTIONS_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getMaxTotalTransactionsSize() {
+    return maxTotalTransactionsSize;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MAX_TOTAL_TRANSACTIONS_SIZE)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMaxTotalTransactionsSize(Long maxTotalTransactionsSize) {
+    this.maxTotalTransactionsSize = maxTotalTransactionsSize;
+  }
+
+
+  public MempoolFeeEstimateResponse minAdmissionTipProportion(String minAdmissionTipProportion) {
+    this.minAdmissionTipProportion = minAdmissionTipProportion;
+    return this;
+  }
+
+   /**
+   * The string-encoded decimal tip proportion which a new transaction needs in order to be admitted into the mempool. It is non-zero only if the mempool is full (i.e. a lower-priority transaction would have to be evicted). 
+   * @return minAdmissionTipProportion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The string-encoded decimal tip proportion which a new transaction needs in order to be admitted into the mempool. It is non-zero only if the mempool is full (i.e. a lower-priority transaction would have to be evicted). ")
+  @JsonProperty(JSON_PROPERTY_MIN_ADMISSION_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getMinAdmissionTipProportion() {
+    return minAdmissionTipProportion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_MIN_ADMISSION_TIP_PROPORTION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setMinAdmissionTipProportion(String minAdmissionTipProportion) {
+    this.minAdmissionTipProportion = minAdmissionTipProportion;
+  }
+
+
+  public MempoolFeeEstimateResponse recentCommittedTips(CommittedTipStatistics recentCommittedTips) {
+    this.recentCommittedTips = recentCommittedTips;
+    return this;
+  }
+
+   /**
+   * Get recentCommittedTips
+   * @return recentCommittedTips
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "")
+  @JsonProperty(JSON_PROPERTY_RECENT_COMMITTED_TIPS)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public CommittedTipStatistics getRecentCommittedTips() {
+    return recentCommittedTips;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_RECENT_COMMITTED_TIPS)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setRecentCommittedTips(CommittedTipStatistics recentCommittedTips) {
+    this.recentCommittedTips = recentCommittedTips;
+  }
+
+
+  public MempoolFeeEstimateResponse suggestedTips(List<SuggestedTip> suggestedTips) {
+    this.suggestedTips = suggestedTips;
+    return this;
+  }
+
+  public MempoolFeeEstimateResponse addSuggestedTipsItem(SuggestedTip suggestedTipsItem) {
+    this.suggestedTips.add(suggestedTipsItem);
+    return this;
+  }
+
+   /**
+   * The suggested tips, in the order of the requested target proposal counts.
+   * @return suggestedTips
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The suggested tips, in the order of the requested target proposal counts.")
+  @JsonProperty(JSON_PROPERTY_SUGGESTED_TIPS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public List<SuggestedTip> getSuggestedTips() {
+    return suggestedTips;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_SUGGESTED_TIPS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setSuggestedTips(List<SuggestedTip> suggestedTips) {
+    this.suggestedTips = suggestedTips;
+  }
+
+
+  /**
+   * Return true if this MempoolFeeEstimateResponse object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    MempoolFeeEstimateResponse mempoolFeeEstimateResponse = (MempoolFeeEstimateResponse) o;
+    return Objects.equals(this.transactionCount, mempoolFeeEstimateResponse.transactionCount) &&
+        Objects.equals(this.maxTransactionCount, mempoolFeeEstimateResponse.maxTransactionCount) &&
+        Objects.equals(this.totalTransactionsSize, mempoolFeeEstimateResponse.totalTransactionsSize) &&
+        Objects.equals(this.maxTotalTransactionsSize, mempoolFeeEstimateResponse.maxTotalTransactionsSize) &&
+        Objects.equals(this.minAdmissionTipProportion, mempoolFeeEstimateResponse.minAdmissionTipProportion) &&
+        Objects.equals(this.recentCommittedTips, mempoolFeeEstimateResponse.recentCommittedTips) &&
+        Objects.equals(this.suggestedTips, mempoolFeeEstimateResponse.suggestedTips);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(transactionCount, maxTransactionCount, totalTransactionsSize, maxTotalTransactionsSize, minAdmissionTipProportion, recentCommittedTips, suggestedTips);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class MempoolFeeEstimateResponse {\n");
+    sb.append("    transactionCount: ").append(toIndentedString(transactionCount)).append("\n");
+    sb.append("    maxTransactionCount: ").append(toIndentedString(maxTransactionCount)).append("\n");
+    sb.append("    totalTransactionsSize: ").append(toIndentedString(totalTransactionsSize)).append("\n");
+    sb.append("    maxTotalTransactionsSize: ").append(toIndentedString(maxTotalTransactionsSize)).append("\n");
+    sb.append("    minAdmissionTipProportion: ").append(toIndentedString(minAdmissionTipProportion)).append("\n");
+    sb.append("    recentCommittedTips: ").append(toIndentedString(recentCommittedTips)).append("\n");
+    sb.append("    suggestedTips: ").append(toIndentedString(suggestedTips)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,244 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.SubstateKey;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * StateSubstateProofRequest
+ */
+@JsonPropertyOrder({
+  StateSubstateProofRequest.JSON_PROPERTY_NETWORK,
+  StateSubstateProofRequest.JSON_PROPERTY_ENTITY_ADDRESS,
+  StateSubstateProofRequest.JSON_PROPERTY_PARTITION_NUMBER,
+  StateSubstateProofRequest.JSON_PROPERTY_SUBSTATE_KEY,
+  StateSubstateProofRequest.JSON_PROPERTY_STATE_VERSION
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class StateSubstateProofRequest {
+  public static final String JSON_PROPERTY_NETWORK = "network";
+  private String network;
+
+  public static final String JSON_PROPERTY_ENTITY_ADDRESS = "entity_address";
+  private String entityAddress;
+
+  public static final String JSON_PROPERTY_PARTITION_NUMBER = "partition_number";
+  private Integer partitionNumber;
+
+  public static final String JSON_PROPERTY_SUBSTATE_KEY = "substate_key";
+  private SubstateKey substateKey;
+
+  public static final String JSON_PROPERTY_STATE_VERSION = "state_version";
+  private Long stateVersion;
+
+  public StateSubstateProofRequest() { 
+  }
+
+  public StateSubstateProofRequest network(String network) {
+    this.network = network;
+    return this;
+  }
+
+   /**
+   * The logical name of the network
+   * @return network
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(example = "{{network}}", required = true, value = "The logical name of the network")
+  @JsonProperty(JSON_PROPERTY_NETWORK)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getNetwork() {
+    return network;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_NETWORK)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setNetwork(String network) {
+    this.network = network;
+  }
+
+
+  public StateSubstateProofRequest entityAddress(String entityAddress) {
+    this.entityAddress = entityAddress;
+    return this;
+  }
+
+   /**
+   * The Bech32m-encoded human readable version of the address of the entity owning the Substate. Both global and internal (e.g. vault) entities are supported. 
+   * @return entityAddress
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The Bech32m-encoded human readable version of the address of the entity owning the Substate. Both global and internal (e.g. vault) entities are supported. ")
+  @JsonProperty(JSON_PROPERTY_ENTITY_ADDRESS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getEntityAddress() {
+    return entityAddress;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_ENTITY_ADDRESS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setEntityAddress(String entityAddress) {
+    this.entityAddress = entityAddress;
+  }
+
+
+  public StateSubstateProofRequest partitionNumber(Integer partitionNumber) {
+    this.partitionNumber = partitionNumber;
+    return this;
+  }
+
+   /**
+   * Get partitionNumber
+   * minimum: 0
+   * maximum: 255
+   * @return partitionNumber
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_PARTITION_NUMBER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Integer getPartitionNumber() {
+    return partitionNumber;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_PARTITION_NUMBER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setPartitionNumber(Integer partitionNumber) {
+    this.partitionNumber = partitionNumber;
+  }
+
+
+  public StateSubstateProofRequest substateKey(SubstateKey substateKey) {
+    this.substateKey = substateKey;
+    return this;
+  }
+
+   /**
+   * Get substateKey
+   * @return substateKey
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_KEY)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public SubstateKey getSubstateKey() {
+    return substateKey;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_KEY)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setSubstateKey(SubstateKey substateKey) {
+    this.substateKey = substateKey;
+  }
+
+
+  public StateSubstateProofRequest stateVersion(Long stateVersion) {
+    this.stateVersion = stateVersion;
+    return this;
+  }
+
+   /**
+   * An optional state version at which the proof should be generated. A ledger proof must exist at exactly this state version. If not provided, the latest ledger proof is used. 
+   * minimum: 1
+   * maximum: 100000000000000
+   * @return stateVersion
+  **/
+  @javax.annotation.Nullable
+  @ApiModelProperty(value = "An optional state version at which the proof should be generated. A ledger proof must exist at exactly this state version. If not provided, the latest ledger proof is used. ")
+  @JsonProperty(JSON_PROPERTY_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+
+  public Long getStateVersion() {
+    return stateVersion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.USE_DEFAULTS)
+  public void setStateVersion(Long stateVersion) {
+    this.stateVersion = stateVersion;
+  }
+
+
+  /**
+   * Return true if this StateSubstateProofRequest object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    StateSubstateProofRequest stateSubstateProofRequest = (StateSubstateProofRequest) o;
+    return Objects.equals(this.network, stateSubstateProofRequest.network) &&
+        Objects.equals(this.entityAddress, stateSubstateProofRequest.entityAddress) &&
+        Objects.equals(this.partitionNumber, stateSubstateProofRequest.partitionNumber) &&
+        Objects.equals(this.substateKey, stateSubstateProofRequest.substateKey) &&
+        Objects.equals(this.stateVersion, stateSubstateProofRequest.stateVersion);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(network, entityAddress, partitionNumber, substateKey, stateVersion);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class StateSubstateProofRequest {\n");
+    sb.append("    network: ").append(toIndentedString(network)).append("\n");
+    sb.append("    entityAddress: ").append(toIndentedString(entityAddress)).append("\n");
+    sb.append("    partitionNumber: ").append(toIndentedString(partitionNumber)).append("\n");
+    sb.append("    substateKey: ").append(toIndentedString(substateKey)).append("\n");
+    sb.append("    stateVersion: ").append(toIndentedString(stateVersion)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,241 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.LedgerProof;
+import com.radixdlt.api.core.generated.models.SubstateInclusionProof;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * StateSubstateProofResponse
+ */
+@JsonPropertyOrder({
+  StateSubstateProofResponse.JSON_PROPERTY_LEDGER_PROOF,
+  StateSubstateProofResponse.JSON_PROPERTY_DB_NODE_KEY_HEX,
+  StateSubstateProofResponse.JSON_PROPERTY_DB_SORT_KEY_HEX,
+  StateSubstateProofResponse.JSON_PROPERTY_SUBSTATE_VALUE_HEX,
+  StateSubstateProofResponse.JSON_PROPERTY_PROOF
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class StateSubstateProofResponse {
+  public static final String JSON_PROPERTY_LEDGER_PROOF = "ledger_proof";
+  private LedgerProof ledgerProof;
+
+  public static final String JSON_PROPERTY_DB_NODE_KEY_HEX = "db_node_key_hex";
+  private String dbNodeKeyHex;
+
+  public static final String JSON_PROPERTY_DB_SORT_KEY_HEX = "db_sort_key_hex";
+  private String dbSortKeyHex;
+
+  public static final String JSON_PROPERTY_SUBSTATE_VALUE_HEX = "substate_value_hex";
+  private String substateValueHex;
+
+  public static final String JSON_PROPERTY_PROOF = "proof";
+  private SubstateInclusionProof proof;
+
+  public StateSubstateProofResponse() { 
+  }
+
+  public StateSubstateProofResponse ledgerProof(LedgerProof ledgerProof) {
+    this.ledgerProof = ledgerProof;
+    return this;
+  }
+
+   /**
+   * Get ledgerProof
+   * @return ledgerProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public LedgerProof getLedgerProof() {
+    return ledgerProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLedgerProof(LedgerProof ledgerProof) {
+    this.ledgerProof = ledgerProof;
+  }
+
+
+  public StateSubstateProofResponse dbNodeKeyHex(String dbNodeKeyHex) {
+    this.dbNodeKeyHex = dbNodeKeyHex;
+    return this;
+  }
+
+   /**
+   * The hex-encoded bytes of the DB node key (i.e. the Entity tier&#39;s leaf key).
+   * @return dbNodeKeyHex
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded bytes of the DB node key (i.e. the Entity tier's leaf key).")
+  @JsonProperty(JSON_PROPERTY_DB_NODE_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getDbNodeKeyHex() {
+    return dbNodeKeyHex;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_DB_NODE_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setDbNodeKeyHex(String dbNodeKeyHex) {
+    this.dbNodeKeyHex = dbNodeKeyHex;
+  }
+
+
+  public StateSubstateProofResponse dbSortKeyHex(String dbSortKeyHex) {
+    this.dbSortKeyHex = dbSortKeyHex;
+    return this;
+  }
+
+   /**
+   * The hex-encoded bytes of the DB sort key (i.e. the Substate tier&#39;s leaf key).
+   * @return dbSortKeyHex
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded bytes of the DB sort key (i.e. the Substate tier's leaf key).")
+  @JsonProperty(JSON_PROPERTY_DB_SORT_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getDbSortKeyHex() {
+    return dbSortKeyHex;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_DB_SORT_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setDbSortKeyHex(String dbSortKeyHex) {
+    this.dbSortKeyHex = dbSortKeyHex;
+  }
+
+
+  public StateSubstateProofResponse substateValueHex(String substateValueHex) {
+    this.substateValueHex = substateValueHex;
+    return this;
+  }
+
+   /**
+   * The hex-encoded SBOR-encoded Substate value (its hash is the Substate tier&#39;s leaf value hash).
+   * @return substateValueHex
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded SBOR-encoded Substate value (its hash is the Substate tier's leaf value hash).")
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_VALUE_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getSubstateValueHex() {
+    return substateValueHex;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_VALUE_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setSubstateValueHex(String substateValueHex) {
+    this.substateValueHex = substateValueHex;
+  }
+
+
+  public StateSubstateProofResponse proof(SubstateInclusionProof proof) {
+    this.proof = proof;
+    return this;
+  }
+
+   /**
+   * Get proof
+   * @return proof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public SubstateInclusionProof getProof() {
+    return proof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setProof(SubstateInclusionProof proof) {
+    this.proof = proof;
+  }
+
+
+  /**
+   * Return true if this StateSubstateProofResponse object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    StateSubstateProofResponse stateSubstateProofResponse = (StateSubstateProofResponse) o;
+    return Objects.equals(this.ledgerProof, stateSubstateProofResponse.ledgerProof) &&
+        Objects.equals(this.dbNodeKeyHex, stateSubstateProofResponse.dbNodeKeyHex) &&
+        Objects.equals(this.dbSortKeyHex, stateSubstateProofResponse.dbSortKeyHex) &&
+        Objects.equals(this.substateValueHex, stateSubstateProofResponse.substateValueHex) &&
+        Objects.equals(this.proof, stateSubstateProofResponse.proof);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(ledgerProof, dbNodeKeyHex, dbSortKeyHex, substateValueHex, proof);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class StateSubstateProofResponse {\n");
+    sb.append("    ledgerProof: ").append(toIndentedString(ledgerProof)).append("\n");
+    sb.append("    dbNodeKeyHex: ").append(toIndentedString(dbNodeKeyHex)).append("\n");
+    sb.append("    dbSortKeyHex: ").append(toIndentedString(dbSortKeyHex)).append("\n");
+    sb.append("    substateValueHex: ").append(toIndentedString(substateValueHex)).append("\n");
+    sb.append("    proof: ").append(toIndentedString(proof)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,119 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.StateTreeProofLevel;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import java.util.ArrayList;
+import java.util.List;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * StateTreeTierProof
+ */
+@JsonPropertyOrder({
+  StateTreeTierProof.JSON_PROPERTY_LEVELS
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class StateTreeTierProof {
+  public static final String JSON_PROPERTY_LEVELS = "levels";
+  private List<StateTreeProofLevel> levels = new ArrayList<>();
+
+  public StateTreeTierProof() { 
+  }
+
+  public StateTreeTierProof levels(List<StateTreeProofLevel> levels) {
+    this.levels = levels;
+    return this;
+  }
+
+  public StateTreeTierProof addLevelsItem(StateTreeProofLevel levelsItem) {
+    this.levels.add(levelsItem);
+    return this;
+  }
+
+   /**
+   * The internal nodes on the path from the tier&#39;s root to the proven leaf (ordered from the root). The proven child of the node at index &#x60;i&#x60; is located at the &#x60;i&#x60;-th nibble of the leaf key. 
+   * @return levels
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The internal nodes on the path from the tier's root to the proven leaf (ordered from the root). The proven child of the node at index `i` is located at the `i`-th nibble of the leaf key. ")
+  @JsonProperty(JSON_PROPERTY_LEVELS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public List<StateTreeProofLevel> getLevels() {
+    return levels;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEVELS)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLevels(List<StateTreeProofLevel> levels) {
+    this.levels = levels;
+  }
+
+
+  /**
+   * Return true if this StateTreeTierProof object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    StateTreeTierProof stateTreeTierProof = (StateTreeTierProof) o;
+    return Objects.equals(this.levels, stateTreeTierProof.levels);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(levels);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class StateTreeTierProof {\n");
+    sb.append("    levels: ").append(toIndentedString(levels)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
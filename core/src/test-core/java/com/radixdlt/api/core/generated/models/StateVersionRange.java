@@ -0,0 +1,147 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * StateVersionRange
+ */
+@JsonPropertyOrder({
+  StateVersionRange.JSON_PROPERTY_FROM_STATE_VERSION,
+  StateVersionRange.JSON_PROPERTY_TO_STATE_VERSION
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class StateVersionRange {
+  public static final String JSON_PROPERTY_FROM_STATE_VERSION = "from_state_version";
+  private Long fromStateVersion;
+
+  public static final String JSON_PROPERTY_TO_STATE_VERSION = "to_state_version";
+  private Long toStateVersion;
+
+  public StateVersionRange() { 
+  }
+
+  public StateVersionRange fromStateVersion(Long fromStateVersion) {
+    this.fromStateVersion = fromStateVersion;
+    return this;
+  }
+
+   /**
+   * Get fromStateVersion
+   * minimum: 1
+   * maximum: 100000000000000
+   * @return fromStateVersion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_FROM_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getFromStateVersion() {
+    return fromStateVersion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_FROM_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setFromStateVersion(Long fromStateVersion) {
+    this.fromStateVersion = fromStateVersion;
+  }
+
+
+  public StateVersionRange toStateVersion(Long toStateVersion) {
+    this.toStateVersion = toStateVersion;
+    return this;
+  }
+
+   /**
+   * Get toStateVersion
+   * minimum: 1
+   * maximum: 100000000000000
+   * @return toStateVersion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_TO_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getToStateVersion() {
+    return toStateVersion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TO_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setToStateVersion(Long toStateVersion) {
+    this.toStateVersion = toStateVersion;
+  }
+
+
+  /**
+   * Return true if this StateVersionRange object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    StateVersionRange stateVersionRange = (StateVersionRange) o;
+    return Objects.equals(this.fromStateVersion, stateVersionRange.fromStateVersion) &&
+        Objects.equals(this.toStateVersion, stateVersionRange.toStateVersion);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(fromStateVersion, toStateVersion);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class StateVersionRange {\n");
+    sb.append("    fromStateVersion: ").append(toIndentedString(fromStateVersion)).append("\n");
+    sb.append("    toStateVersion: ").append(toIndentedString(toStateVersion)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
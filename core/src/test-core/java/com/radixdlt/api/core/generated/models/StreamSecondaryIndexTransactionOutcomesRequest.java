@@ -0,0 +1,242 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * A request to retrieve a sublist of committed transactions indexed under the given key by the given secondary index. 
+ */
+@ApiModel(description = "A request to retrieve a sublist of committed transactions indexed under the given key by the given secondary index. ")
+@JsonPropertyOrder({
+  StreamSecondaryIndexTransactionOutcomesRequest.JSON_PROPERTY_NETWORK,
+  StreamSecondaryIndexTransactionOutcomesRequest.JSON_PROPERTY_INDEX_NAME,
+  StreamSecondaryIndexTransactionOutcomesRequest.JSON_PROPERTY_KEY_HEX,
+  StreamSecondaryIndexTransactionOutcomesRequest.JSON_PROPERTY_FROM_STATE_VERSION,
+  StreamSecondaryIndexTransactionOutcomesRequest.JSON_PROPERTY_LIMIT
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class StreamSecondaryIndexTransactionOutcomesRequest {
+  public static final String JSON_PROPERTY_NETWORK = "network";
+  private String network;
+
+  public static final String JSON_PROPERTY_INDEX_NAME = "index_name";
+  private String indexName;
+
+  public static final String JSON_PROPERTY_KEY_HEX = "key_hex";
+  private String keyHex;
+
+  public static final String JSON_PROPERTY_FROM_STATE_VERSION = "from_state_version";
+  private Long fromStateVersion;
+
+  public static final String JSON_PROPERTY_LIMIT = "limit";
+  private Integer limit;
+
+  public StreamSecondaryIndexTransactionOutcomesRequest() { 
+  }
+
+  public StreamSecondaryIndexTransactionOutcomesRequest network(String network) {
+    this.network = network;
+    return this;
+  }
+
+   /**
+   * The logical name of the network
+   * @return network
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(example = "{{network}}", required = true, value = "The logical name of the network")
+  @JsonProperty(JSON_PROPERTY_NETWORK)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getNetwork() {
+    return network;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_NETWORK)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setNetwork(String network) {
+    this.network = network;
+  }
+
+
+  public StreamSecondaryIndexTransactionOutcomesRequest indexName(String indexName) {
+    this.indexName = indexName;
+    return this;
+  }
+
+   /**
+   * The name of a secondary index enabled on the node (e.g. &#x60;changed_entity&#x60; or &#x60;emitted_event_type&#x60;).
+   * @return indexName
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The name of a secondary index enabled on the node (e.g. `changed_entity` or `emitted_event_type`).")
+  @JsonProperty(JSON_PROPERTY_INDEX_NAME)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getIndexName() {
+    return indexName;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_INDEX_NAME)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setIndexName(String indexName) {
+    this.indexName = indexName;
+  }
+
+
+  public StreamSecondaryIndexTransactionOutcomesRequest keyHex(String keyHex) {
+    this.keyHex = keyHex;
+    return this;
+  }
+
+   /**
+   * The hex-encoded key, in the index-specific encoding.
+   * @return keyHex
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The hex-encoded key, in the index-specific encoding.")
+  @JsonProperty(JSON_PROPERTY_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public String getKeyHex() {
+    return keyHex;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_KEY_HEX)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setKeyHex(String keyHex) {
+    this.keyHex = keyHex;
+  }
+
+
+  public StreamSecondaryIndexTransactionOutcomesRequest fromStateVersion(Long fromStateVersion) {
+    this.fromStateVersion = fromStateVersion;
+    return this;
+  }
+
+   /**
+   * Get fromStateVersion
+   * minimum: 1
+   * maximum: 100000000000000
+   * @return fromStateVersion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_FROM_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getFromStateVersion() {
+    return fromStateVersion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_FROM_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setFromStateVersion(Long fromStateVersion) {
+    this.fromStateVersion = fromStateVersion;
+  }
+
+
+  public StreamSecondaryIndexTransactionOutcomesRequest limit(Integer limit) {
+    this.limit = limit;
+    return this;
+  }
+
+   /**
+   * The maximum number of transactions that will be returned.
+   * @return limit
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The maximum number of transactions that will be returned.")
+  @JsonProperty(JSON_PROPERTY_LIMIT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Integer getLimit() {
+    return limit;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LIMIT)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLimit(Integer limit) {
+    this.limit = limit;
+  }
+
+
+  /**
+   * Return true if this StreamSecondaryIndexTransactionOutcomesRequest object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    StreamSecondaryIndexTransactionOutcomesRequest streamSecondaryIndexTransactionOutcomesRequest = (StreamSecondaryIndexTransactionOutcomesRequest) o;
+    return Objects.equals(this.network, streamSecondaryIndexTransactionOutcomesRequest.network) &&
+        Objects.equals(this.indexName, streamSecondaryIndexTransactionOutcomesRequest.indexName) &&
+        Objects.equals(this.keyHex, streamSecondaryIndexTransactionOutcomesRequest.keyHex) &&
+        Objects.equals(this.fromStateVersion, streamSecondaryIndexTransactionOutcomesRequest.fromStateVersion) &&
+        Objects.equals(this.limit, streamSecondaryIndexTransactionOutcomesRequest.limit);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(network, indexName, keyHex, fromStateVersion, limit);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class StreamSecondaryIndexTransactionOutcomesRequest {\n");
+    sb.append("    network: ").append(toIndentedString(network)).append("\n");
+    sb.append("    indexName: ").append(toIndentedString(indexName)).append("\n");
+    sb.append("    keyHex: ").append(toIndentedString(keyHex)).append("\n");
+    sb.append("    fromStateVersion: ").append(toIndentedString(fromStateVersion)).append("\n");
+    sb.append("    limit: ").append(toIndentedString(limit)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,177 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.StateTreeTierProof;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * A Merkle proof of a Substate&#39;s inclusion in the state tree, which consists of 3 nested tiers of Jellyfish Merkle Trees. The Entity tier (keyed by DB node keys) has leaves holding root hashes of Partition tiers (keyed by single-byte partition numbers), which in turn have leaves holding root hashes of Substate tiers (keyed by DB sort keys), whose leaves hold hashes of Substate values. Within each tier, a leaf hash is &#x60;blake2b_256(leaf_key ++ value_hash)&#x60;, and an internal node hash is computed from its 16 children as a binary Merkle tree of &#x60;blake2b_256(left ++ right)&#x60; hashes, in which empty subtrees are represented by 32 zero bytes, and subtrees containing only a single leaf are represented by that leaf. 
+ */
+@ApiModel(description = "A Merkle proof of a Substate's inclusion in the state tree, which consists of 3 nested tiers of Jellyfish Merkle Trees. The Entity tier (keyed by DB node keys) has leaves holding root hashes of Partition tiers (keyed by single-byte partition numbers), which in turn have leaves holding root hashes of Substate tiers (keyed by DB sort keys), whose leaves hold hashes of Substate values. Within each tier, a leaf hash is `blake2b_256(leaf_key ++ value_hash)`, and an internal node hash is computed from its 16 children as a binary Merkle tree of `blake2b_256(left ++ right)` hashes, in which empty subtrees are represented by 32 zero bytes, and subtrees containing only a single leaf are represented by that leaf. ")
+@JsonPropertyOrder({
+  SubstateInclusionProof.JSON_PROPERTY_ENTITY_TIER,
+  SubstateInclusionProof.JSON_PROPERTY_PARTITION_TIER,
+  SubstateInclusionProof.JSON_PROPERTY_SUBSTATE_TIER
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class SubstateInclusionProof {
+  public static final String JSON_PROPERTY_ENTITY_TIER = "entity_tier";
+  private StateTreeTierProof entityTier;
+
+  public static final String JSON_PROPERTY_PARTITION_TIER = "partition_tier";
+  private StateTreeTierProof partitionTier;
+
+  public static final String JSON_PROPERTY_SUBSTATE_TIER = "substate_tier";
+  private StateTreeTierProof substateTier;
+
+  public SubstateInclusionProof() { 
+  }
+
+  public SubstateInclusionProof entityTier(StateTreeTierProof entityTier) {
+    this.entityTier = entityTier;
+    return this;
+  }
+
+   /**
+   * Get entityTier
+   * @return entityTier
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_ENTITY_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public StateTreeTierProof getEntityTier() {
+    return entityTier;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_ENTITY_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setEntityTier(StateTreeTierProof entityTier) {
+    this.entityTier = entityTier;
+  }
+
+
+  public SubstateInclusionProof partitionTier(StateTreeTierProof partitionTier) {
+    this.partitionTier = partitionTier;
+    return this;
+  }
+
+   /**
+   * Get partitionTier
+   * @return partitionTier
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_PARTITION_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public StateTreeTierProof getPartitionTier() {
+    return partitionTier;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_PARTITION_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setPartitionTier(StateTreeTierProof partitionTier) {
+    this.partitionTier = partitionTier;
+  }
+
+
+  public SubstateInclusionProof substateTier(StateTreeTierProof substateTier) {
+    this.substateTier = substateTier;
+    return this;
+  }
+
+   /**
+   * Get substateTier
+   * @return substateTier
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public StateTreeTierProof getSubstateTier() {
+    return substateTier;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_SUBSTATE_TIER)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setSubstateTier(StateTreeTierProof substateTier) {
+    this.substateTier = substateTier;
+  }
+
+
+  /**
+   * Return true if this SubstateInclusionProof object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    SubstateInclusionProof substateInclusionProof = (SubstateInclusionProof) o;
+    return Objects.equals(this.entityTier, substateInclusionProof.entityTier) &&
+        Objects.equals(this.partitionTier, substateInclusionProof.partitionTier) &&
+        Objects.equals(this.substateTier, substateInclusionProof.substateTier);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(entityTier, partitionTier, substateTier);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class SubstateInclusionProof {\n");
+    sb.append("    entityTier: ").append(toIndentedString(entityTier)).append("\n");
+    sb.append("    partitionTier: ").append(toIndentedString(partitionTier)).append("\n");
+    sb.append("    substateTier: ").append(toIndentedString(substateTier)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,209 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.AccumulatorTreeConsistencyProof;
+import com.radixdlt.api.core.generated.models.LedgerProof;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * TransactionConsistencyProofResponse
+ */
+@JsonPropertyOrder({
+  TransactionConsistencyProofResponse.JSON_PROPERTY_FROM_LEDGER_PROOF,
+  TransactionConsistencyProofResponse.JSON_PROPERTY_TO_LEDGER_PROOF,
+  TransactionConsistencyProofResponse.JSON_PROPERTY_TRANSACTION_TREE_PROOF,
+  TransactionConsistencyProofResponse.JSON_PROPERTY_RECEIPT_TREE_PROOF
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class TransactionConsistencyProofResponse {
+  public static final String JSON_PROPERTY_FROM_LEDGER_PROOF = "from_ledger_proof";
+  private LedgerProof fromLedgerProof;
+
+  public static final String JSON_PROPERTY_TO_LEDGER_PROOF = "to_ledger_proof";
+  private LedgerProof toLedgerProof;
+
+  public static final String JSON_PROPERTY_TRANSACTION_TREE_PROOF = "transaction_tree_proof";
+  private AccumulatorTreeConsistencyProof transactionTreeProof;
+
+  public static final String JSON_PROPERTY_RECEIPT_TREE_PROOF = "receipt_tree_proof";
+  private AccumulatorTreeConsistencyProof receiptTreeProof;
+
+  public TransactionConsistencyProofResponse() { 
+  }
+
+  public TransactionConsistencyProofResponse fromLedgerProof(LedgerProof fromLedgerProof) {
+    this.fromLedgerProof = fromLedgerProof;
+    return this;
+  }
+
+   /**
+   * Get fromLedgerProof
+   * @return fromLedgerProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_FROM_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public LedgerProof getFromLedgerProof() {
+    return fromLedgerProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_FROM_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setFromLedgerProof(LedgerProof fromLedgerProof) {
+    this.fromLedgerProof = fromLedgerProof;
+  }
+
+
+  public TransactionConsistencyProofResponse toLedgerProof(LedgerProof toLedgerProof) {
+    this.toLedgerProof = toLedgerProof;
+    return this;
+  }
+
+   /**
+   * Get toLedgerProof
+   * @return toLedgerProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_TO_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public LedgerProof getToLedgerProof() {
+    return toLedgerProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TO_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setToLedgerProof(LedgerProof toLedgerProof) {
+    this.toLedgerProof = toLedgerProof;
+  }
+
+
+  public TransactionConsistencyProofResponse transactionTreeProof(AccumulatorTreeConsistencyProof transactionTreeProof) {
+    this.transactionTreeProof = transactionTreeProof;
+    return this;
+  }
+
+   /**
+   * Get transactionTreeProof
+   * @return transactionTreeProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public AccumulatorTreeConsistencyProof getTransactionTreeProof() {
+    return transactionTreeProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTransactionTreeProof(AccumulatorTreeConsistencyProof transactionTreeProof) {
+    this.transactionTreeProof = transactionTreeProof;
+  }
+
+
+  public TransactionConsistencyProofResponse receiptTreeProof(AccumulatorTreeConsistencyProof receiptTreeProof) {
+    this.receiptTreeProof = receiptTreeProof;
+    return this;
+  }
+
+   /**
+   * Get receiptTreeProof
+   * @return receiptTreeProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_RECEIPT_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public AccumulatorTreeConsistencyProof getReceiptTreeProof() {
+    return receiptTreeProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_RECEIPT_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setReceiptTreeProof(AccumulatorTreeConsistencyProof receiptTreeProof) {
+    this.receiptTreeProof = receiptTreeProof;
+  }
+
+
+  /**
+   * Return true if this TransactionConsistencyProofResponse object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    TransactionConsistencyProofResponse transactionConsistencyProofResponse = (TransactionConsistencyProofResponse) o;
+    return Objects.equals(this.fromLedgerProof, transactionConsistencyProofResponse.fromLedgerProof) &&
+        Objects.equals(this.toLedgerProof, transactionConsistencyProofResponse.toLedgerProof) &&
+        Objects.equals(this.transactionTreeProof, transactionConsistencyProofResponse.transactionTreeProof) &&
+        Objects.equals(this.receiptTreeProof, transactionConsistencyProofResponse.receiptTreeProof);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(fromLedgerProof, toLedgerProof, transactionTreeProof, receiptTreeProof);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class TransactionConsistencyProofResponse {\n");
+    sb.append("    fromLedgerProof: ").append(toIndentedString(fromLedgerProof)).append("\n");
+    sb.append("    toLedgerProof: ").append(toIndentedString(toLedgerProof)).append("\n");
+    sb.append("    transactionTreeProof: ").append(toIndentedString(transactionTreeProof)).append("\n");
+    sb.append("    receiptTreeProof: ").append(toIndentedString(receiptTreeProof)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -0,0 +1,211 @@
+/*
+ * Radix Core API
+ * This API is exposed by the Babylon Radix node to give clients access to the Radix Engine, Mempool and State in the node.  The default configuration is intended for use by node-runners on a private network, and is not intended to be exposed publicly. Very heavy load may impact the node's function. The node exposes a configuration flag which allows disabling certain endpoints which may be problematic, but monitoring is advised. This configuration parameter is `api.core.flags.enable_unbounded_endpoints` / `RADIXDLT_CORE_API_FLAGS_ENABLE_UNBOUNDED_ENDPOINTS`.  This API exposes queries against the node's current state (see `/lts/state/` or `/state/`), and streams of transaction history (under `/lts/stream/` or `/stream`).  If you require queries against snapshots of historical ledger state, you may also wish to consider using the [Gateway API](https://docs-babylon.radixdlt.com/).  ## Integration and forward compatibility guarantees  Integrators (such as exchanges) are recommended to use the `/lts/` endpoints - they have been designed to be clear and simple for integrators wishing to create and monitor transactions involving fungible transfers to/from accounts.  All endpoints under `/lts/` have high guarantees of forward compatibility in future node versions. We may add new fields, but existing fields will not be changed. Assuming the integrating code uses a permissive JSON parser which ignores unknown fields, any additions will not affect existing code.  Other endpoints may be changed with new node versions carrying protocol-updates, although any breaking changes will be flagged clearly in the corresponding release notes.  All responses may have additional fields added, so clients are advised to use JSON parsers which ignore unknown fields on JSON objects. 
+ *
+ * The version of the OpenAPI document: v1.3.0
+ * 
+ *
+ * NOTE: This class is auto generated by OpenAPI Generator (https://openapi-generator.tech).
+ * https://openapi-generator.tech
+ * Do not edit the class manually.
+ */
+
+
+package com.radixdlt.api.core.generated.models;
+
+import java.util.Objects;
+import java.util.Arrays;
+import java.util.Map;
+import java.util.HashMap;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonTypeName;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.radixdlt.api.core.generated.models.AccumulatorTreeInclusionProof;
+import com.radixdlt.api.core.generated.models.LedgerProof;
+import io.swagger.annotations.ApiModel;
+import io.swagger.annotations.ApiModelProperty;
+import com.fasterxml.jackson.annotation.JsonPropertyOrder;
+
+
+/**
+ * TransactionInclusionProofResponse
+ */
+@JsonPropertyOrder({
+  TransactionInclusionProofResponse.JSON_PROPERTY_STATE_VERSION,
+  TransactionInclusionProofResponse.JSON_PROPERTY_LEDGER_PROOF,
+  TransactionInclusionProofResponse.JSON_PROPERTY_TRANSACTION_TREE_PROOF,
+  TransactionInclusionProofResponse.JSON_PROPERTY_RECEIPT_TREE_PROOF
+})
+@javax.annotation.processing.Generated(value = "org.openapitools.codegen.languages.JavaClientCodegen")
+public class TransactionInclusionProofResponse {
+  public static final String JSON_PROPERTY_STATE_VERSION = "state_version";
+  private Long stateVersion;
+
+  public static final String JSON_PROPERTY_LEDGER_PROOF = "ledger_proof";
+  private LedgerProof ledgerProof;
+
+  public static final String JSON_PROPERTY_TRANSACTION_TREE_PROOF = "transaction_tree_proof";
+  private AccumulatorTreeInclusionProof transactionTreeProof;
+
+  public static final String JSON_PROPERTY_RECEIPT_TREE_PROOF = "receipt_tree_proof";
+  private AccumulatorTreeInclusionProof receiptTreeProof;
+
+  public TransactionInclusionProofResponse() { 
+  }
+
+  public TransactionInclusionProofResponse stateVersion(Long stateVersion) {
+    this.stateVersion = stateVersion;
+    return this;
+  }
+
+   /**
+   * The state version at which the transaction was committed.
+   * minimum: 1
+   * maximum: 100000000000000
+   * @return stateVersion
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "The state version at which the transaction was committed.")
+  @JsonProperty(JSON_PROPERTY_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public Long getStateVersion() {
+    return stateVersion;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_STATE_VERSION)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setStateVersion(Long stateVersion) {
+    this.stateVersion = stateVersion;
+  }
+
+
+  public TransactionInclusionProofResponse ledgerProof(LedgerProof ledgerProof) {
+    this.ledgerProof = ledgerProof;
+    return this;
+  }
+
+   /**
+   * Get ledgerProof
+   * @return ledgerProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public LedgerProof getLedgerProof() {
+    return ledgerProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_LEDGER_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setLedgerProof(LedgerProof ledgerProof) {
+    this.ledgerProof = ledgerProof;
+  }
+
+
+  public TransactionInclusionProofResponse transactionTreeProof(AccumulatorTreeInclusionProof transactionTreeProof) {
+    this.transactionTreeProof = transactionTreeProof;
+    return this;
+  }
+
+   /**
+   * Get transactionTreeProof
+   * @return transactionTreeProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public AccumulatorTreeInclusionProof getTransactionTreeProof() {
+    return transactionTreeProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_TRANSACTION_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setTransactionTreeProof(AccumulatorTreeInclusionProof transactionTreeProof) {
+    this.transactionTreeProof = transactionTreeProof;
+  }
+
+
+  public TransactionInclusionProofResponse receiptTreeProof(AccumulatorTreeInclusionProof receiptTreeProof) {
+    this.receiptTreeProof = receiptTreeProof;
+    return this;
+  }
+
+   /**
+   * Get receiptTreeProof
+   * @return receiptTreeProof
+  **/
+  @javax.annotation.Nonnull
+  @ApiModelProperty(required = true, value = "")
+  @JsonProperty(JSON_PROPERTY_RECEIPT_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+
+  public AccumulatorTreeInclusionProof getReceiptTreeProof() {
+    return receiptTreeProof;
+  }
+
+
+  @JsonProperty(JSON_PROPERTY_RECEIPT_TREE_PROOF)
+  @JsonInclude(value = JsonInclude.Include.ALWAYS)
+  public void setReceiptTreeProof(AccumulatorTreeInclusionProof receiptTreeProof) {
+    this.receiptTreeProof = receiptTreeProof;
+  }
+
+
+  /**
+   * Return true if this TransactionInclusionProofResponse object is equal to o.
+   */
+  @Override
+  public boolean equals(Object o) {
+    if (this == o) {
+      return true;
+    }
+    if (o == null || getClass() != o.getClass()) {
+      return false;
+    }
+    TransactionInclusionProofResponse transactionInclusionProofResponse = (TransactionInclusionProofResponse) o;
+    return Objects.equals(this.stateVersion, transactionInclusionProofResponse.stateVersion) &&
+        Objects.equals(this.ledgerProof, transactionInclusionProofResponse.ledgerProof) &&
+        Objects.equals(this.transactionTreeProof, transactionInclusionProofResponse.transactionTreeProof) &&
+        Objects.equals(this.receiptTreeProof, transactionInclusionProofResponse.receiptTreeProof);
+  }
+
+  @Override
+  public int hashCode() {
+    return Objects.hash(stateVersion, ledgerProof, transactionTreeProof, receiptTreeProof);
+  }
+
+  @Override
+  public String toString() {
+    StringBuilder sb = new StringBuilder();
+    sb.append("class TransactionInclusionProofResponse {\n");
+    sb.append("    stateVersion: ").append(toIndentedString(stateVersion)).append("\n");
+    sb.append("    ledgerProof: ").append(toIndentedString(ledgerProof)).append("\n");
+    sb.append("    transactionTreeProof: ").append(toIndentedString(transactionTreeProof)).append("\n");
+    sb.append("    receiptTreeProof: ").append(toIndentedString(receiptTreeProof)).append("\n");
+    sb.append("}");
+    return sb.toString();
+  }
+
+  /**
+   * Convert the given object to string with each line indented by 4 spaces
+   * (except the first line).
+   */
+  private String toIndentedString(Object o) {
+    if (o == null) {
+      return "null";
+    }
+    return o.toString().replace("\n", "\n    ");
+  }
+
+}
+
@@ -26,9 +26,12 @@ import com.fasterxml.jackson.annotation.JsonTypeInfo;
 import com.fasterxml.jackson.annotation.JsonTypeName;
 import com.fasterxml.jackson.annotation.JsonValue;
 import com.radixdlt.api.core.generated.models.TransactionSubmitErrorDetailsType;
+import com.radixdlt.api.core.generated.models.TransactionSubmitFeePayerQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitIntentAlreadyCommitted;
+import com.radixdlt.api.core.generated.models.TransactionSubmitNotaryQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitPriorityThresholdNotMetErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitRejectedErrorDetails;
+import com.radixdlt.api.core.generated.models.TransactionSubmitReplacementTipTooLowErrorDetails;
 import io.swagger.annotations.ApiModel;
 import io.swagger.annotations.ApiModelProperty;
 import com.fasterxml.jackson.annotation.JsonPropertyOrder;
@@ -48,12 +51,18 @@ import com.radixdlt.api.core.generated.client.JSON;
 )
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type", visible = true)
 @JsonSubTypes({
+  @JsonSubTypes.Type(value = TransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "FeePayerQuotaExceeded"),
   @JsonSubTypes.Type(value = TransactionSubmitIntentAlreadyCommitted.class, name = "IntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = TransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "NotaryQuotaExceeded"),
   @JsonSubTypes.Type(value = TransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
   @JsonSubTypes.Type(value = TransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = TransactionSubmitReplacementTipTooLowErrorDetails.class, name = "ReplacementTipTooLow"),
+  @JsonSubTypes.Type(value = TransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "TransactionSubmitFeePayerQuotaExceededErrorDetails"),
   @JsonSubTypes.Type(value = TransactionSubmitIntentAlreadyCommitted.class, name = "TransactionSubmitIntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = TransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "TransactionSubmitNotaryQuotaExceededErrorDetails"),
   @JsonSubTypes.Type(value = TransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "TransactionSubmitPriorityThresholdNotMetErrorDetails"),
   @JsonSubTypes.Type(value = TransactionSubmitRejectedErrorDetails.class, name = "TransactionSubmitRejectedErrorDetails"),
+  @JsonSubTypes.Type(value = TransactionSubmitReplacementTipTooLowErrorDetails.class, name = "TransactionSubmitReplacementTipTooLowErrorDetails"),
 })
 
 public class TransactionSubmitErrorDetails {
@@ -132,12 +141,18 @@ public class TransactionSubmitErrorDetails {
 static {
   // Initialize and register the discriminator mappings.
   Map<String, Class<?>> mappings = new HashMap<String, Class<?>>();
+  mappings.put("FeePayerQuotaExceeded", TransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("IntentAlreadyCommitted", TransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("NotaryQuotaExceeded", TransactionSubmitNotaryQuotaExceededErrorDetails.class);
   mappings.put("PriorityThresholdNotMet", TransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   mappings.put("Rejected", TransactionSubmitRejectedErrorDetails.class);
+  mappings.put("ReplacementTipTooLow", TransactionSubmitReplacementTipTooLowErrorDetails.class);
+  mappings.put("TransactionSubmitFeePayerQuotaExceededErrorDetails", TransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("TransactionSubmitIntentAlreadyCommitted", TransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("TransactionSubmitNotaryQuotaExceededErrorDetails", TransactionSubmitNotaryQuotaExceededErrorDetails.class);
   mappings.put("TransactionSubmitPriorityThresholdNotMetErrorDetails", TransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   mappings.put("TransactionSubmitRejectedErrorDetails", TransactionSubmitRejectedErrorDetails.class);
+  mappings.put("TransactionSubmitReplacementTipTooLowErrorDetails", TransactionSubmitReplacementTipTooLowErrorDetails.class);
   mappings.put("TransactionSubmitErrorDetails", TransactionSubmitErrorDetails.class);
   JSON.registerDiscriminator(TransactionSubmitErrorDetails.class, "type", mappings);
 }
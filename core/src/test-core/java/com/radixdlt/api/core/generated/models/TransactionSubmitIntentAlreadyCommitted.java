@@ -29,9 +29,12 @@ import com.radixdlt.api.core.generated.models.CommittedIntentMetadata;
 import com.radixdlt.api.core.generated.models.LtsTransactionSubmitIntentAlreadyCommittedAllOf;
 import com.radixdlt.api.core.generated.models.TransactionSubmitErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitErrorDetailsType;
+import com.radixdlt.api.core.generated.models.TransactionSubmitFeePayerQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitIntentAlreadyCommitted;
+import com.radixdlt.api.core.generated.models.TransactionSubmitNotaryQuotaExceededErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitPriorityThresholdNotMetErrorDetails;
 import com.radixdlt.api.core.generated.models.TransactionSubmitRejectedErrorDetails;
+import com.radixdlt.api.core.generated.models.TransactionSubmitReplacementTipTooLowErrorDetails;
 import io.swagger.annotations.ApiModel;
 import io.swagger.annotations.ApiModelProperty;
 import com.fasterxml.jackson.annotation.JsonPropertyOrder;
@@ -51,9 +54,12 @@ import com.radixdlt.api.core.generated.client.JSON;
 )
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type", visible = true)
 @JsonSubTypes({
+  @JsonSubTypes.Type(value = TransactionSubmitFeePayerQuotaExceededErrorDetails.class, name = "FeePayerQuotaExceeded"),
   @JsonSubTypes.Type(value = TransactionSubmitIntentAlreadyCommitted.class, name = "IntentAlreadyCommitted"),
+  @JsonSubTypes.Type(value = TransactionSubmitNotaryQuotaExceededErrorDetails.class, name = "NotaryQuotaExceeded"),
   @JsonSubTypes.Type(value = TransactionSubmitPriorityThresholdNotMetErrorDetails.class, name = "PriorityThresholdNotMet"),
   @JsonSubTypes.Type(value = TransactionSubmitRejectedErrorDetails.class, name = "Rejected"),
+  @JsonSubTypes.Type(value = TransactionSubmitReplacementTipTooLowErrorDetails.class, name = "ReplacementTipTooLow"),
 })
 
 public class TransactionSubmitIntentAlreadyCommitted extends TransactionSubmitErrorDetails {
@@ -134,9 +140,12 @@ public class TransactionSubmitIntentAlreadyCommitted extends TransactionSubmitEr
 static {
   // Initialize and register the discriminator mappings.
   Map<String, Class<?>> mappings = new HashMap<String, Class<?>>();
+  mappings.put("FeePayerQuotaExceeded", TransactionSubmitFeePayerQuotaExceededErrorDetails.class);
   mappings.put("IntentAlreadyCommitted", TransactionSubmitIntentAlreadyCommitted.class);
+  mappings.put("NotaryQuotaExceeded", TransactionSubmitNotaryQuotaExceededErrorDetails.class);
   mappings.put("PriorityThresholdNotMet", TransactionSubmitPriorityThresholdNotMetErrorDetails.class);
   mappings.put("Rejected", TransactionSubmitRejectedErrorDetails.class);
+  mappings.put("ReplacementTipTooLow", TransactionSubmitReplacementTipTooLowErrorDetails.class);
   mappings.put("TransactionSubmitIntentAlreadyCommitted", TransactionSubmitIntentAlreadyCommitted.class);
   JSON.registerDiscriminator(TransactionSubmitIntentAlreadyCommitted.class, "type", mappings);
 }
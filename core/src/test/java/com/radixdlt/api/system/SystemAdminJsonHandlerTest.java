@@ -0,0 +1,196 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+package com.radixdlt.api.system;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.mockito.ArgumentMatchers.anyInt;
+import static org.mockito.ArgumentMatchers.anyString;
+import static org.mockito.Mockito.doAnswer;
+import static org.mockito.Mockito.mock;
+import static org.mockito.Mockito.verify;
+import static org.mockito.Mockito.when;
+
+import com.radixdlt.api.common.JSON;
+import com.radixdlt.api.system.errors.SystemApiErrorCode;
+import com.radixdlt.api.system.generated.models.Error;
+import io.undertow.io.Sender;
+import io.undertow.server.HttpServerExchange;
+import io.undertow.util.HeaderMap;
+import io.undertow.util.Headers;
+import java.io.ByteArrayInputStream;
+import java.util.Optional;
+import java.util.concurrent.atomic.AtomicBoolean;
+import java.util.concurrent.atomic.AtomicReference;
+import org.junit.Test;
+
+public class SystemAdminJsonHandlerTest {
+  private static final String ADMIN_TOKEN = "test-admin-token";
+
+  @Test
+  public void admin_endpoint_is_forbidden_when_no_token_is_configured() throws Exception {
+    final var handler = new TestHandler(Optional.empty());
+
+    final var response = handleRequest(handler, "Bearer " + ADMIN_TOKEN);
+
+    assertThat(response.status()).isEqualTo(SystemApiErrorCode.FORBIDDEN.getErrorCode());
+    assertThat(response.error().getCode()).isEqualTo(SystemApiErrorCode.FORBIDDEN.getErrorCode());
+    assertThat(handler.wasCalled()).isFalse();
+  }
+
+  @Test
+  public void admin_endpoint_is_unauthorized_without_a_token() throws Exception {
+    final var handler = new TestHandler(Optional.of(ADMIN_TOKEN));
+
+    final var response = handleRequest(handler, null);
+
+    assertThat(response.status()).isEqualTo(SystemApiErrorCode.UNAUTHORIZED.getErrorCode());
+    assertThat(handler.wasCalled()).isFalse();
+  }
+
+  @Test
+  public void admin_endpoint_is_unauthorized_with_an_invalid_token() throws Exception {
+    final var handler = new TestHandler(Optional.of(ADMIN_TOKEN));
+
+    // A wrong token, a non-bearer scheme and a prefix of the right token:
+    for (final var authorization :
+        new String[] {
+          "Bearer wrong-token", "Basic " + ADMIN_TOKEN, "Bearer " + ADMIN_TOKEN.substring(1)
+        }) {
+      final var response = handleRequest(handler, authorization);
+      assertThat(response.status()).isEqualTo(SystemApiErrorCode.UNAUTHORIZED.getErrorCode());
+    }
+    assertThat(handler.wasCalled()).isFalse();
+  }
+
+  @Test
+  public void admin_endpoint_is_handled_with_a_valid_token() throws Exception {
+    final var handler = new TestHandler(Optional.of(ADMIN_TOKEN));
+
+    final var response = handleRequest(handler, "Bearer " + ADMIN_TOKEN);
+
+    assertThat(response.status()).isEqualTo(200);
+    assertThat(handler.wasCalled()).isTrue();
+  }
+
+  private record Response(int status, String body) {
+    Error error() throws Exception {
+      return JSON.getDefault().getMapper().readValue(body, Error.class);
+    }
+  }
+
+  private static Response handleRequest(SystemAdminJsonHandler<?> handler, String authorization)
+      throws Exception {
+    final var requestHeaders = new HeaderMap();
+    if (authorization != null) {
+      requestHeaders.put(Headers.AUTHORIZATION, authorization);
+    }
+    final var exchange = mock(HttpServerExchange.class);
+    when(exchange.isInIoThread()).thenReturn(false);
+    when(exchange.getInputStream()).thenReturn(new ByteArrayInputStream(new byte[0]));
+    when(exchange.getRequestHeaders()).thenReturn(requestHeaders);
+    when(exchange.getResponseHeaders()).thenReturn(new HeaderMap());
+    final var sender = mock(Sender.class);
+    final var body = new AtomicReference<String>();
+    doAnswer(
+            invocation -> {
+              body.set(invocation.getArgument(0));
+              return null;
+            })
+        .when(sender)
+        .send(anyString());
+    when(exchange.getResponseSender()).thenReturn(sender);
+    final var status = new AtomicReference<Integer>();
+    doAnswer(
+            invocation -> {
+              status.set(invocation.getArgument(0));
+              return exchange;
+            })
+        .when(exchange)
+        .setStatusCode(anyInt());
+
+    handler.handleRequest(exchange);
+
+    verify(sender).send(anyString());
+    return new Response(status.get(), body.get());
+  }
+
+  private static final class TestHandler extends SystemAdminJsonHandler<Error> {
+    private final AtomicBoolean called = new AtomicBoolean(false);
+
+    TestHandler(Optional<String> adminToken) {
+      super(new SystemApiConfig(false, "", adminToken));
+    }
+
+    @Override
+    protected Error handleAuthorizedRequest(byte[] requestBody) {
+      called.set(true);
+      return new Error().code(0).message("handled");
+    }
+
+    boolean wasCalled() {
+      return called.get();
+    }
+  }
+}
@@ -0,0 +1,136 @@
+/* Copyright 2021 Radix Publishing Ltd incorporated in Jersey (Channel Islands).
+ *
+ * Licensed under the Radix License, Version 1.0 (the "License"); you may not use this
+ * file except in compliance with the License. You may obtain a copy of the License at:
+ *
+ * radixfoundation.org/licenses/LICENSE-v1
+ *
+ * The Licensor hereby grants permission for the Canonical version of the Work to be
+ * published, distributed and used under or by reference to the Licensor’s trademark
+ * Radix ® and use of any unregistered trade names, logos or get-up.
+ *
+ * The Licensor provides the Work (and each Contributor provides its Contributions) on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied,
+ * including, without limitation, any warranties or conditions of TITLE, NON-INFRINGEMENT,
+ * MERCHANTABILITY, or FITNESS FOR A PARTICULAR PURPOSE.
+ *
+ * Whilst the Work is capable of being deployed, used and adopted (instantiated) to create
+ * a distributed ledger it is your responsibility to test and validate the code, together
+ * with all logic and performance of that code under all foreseeable scenarios.
+ *
+ * The Licensor does not make or purport to make and hereby excludes liability for all
+ * and any representation, warranty or undertaking in any form whatsoever, whether express
+ * or implied, to any entity or person, including any representation, warranty or
+ * undertaking, as to the functionality security use, value or other characteristics of
+ * any distributed ledger nor in respect the functioning or value of any tokens which may
+ * be created stored or transferred using the Work. The Licensor does not warrant that the
+ * Work or any use of the Work complies with any law or regulation in any territory where
+ * it may be implemented or used or that it will be appropriate for any specific purpose.
+ *
+ * Neither the licensor nor any current or former employees, officers, directors, partners,
+ * trustees, representatives, agents, advisors, contractors, or volunteers of the Licensor
+ * shall be liable for any direct or indirect, special, incidental, consequential or other
+ * losses of any kind, in tort, contract or otherwise (including but not limited to loss
+ * of revenue, income or profits, or loss of use or data, or loss of reputation, or loss
+ * of any economic or other opportunity of whatsoever nature or howsoever arising), arising
+ * out of or in connection with (without limitation of any use, misuse, of any ledger system
+ * or use made or its functionality or any performance or operation of any code or protocol
+ * caused by bugs or programming or logic errors or otherwise);
+ *
+ * A. any offer, purchase, holding, use, sale, exchange or transmission of any
+ * cryptographic keys, tokens or assets created, exchanged, stored or arising from any
+ * interaction with the Work;
+ *
+ * B. any failure in a transmission or loss of any token or assets keys or other digital
+ * artefacts due to errors in transmission;
+ *
+ * C. bugs, hacks, logic errors or faults in the Work or any communication;
+ *
+ * D. system software or apparatus including but not limited to losses caused by errors
+ * in holding or transmitting tokens by any third-party;
+ *
+ * E. breaches or failure of security including hacker attacks, loss or disclosure of
+ * password, loss of private key, unauthorised use or misuse of such passwords or keys;
+ *
+ * F. any losses including loss of anticipated savings or other benefits resulting from
+ * use of the Work or any changes to the Work (however implemented).
+ *
+ * You are solely responsible for; testing, validating and evaluation of all operation
+ * logic, functionality, security and appropriateness of using the Work for any commercial
+ * or non-commercial purpose and for any reproduction or redistribution by You of the
+ * Work. You assume all risks associated with Your use of the Work and the exercise of
+ * permissions under this License.
+ */
+
+package com.radixdlt.consensus;
+
+import static org.assertj.core.api.Assertions.assertThat;
+
+import com.google.common.collect.ImmutableSet;
+import com.google.common.hash.HashCode;
+import com.radixdlt.consensus.bft.BFTValidator;
+import com.radixdlt.consensus.bft.BFTValidatorId;
+import com.radixdlt.consensus.bft.Round;
+import com.radixdlt.crypto.Blake2b256Hasher;
+import com.radixdlt.crypto.ECKeyPair;
+import com.radixdlt.crypto.Hasher;
+import com.radixdlt.serialization.DefaultSerialization;
+import com.radixdlt.utils.Bytes;
+import com.radixdlt.utils.UInt192;
+import java.util.Arrays;
+import java.util.Optional;
+import org.junit.Test;
+
+/**
+ * The Rust ledger proof verifier re-implements the consensus vote hash, so this known-answer vector
+ * is pinned on both sides: it must stay in sync with the Rust {@code
+ * consensus_vote_hash_matches_java_known_answer()} test.
+ */
+public class ConsensusHasherTest {
+  private static final Hasher hasher = new Blake2b256Hasher(DefaultSerialization.getInstance());
+
+  @Test
+  public void vote_hash_and_signature_match_known_answer() throws Exception {
+    final var signer = ECKeyPair.fromPrivateKey(privateKeyOf(1));
+    final var nextValidatorKey = ECKeyPair.fromPrivateKey(privateKeyOf(2)).getPublicKey();
+    final var header =
+        LedgerHeader.create(
+            5,
+            Round.of(17),
+            1234,
+            LedgerHashes.create(filledHash(3), filledHash(1), filledHash(2)),
+            1700000000000L,
+            1700000000500L,
+            NextEpoch.create(
+                6,
+                ImmutableSet.of(
+                    BFTValidator.from(
+                        BFTValidatorId.withKeyAndFakeDeterministicAddress(nextValidatorKey),
+                        UInt192.from("100000000000000000000")))),
+            "test-protocol-version");
+
+    final var voteHash =
+        ConsensusHasher.toHash(filledHash(7), Optional.of(header), 1700000000600L, hasher);
+    assertThat(voteHash.toString())
+        .isEqualTo("e74568cab85cf044101013c67e35ad05a1b0ba12093f880f27d7c0aa7d479ae9");
+
+    final var signature = signer.sign(voteHash.asBytes(), true, true);
+    assertThat(Bytes.toHexString(signature.getConcatRecoveryRSBytes()))
+        .isEqualTo(
+            "00e1ae2458d270c5b1c44130912d5e432a6ac8fd560a834d4d3d73f98ffd2f57f2"
+                + "6e0373a9770a2c9287b5752f0437f9cdb956ff3f32d3af3ac7fe1254708345b6");
+    assertThat(signer.getPublicKey().verify(voteHash, signature)).isTrue();
+  }
+
+  private static byte[] privateKeyOf(int value) {
+    final var bytes = new byte[32];
+    bytes[31] = (byte) value;
+    return bytes;
+  }
+
+  private static HashCode filledHash(int value) {
+    final var bytes = new byte[32];
+    Arrays.fill(bytes, (byte) value);
+    return HashCode.fromBytes(bytes);
+  }
+}